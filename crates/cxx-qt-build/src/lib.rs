@@ -302,6 +302,10 @@ impl CxxQtBuilder {
         qt_modules.insert("Gui".to_owned());
         #[cfg(feature = "qt_qml")]
         qt_modules.insert("Qml".to_owned());
+        #[cfg(feature = "qt_quick")]
+        qt_modules.insert("Quick".to_owned());
+        #[cfg(feature = "qt_widgets")]
+        qt_modules.insert("Widgets".to_owned());
         Self {
             rust_sources: vec![],
             qobject_headers: vec![],
@@ -516,6 +520,12 @@ impl CxxQtBuilder {
             // Enable Qt Gui in C++ if the feature is enabled
             #[cfg(feature = "qt_qml")]
             builder.define("CXX_QT_QML_FEATURE", None);
+            // Enable Qt Quick in C++ if the feature is enabled
+            #[cfg(feature = "qt_quick")]
+            builder.define("CXX_QT_QUICK_FEATURE", None);
+            // Enable Qt Widgets in C++ if the feature is enabled
+            #[cfg(feature = "qt_widgets")]
+            builder.define("CXX_QT_WIDGETS_FEATURE", None);
             for include_dir in qtbuild.include_paths() {
                 builder.include(&include_dir);
             }
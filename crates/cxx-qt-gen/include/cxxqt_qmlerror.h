@@ -0,0 +1,111 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#pragma once
+
+#include <type_traits>
+
+#include <QtCore/QObject>
+#include <QtCore/QVariant>
+
+#ifdef CXX_QT_QML_FEATURE
+#include <QtQml/QJSEngine>
+#include <QtQml/QJSValue>
+#endif
+
+#include "rust/cxx.h"
+
+namespace rust::cxxqtlib1 {
+
+// The type of JavaScript error to throw for an error of a Result invokable,
+// this matches QJSValue::ErrorType but is available without QtQml
+enum class QmlErrorType
+{
+  GenericError,
+  EvalError,
+  RangeError,
+  ReferenceError,
+  SyntaxError,
+  TypeError,
+  URIError,
+};
+
+// This is used by the generated code of CXX-Qt when a Result invokable
+// returns an error.
+//
+// If the QObject belongs to a QJSEngine then the error is thrown into the
+// engine as a JavaScript error, so that it can be caught in QML, and a
+// default constructed value is returned. The JavaScript error has the given
+// code as its code property when one is given, this requires Qt 6.1 or later.
+//
+// If the QObject does not belong to a QJSEngine, or the QML feature is not
+// enabled, then the rust::Error is rethrown to the caller.
+template<typename T>
+T
+qmlThrowError(const QObject& object,
+              const ::rust::Error& error,
+              QmlErrorType errorType,
+              const QVariant& code = QVariant())
+{
+  static_assert(std::is_void_v<T> || std::is_default_constructible_v<T>,
+                "A #[qinvokable] returning a Result must have a default "
+                "constructible type, as a value is returned to QML after the "
+                "error is thrown into the QJSEngine");
+
+#ifdef CXX_QT_QML_FEATURE
+  if (QJSEngine* engine = qjsEngine(&object)) {
+    QJSValue::ErrorType jsErrorType = QJSValue::GenericError;
+    switch (errorType) {
+      case QmlErrorType::GenericError:
+        jsErrorType = QJSValue::GenericError;
+        break;
+      case QmlErrorType::EvalError:
+        jsErrorType = QJSValue::EvalError;
+        break;
+      case QmlErrorType::RangeError:
+        jsErrorType = QJSValue::RangeError;
+        break;
+      case QmlErrorType::ReferenceError:
+        jsErrorType = QJSValue::ReferenceError;
+        break;
+      case QmlErrorType::SyntaxError:
+        jsErrorType = QJSValue::SyntaxError;
+        break;
+      case QmlErrorType::TypeError:
+        jsErrorType = QJSValue::TypeError;
+        break;
+      case QmlErrorType::URIError:
+        jsErrorType = QJSValue::URIError;
+        break;
+    }
+
+    const QString message = QString::fromUtf8(error.what());
+#if QT_VERSION >= QT_VERSION_CHECK(6, 1, 0)
+    if (code.isValid()) {
+      QJSValue jsError = engine->newErrorObject(jsErrorType, message);
+      jsError.setProperty(QStringLiteral("code"), engine->toScriptValue(code));
+      engine->throwError(jsError);
+    } else {
+      engine->throwError(jsErrorType, message);
+    }
+#else
+    Q_UNUSED(code);
+    engine->throwError(jsErrorType, message);
+#endif
+
+    return T();
+  }
+#else
+  Q_UNUSED(object);
+  Q_UNUSED(errorType);
+  Q_UNUSED(code);
+#endif
+
+  throw error;
+}
+
+}
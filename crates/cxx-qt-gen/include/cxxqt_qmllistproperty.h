@@ -0,0 +1,22 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#pragma once
+
+#include <QtQml/QQmlListProperty>
+
+namespace rust::cxxqtlib1 {
+
+// The size type of the callbacks of a QQmlListProperty changed from int
+// in Qt 5 to qsizetype in Qt 6
+#if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+using QmlListPropertySize = qsizetype;
+#else
+using QmlListPropertySize = int;
+#endif
+
+}
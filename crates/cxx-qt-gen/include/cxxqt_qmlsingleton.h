@@ -0,0 +1,71 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#pragma once
+
+#include <mutex>
+
+#include <QtCore/QHash>
+#include <QtCore/QObject>
+#include <QtCore/QPointer>
+#include <QtQml/QQmlEngine>
+
+namespace rust::cxxqtlib1 {
+
+// The instances of a QML singleton that have been created by its create
+// function, these are owned by the QQmlEngine they were created for.
+//
+// Engines can live on different threads, so the instances are guarded
+// by a mutex.
+template<typename T>
+struct QmlSingletonInstances
+{
+  ::std::mutex mutex;
+  QHash<const QQmlEngine*, QPointer<T>> instances;
+};
+
+template<typename T>
+QmlSingletonInstances<T>&
+qmlSingletonInstances()
+{
+  static QmlSingletonInstances<T> instances;
+  return instances;
+}
+
+// This is used by the generated create function of a QML singleton to
+// remember the instance for the given QQmlEngine, the entry is removed
+// when the engine is destroyed
+template<typename T>
+void
+qmlSingletonInsert(QQmlEngine& engine, T* instance)
+{
+  auto& singletons = qmlSingletonInstances<T>();
+  {
+    const ::std::lock_guard<::std::mutex> guard(singletons.mutex);
+    singletons.instances.insert(&engine, instance);
+  }
+
+  const QQmlEngine* enginePtr = &engine;
+  QObject::connect(&engine, &QObject::destroyed, [enginePtr]() {
+    auto& singletons = qmlSingletonInstances<T>();
+    const ::std::lock_guard<::std::mutex> guard(singletons.mutex);
+    singletons.instances.remove(enginePtr);
+  });
+}
+
+// This is used by the generated code of CXX-Qt to retrieve the instance
+// of a QML singleton for the given QQmlEngine
+template<typename T>
+T*
+qmlSingletonInstance(const QQmlEngine& engine)
+{
+  auto& singletons = qmlSingletonInstances<T>();
+  const ::std::lock_guard<::std::mutex> guard(singletons.mutex);
+  return singletons.instances.value(&engine).data();
+}
+
+}
@@ -11,7 +11,8 @@ use crate::{
         },
         naming::{method::QMethodName, qobject::QObjectName},
         utils::cpp::{
-            syn_return_type_to_cpp_except, syn_type_to_cpp_return_type, syn_type_to_cpp_type,
+            escape_cpp_string, generate_with_q_revision, syn_return_type_to_cpp_except,
+            syn_type_to_cpp_return_type, syn_type_to_cpp_type,
         },
     },
     parser::{
@@ -20,11 +21,12 @@ use crate::{
     },
 };
 use indoc::formatdoc;
-use syn::{spanned::Spanned, Error, FnArg, Pat, PatIdent, PatType, Result};
+use syn::{spanned::Spanned, Error, FnArg, Lit, Pat, PatIdent, PatType, Result};
 
 pub fn generate_cpp_methods(
     invokables: &Vec<ParsedMethod>,
     qobject_idents: &QObjectName,
+    namespace_internals: &str,
     cxx_mappings: &ParsedCxxMappings,
 ) -> Result<GeneratedCppQObjectBlocks> {
     let mut generated = GeneratedCppQObjectBlocks::default();
@@ -79,60 +81,128 @@ pub fn generate_cpp_methods(
             .collect::<Vec<String>>()
             .join(", ");
         let is_const = if !invokable.mutable { " const" } else { "" };
+        let is_qinvokable = if invokable.is_qinvokable {
+            "Q_INVOKABLE "
+        } else {
+            ""
+        };
 
-        generated.methods.push(CppFragment::Pair {
-            header: format!(
-                "{is_qinvokable}{is_virtual}{return_cxx_ty} {ident}({parameter_types}){is_const}{is_final}{is_override};",
-                return_cxx_ty = if let Some(return_cxx_ty) = &return_cxx_ty {
-                    return_cxx_ty
-                } else {
-                    "void"
-                },
-                ident = idents.name.cpp,
-                parameter_types = parameter_types,
-                is_qinvokable = if invokable.is_qinvokable {
-                    "Q_INVOKABLE "
-                } else {
-                    ""
-                },
-                is_final = if invokable.specifiers.contains(&ParsedQInvokableSpecifiers::Final) {
-                    " final"
-                } else {
-                    ""
-                },
-                is_override = if invokable.specifiers.contains(&ParsedQInvokableSpecifiers::Override) {
-                    " override"
-                } else {
-                    ""
-                },
-                is_virtual = if invokable.specifiers.contains(&ParsedQInvokableSpecifiers::Virtual) {
-                    "virtual "
+        // A Q_INVOKABLE that returns a Result reports errors to QML rather than throwing
+        let qjs_error =
+            if invokable.is_qinvokable && !invokable.is_static && invokable.returns_result() {
+                Some(if let Some(qjs_error) = &invokable.qjs_error {
+                    let code = match &qjs_error.code {
+                        Some(Lit::Int(code)) => {
+                            format!(", QVariant({code})", code = code.base10_digits())
+                        }
+                        Some(Lit::Str(code)) => format!(
+                            ", QVariant(QStringLiteral(\"{code}\"))",
+                            code = escape_cpp_string(&code.value())
+                        ),
+                        _ => "".to_owned(),
+                    };
+                    format!("{error_type}{code}", error_type = qjs_error.error_type)
                 } else {
-                    ""
+                    "GenericError".to_owned()
+                })
+            } else {
+                None
+            };
+
+        // A static method has no instance to lock, it calls the wrapper
+        // which CXX declares as a free function in the internal namespace
+        if invokable.is_static {
+            let return_cxx_ty = return_cxx_ty.as_deref().unwrap_or("void");
+            let ident = &idents.name.cpp;
+            generated.methods.push(CppFragment::Pair {
+                header: generate_with_q_revision(&invokable.revision, |revision| {
+                    format!(
+                        "{revision}{is_qinvokable}static {return_cxx_ty} {ident}({parameter_types});"
+                    )
+                }),
+                source: formatdoc! {
+                    r#"
+                    {return_cxx_ty}
+                    {qobject_ident}::{ident}({parameter_types})
+                    {{
+                        {maybe_return}::{namespace_internals}::{body};
+                    }}
+                    "#,
+                    maybe_return = if return_cxx_ty == "void" { "" } else { "return " },
                 },
-            ),
+            });
+            continue;
+        }
+
+        let body = if return_cxx_ty.is_some() {
+            format!("return {body};")
+        } else {
+            format!("{body};")
+        };
+        // An error from Rust is thrown into the QJSEngine so that QML can catch it,
+        // when there is no QJSEngine the error is rethrown to the C++ caller
+        let body = if let Some(error_arguments) = &qjs_error {
+            formatdoc! {
+                r#"
+                try {{
+                        {body}
+                    }} catch (const ::rust::Error& error) {{
+                        {maybe_return}::rust::cxxqtlib1::qmlThrowError<{return_cxx_ty}>(
+                          *this, error, ::rust::cxxqtlib1::QmlErrorType::{error_arguments});
+                    }}"#,
+                maybe_return = if return_cxx_ty.is_some() { "return " } else { "" },
+                return_cxx_ty = return_cxx_ty.as_deref().unwrap_or("void"),
+            }
+        } else {
+            body
+        };
+
+        generated.methods.push(CppFragment::Pair {
+            header: generate_with_q_revision(&invokable.revision, |revision| {
+                format!(
+                    "{revision}{is_qinvokable}{is_virtual}{return_cxx_ty} {ident}({parameter_types}){is_const}{is_final}{is_override};",
+                    return_cxx_ty = if let Some(return_cxx_ty) = &return_cxx_ty {
+                        return_cxx_ty
+                    } else {
+                        "void"
+                    },
+                    ident = idents.name.cpp,
+                    parameter_types = parameter_types,
+                    is_final = if invokable.specifiers.contains(&ParsedQInvokableSpecifiers::Final) {
+                        " final"
+                    } else {
+                        ""
+                    },
+                    is_override = if invokable.specifiers.contains(&ParsedQInvokableSpecifiers::Override) {
+                        " override"
+                    } else {
+                        ""
+                    },
+                    is_virtual = if invokable.specifiers.contains(&ParsedQInvokableSpecifiers::Virtual) {
+                        "virtual "
+                    } else {
+                        ""
+                    },
+                )
+            }),
             source: formatdoc! {
                 r#"
                     {return_cxx_ty}
                     {qobject_ident}::{ident}({parameter_types}){is_const}
                     {{
                         const ::rust::cxxqtlib1::MaybeLockGuard<{qobject_ident}> guard(*this);
-                        {body};
+                        {body}
                     }}
                     "#,
-                return_cxx_ty = if let Some(return_cxx_ty) = &return_cxx_ty {
-                    return_cxx_ty
-                } else {
-                    "void"
-                },
+                return_cxx_ty = return_cxx_ty.as_deref().unwrap_or("void"),
                 ident = idents.name.cpp,
-                body = if return_cxx_ty.is_some() {
-                    format!("return {body}", body = body)
-                } else {
-                    body
-                },
             },
         });
+        if qjs_error.is_some() {
+            generated
+                .includes
+                .insert("#include <cxx-qt-common/cxxqt_qmlerror.h>".to_owned());
+        }
 
         // Note that we are generating a header to match the extern "Rust" method
         // in Rust for our invokable.
@@ -157,8 +227,10 @@ pub fn generate_cpp_methods(
 mod tests {
     use super::*;
 
-    use crate::generator::naming::qobject::tests::create_qobjectname;
-    use crate::parser::parameter::ParsedFunctionParameter;
+    use crate::generator::naming::{qobject::tests::create_qobjectname, CombinedIdent};
+    use crate::parser::{
+        method::ParsedQJsError, parameter::ParsedFunctionParameter, qobject::QmlVersion,
+    };
     use indoc::indoc;
     use pretty_assertions::assert_str_eq;
     use quote::format_ident;
@@ -176,6 +248,10 @@ mod tests {
                 parameters: vec![],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                revision: None,
+                ident: CombinedIdent::from_rust_function(format_ident!("void_invokable")),
+                is_static: false,
+                qjs_error: None,
             },
             ParsedMethod {
                 method: parse_quote! { fn trivial_invokable(self: &MyObject, param: i32) -> i32; },
@@ -188,6 +264,10 @@ mod tests {
                 }],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                revision: None,
+                ident: CombinedIdent::from_rust_function(format_ident!("trivial_invokable")),
+                is_static: false,
+                qjs_error: None,
             },
             ParsedMethod {
                 method: parse_quote! { fn opaque_invokable(self: Pin<&mut MyObject>, param: &QColor) -> UniquePtr<QColor>; },
@@ -200,6 +280,10 @@ mod tests {
                 }],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                revision: None,
+                ident: CombinedIdent::from_rust_function(format_ident!("opaque_invokable")),
+                is_static: false,
+                qjs_error: None,
             },
             ParsedMethod {
                 method: parse_quote! { fn specifiers_invokable(self: &MyObject, param: i32) -> i32; },
@@ -218,6 +302,10 @@ mod tests {
                     specifiers
                 },
                 is_qinvokable: true,
+                revision: None,
+                ident: CombinedIdent::from_rust_function(format_ident!("specifiers_invokable")),
+                is_static: false,
+                qjs_error: None,
             },
             ParsedMethod {
                 method: parse_quote! { fn cpp_method(self: &MyObject); },
@@ -227,13 +315,21 @@ mod tests {
                 parameters: vec![],
                 specifiers: HashSet::new(),
                 is_qinvokable: false,
+                revision: None,
+                ident: CombinedIdent::from_rust_function(format_ident!("cpp_method")),
+                is_static: false,
+                qjs_error: None,
             },
         ];
         let qobject_idents = create_qobjectname();
 
-        let generated =
-            generate_cpp_methods(&invokables, &qobject_idents, &ParsedCxxMappings::default())
-                .unwrap();
+        let generated = generate_cpp_methods(
+            &invokables,
+            &qobject_idents,
+            "rust",
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
 
         // methods
         assert_eq!(generated.methods.len(), 5);
@@ -398,6 +494,10 @@ mod tests {
             }],
             specifiers: HashSet::new(),
             is_qinvokable: true,
+            revision: None,
+            ident: CombinedIdent::from_rust_function(format_ident!("trivial_invokable")),
+            is_static: false,
+            qjs_error: None,
         }];
         let qobject_idents = create_qobjectname();
 
@@ -409,7 +509,8 @@ mod tests {
             .cxx_names
             .insert("B".to_owned(), "B2".to_owned());
 
-        let generated = generate_cpp_methods(&invokables, &qobject_idents, &cxx_mappings).unwrap();
+        let generated =
+            generate_cpp_methods(&invokables, &qobject_idents, "rust", &cxx_mappings).unwrap();
 
         // methods
         assert_eq!(generated.methods.len(), 1);
@@ -445,4 +546,332 @@ mod tests {
             "B2 trivialInvokableWrapper(A1 param) const noexcept;"
         );
     }
+
+    #[test]
+    fn test_generate_cpp_invokables_revision() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn revision_invokable(self: &MyObject); },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            revision: Some(QmlVersion { major: 1, minor: 2 }),
+            ident: CombinedIdent::from_rust_function(format_ident!("revision_invokable")),
+            is_static: false,
+            qjs_error: None,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_cpp_methods(
+            &invokables,
+            &qobject_idents,
+            "rust",
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        let header = if let CppFragment::Pair { header, .. } = &generated.methods[0] {
+            header
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            header,
+            indoc! {"
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+            Q_REVISION(1, 2) Q_INVOKABLE void revisionInvokable() const;
+            #else
+            Q_REVISION(2) Q_INVOKABLE void revisionInvokable() const;
+            #endif"}
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_result() {
+        let invokables = vec![
+            ParsedMethod {
+                method: parse_quote! { fn result_invokable(self: &MyObject) -> Result<i32>; },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: false,
+                safe: true,
+                parameters: vec![],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                revision: None,
+                ident: CombinedIdent::from_rust_function(format_ident!("result_invokable")),
+                is_static: false,
+                qjs_error: None,
+            },
+            ParsedMethod {
+                method: parse_quote! { fn result_void_invokable(self: Pin<&mut MyObject>) -> Result<()>; },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: true,
+                safe: true,
+                parameters: vec![],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                revision: None,
+                ident: CombinedIdent::from_rust_function(format_ident!("result_void_invokable")),
+                is_static: false,
+                qjs_error: Some(ParsedQJsError {
+                    error_type: format_ident!("RangeError"),
+                    code: None,
+                }),
+            },
+            ParsedMethod {
+                method: parse_quote! { fn result_code_invokable(self: &MyObject) -> Result<i32>; },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: false,
+                safe: true,
+                parameters: vec![],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                revision: None,
+                ident: CombinedIdent::from_rust_function(format_ident!("result_code_invokable")),
+                is_static: false,
+                qjs_error: Some(ParsedQJsError {
+                    error_type: format_ident!("TypeError"),
+                    code: Some(parse_quote! { "E_\"CODE\"" }),
+                }),
+            },
+            ParsedMethod {
+                method: parse_quote! { fn result_cpp_method(self: &MyObject) -> Result<i32>; },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: false,
+                safe: true,
+                parameters: vec![],
+                specifiers: HashSet::new(),
+                is_qinvokable: false,
+                revision: None,
+                ident: CombinedIdent::from_rust_function(format_ident!("result_cpp_method")),
+                is_static: false,
+                qjs_error: None,
+            },
+        ];
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_cpp_methods(
+            &invokables,
+            &qobject_idents,
+            "rust",
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+
+        assert!(generated
+            .includes
+            .contains("#include <cxx-qt-common/cxxqt_qmlerror.h>"));
+        assert_eq!(generated.methods.len(), 4);
+
+        // result_invokable
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE ::std::int32_t resultInvokable() const;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            ::std::int32_t
+            MyObject::resultInvokable() const
+            {
+                const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+                try {
+                    return resultInvokableWrapper();
+                } catch (const ::rust::Error& error) {
+                    return ::rust::cxxqtlib1::qmlThrowError<::std::int32_t>(
+                      *this, error, ::rust::cxxqtlib1::QmlErrorType::GenericError);
+                }
+            }
+            "#}
+        );
+
+        // result_void_invokable
+        let source = if let CppFragment::Pair { source, .. } = &generated.methods[1] {
+            source
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::resultVoidInvokable()
+            {
+                const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+                try {
+                    resultVoidInvokableWrapper();
+                } catch (const ::rust::Error& error) {
+                    ::rust::cxxqtlib1::qmlThrowError<void>(
+                      *this, error, ::rust::cxxqtlib1::QmlErrorType::RangeError);
+                }
+            }
+            "#}
+        );
+
+        // result_code_invokable
+        let source = if let CppFragment::Pair { source, .. } = &generated.methods[2] {
+            source
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            ::std::int32_t
+            MyObject::resultCodeInvokable() const
+            {
+                const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+                try {
+                    return resultCodeInvokableWrapper();
+                } catch (const ::rust::Error& error) {
+                    return ::rust::cxxqtlib1::qmlThrowError<::std::int32_t>(
+                      *this, error, ::rust::cxxqtlib1::QmlErrorType::TypeError, QVariant(QStringLiteral("E_\"CODE\"")));
+                }
+            }
+            "#}
+        );
+
+        // result_cpp_method keeps the exception for C++ callers
+        let source = if let CppFragment::Pair { source, .. } = &generated.methods[3] {
+            source
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            ::std::int32_t
+            MyObject::resultCppMethod() const
+            {
+                const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+                return resultCppMethodWrapper();
+            }
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_static_and_overloaded() {
+        let invokables = vec![
+            ParsedMethod {
+                method: parse_quote! { fn create_default() -> i32; },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: false,
+                safe: true,
+                parameters: vec![],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                revision: None,
+                ident: CombinedIdent::from_rust_function(format_ident!("create_default")),
+                is_static: true,
+                qjs_error: None,
+            },
+            ParsedMethod {
+                method: parse_quote! { fn add_int(self: Pin<&mut MyObject>, value: i32); },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: true,
+                safe: true,
+                parameters: vec![ParsedFunctionParameter {
+                    ident: format_ident!("value"),
+                    ty: parse_quote! { i32 },
+                }],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                revision: None,
+                ident: CombinedIdent {
+                    cpp: format_ident!("add"),
+                    rust: format_ident!("add_int"),
+                },
+                is_static: false,
+                qjs_error: None,
+            },
+            ParsedMethod {
+                method: parse_quote! { fn add_double(self: Pin<&mut MyObject>, value: f64); },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: true,
+                safe: true,
+                parameters: vec![ParsedFunctionParameter {
+                    ident: format_ident!("value"),
+                    ty: parse_quote! { f64 },
+                }],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                revision: None,
+                ident: CombinedIdent {
+                    cpp: format_ident!("add"),
+                    rust: format_ident!("add_double"),
+                },
+                is_static: false,
+                qjs_error: None,
+            },
+        ];
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_cpp_methods(
+            &invokables,
+            &qobject_idents,
+            "rust::cxx_qt_my_object",
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+
+        // methods
+        assert_eq!(generated.methods.len(), 3);
+
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "Q_INVOKABLE static ::std::int32_t createDefault();");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            ::std::int32_t
+            MyObject::createDefault()
+            {
+                return ::rust::cxx_qt_my_object::createDefaultWrapper();
+            }
+            "#}
+        );
+
+        let header = if let CppFragment::Pair { header, .. } = &generated.methods[1] {
+            header
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "Q_INVOKABLE void add(::std::int32_t value);");
+
+        let header = if let CppFragment::Pair { header, .. } = &generated.methods[2] {
+            header
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "Q_INVOKABLE void add(double value);");
+
+        // private methods, the static method wrapper is declared by CXX
+        assert_eq!(generated.private_methods.len(), 2);
+
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(header, "void addIntWrapper(::std::int32_t value) noexcept;");
+
+        let header = if let CppFragment::Header(header) = &generated.private_methods[1] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(header, "void addDoubleWrapper(double value) noexcept;");
+    }
 }
@@ -12,12 +12,16 @@ pub mod locking;
 pub mod method;
 pub mod property;
 pub mod qenum;
+pub mod qmlattached;
+pub mod qmlforeign;
+pub mod qmlsingleton;
 pub mod qobject;
 pub mod signal;
 pub mod threading;
 
 use crate::parser::Parser;
 use externcxxqt::GeneratedCppExternCxxQtBlocks;
+use qmlforeign::GeneratedCppQmlForeignBlocks;
 use qobject::GeneratedCppQObject;
 use syn::Result;
 
@@ -31,6 +35,8 @@ pub struct GeneratedCppBlocks {
     pub qobjects: Vec<GeneratedCppQObject>,
     /// Generated extern C++Qt blocks
     pub extern_cxx_qt: Vec<GeneratedCppExternCxxQtBlocks>,
+    /// Generated QML_FOREIGN registrations of extern C++Qt types
+    pub qml_foreign: GeneratedCppQmlForeignBlocks,
 }
 
 impl GeneratedCppBlocks {
@@ -48,6 +54,11 @@ impl GeneratedCppBlocks {
                 &parser.cxx_qt_data.extern_cxxqt_blocks,
                 &parser.cxx_qt_data.cxx_mappings,
             )?,
+            qml_foreign: qmlforeign::generate(
+                &parser.cxx_qt_data.extern_cxxqt_blocks,
+                &parser.cxx_qt_data.cxx_mappings,
+                &parser.cxx_file_stem,
+            ),
         })
     }
 }
@@ -0,0 +1,234 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+        naming::property::QPropertyName,
+    },
+    parser::qobject::QmlVersion,
+};
+use indoc::{formatdoc, indoc};
+
+/// Bindable properties are only available in Qt 6, so give a clear error with Qt 5
+const BINDABLE_INCLUDE: &str = indoc! {r#"
+    #include <QtCore/QtGlobal>
+    #if QT_VERSION < QT_VERSION_CHECK(6, 0, 0)
+    #error "A bindable #[qproperty] requires Qt 6"
+    #endif
+    #include <QtCore/QProperty>"#};
+
+pub fn generate(
+    idents: &QPropertyName,
+    qobject_ident: &str,
+    cxx_ty: &str,
+    revision: &Option<QmlVersion>,
+) -> GeneratedCppQObjectBlocks {
+    let ident_getter = &idents.getter.cpp;
+    let ident_setter = &idents.setter.cpp;
+    let ident_bindable = &idents.bindable.cpp;
+    let ident_set_binding = &idents.set_binding.cpp;
+    let ident_notify = &idents.notify.cpp;
+    // The value is stored on the C++ side so that Qt can track the bindings
+    let member = format!("m_{}", idents.name.cpp);
+
+    GeneratedCppQObjectBlocks {
+        includes: [BINDABLE_INCLUDE.to_owned()].into(),
+        metaobjects: vec![super::meta::generate_with_revision(revision, |revision| {
+            format!(
+                "Q_PROPERTY({cxx_ty} {ident} READ {ident_getter} WRITE {ident_setter} NOTIFY {ident_notify} BINDABLE {ident_bindable}{revision})",
+                ident = idents.name.cpp,
+            )
+        })],
+        // Note that the getter and setter pass by value so that they match
+        // the methods that are declared in the CXX bridge
+        methods: vec![
+            CppFragment::Pair {
+                header: format!("{cxx_ty} {ident_getter}() const;"),
+                source: formatdoc! {
+                    r#"
+                    {cxx_ty}
+                    {qobject_ident}::{ident_getter}() const
+                    {{
+                        const ::rust::cxxqtlib1::MaybeLockGuard<{qobject_ident}> guard(*this);
+                        return {member}.value();
+                    }}
+                    "#
+                },
+            },
+            CppFragment::Pair {
+                header: format!("Q_SLOT void {ident_setter}({cxx_ty} value);"),
+                source: formatdoc! {
+                    r#"
+                    void
+                    {qobject_ident}::{ident_setter}({cxx_ty} value)
+                    {{
+                        const ::rust::cxxqtlib1::MaybeLockGuard<{qobject_ident}> guard(*this);
+                        {member}.setValue(::std::move(value));
+                    }}
+                    "#
+                },
+            },
+            CppFragment::Pair {
+                header: format!("QBindable<{cxx_ty}> {ident_bindable}();"),
+                source: formatdoc! {
+                    r#"
+                    QBindable<{cxx_ty}>
+                    {qobject_ident}::{ident_bindable}()
+                    {{
+                        return QBindable<{cxx_ty}>(&{member});
+                    }}
+                    "#
+                },
+            },
+            CppFragment::Pair {
+                header: format!(
+                    "void {ident_set_binding}(::rust::Fn<{cxx_ty}({qobject_ident} const&)> func);"
+                ),
+                source: formatdoc! {
+                    r#"
+                    void
+                    {qobject_ident}::{ident_set_binding}(::rust::Fn<{cxx_ty}({qobject_ident} const&)> func)
+                    {{
+                        const ::rust::cxxqtlib1::MaybeLockGuard<{qobject_ident}> guard(*this);
+                        {member}.setBinding([this, func = ::std::move(func)]() {{
+                            const ::rust::cxxqtlib1::MaybeLockGuard<{qobject_ident}> guard(*this);
+                            return func(*this);
+                        }});
+                    }}
+                    "#
+                },
+            },
+        ],
+        private_methods: vec![CppFragment::Header(format!(
+            "Q_OBJECT_BINDABLE_PROPERTY({qobject_ident}, {cxx_ty}, {member}, &{qobject_ident}::{ident_notify})"
+        ))],
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_str_eq;
+    use quote::format_ident;
+
+    #[test]
+    fn test_generate_cpp_bindable_property() {
+        let generated = generate(
+            &QPropertyName::from(&format_ident!("total")),
+            "MyObject",
+            "::std::int32_t",
+            &None,
+        );
+
+        assert_eq!(generated.includes.len(), 1);
+        assert_str_eq!(
+            generated.includes.iter().next().unwrap(),
+            indoc! {r#"
+            #include <QtCore/QtGlobal>
+            #if QT_VERSION < QT_VERSION_CHECK(6, 0, 0)
+            #error "A bindable #[qproperty] requires Qt 6"
+            #endif
+            #include <QtCore/QProperty>"#}
+        );
+
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(::std::int32_t total READ getTotal WRITE setTotal NOTIFY totalChanged BINDABLE bindableTotal)"
+        );
+
+        assert_eq!(generated.methods.len(), 4);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "::std::int32_t getTotal() const;");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            ::std::int32_t
+            MyObject::getTotal() const
+            {
+                const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+                return m_total.value();
+            }
+            "#}
+        );
+
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[1] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "Q_SLOT void setTotal(::std::int32_t value);");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::setTotal(::std::int32_t value)
+            {
+                const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+                m_total.setValue(::std::move(value));
+            }
+            "#}
+        );
+
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[2] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "QBindable<::std::int32_t> bindableTotal();");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QBindable<::std::int32_t>
+            MyObject::bindableTotal()
+            {
+                return QBindable<::std::int32_t>(&m_total);
+            }
+            "#}
+        );
+
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[3] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            header,
+            "void setTotalBinding(::rust::Fn<::std::int32_t(MyObject const&)> func);"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::setTotalBinding(::rust::Fn<::std::int32_t(MyObject const&)> func)
+            {
+                const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+                m_total.setBinding([this, func = ::std::move(func)]() {
+                    const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+                    return func(*this);
+                });
+            }
+            "#}
+        );
+
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "Q_OBJECT_BINDABLE_PROPERTY(MyObject, ::std::int32_t, m_total, &MyObject::totalChanged)"
+        );
+    }
+}
@@ -0,0 +1,308 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+        naming::property::{QListPropertyName, QPropertyName},
+    },
+    parser::qobject::QmlVersion,
+};
+use indoc::formatdoc;
+
+pub fn generate(
+    idents: &QPropertyName,
+    list_idents: &QListPropertyName,
+    qobject_ident: &str,
+    element_cxx_ty: &str,
+    revision: &Option<QmlVersion>,
+) -> GeneratedCppQObjectBlocks {
+    let list_ty = format!("QQmlListProperty<{element_cxx_ty}>");
+    let size_ty = "::rust::cxxqtlib1::QmlListPropertySize";
+    let ident_getter = &idents.getter.cpp;
+    let append = &list_idents.append.cpp;
+    let count = &list_idents.count.cpp;
+    let at = &list_idents.at.cpp;
+    let clear = &list_idents.clear.cpp;
+    let replace = &list_idents.replace.cpp;
+    let remove_last = &list_idents.remove_last.cpp;
+
+    // Each of the callbacks of the QQmlListProperty lock the QObject and then call the Rust wrapper
+    let callback =
+        |return_ty: &str, ident: &syn::Ident, parameters: &str, body: String| CppFragment::Pair {
+            header: format!("static {return_ty} {ident}({list_ty}* list{parameters});"),
+            source: formatdoc! {
+                r#"
+                {return_ty}
+                {qobject_ident}::{ident}({list_ty}* list{parameters})
+                {{
+                    auto* self = static_cast<{qobject_ident}*>(list->object);
+                    const ::rust::cxxqtlib1::MaybeLockGuard<{qobject_ident}> guard(*self);
+                    {body}
+                }}
+                "#
+            },
+        };
+
+    GeneratedCppQObjectBlocks {
+        includes: ["#include <cxx-qt-common/cxxqt_qmllistproperty.h>".to_owned()].into(),
+        metaobjects: vec![super::meta::generate_with_revision(revision, |revision| {
+            format!(
+                "Q_PROPERTY({list_ty} {ident} READ {ident_getter} NOTIFY {ident_notify}{revision})",
+                ident = idents.name.cpp,
+                ident_notify = idents.notify.cpp,
+            )
+        })],
+        methods: vec![CppFragment::Pair {
+            header: format!("{list_ty} {ident_getter}();"),
+            source: formatdoc! {
+                r#"
+                {list_ty}
+                {qobject_ident}::{ident_getter}()
+                {{
+                    return {list_ty}(this, nullptr, &{qobject_ident}::{append}, &{qobject_ident}::{count}, &{qobject_ident}::{at}, &{qobject_ident}::{clear}, &{qobject_ident}::{replace}, &{qobject_ident}::{remove_last});
+                }}
+                "#
+            },
+        }],
+        private_methods: vec![
+            callback(
+                "void",
+                append,
+                &format!(", {element_cxx_ty}* item"),
+                format!(
+                    "self->{wrapper}(item);",
+                    wrapper = list_idents.append_wrapper.cpp
+                ),
+            ),
+            callback(
+                size_ty,
+                count,
+                "",
+                format!(
+                    "return static_cast<{size_ty}>(self->{wrapper}());",
+                    wrapper = list_idents.count_wrapper.cpp
+                ),
+            ),
+            callback(
+                &format!("{element_cxx_ty}*"),
+                at,
+                &format!(", {size_ty} index"),
+                format!(
+                    "return self->{wrapper}(static_cast<::rust::isize>(index));",
+                    wrapper = list_idents.at_wrapper.cpp
+                ),
+            ),
+            callback(
+                "void",
+                clear,
+                "",
+                format!(
+                    "self->{wrapper}();",
+                    wrapper = list_idents.clear_wrapper.cpp
+                ),
+            ),
+            callback(
+                "void",
+                replace,
+                &format!(", {size_ty} index, {element_cxx_ty}* item"),
+                format!(
+                    "self->{wrapper}(static_cast<::rust::isize>(index), item);",
+                    wrapper = list_idents.replace_wrapper.cpp
+                ),
+            ),
+            callback(
+                "void",
+                remove_last,
+                "",
+                format!(
+                    "self->{wrapper}();",
+                    wrapper = list_idents.remove_last_wrapper.cpp
+                ),
+            ),
+            CppFragment::Header(format!(
+                "void {wrapper}({element_cxx_ty}* item) noexcept;",
+                wrapper = list_idents.append_wrapper.cpp
+            )),
+            CppFragment::Header(format!(
+                "::rust::isize {wrapper}() const noexcept;",
+                wrapper = list_idents.count_wrapper.cpp
+            )),
+            CppFragment::Header(format!(
+                "{element_cxx_ty}* {wrapper}(::rust::isize index) const noexcept;",
+                wrapper = list_idents.at_wrapper.cpp
+            )),
+            CppFragment::Header(format!(
+                "void {wrapper}() noexcept;",
+                wrapper = list_idents.clear_wrapper.cpp
+            )),
+            CppFragment::Header(format!(
+                "void {wrapper}(::rust::isize index, {element_cxx_ty}* item) noexcept;",
+                wrapper = list_idents.replace_wrapper.cpp
+            )),
+            CppFragment::Header(format!(
+                "void {wrapper}() noexcept;",
+                wrapper = list_idents.remove_last_wrapper.cpp
+            )),
+        ],
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+    use quote::format_ident;
+
+    #[test]
+    fn test_generate_cpp_list_property() {
+        let ident = format_ident!("items");
+        let generated = generate(
+            &QPropertyName::from(&ident),
+            &QListPropertyName::from(&ident),
+            "MyObject",
+            "Child",
+            &None,
+        );
+
+        assert!(generated
+            .includes
+            .contains("#include <cxx-qt-common/cxxqt_qmllistproperty.h>"));
+
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(QQmlListProperty<Child> items READ getItems NOTIFY itemsChanged)"
+        );
+
+        assert_eq!(generated.methods.len(), 1);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(header, "QQmlListProperty<Child> getItems();");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QQmlListProperty<Child>
+            MyObject::getItems()
+            {
+                return QQmlListProperty<Child>(this, nullptr, &MyObject::itemsAppend, &MyObject::itemsCount, &MyObject::itemsAt, &MyObject::itemsClear, &MyObject::itemsReplace, &MyObject::itemsRemoveLast);
+            }
+            "#}
+        );
+
+        assert_eq!(generated.private_methods.len(), 12);
+        let (header, source) =
+            if let CppFragment::Pair { header, source } = &generated.private_methods[0] {
+                (header, source)
+            } else {
+                panic!("Expected pair!")
+            };
+        assert_str_eq!(
+            header,
+            "static void itemsAppend(QQmlListProperty<Child>* list, Child* item);"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::itemsAppend(QQmlListProperty<Child>* list, Child* item)
+            {
+                auto* self = static_cast<MyObject*>(list->object);
+                const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*self);
+                self->itemsAppendWrapper(item);
+            }
+            "#}
+        );
+
+        let (header, source) =
+            if let CppFragment::Pair { header, source } = &generated.private_methods[1] {
+                (header, source)
+            } else {
+                panic!("Expected pair!")
+            };
+        assert_str_eq!(
+            header,
+            "static ::rust::cxxqtlib1::QmlListPropertySize itemsCount(QQmlListProperty<Child>* list);"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            ::rust::cxxqtlib1::QmlListPropertySize
+            MyObject::itemsCount(QQmlListProperty<Child>* list)
+            {
+                auto* self = static_cast<MyObject*>(list->object);
+                const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*self);
+                return static_cast<::rust::cxxqtlib1::QmlListPropertySize>(self->itemsCountWrapper());
+            }
+            "#}
+        );
+
+        let (header, source) =
+            if let CppFragment::Pair { header, source } = &generated.private_methods[2] {
+                (header, source)
+            } else {
+                panic!("Expected pair!")
+            };
+        assert_str_eq!(
+            header,
+            "static Child* itemsAt(QQmlListProperty<Child>* list, ::rust::cxxqtlib1::QmlListPropertySize index);"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            Child*
+            MyObject::itemsAt(QQmlListProperty<Child>* list, ::rust::cxxqtlib1::QmlListPropertySize index)
+            {
+                auto* self = static_cast<MyObject*>(list->object);
+                const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*self);
+                return self->itemsAtWrapper(static_cast<::rust::isize>(index));
+            }
+            "#}
+        );
+
+        let header = |index: usize| {
+            if let CppFragment::Pair { header, .. } = &generated.private_methods[index] {
+                header.clone()
+            } else if let CppFragment::Header(header) = &generated.private_methods[index] {
+                header.clone()
+            } else {
+                panic!("Expected pair or header!")
+            }
+        };
+        assert_str_eq!(
+            header(3),
+            "static void itemsClear(QQmlListProperty<Child>* list);"
+        );
+        assert_str_eq!(
+            header(4),
+            "static void itemsReplace(QQmlListProperty<Child>* list, ::rust::cxxqtlib1::QmlListPropertySize index, Child* item);"
+        );
+        assert_str_eq!(
+            header(5),
+            "static void itemsRemoveLast(QQmlListProperty<Child>* list);"
+        );
+        assert_str_eq!(header(6), "void itemsAppendWrapper(Child* item) noexcept;");
+        assert_str_eq!(
+            header(7),
+            "::rust::isize itemsCountWrapper() const noexcept;"
+        );
+        assert_str_eq!(
+            header(8),
+            "Child* itemsAtWrapper(::rust::isize index) const noexcept;"
+        );
+        assert_str_eq!(header(9), "void itemsClearWrapper() noexcept;");
+        assert_str_eq!(
+            header(10),
+            "void itemsReplaceWrapper(::rust::isize index, Child* item) noexcept;"
+        );
+        assert_str_eq!(header(11), "void itemsRemoveLastWrapper() noexcept;");
+    }
+}
@@ -3,16 +3,42 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::generator::naming::property::QPropertyName;
+use crate::{
+    generator::{naming::property::QPropertyName, utils::cpp::qt_version_switch},
+    parser::qobject::QmlVersion,
+};
 
 /// Generate the metaobject line for a given property
-pub fn generate(idents: &QPropertyName, cxx_ty: &str) -> String {
-    format!(
-        "Q_PROPERTY({ty} {ident} READ {ident_getter} WRITE {ident_setter} NOTIFY {ident_notify})",
-        ty = cxx_ty,
-        ident = idents.name.cpp,
-        ident_getter = idents.getter.cpp,
-        ident_setter = idents.setter.cpp,
-        ident_notify = idents.notify.cpp,
-    )
+pub fn generate(idents: &QPropertyName, cxx_ty: &str, revision: &Option<QmlVersion>) -> String {
+    generate_with_revision(revision, |revision| {
+        format!(
+            "Q_PROPERTY({ty} {ident} READ {ident_getter} WRITE {ident_setter} NOTIFY {ident_notify}{revision})",
+            ty = cxx_ty,
+            ident = idents.name.cpp,
+            ident_getter = idents.getter.cpp,
+            ident_setter = idents.setter.cpp,
+            ident_notify = idents.notify.cpp,
+        )
+    })
+}
+
+/// Generate a Q_PROPERTY with the REVISION attribute if there is a revision
+///
+/// Qt 5 only supports a single integer revision, which is the minor version,
+/// so the Q_PROPERTY is generated for both Qt 6 and Qt 5.
+pub fn generate_with_revision(
+    revision: &Option<QmlVersion>,
+    q_property: impl Fn(&str) -> String,
+) -> String {
+    if let Some(revision) = revision {
+        qt_version_switch(
+            &q_property(&format!(
+                " REVISION({}, {})",
+                revision.major, revision.minor
+            )),
+            &q_property(&format!(" REVISION {}", revision.minor)),
+        )
+    } else {
+        q_property("")
+    }
 }
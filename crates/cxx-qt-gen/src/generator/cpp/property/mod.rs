@@ -5,13 +5,21 @@
 
 use crate::generator::{
     cpp::{qobject::GeneratedCppQObjectBlocks, signal::generate_cpp_signals},
-    naming::{property::QPropertyName, qobject::QObjectName},
+    naming::{
+        property::{QListPropertyName, QPropertyName},
+        qobject::QObjectName,
+    },
     utils::cpp::syn_type_to_cpp_type,
 };
-use crate::parser::{mappings::ParsedCxxMappings, property::ParsedQProperty};
+use crate::parser::{
+    mappings::ParsedCxxMappings,
+    property::{ParsedQListProperty, ParsedQProperty},
+};
 use syn::Result;
 
+mod bindable;
 mod getter;
+mod list;
 mod meta;
 mod setter;
 mod signal;
@@ -30,19 +38,64 @@ pub fn generate_cpp_properties(
         let idents = QPropertyName::from(property);
         let cxx_ty = syn_type_to_cpp_type(&property.ty, cxx_mappings)?;
 
-        generated.metaobjects.push(meta::generate(&idents, &cxx_ty));
-        generated
-            .methods
-            .push(getter::generate(&idents, &qobject_ident, &cxx_ty));
-        generated
-            .private_methods
-            .push(getter::generate_wrapper(&idents, &cxx_ty));
-        generated
-            .methods
-            .push(setter::generate(&idents, &qobject_ident, &cxx_ty));
-        generated
-            .private_methods
-            .push(setter::generate_wrapper(&idents, &cxx_ty));
+        // Bindable properties are stored in C++ rather than in the Rust struct
+        if property.bindable {
+            generated.append(&mut bindable::generate(
+                &idents,
+                &qobject_ident,
+                &cxx_ty,
+                &property.revision,
+            ));
+        } else {
+            generated
+                .metaobjects
+                .push(meta::generate(&idents, &cxx_ty, &property.revision));
+            generated
+                .methods
+                .push(getter::generate(&idents, &qobject_ident, &cxx_ty));
+            generated
+                .private_methods
+                .push(getter::generate_wrapper(&idents, &cxx_ty));
+            generated
+                .methods
+                .push(setter::generate(&idents, &qobject_ident, &cxx_ty));
+            generated
+                .private_methods
+                .push(setter::generate_wrapper(&idents, &cxx_ty));
+        }
+        signals.push(signal::generate(&idents, qobject_idents));
+    }
+
+    generated.append(&mut generate_cpp_signals(
+        &signals,
+        qobject_idents,
+        cxx_mappings,
+    )?);
+
+    Ok(generated)
+}
+
+pub fn generate_cpp_list_properties(
+    list_properties: &[ParsedQListProperty],
+    qobject_idents: &QObjectName,
+    cxx_mappings: &ParsedCxxMappings,
+) -> Result<GeneratedCppQObjectBlocks> {
+    let mut generated = GeneratedCppQObjectBlocks::default();
+    let mut signals = vec![];
+    let qobject_ident = qobject_idents.cpp_class.cpp.to_string();
+
+    for list_property in list_properties {
+        let idents = QPropertyName::from(&list_property.ident);
+        let list_idents = QListPropertyName::from(&list_property.ident);
+        let element_cxx_ty = syn_type_to_cpp_type(&list_property.element_ty, cxx_mappings)?;
+
+        generated.append(&mut list::generate(
+            &idents,
+            &list_idents,
+            &qobject_ident,
+            &element_cxx_ty,
+            &list_property.revision,
+        ));
         signals.push(signal::generate(&idents, qobject_idents));
     }
 
@@ -60,6 +113,7 @@ mod tests {
     use super::*;
 
     use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::parser::qobject::QmlVersion;
     use crate::CppFragment;
     use indoc::indoc;
     use pretty_assertions::assert_str_eq;
@@ -72,10 +126,14 @@ mod tests {
             ParsedQProperty {
                 ident: format_ident!("trivial_property"),
                 ty: parse_quote! { i32 },
+                revision: None,
+                bindable: false,
             },
             ParsedQProperty {
                 ident: format_ident!("opaque_property"),
                 ty: parse_quote! { UniquePtr<QColor> },
+                revision: None,
+                bindable: false,
             },
         ];
         let qobject_idents = create_qobjectname();
@@ -288,6 +346,8 @@ mod tests {
         let properties = vec![ParsedQProperty {
             ident: format_ident!("mapped_property"),
             ty: parse_quote! { A1 },
+            revision: None,
+            bindable: false,
         }];
         let qobject_idents = create_qobjectname();
 
@@ -393,4 +453,139 @@ mod tests {
         };
         assert_str_eq!(header, "void setMappedPropertyWrapper(A1 value) noexcept;");
     }
+
+    #[test]
+    fn test_generate_cpp_properties_bindable() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("total"),
+            ty: parse_quote! { i32 },
+            revision: None,
+            bindable: true,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated =
+            generate_cpp_properties(&properties, &qobject_idents, &ParsedCxxMappings::default())
+                .unwrap();
+
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(::std::int32_t total READ getTotal WRITE setTotal NOTIFY totalChanged BINDABLE bindableTotal)"
+        );
+
+        // getter, setter, bindable, binding setter and then the signal and its connect
+        assert_eq!(generated.methods.len(), 6);
+        let header = if let CppFragment::Header(header) = &generated.methods[4] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(header, "Q_SIGNAL void totalChanged();");
+
+        // the storage of the value rather than the Rust wrappers
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "Q_OBJECT_BINDABLE_PROPERTY(MyObject, ::std::int32_t, m_total, &MyObject::totalChanged)"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_properties_revision() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("trivial_property"),
+            ty: parse_quote! { i32 },
+            revision: Some(QmlVersion { major: 1, minor: 2 }),
+            bindable: false,
+        }];
+        let list_properties = vec![ParsedQListProperty {
+            ident: format_ident!("items"),
+            element_ty: parse_quote! { Child },
+            revision: Some(QmlVersion { major: 1, minor: 3 }),
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated =
+            generate_cpp_properties(&properties, &qobject_idents, &ParsedCxxMappings::default())
+                .unwrap();
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            generated.metaobjects[0],
+            indoc! {"
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+            Q_PROPERTY(::std::int32_t trivialProperty READ getTrivialProperty WRITE setTrivialProperty NOTIFY trivialPropertyChanged REVISION(1, 2))
+            #else
+            Q_PROPERTY(::std::int32_t trivialProperty READ getTrivialProperty WRITE setTrivialProperty NOTIFY trivialPropertyChanged REVISION 2)
+            #endif"}
+        );
+
+        let generated = generate_cpp_list_properties(
+            &list_properties,
+            &qobject_idents,
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            generated.metaobjects[0],
+            indoc! {"
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+            Q_PROPERTY(QQmlListProperty<Child> items READ getItems NOTIFY itemsChanged REVISION(1, 3))
+            #else
+            Q_PROPERTY(QQmlListProperty<Child> items READ getItems NOTIFY itemsChanged REVISION 3)
+            #endif"}
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_list_properties() {
+        let list_properties = vec![ParsedQListProperty {
+            ident: format_ident!("items"),
+            element_ty: parse_quote! { Child },
+            revision: None,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut cxx_mapping = ParsedCxxMappings::default();
+        cxx_mapping
+            .namespaces
+            .insert("Child".to_owned(), "my_namespace".to_owned());
+
+        let generated =
+            generate_cpp_list_properties(&list_properties, &qobject_idents, &cxx_mapping).unwrap();
+
+        // metaobjects
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(QQmlListProperty<::my_namespace::Child> items READ getItems NOTIFY itemsChanged)"
+        );
+
+        // methods, the getter and then the signal and its connect
+        assert_eq!(generated.methods.len(), 3);
+        let header = if let CppFragment::Pair { header, .. } = &generated.methods[0] {
+            header
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(
+            header,
+            "QQmlListProperty<::my_namespace::Child> getItems();"
+        );
+        let header = if let CppFragment::Header(header) = &generated.methods[1] {
+            header
+        } else {
+            panic!("Expected header!")
+        };
+        assert_str_eq!(header, "Q_SIGNAL void itemsChanged();");
+
+        // private methods, the callbacks and their Rust wrappers
+        assert_eq!(generated.private_methods.len(), 12);
+    }
 }
@@ -0,0 +1,94 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::cpp::{
+    fragment::CppFragment, qobject::GeneratedCppQObjectBlocks, GeneratedCppQObject,
+};
+use crate::parser::mappings::ParsedCxxMappings;
+use indoc::formatdoc;
+use syn::Ident;
+
+pub fn generate(
+    qobject: &GeneratedCppQObject,
+    attached: &Ident,
+    cxx_mappings: &ParsedCxxMappings,
+) -> GeneratedCppQObjectBlocks {
+    let class_name = &qobject.ident;
+    let namespace_internals = &qobject.namespace_internals;
+    // qmltyperegistrar looks up the attached type by its qualified name,
+    // so remove the leading :: of the global namespace
+    let attached = cxx_mappings
+        .cxx(&attached.to_string())
+        .trim_start_matches("::")
+        .to_owned();
+
+    GeneratedCppQObjectBlocks {
+        includes: ["#include <QtQml/QQmlEngine>".to_owned()].into(),
+        metaobjects: vec![format!("QML_ATTACHED({attached})")],
+        methods: vec![CppFragment::Pair {
+            header: format!("static {attached}* qmlAttachedProperties(QObject* object);"),
+            source: formatdoc! {
+                r#"
+                {attached}*
+                {class_name}::qmlAttachedProperties(QObject* object)
+                {{
+                  return ::{namespace_internals}::qmlAttachedPropertiesRs(*object);
+                }}
+                "#
+            },
+        }],
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_str_eq;
+    use quote::format_ident;
+
+    #[test]
+    fn test_generate_cpp_qmlattached() {
+        let qobject = GeneratedCppQObject {
+            ident: "MyObject".to_string(),
+            rust_ident: "MyObjectRust".to_string(),
+            namespace_internals: "rust".to_string(),
+            blocks: GeneratedCppQObjectBlocks::default(),
+        };
+
+        let mut cxx_mappings = ParsedCxxMappings::default();
+        cxx_mappings
+            .namespaces
+            .insert("MyAttached".to_string(), "cxx_qt".to_string());
+
+        let generated = generate(&qobject, &format_ident!("MyAttached"), &cxx_mappings);
+
+        assert!(generated.includes.contains("#include <QtQml/QQmlEngine>"));
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(generated.metaobjects[0], "QML_ATTACHED(cxx_qt::MyAttached)");
+
+        assert_eq!(generated.methods.len(), 1);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            header,
+            "static cxx_qt::MyAttached* qmlAttachedProperties(QObject* object);"
+        );
+        assert_str_eq!(
+            source,
+            indoc::indoc! {r#"
+            cxx_qt::MyAttached*
+            MyObject::qmlAttachedProperties(QObject* object)
+            {
+              return ::rust::qmlAttachedPropertiesRs(*object);
+            }
+            "#}
+        );
+    }
+}
@@ -0,0 +1,206 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::collections::BTreeSet;
+
+use crate::{
+    generator::cpp::qobject::{generate_qml_element_specifiers, generate_qml_extended_specifier},
+    parser::{externcxxqt::ParsedExternCxxQt, mappings::ParsedCxxMappings},
+    syntax::path::path_compare_str,
+};
+use indoc::formatdoc;
+use syn::{ForeignItem, LitStr};
+
+#[derive(Default)]
+pub struct GeneratedCppQmlForeignBlocks {
+    /// Includes for the C++ types and QML
+    pub includes: BTreeSet<String>,
+    /// Classes which register the C++ types with QML
+    pub classes: Vec<String>,
+    /// Namespace of the classes
+    pub namespace: String,
+}
+
+pub fn generate(
+    blocks: &[ParsedExternCxxQt],
+    cxx_mappings: &ParsedCxxMappings,
+    cxx_file_stem: &str,
+) -> GeneratedCppQmlForeignBlocks {
+    // The Rust idents are unique within a bridge and the file stems are unique within a crate,
+    // so use the file stem in the namespace to avoid redefinitions across bridges
+    let file_stem_namespace = cxx_file_stem
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    let mut generated = GeneratedCppQmlForeignBlocks {
+        namespace: format!("rust::cxxqtgen1::{file_stem_namespace}"),
+        ..Default::default()
+    };
+
+    for block in blocks.iter().filter(|block| !block.qml_foreign.is_empty()) {
+        generated
+            .includes
+            .insert("#include <QtQml/QQmlEngine>".to_owned());
+
+        // The declarations of the C++ types are needed for QML_FOREIGN,
+        // so include the headers from the extern "C++Qt" block
+        for item in &block.passthrough_items {
+            if let ForeignItem::Macro(foreign_macro) = item {
+                if path_compare_str(&foreign_macro.mac.path, &["include"]) {
+                    let include = if let Ok(path) = foreign_macro.mac.parse_body::<LitStr>() {
+                        format!("\"{}\"", path.value())
+                    } else {
+                        foreign_macro
+                            .mac
+                            .tokens
+                            .to_string()
+                            .split_whitespace()
+                            .collect()
+                    };
+                    generated.includes.insert(format!("#include {include}"));
+                }
+            }
+        }
+
+        for qml_foreign in &block.qml_foreign {
+            // qmltyperegistrar looks up the foreign type by its qualified name,
+            // so remove the leading :: of the global namespace
+            let foreign_ty = cxx_mappings
+                .cxx(&qml_foreign.ident.to_string())
+                .trim_start_matches("::")
+                .to_owned();
+
+            let mut specifiers = vec![format!("QML_FOREIGN({foreign_ty})")];
+            specifiers.append(&mut generate_qml_element_specifiers(
+                &qml_foreign.qml_metadata,
+            ));
+            if let Some(extended) = &qml_foreign.qml_metadata.extended {
+                specifiers.push(generate_qml_extended_specifier(extended, cxx_mappings));
+            }
+
+            generated.classes.push(formatdoc! {r#"
+                class {ident}QmlForeign
+                {{
+                  Q_GADGET
+                  {specifiers}
+                }};
+                "#,
+                ident = qml_foreign.ident,
+                specifiers = specifiers.join("\n  "),
+            });
+        }
+    }
+
+    generated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_cpp_qml_foreign() {
+        let blocks = vec![
+            ParsedExternCxxQt::parse(parse_quote! {
+                unsafe extern "C++Qt" {
+                    include!(<QtWidgets/QPushButton>);
+                    include!("custom/object.h");
+
+                    #[qml_element = "PushButton"]
+                    #[qml_extended(PushButtonExtension)]
+                    type QPushButton;
+
+                    #[qml_element]
+                    #[qml_uncreatable]
+                    #[namespace = "custom"]
+                    type CustomObject;
+                }
+            })
+            .unwrap(),
+            ParsedExternCxxQt::parse(parse_quote! {
+                unsafe extern "C++Qt" {
+                    include!(<QtWidgets/QLabel>);
+                    type QLabel;
+                }
+            })
+            .unwrap(),
+        ];
+
+        let mut cxx_mappings = ParsedCxxMappings::default();
+        cxx_mappings
+            .namespaces
+            .insert("CustomObject".to_owned(), "custom".to_owned());
+
+        let generated = generate(&blocks, &cxx_mappings, "ffi");
+        assert_eq!(generated.namespace, "rust::cxxqtgen1::ffi");
+        assert_eq!(
+            generated.includes.into_iter().collect::<Vec<_>>(),
+            vec![
+                "#include \"custom/object.h\"".to_owned(),
+                "#include <QtQml/QQmlEngine>".to_owned(),
+                "#include <QtWidgets/QPushButton>".to_owned(),
+            ]
+        );
+
+        assert_eq!(generated.classes.len(), 2);
+        assert_str_eq!(
+            generated.classes[0],
+            indoc! {r#"
+            class QPushButtonQmlForeign
+            {
+              Q_GADGET
+              QML_FOREIGN(QPushButton)
+              Q_CLASSINFO("QML.Element", "PushButton")
+              QML_EXTENDED(PushButtonExtension)
+            };
+            "#}
+        );
+        assert_str_eq!(
+            generated.classes[1],
+            indoc! {r#"
+            class CustomObjectQmlForeign
+            {
+              Q_GADGET
+              QML_FOREIGN(custom::CustomObject)
+              Q_CLASSINFO("QML.Element", "CustomObject")
+              Q_CLASSINFO("QML.Creatable", "false")
+            };
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_qml_foreign_none() {
+        let blocks = vec![ParsedExternCxxQt::parse(parse_quote! {
+            unsafe extern "C++Qt" {
+                include!(<QtWidgets/QPushButton>);
+                type QPushButton;
+            }
+        })
+        .unwrap()];
+
+        let generated = generate(&blocks, &ParsedCxxMappings::default(), "ffi");
+        assert!(generated.includes.is_empty());
+        assert!(generated.classes.is_empty());
+    }
+
+    #[test]
+    fn test_generate_cpp_qml_foreign_namespace() {
+        let blocks = vec![ParsedExternCxxQt::parse(parse_quote! {
+            unsafe extern "C++Qt" {
+                #[qml_element]
+                type QPushButton;
+            }
+        })
+        .unwrap()];
+
+        let generated = generate(&blocks, &ParsedCxxMappings::default(), "my-widgets");
+        assert_eq!(generated.namespace, "rust::cxxqtgen1::my_widgets");
+    }
+}
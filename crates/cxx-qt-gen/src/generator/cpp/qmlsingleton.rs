@@ -0,0 +1,136 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::cpp::{
+    fragment::CppFragment, qobject::GeneratedCppQObjectBlocks, GeneratedCppQObject,
+};
+use indoc::formatdoc;
+
+pub fn generate(
+    qobject: &GeneratedCppQObject,
+    base_class: &str,
+    class_initializers: &[String],
+) -> GeneratedCppQObjectBlocks {
+    let class_name = &qobject.ident;
+    let rust_obj = &qobject.rust_ident;
+    let namespace_internals = &qobject.namespace_internals;
+    let initializers = class_initializers
+        .iter()
+        .map(|initializer| format!("\n  , {initializer}"))
+        .collect::<Vec<_>>()
+        .join("");
+
+    GeneratedCppQObjectBlocks {
+        includes: ["#include <cxx-qt-common/cxxqt_qmlsingleton.h>".to_owned()].into(),
+        methods: vec![CppFragment::Pair {
+            header: format!(
+                "static {class_name}* create(QQmlEngine* qmlEngine, QJSEngine* jsEngine);"
+            ),
+            // The singleton is owned by the engine, so remember which instance belongs to which
+            // engine so that it can be retrieved later from Rust.
+            source: formatdoc! {
+                r#"
+                {class_name}*
+                {class_name}::create(QQmlEngine* qmlEngine, QJSEngine* jsEngine)
+                {{
+                  Q_UNUSED(jsEngine);
+
+                  auto* instance = new {class_name}(::{namespace_internals}::createSingletonRs(*qmlEngine));
+                  ::rust::cxxqtlib1::qmlSingletonInsert(*qmlEngine, instance);
+                  return instance;
+                }}
+                "#
+            },
+        }],
+        // This constructor takes the Rust struct created by the factory, it is private so that
+        // only the create function can use it.
+        private_methods: vec![CppFragment::Pair {
+            header: format!("explicit {class_name}(::rust::Box<{rust_obj}>&& rustObj);"),
+            source: formatdoc! {
+                r#"
+                {class_name}::{class_name}(::rust::Box<{rust_obj}>&& rustObj)
+                  : {base_class}()
+                  , ::rust::cxxqtlib1::CxxQtType<{rust_obj}>(::std::move(rustObj)){initializers}
+                {{ }}
+                "#
+            },
+        }],
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_str_eq;
+
+    #[test]
+    fn test_generate_cpp_qmlsingleton() {
+        let qobject = GeneratedCppQObject {
+            ident: "MyObject".to_string(),
+            rust_ident: "MyObjectRust".to_string(),
+            namespace_internals: "rust".to_string(),
+            blocks: GeneratedCppQObjectBlocks::default(),
+        };
+
+        let generated = generate(
+            &qobject,
+            "QObject",
+            &["::rust::cxxqtlib1::CxxQtLocking()".to_string()],
+        );
+
+        assert!(generated
+            .includes
+            .contains("#include <cxx-qt-common/cxxqt_qmlsingleton.h>"));
+
+        assert_eq!(generated.methods.len(), 1);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            header,
+            "static MyObject* create(QQmlEngine* qmlEngine, QJSEngine* jsEngine);"
+        );
+        assert_str_eq!(
+            source,
+            indoc::indoc! {r#"
+            MyObject*
+            MyObject::create(QQmlEngine* qmlEngine, QJSEngine* jsEngine)
+            {
+              Q_UNUSED(jsEngine);
+
+              auto* instance = new MyObject(::rust::createSingletonRs(*qmlEngine));
+              ::rust::cxxqtlib1::qmlSingletonInsert(*qmlEngine, instance);
+              return instance;
+            }
+            "#}
+        );
+
+        assert_eq!(generated.private_methods.len(), 1);
+        let (header, source) =
+            if let CppFragment::Pair { header, source } = &generated.private_methods[0] {
+                (header, source)
+            } else {
+                panic!("Expected pair")
+            };
+        assert_str_eq!(
+            header,
+            "explicit MyObject(::rust::Box<MyObjectRust>&& rustObj);"
+        );
+        assert_str_eq!(
+            source,
+            indoc::indoc! {r#"
+            MyObject::MyObject(::rust::Box<MyObjectRust>&& rustObj)
+              : QObject()
+              , ::rust::cxxqtlib1::CxxQtType<MyObjectRust>(::std::move(rustObj))
+              , ::rust::cxxqtlib1::CxxQtLocking()
+            { }
+            "#}
+        );
+    }
+}
@@ -5,15 +5,24 @@
 
 use crate::generator::{
     cpp::{
-        constructor, cxxqttype, fragment::CppFragment, inherit, locking,
-        method::generate_cpp_methods, property::generate_cpp_properties, qenum,
-        signal::generate_cpp_signals, threading,
+        constructor, cxxqttype,
+        fragment::CppFragment,
+        inherit, locking,
+        method::generate_cpp_methods,
+        property::{generate_cpp_list_properties, generate_cpp_properties},
+        qenum, qmlattached, qmlsingleton,
+        signal::generate_cpp_signals,
+        threading,
     },
-    naming::{namespace::NamespaceName, qobject::QObjectName},
+    naming::{namespace::NamespaceName, property::QPropertyName, qobject::QObjectName},
+    utils::cpp::{escape_cpp_string, qt_version_switch},
+};
+use crate::parser::{
+    mappings::ParsedCxxMappings,
+    qobject::{ParsedQObject, QmlElementMetadata},
 };
-use crate::parser::{mappings::ParsedCxxMappings, qobject::ParsedQObject};
 use std::collections::BTreeSet;
-use syn::Result;
+use syn::{Ident, Result};
 
 #[derive(Default)]
 pub struct GeneratedCppQObjectBlocks {
@@ -42,24 +51,27 @@ impl GeneratedCppQObjectBlocks {
     }
 
     pub fn from(qobject: &ParsedQObject) -> GeneratedCppQObjectBlocks {
-        let mut qml_specifiers = Vec::new();
-        if let Some(qml_metadata) = &qobject.qml_metadata {
-            // Somehow moc doesn't include the info in metatypes.json that qmltyperegistrar needs
-            // when using the QML_ELEMENT/QML_NAMED_ELEMENT macros, but moc works when using what
-            // those macros expand to.
+        let mut qml_specifiers = qobject
+            .qml_metadata
+            .as_ref()
+            .map(generate_qml_element_specifiers)
+            .unwrap_or_default();
+
+        if let Some(default_property) = &qobject.default_property {
             qml_specifiers.push(format!(
-                "Q_CLASSINFO(\"QML.Element\", \"{}\")",
-                qml_metadata.name
+                "Q_CLASSINFO(\"DefaultProperty\", \"{}\")",
+                QPropertyName::from(default_property).name.cpp
             ));
+        }
 
-            if qml_metadata.uncreatable {
-                qml_specifiers.push("Q_CLASSINFO(\"QML.Creatable\", \"false\")".to_owned());
-            }
-
-            if qml_metadata.singleton {
-                qml_specifiers.push("QML_SINGLETON".to_owned());
-            }
+        for (key, value) in &qobject.class_info {
+            qml_specifiers.push(format!(
+                "Q_CLASSINFO(\"{key}\", \"{value}\")",
+                key = escape_cpp_string(key),
+                value = escape_cpp_string(value)
+            ));
         }
+
         GeneratedCppQObjectBlocks {
             metaobjects: qml_specifiers,
             ..Default::default()
@@ -67,6 +79,58 @@ impl GeneratedCppQObjectBlocks {
     }
 }
 
+/// Generate the specifiers which register a type as a QML element
+pub fn generate_qml_element_specifiers(qml_metadata: &QmlElementMetadata) -> Vec<String> {
+    // Somehow moc doesn't include the info in metatypes.json that qmltyperegistrar needs
+    // when using the QML_ELEMENT/QML_NAMED_ELEMENT macros, but moc works when using what
+    // those macros expand to.
+    let mut qml_specifiers = vec![format!(
+        "Q_CLASSINFO(\"QML.Element\", \"{}\")",
+        qml_metadata.name
+    )];
+
+    // QML_ADDED_IN_VERSION is Qt 6 only, so use the class info it expands to,
+    // which is the version encoded as a QTypeRevision, (major << 8) | minor.
+    // Qt 5 only has a minor version for the type.
+    if let Some(added_in) = &qml_metadata.added_in {
+        qml_specifiers.push(qt_version_switch(
+            &format!(
+                "Q_CLASSINFO(\"QML.AddedInVersion\", \"{}\")",
+                (u16::from(added_in.major) << 8) | u16::from(added_in.minor)
+            ),
+            &format!(
+                "Q_CLASSINFO(\"QML.AddedInMinorVersion\", \"{}\")",
+                added_in.minor
+            ),
+        ));
+    }
+
+    if qml_metadata.uncreatable {
+        qml_specifiers.push("Q_CLASSINFO(\"QML.Creatable\", \"false\")".to_owned());
+    }
+
+    if qml_metadata.singleton {
+        qml_specifiers.push("QML_SINGLETON".to_owned());
+    }
+
+    qml_specifiers
+}
+
+/// Generate the QML_EXTENDED specifier for the given extension type
+pub fn generate_qml_extended_specifier(
+    extended: &Ident,
+    cxx_mappings: &ParsedCxxMappings,
+) -> String {
+    // qmltyperegistrar looks up the extension type by its qualified name,
+    // so remove the leading :: of the global namespace
+    format!(
+        "QML_EXTENDED({})",
+        cxx_mappings
+            .cxx(&extended.to_string())
+            .trim_start_matches("::")
+    )
+}
+
 #[derive(Default)]
 pub struct GeneratedCppQObject {
     /// Ident of the C++ QObject
@@ -119,9 +183,15 @@ impl GeneratedCppQObject {
             &qobject_idents,
             cxx_mappings,
         )?);
+        generated.blocks.append(&mut generate_cpp_list_properties(
+            &qobject.list_properties,
+            &qobject_idents,
+            cxx_mappings,
+        )?);
         generated.blocks.append(&mut generate_cpp_methods(
             &qobject.methods,
             &qobject_idents,
+            &generated.namespace_internals,
             cxx_mappings,
         )?);
         generated.blocks.append(&mut generate_cpp_signals(
@@ -157,13 +227,55 @@ impl GeneratedCppQObject {
             class_initializers.push(initializer);
         }
 
-        generated.blocks.append(&mut constructor::generate(
-            &generated,
-            &qobject.constructors,
-            base_class,
-            &class_initializers,
-            cxx_mappings,
-        )?);
+        // If this QML element has an extension object then add the specifier
+        if let Some(extended) = qobject
+            .qml_metadata
+            .as_ref()
+            .and_then(|qml_metadata| qml_metadata.extended.as_ref())
+        {
+            generated
+                .blocks
+                .includes
+                .insert("#include <QtQml/QQmlEngine>".to_owned());
+            generated
+                .blocks
+                .metaobjects
+                .push(generate_qml_extended_specifier(extended, cxx_mappings));
+        }
+
+        // If this QML element has an attached object then add generation
+        if let Some(attached) = qobject
+            .qml_metadata
+            .as_ref()
+            .and_then(|qml_metadata| qml_metadata.attached.as_ref())
+        {
+            generated.blocks.append(&mut qmlattached::generate(
+                &generated,
+                attached,
+                cxx_mappings,
+            ));
+        }
+
+        // If this QML singleton is created by a factory in Rust then add generation
+        if qobject.qml_singleton_factory.is_some() {
+            generated.blocks.append(&mut qmlsingleton::generate(
+                &generated,
+                &base_class,
+                &class_initializers,
+            ));
+        }
+
+        // A QML singleton created by a factory does not need a Default constructor,
+        // unless there are other constructors declared
+        if qobject.qml_singleton_factory.is_none() || !qobject.constructors.is_empty() {
+            generated.blocks.append(&mut constructor::generate(
+                &generated,
+                &qobject.constructors,
+                base_class,
+                &class_initializers,
+                cxx_mappings,
+            )?);
+        }
 
         Ok(generated)
     }
@@ -174,6 +286,8 @@ mod tests {
     use super::*;
 
     use crate::parser::Parser;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
     use syn::{parse_quote, ItemMod};
 
     #[test]
@@ -298,6 +412,218 @@ mod tests {
             "Q_CLASSINFO(\"QML.Element\", \"MyObject\")"
         );
         assert_eq!(cpp.blocks.metaobjects[1], "QML_SINGLETON");
+        // Without a factory QML default constructs the singleton
+        assert!(!cpp
+            .blocks
+            .includes
+            .contains("#include <cxx-qt-common/cxxqt_qmlsingleton.h>"));
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_singleton_factory() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qqmlengine.h");
+                    type QQmlEngine = cxx_qt_lib::QQmlEngine;
+                }
+
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_singleton]
+                    type MyObject = super::MyObjectRust;
+                }
+
+                impl cxx_qt::QmlSingletonFactory<QQmlEngine> for MyObject {}
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let cpp = GeneratedCppQObject::from(
+            parser.cxx_qt_data.qobjects.values().next().unwrap(),
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+        assert!(cpp
+            .blocks
+            .includes
+            .contains("#include <cxx-qt-common/cxxqt_qmlsingleton.h>"));
+        assert!(cpp.blocks.methods.iter().any(|method| matches!(
+            method,
+            CppFragment::Pair { header, .. }
+                if header == "static MyObject* create(QQmlEngine* qmlEngine, QJSEngine* jsEngine);"
+        )));
+        // The singleton is created by the factory, so there is no Default constructor
+        assert!(!cpp.blocks.methods.iter().any(|method| matches!(
+            method,
+            CppFragment::Pair { header, .. }
+                if header == "explicit MyObject(QObject* parent = nullptr);"
+        )));
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_attached() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_attached(MyAttached)]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let cpp = GeneratedCppQObject::from(
+            parser.cxx_qt_data.qobjects.values().next().unwrap(),
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 2);
+        assert_eq!(
+            cpp.blocks.metaobjects[0],
+            "Q_CLASSINFO(\"QML.Element\", \"MyObject\")"
+        );
+        assert_eq!(cpp.blocks.metaobjects[1], "QML_ATTACHED(MyAttached)");
+        assert!(cpp.blocks.methods.iter().any(|method| matches!(
+            method,
+            CppFragment::Pair { header, .. }
+                if header == "static MyAttached* qmlAttachedProperties(QObject* object);"
+        )));
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_default_property() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qproperty(QQmlListProperty<Child>, child_items)]
+                    #[qml_default_property(child_items)]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let cpp = GeneratedCppQObject::from(
+            parser.cxx_qt_data.qobjects.values().next().unwrap(),
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 3);
+        assert_eq!(
+            cpp.blocks.metaobjects[0],
+            "Q_CLASSINFO(\"QML.Element\", \"MyObject\")"
+        );
+        assert_eq!(
+            cpp.blocks.metaobjects[1],
+            "Q_CLASSINFO(\"DefaultProperty\", \"childItems\")"
+        );
+        assert_eq!(
+            cpp.blocks.metaobjects[2],
+            "Q_PROPERTY(QQmlListProperty<Child> childItems READ getChildItems NOTIFY childItemsChanged)"
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_class_info() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qclassinfo("D-Bus Interface" = "com.kdab.MyObject", path = r#"C:\dir "quoted""#)]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let cpp = GeneratedCppQObject::from(
+            parser.cxx_qt_data.qobjects.values().next().unwrap(),
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 2);
+        assert_eq!(
+            cpp.blocks.metaobjects[0],
+            "Q_CLASSINFO(\"D-Bus Interface\", \"com.kdab.MyObject\")"
+        );
+        assert_eq!(
+            cpp.blocks.metaobjects[1],
+            r#"Q_CLASSINFO("path", "C:\\dir \"quoted\"")"#
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_extended() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_extended(MyExtension)]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let cpp = GeneratedCppQObject::from(
+            parser.cxx_qt_data.qobjects.values().next().unwrap(),
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 2);
+        assert_eq!(
+            cpp.blocks.metaobjects[0],
+            "Q_CLASSINFO(\"QML.Element\", \"MyObject\")"
+        );
+        assert_eq!(cpp.blocks.metaobjects[1], "QML_EXTENDED(MyExtension)");
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_added_in() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_added_in(1, 2)]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let cpp = GeneratedCppQObject::from(
+            parser.cxx_qt_data.qobjects.values().next().unwrap(),
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 2);
+        assert_eq!(
+            cpp.blocks.metaobjects[0],
+            "Q_CLASSINFO(\"QML.Element\", \"MyObject\")"
+        );
+        assert_str_eq!(
+            cpp.blocks.metaobjects[1],
+            indoc! {r#"
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+            Q_CLASSINFO("QML.AddedInVersion", "258")
+            #else
+            Q_CLASSINFO("QML.AddedInMinorVersion", "2")
+            #endif"#}
+        );
     }
 
     #[test]
@@ -7,7 +7,7 @@ use crate::{
     generator::{
         cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
         naming::{qobject::QObjectName, signals::QSignalName},
-        utils::cpp::syn_type_to_cpp_type,
+        utils::cpp::{generate_with_q_revision, syn_type_to_cpp_type},
     },
     parser::{
         mappings::ParsedCxxMappings, parameter::ParsedFunctionParameter, signals::ParsedSignal,
@@ -143,9 +143,16 @@ pub fn generate_cpp_signals(
 
         // Generate the Q_SIGNAL if this is not an existing signal
         if !signal.inherit {
-            generated.methods.push(CppFragment::Header(format!(
-                "Q_SIGNAL void {signal_ident}({parameters_types_signal});"
-            )));
+            generated
+                .methods
+                .push(CppFragment::Header(generate_with_q_revision(
+                    &signal.revision,
+                    |revision| {
+                        format!(
+                            "{revision}Q_SIGNAL void {signal_ident}({parameters_types_signal});"
+                        )
+                    },
+                )));
         }
 
         generated.methods.push(CppFragment::Pair {
@@ -179,7 +186,7 @@ mod tests {
     use super::*;
 
     use crate::generator::naming::{qobject::tests::create_qobjectname, CombinedIdent};
-    use crate::parser::parameter::ParsedFunctionParameter;
+    use crate::parser::{parameter::ParsedFunctionParameter, qobject::QmlVersion};
     use indoc::indoc;
     use pretty_assertions::assert_str_eq;
     use quote::format_ident;
@@ -209,6 +216,7 @@ mod tests {
             },
             safe: true,
             inherit: false,
+            revision: None,
         }];
         let qobject_idents = create_qobjectname();
 
@@ -272,6 +280,7 @@ mod tests {
             },
             safe: true,
             inherit: false,
+            revision: None,
         }];
         let qobject_idents = create_qobjectname();
 
@@ -334,6 +343,7 @@ mod tests {
             },
             safe: true,
             inherit: true,
+            revision: None,
         }];
         let qobject_idents = create_qobjectname();
 
@@ -367,6 +377,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_cpp_signals_revision() {
+        let signals = vec![ParsedSignal {
+            method: parse_quote! {
+                fn data_changed(self: Pin<&mut MyObject>);
+            },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: true,
+            parameters: vec![],
+            ident: CombinedIdent {
+                cpp: format_ident!("dataChanged"),
+                rust: format_ident!("data_changed"),
+            },
+            safe: true,
+            inherit: false,
+            revision: Some(QmlVersion { major: 1, minor: 2 }),
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated =
+            generate_cpp_signals(&signals, &qobject_idents, &ParsedCxxMappings::default()).unwrap();
+
+        assert_eq!(generated.methods.len(), 2);
+        let header = if let CppFragment::Header(header) = &generated.methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            indoc! {"
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+            Q_REVISION(1, 2) Q_SIGNAL void dataChanged();
+            #else
+            Q_REVISION(2) Q_SIGNAL void dataChanged();
+            #endif"}
+        );
+    }
+
     #[test]
     fn test_generate_cpp_signal_free() {
         let signal = ParsedSignal {
@@ -382,6 +431,7 @@ mod tests {
             },
             safe: true,
             inherit: false,
+            revision: None,
         };
 
         let generated = generate_cpp_free_signal(&signal, &ParsedCxxMappings::default()).unwrap();
@@ -436,6 +486,7 @@ mod tests {
             },
             safe: true,
             inherit: false,
+            revision: None,
         };
 
         let mut cxx_mappings = ParsedCxxMappings::default();
@@ -5,7 +5,7 @@
 use crate::{generator::naming::CombinedIdent, parser::method::ParsedMethod};
 use convert_case::{Case, Casing};
 use quote::format_ident;
-use syn::{ForeignItemFn, Ident};
+use syn::Ident;
 
 /// Names for parts of a method (which could be a Q_INVOKABLE)
 pub struct QMethodName {
@@ -15,16 +15,10 @@ pub struct QMethodName {
 
 impl From<&ParsedMethod> for QMethodName {
     fn from(invokable: &ParsedMethod) -> Self {
-        Self::from(&invokable.method)
-    }
-}
-
-impl From<&ForeignItemFn> for QMethodName {
-    fn from(method: &ForeignItemFn) -> Self {
-        let ident = &method.sig.ident;
         Self {
-            name: CombinedIdent::from_rust_function(ident.clone()),
-            wrapper: CombinedIdent::wrapper_from_invokable(ident),
+            name: invokable.ident.clone(),
+            // The wrapper uses the Rust name so that it is unique even when the C++ name is overloaded
+            wrapper: CombinedIdent::wrapper_from_invokable(&invokable.ident.rust),
         }
     }
 }
@@ -60,6 +54,10 @@ mod tests {
             parameters: vec![],
             specifiers: HashSet::new(),
             is_qinvokable: true,
+            revision: None,
+            ident: CombinedIdent::from_rust_function(format_ident!("my_invokable")),
+            is_static: false,
+            qjs_error: None,
         };
 
         let invokable = QMethodName::from(&parsed);
@@ -71,4 +69,32 @@ mod tests {
             format_ident!("my_invokable_wrapper")
         );
     }
+
+    #[test]
+    fn test_from_impl_method_overloaded() {
+        let parsed = ParsedMethod {
+            method: parse_quote! {
+                fn add_string(self: &MyObject, value: &QString);
+            },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            revision: None,
+            ident: CombinedIdent {
+                cpp: format_ident!("add"),
+                rust: format_ident!("add_string"),
+            },
+            is_static: false,
+            qjs_error: None,
+        };
+
+        let invokable = QMethodName::from(&parsed);
+        assert_eq!(invokable.name.cpp, format_ident!("add"));
+        assert_eq!(invokable.name.rust, format_ident!("add_string"));
+        assert_eq!(invokable.wrapper.cpp, format_ident!("addStringWrapper"));
+        assert_eq!(invokable.wrapper.rust, format_ident!("add_string_wrapper"));
+    }
 }
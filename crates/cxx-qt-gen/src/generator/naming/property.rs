@@ -16,6 +16,8 @@ pub struct QPropertyName {
     pub setter: CombinedIdent,
     pub setter_wrapper: CombinedIdent,
     pub notify: CombinedIdent,
+    pub bindable: CombinedIdent,
+    pub set_binding: CombinedIdent,
 }
 
 impl From<&Ident> for QPropertyName {
@@ -29,6 +31,8 @@ impl From<&Ident> for QPropertyName {
             setter_wrapper: CombinedIdent::wrapper_from_combined_property(&setter),
             setter,
             notify: CombinedIdent::notify_from_property(ident),
+            bindable: CombinedIdent::bindable_from_property(ident),
+            set_binding: CombinedIdent::set_binding_from_property(ident),
         }
     }
 }
@@ -39,7 +43,75 @@ impl From<&ParsedQProperty> for QPropertyName {
     }
 }
 
+/// Names for the functions of a QQmlListProperty Q_PROPERTY
+pub struct QListPropertyName {
+    pub append: CombinedIdent,
+    pub append_wrapper: CombinedIdent,
+    pub count: CombinedIdent,
+    pub count_wrapper: CombinedIdent,
+    pub at: CombinedIdent,
+    pub at_wrapper: CombinedIdent,
+    pub clear: CombinedIdent,
+    pub clear_wrapper: CombinedIdent,
+    pub replace: CombinedIdent,
+    pub replace_wrapper: CombinedIdent,
+    pub remove_last: CombinedIdent,
+    pub remove_last_wrapper: CombinedIdent,
+}
+
+impl From<&Ident> for QListPropertyName {
+    fn from(ident: &Ident) -> Self {
+        let append = CombinedIdent::function_from_property(ident, "append");
+        let count = CombinedIdent::function_from_property(ident, "count");
+        let at = CombinedIdent::function_from_property(ident, "at");
+        let clear = CombinedIdent::function_from_property(ident, "clear");
+        let replace = CombinedIdent::function_from_property(ident, "replace");
+        let remove_last = CombinedIdent::function_from_property(ident, "remove_last");
+        Self {
+            append_wrapper: CombinedIdent::wrapper_from_combined_property(&append),
+            append,
+            count_wrapper: CombinedIdent::wrapper_from_combined_property(&count),
+            count,
+            at_wrapper: CombinedIdent::wrapper_from_combined_property(&at),
+            at,
+            clear_wrapper: CombinedIdent::wrapper_from_combined_property(&clear),
+            clear,
+            replace_wrapper: CombinedIdent::wrapper_from_combined_property(&replace),
+            replace,
+            remove_last_wrapper: CombinedIdent::wrapper_from_combined_property(&remove_last),
+            remove_last,
+        }
+    }
+}
+
 impl CombinedIdent {
+    /// For a given ident and suffix generate the Rust and C++ names of a list function
+    fn function_from_property(ident: &Ident, suffix: &str) -> Self {
+        let ident = format_ident!("{ident}_{suffix}");
+        Self {
+            cpp: format_ident!("{}", ident.to_string().to_case(Case::Camel)),
+            rust: ident,
+        }
+    }
+
+    /// For a given ident generate the Rust and C++ names of the QBindable accessor
+    fn bindable_from_property(ident: &Ident) -> Self {
+        let ident = format_ident!("bindable_{ident}");
+        Self {
+            cpp: format_ident!("{}", ident.to_string().to_case(Case::Camel)),
+            rust: ident,
+        }
+    }
+
+    /// For a given ident generate the Rust and C++ names of the binding setter
+    fn set_binding_from_property(ident: &Ident) -> Self {
+        let ident = format_ident!("set_{ident}_binding");
+        Self {
+            cpp: format_ident!("{}", ident.to_string().to_case(Case::Camel)),
+            rust: ident,
+        }
+    }
+
     /// For a given ident generate the Rust and C++ getter names
     fn getter_from_property(ident: Ident) -> Self {
         Self {
@@ -94,6 +166,8 @@ pub mod tests {
         let property = ParsedQProperty {
             ident: format_ident!("my_property"),
             ty,
+            revision: None,
+            bindable: false,
         };
         QPropertyName::from(&property)
     }
@@ -109,5 +183,35 @@ pub mod tests {
         assert_eq!(names.setter.rust, format_ident!("set_my_property"));
         assert_eq!(names.notify.cpp, format_ident!("myPropertyChanged"));
         assert_eq!(names.notify.rust, format_ident!("my_property_changed"));
+        assert_eq!(names.bindable.cpp, format_ident!("bindableMyProperty"));
+        assert_eq!(names.set_binding.cpp, format_ident!("setMyPropertyBinding"));
+        assert_eq!(
+            names.set_binding.rust,
+            format_ident!("set_my_property_binding")
+        );
+    }
+
+    #[test]
+    fn test_list_property() {
+        let names = QListPropertyName::from(&format_ident!("my_items"));
+        assert_eq!(names.append.cpp, format_ident!("myItemsAppend"));
+        assert_eq!(names.append.rust, format_ident!("my_items_append"));
+        assert_eq!(
+            names.append_wrapper.cpp,
+            format_ident!("myItemsAppendWrapper")
+        );
+        assert_eq!(names.count.cpp, format_ident!("myItemsCount"));
+        assert_eq!(names.at.cpp, format_ident!("myItemsAt"));
+        assert_eq!(names.clear.cpp, format_ident!("myItemsClear"));
+        assert_eq!(names.replace.cpp, format_ident!("myItemsReplace"));
+        assert_eq!(names.remove_last.cpp, format_ident!("myItemsRemoveLast"));
+        assert_eq!(
+            names.remove_last.rust,
+            format_ident!("my_items_remove_last")
+        );
+        assert_eq!(
+            names.remove_last_wrapper.cpp,
+            format_ident!("myItemsRemoveLastWrapper")
+        );
     }
 }
@@ -10,7 +10,7 @@ use syn::Ident;
 
 /// Names for parts of a Q_OBJECT
 pub struct QObjectName {
-    // Store the ident so that cxx_qt_thread_method can use it later
+    // Store the ident so that cxx_qt_ffi_method can use it later
     ident: Ident,
     /// The name of the C++ class
     pub cpp_class: CombinedIdent,
@@ -42,8 +42,8 @@ impl QObjectName {
         }
     }
 
-    /// For a given ident generate the mangled threading suffix ident
-    pub fn cxx_qt_thread_method(&self, suffix: &str) -> Ident {
+    /// For a given ident generate the mangled suffix ident for an ffi method
+    pub fn cxx_qt_ffi_method(&self, suffix: &str) -> Ident {
         format_ident!(
             "cxx_qt_ffi_{ident}_{suffix}",
             ident = self.ident.to_string().to_case(Case::Snake)
@@ -98,15 +98,15 @@ pub mod tests {
         );
 
         assert_eq!(
-            names.cxx_qt_thread_method("threading_clone"),
+            names.cxx_qt_ffi_method("threading_clone"),
             "cxx_qt_ffi_my_object_threading_clone"
         );
         assert_eq!(
-            names.cxx_qt_thread_method("threading_drop"),
+            names.cxx_qt_ffi_method("threading_drop"),
             "cxx_qt_ffi_my_object_threading_drop"
         );
         assert_eq!(
-            names.cxx_qt_thread_method("queue_boxed_fn"),
+            names.cxx_qt_ffi_method("queue_boxed_fn"),
             "cxx_qt_ffi_my_object_queue_boxed_fn"
         );
     }
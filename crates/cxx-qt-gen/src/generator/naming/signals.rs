@@ -61,6 +61,7 @@ mod tests {
             },
             safe: true,
             inherit: false,
+            revision: None,
         };
 
         let names = QSignalName::from(&qsignal);
@@ -90,6 +91,7 @@ mod tests {
             },
             safe: true,
             inherit: false,
+            revision: None,
         };
 
         let names = QSignalName::from(&qsignal);
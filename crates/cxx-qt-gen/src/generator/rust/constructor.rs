@@ -3,7 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use crate::{
     generator::{
@@ -22,8 +22,8 @@ use convert_case::{Case, Casing};
 use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote};
 use syn::{
-    parse_quote, parse_quote_spanned, spanned::Spanned, Error, Expr, FnArg, Ident, Item, Lifetime,
-    Path, Result, Type,
+    parse_quote, parse_quote_spanned, spanned::Spanned, Error, Expr, FnArg, GenericArgument, Ident,
+    Item, Lifetime, Path, PathArguments, Result, Type,
 };
 
 const CONSTRUCTOR_ARGUMENTS: &str = "CxxQtConstructorArguments";
@@ -95,6 +95,112 @@ fn generate_default_constructor(
     }
 }
 
+/// The name of a type within the name of a function which creates the QObject from Rust
+///
+/// References and pointers use the name of the type they point to,
+/// and generic arguments are appended, e.g. `&QList<QString>` is `qlist_qstring`.
+fn type_to_new_name(ty: &Type) -> Result<String> {
+    match ty {
+        Type::Path(type_path) => {
+            let segment = type_path
+                .path
+                .segments
+                .last()
+                .ok_or_else(|| Error::new_spanned(ty, "Expected a type with a name"))?;
+            let mut names = vec![segment.ident.to_string().to_lowercase()];
+            if let PathArguments::AngleBracketed(arguments) = &segment.arguments {
+                for argument in &arguments.args {
+                    if let GenericArgument::Type(ty) = argument {
+                        names.push(type_to_new_name(ty)?);
+                    }
+                }
+            }
+            Ok(names.join("_"))
+        }
+        Type::Reference(type_ref) => type_to_new_name(&type_ref.elem),
+        Type::Ptr(type_ptr) => type_to_new_name(&type_ptr.elem),
+        _ => Err(Error::new_spanned(
+            ty,
+            "Cannot name the function which creates the QObject from Rust with this constructor argument",
+        )),
+    }
+}
+
+/// The name of the function which creates the QObject from Rust with the given constructor arguments
+///
+/// A constructor without arguments is called `new`, otherwise the names of the argument types
+/// are appended, e.g. `(i32, &QString)` is `new_i32_qstring`.
+fn new_function_name(arguments: &[Type]) -> Result<String> {
+    let mut names = vec!["new".to_owned()];
+    for argument in arguments {
+        names.push(type_to_new_name(argument)?);
+    }
+    Ok(names.join("_"))
+}
+
+fn generate_new_functions(
+    index: usize,
+    arguments: &[Type],
+    lifetime: &Option<TokenStream>,
+    safety: &Option<TokenStream>,
+    qobject_idents: &QObjectName,
+    qualified_mappings: &BTreeMap<Ident, Path>,
+    module_ident: &Ident,
+) -> Result<GeneratedRustQObject> {
+    let cpp_struct_ident = &qobject_idents.cpp_class.rust;
+    let qualified_impl =
+        syn_ident_cxx_bridge_to_qualified_impl(cpp_struct_ident, qualified_mappings);
+
+    let new_name = new_function_name(arguments)?;
+    let new_ident = format_ident!("{new_name}");
+    let new_with_parent_ident = format_ident!("{new_name}_with_parent");
+    let new_ffi_ident = qobject_idents.cxx_qt_ffi_method(&format!("new_{index}"));
+    let new_with_parent_ffi_ident =
+        qobject_idents.cxx_qt_ffi_method(&format!("new_with_parent_{index}"));
+
+    let parameters = argument_members(arguments);
+    let parameters_qualified = map_types(arguments, |(index, ty)| {
+        let arg_name = format_ident!("arg{index}");
+        let ty = syn_type_cxx_bridge_to_qualified(ty, qualified_mappings);
+        quote! {
+            #arg_name: #ty
+        }
+    });
+    let argument_names = map_types(arguments, |(index, _ty)| {
+        let arg_name = format_ident!("arg{index}");
+        quote! { #arg_name }
+    });
+
+    Ok(GeneratedRustQObject {
+        cxx_mod_contents: vec![parse_quote! {
+            unsafe extern "C++" {
+                #[cxx_name = "qobjectNew"]
+                #[namespace = "rust::cxxqtlib1"]
+                #[doc(hidden)]
+                #safety fn #new_ffi_ident #lifetime(#(#parameters),*) -> UniquePtr<#cpp_struct_ident>;
+
+                #[cxx_name = "qobjectNewWithParent"]
+                #[namespace = "rust::cxxqtlib1"]
+                #[doc(hidden)]
+                #safety fn #new_with_parent_ffi_ident #lifetime(parent: Pin<&mut CxxQtQObject>, #(#parameters),*) -> *mut #cpp_struct_ident;
+            }
+        }],
+        cxx_qt_mod_contents: vec![parse_quote! {
+            impl #qualified_impl {
+                #[doc = "Create a new instance of the QObject which is owned by the returned UniquePtr"]
+                pub #safety fn #new_ident #lifetime(#(#parameters_qualified),*) -> cxx::UniquePtr<Self> {
+                    #module_ident::#new_ffi_ident(#(#argument_names),*)
+                }
+
+                #[doc = "Create a new instance of the QObject which is owned by the given parent"]
+                pub #safety fn #new_with_parent_ident #lifetime(parent: core::pin::Pin<&mut cxx_qt_lib::QObject>, #(#parameters_qualified),*) -> *mut Self {
+                    #module_ident::#new_with_parent_ffi_ident(parent, #(#argument_names),*)
+                }
+            }
+        }],
+    })
+}
+
 fn generate_arguments_struct(
     namespace_internals: &str,
     struct_name: &CombinedIdent,
@@ -187,9 +293,23 @@ pub fn generate(
     module_ident: &Ident,
 ) -> Result<GeneratedRustQObject> {
     if constructors.is_empty() {
-        return Ok(generate_default_constructor(qobject_idents, namespace));
+        let mut result = generate_default_constructor(qobject_idents, namespace);
+        result.append(&mut generate_new_functions(
+            0,
+            &[],
+            &None,
+            &None,
+            qobject_idents,
+            qualified_mappings,
+            module_ident,
+        )?);
+        return Ok(result);
     }
 
+    // The functions to create the QObject from Rust are appended after all other constructor items
+    let mut new_functions = GeneratedRustQObject::default();
+    let mut new_names = BTreeSet::new();
+
     let mut result = GeneratedRustQObject::default();
     let namespace_internals = &namespace.internal;
 
@@ -435,8 +555,26 @@ pub fn generate(
                     qobject,
                     (#(#extract_initialize_arguments,)*));
             }
-        }])
+        }]);
+
+        let new_name = new_function_name(&constructor.arguments)?;
+        if !new_names.insert(new_name.clone()) {
+            return Err(Error::new_spanned(
+                &constructor.imp,
+                format!("Another constructor already generates the `{new_name}` function, the argument types of constructors must have different names"),
+            ));
+        }
+        new_functions.append(&mut generate_new_functions(
+            index,
+            &constructor.arguments,
+            &arguments_lifetime,
+            &route_arguments_safety,
+            qobject_idents,
+            qualified_mappings,
+            module_ident,
+        )?);
     }
+    result.append(&mut new_functions);
     Ok(result)
 }
 
@@ -482,8 +620,8 @@ mod tests {
     fn default_constructor() {
         let blocks = generate_mocked(&[]);
 
-        assert_eq!(blocks.cxx_mod_contents.len(), 1);
-        assert_eq!(blocks.cxx_qt_mod_contents.len(), 1);
+        assert_eq!(blocks.cxx_mod_contents.len(), 2);
+        assert_eq!(blocks.cxx_qt_mod_contents.len(), 2);
 
         assert_tokens_eq(
             &blocks.cxx_mod_contents[0],
@@ -505,6 +643,38 @@ mod tests {
                 }
             },
         );
+        assert_tokens_eq(
+            &blocks.cxx_mod_contents[1],
+            quote! {
+                unsafe extern "C++" {
+                    #[cxx_name = "qobjectNew"]
+                    #[namespace = "rust::cxxqtlib1"]
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_my_object_new_0() -> UniquePtr<MyObject>;
+
+                    #[cxx_name = "qobjectNewWithParent"]
+                    #[namespace = "rust::cxxqtlib1"]
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_my_object_new_with_parent_0(parent: Pin<&mut CxxQtQObject>, ) -> *mut MyObject;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &blocks.cxx_qt_mod_contents[1],
+            quote! {
+                impl MyObject {
+                    #[doc = "Create a new instance of the QObject which is owned by the returned UniquePtr"]
+                    pub fn new() -> cxx::UniquePtr<Self> {
+                        ffi::cxx_qt_ffi_my_object_new_0()
+                    }
+
+                    #[doc = "Create a new instance of the QObject which is owned by the given parent"]
+                    pub fn new_with_parent(parent: core::pin::Pin<&mut cxx_qt_lib::QObject>, ) -> *mut Self {
+                        ffi::cxx_qt_ffi_my_object_new_with_parent_0(parent, )
+                    }
+                }
+            },
+        );
     }
 
     fn assert_empty_argument_struct<T: quote::ToTokens>(
@@ -760,6 +930,75 @@ mod tests {
         );
     }
 
+    fn assert_new_functions_blocks(blocks: &GeneratedRustQObject) {
+        // The functions to create the QObject from Rust are after the other constructor blocks
+        assert_tokens_eq(
+            &blocks.cxx_mod_contents[10],
+            quote! {
+                unsafe extern "C++" {
+                    #[cxx_name = "qobjectNew"]
+                    #[namespace = "rust::cxxqtlib1"]
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_my_object_new_0() -> UniquePtr<MyObject>;
+
+                    #[cxx_name = "qobjectNewWithParent"]
+                    #[namespace = "rust::cxxqtlib1"]
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_my_object_new_with_parent_0(parent: Pin<&mut CxxQtQObject>, ) -> *mut MyObject;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &blocks.cxx_mod_contents[11],
+            quote! {
+                unsafe extern "C++" {
+                    #[cxx_name = "qobjectNew"]
+                    #[namespace = "rust::cxxqtlib1"]
+                    #[doc(hidden)]
+                    unsafe fn cxx_qt_ffi_my_object_new_1(arg0: *const QObject) -> UniquePtr<MyObject>;
+
+                    #[cxx_name = "qobjectNewWithParent"]
+                    #[namespace = "rust::cxxqtlib1"]
+                    #[doc(hidden)]
+                    unsafe fn cxx_qt_ffi_my_object_new_with_parent_1(parent: Pin<&mut CxxQtQObject>, arg0: *const QObject) -> *mut MyObject;
+                }
+            },
+        );
+
+        assert_tokens_eq(
+            &blocks.cxx_qt_mod_contents[6],
+            quote! {
+                impl MyObject {
+                    #[doc = "Create a new instance of the QObject which is owned by the returned UniquePtr"]
+                    pub fn new() -> cxx::UniquePtr<Self> {
+                        ffi::cxx_qt_ffi_my_object_new_0()
+                    }
+
+                    #[doc = "Create a new instance of the QObject which is owned by the given parent"]
+                    pub fn new_with_parent(parent: core::pin::Pin<&mut cxx_qt_lib::QObject>, ) -> *mut Self {
+                        ffi::cxx_qt_ffi_my_object_new_with_parent_0(parent, )
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &blocks.cxx_qt_mod_contents[7],
+            quote! {
+                impl MyObject {
+                    #[doc = "Create a new instance of the QObject which is owned by the returned UniquePtr"]
+                    pub unsafe fn new_qobject(arg0: *const QObject) -> cxx::UniquePtr<Self> {
+                        ffi::cxx_qt_ffi_my_object_new_1(arg0)
+                    }
+
+                    #[doc = "Create a new instance of the QObject which is owned by the given parent"]
+                    pub unsafe fn new_qobject_with_parent(parent: core::pin::Pin<&mut cxx_qt_lib::QObject>, arg0: *const QObject) -> *mut Self {
+                        ffi::cxx_qt_ffi_my_object_new_with_parent_1(parent, arg0)
+                    }
+                }
+            },
+        );
+    }
+
     #[test]
     fn multiple_constructors() {
         let blocks = generate_mocked(&[
@@ -781,8 +1020,8 @@ mod tests {
             },
         ]);
 
-        assert_eq!(blocks.cxx_mod_contents.len(), 10);
-        assert_eq!(blocks.cxx_qt_mod_contents.len(), 6);
+        assert_eq!(blocks.cxx_mod_contents.len(), 12);
+        assert_eq!(blocks.cxx_qt_mod_contents.len(), 8);
 
         let namespace_attr = quote! {
                 #[namespace = "ffi::cxx_qt_my_object"]
@@ -791,6 +1030,8 @@ mod tests {
         assert_empty_constructor_blocks(&blocks, &namespace_attr);
 
         assert_full_constructor_blocks(&blocks, &namespace_attr);
+
+        assert_new_functions_blocks(&blocks);
     }
 
     #[test]
@@ -806,6 +1047,45 @@ mod tests {
             &format_ident!("ffi"),
         );
 
+        assert!(result.is_err());
+    }
+    #[test]
+    fn constructor_new_function_names() {
+        assert_eq!(new_function_name(&[]).unwrap(), "new");
+        assert_eq!(
+            new_function_name(&[parse_quote! { i32 }, parse_quote! { &'a QString }]).unwrap(),
+            "new_i32_qstring"
+        );
+        assert_eq!(
+            new_function_name(&[
+                parse_quote! { *mut QObject },
+                parse_quote! { &cxx_qt_lib::QList<QString> }
+            ])
+            .unwrap(),
+            "new_qobject_qlist_qstring"
+        );
+        assert!(new_function_name(&[parse_quote! { (i32, i32) }]).is_err());
+    }
+
+    #[test]
+    fn constructors_with_same_new_function_name() {
+        let result = super::generate(
+            &[
+                Constructor {
+                    arguments: vec![parse_quote! { i32 }],
+                    ..mock_constructor()
+                },
+                Constructor {
+                    arguments: vec![parse_quote! { &i32 }],
+                    ..mock_constructor()
+                },
+            ],
+            &mock_name(),
+            &mock_namespace(),
+            &BTreeMap::<Ident, Path>::default(),
+            &format_ident!("ffi"),
+        );
+
         assert!(result.is_err());
     }
 }
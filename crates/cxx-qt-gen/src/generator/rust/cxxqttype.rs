@@ -17,6 +17,7 @@ use super::fragment::RustFragmentPair;
 pub fn generate(
     qobject_ident: &QObjectName,
     qualified_mappings: &BTreeMap<Ident, Path>,
+    module_ident: &Ident,
 ) -> Result<GeneratedRustQObject> {
     let mut blocks = GeneratedRustQObject::default();
 
@@ -24,6 +25,8 @@ pub fn generate(
     let rust_struct_ident = &qobject_ident.rust_struct.rust;
     let qualified_impl =
         syn_ident_cxx_bridge_to_qualified_impl(cpp_struct_ident, qualified_mappings);
+    let upcast_ident = qobject_ident.cxx_qt_ffi_method("upcast");
+    let upcast_mut_ident = qobject_ident.cxx_qt_ffi_method("upcast_mut");
 
     let fragment = RustFragmentPair {
         cxx_bridge: vec![
@@ -41,6 +44,19 @@ pub fn generate(
                     fn cxx_qt_ffi_rust_mut(self: Pin<&mut #cpp_struct_ident>) -> Pin<&mut #rust_struct_ident>;
                 }
             },
+            quote! {
+                unsafe extern "C++" {
+                    #[cxx_name = "upcastQObject"]
+                    #[namespace = "rust::cxxqtlib1"]
+                    #[doc(hidden)]
+                    fn #upcast_ident(object: &#cpp_struct_ident) -> &CxxQtQObject;
+
+                    #[cxx_name = "upcastQObjectMut"]
+                    #[namespace = "rust::cxxqtlib1"]
+                    #[doc(hidden)]
+                    fn #upcast_mut_ident(object: Pin<&mut #cpp_struct_ident>) -> Pin<&mut CxxQtQObject>;
+                }
+            },
         ],
         implementation: vec![
             quote! {
@@ -65,6 +81,17 @@ pub fn generate(
                     }
                 }
             },
+            quote! {
+                impl cxx_qt_lib::Upcast<cxx_qt_lib::QObject> for #qualified_impl {
+                    fn upcast(&self) -> &cxx_qt_lib::QObject {
+                        #module_ident::#upcast_ident(self)
+                    }
+
+                    fn upcast_pin(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut cxx_qt_lib::QObject> {
+                        #module_ident::#upcast_mut_ident(self)
+                    }
+                }
+            },
         ],
     };
 
@@ -83,6 +110,7 @@ mod tests {
     use super::*;
 
     use crate::tests::assert_tokens_eq;
+    use quote::format_ident;
 
     use crate::parser::qobject::tests::create_parsed_qobject;
 
@@ -91,10 +119,15 @@ mod tests {
         let qobject = create_parsed_qobject();
         let qobject_idents = QObjectName::from(&qobject);
 
-        let generated = generate(&qobject_idents, &BTreeMap::<Ident, Path>::default()).unwrap();
+        let generated = generate(
+            &qobject_idents,
+            &BTreeMap::<Ident, Path>::default(),
+            &format_ident!("ffi"),
+        )
+        .unwrap();
 
-        assert_eq!(generated.cxx_mod_contents.len(), 2);
-        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+        assert_eq!(generated.cxx_mod_contents.len(), 3);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 3);
 
         // CXX bridges
 
@@ -118,6 +151,22 @@ mod tests {
                 }
             },
         );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[2],
+            quote! {
+                unsafe extern "C++" {
+                    #[cxx_name = "upcastQObject"]
+                    #[namespace = "rust::cxxqtlib1"]
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_my_object_upcast(object: &MyObject) -> &CxxQtQObject;
+
+                    #[cxx_name = "upcastQObjectMut"]
+                    #[namespace = "rust::cxxqtlib1"]
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_my_object_upcast_mut(object: Pin<&mut MyObject>) -> Pin<&mut CxxQtQObject>;
+                }
+            },
+        );
 
         // CXX-Qt generated contents
         assert_tokens_eq(
@@ -148,5 +197,19 @@ mod tests {
                 }
             },
         );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[2],
+            quote! {
+                impl cxx_qt_lib::Upcast<cxx_qt_lib::QObject> for MyObject {
+                    fn upcast(&self) -> &cxx_qt_lib::QObject {
+                        ffi::cxx_qt_ffi_my_object_upcast(self)
+                    }
+
+                    fn upcast_pin(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut cxx_qt_lib::QObject> {
+                        ffi::cxx_qt_ffi_my_object_upcast_mut(self)
+                    }
+                }
+            },
+        );
     }
 }
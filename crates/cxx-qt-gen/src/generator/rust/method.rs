@@ -3,20 +3,96 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use std::collections::BTreeMap;
+
 use crate::{
     generator::{
-        naming::{method::QMethodName, qobject::QObjectName},
+        naming::{method::QMethodName, namespace::NamespaceName, qobject::QObjectName},
         rust::{fragment::RustFragmentPair, qobject::GeneratedRustQObject},
+        utils::rust::{syn_ident_cxx_bridge_to_qualified_impl, syn_type_cxx_bridge_to_qualified},
     },
     parser::method::ParsedMethod,
 };
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::Result;
+use syn::{Ident, Path, Result, ReturnType};
+
+/// Generate the wrapper of a static method, as there is no self this is a free function
+fn generate_static_method(
+    invokable: &ParsedMethod,
+    idents: &QMethodName,
+    qobject_idents: &QObjectName,
+    namespace_idents: &NamespaceName,
+    qualified_mappings: &BTreeMap<Ident, Path>,
+) -> RustFragmentPair {
+    let wrapper_ident_cpp = idents.wrapper.cpp.to_string();
+    let wrapper_ident_rust = qobject_idents.cxx_qt_ffi_method(&idents.wrapper.rust.to_string());
+    let invokable_ident_rust = &idents.name.rust;
+    let namespace_internals = &namespace_idents.internal;
+    let qualified_impl =
+        syn_ident_cxx_bridge_to_qualified_impl(&qobject_idents.cpp_class.rust, qualified_mappings);
+
+    let parameter_idents = invokable
+        .parameters
+        .iter()
+        .map(|parameter| &parameter.ident)
+        .collect::<Vec<&Ident>>();
+    let parameters_cxx = invokable
+        .parameters
+        .iter()
+        .map(|parameter| {
+            let ident = &parameter.ident;
+            let ty = &parameter.ty;
+            quote! { #ident: #ty }
+        })
+        .collect::<Vec<TokenStream>>();
+    let parameters_qualified = invokable
+        .parameters
+        .iter()
+        .map(|parameter| {
+            let ident = &parameter.ident;
+            let ty = syn_type_cxx_bridge_to_qualified(&parameter.ty, qualified_mappings);
+            quote! { #ident: #ty }
+        })
+        .collect::<Vec<TokenStream>>();
+
+    let return_type = &invokable.method.sig.output;
+    let return_type_qualified = if let ReturnType::Type(arrow, ty) = return_type {
+        let ty = syn_type_cxx_bridge_to_qualified(ty, qualified_mappings);
+        quote! { #arrow #ty }
+    } else {
+        quote! {}
+    };
+
+    let unsafe_call = if invokable.safe {
+        None
+    } else {
+        Some(quote! { unsafe })
+    };
+
+    RustFragmentPair {
+        cxx_bridge: vec![quote! {
+            extern "Rust" {
+                #[doc(hidden)]
+                #[cxx_name = #wrapper_ident_cpp]
+                #[namespace = #namespace_internals]
+                #unsafe_call fn #wrapper_ident_rust(#(#parameters_cxx),*) #return_type;
+            }
+        }],
+        implementation: vec![quote! {
+            #[doc(hidden)]
+            pub #unsafe_call fn #wrapper_ident_rust(#(#parameters_qualified),*) #return_type_qualified {
+                #qualified_impl::#invokable_ident_rust(#(#parameter_idents),*)
+            }
+        }],
+    }
+}
 
 pub fn generate_rust_methods(
     invokables: &Vec<ParsedMethod>,
     qobject_idents: &QObjectName,
+    namespace_idents: &NamespaceName,
+    qualified_mappings: &BTreeMap<Ident, Path>,
 ) -> Result<GeneratedRustQObject> {
     let mut generated = GeneratedRustQObject::default();
     let cpp_class_name_rust = &qobject_idents.cpp_class.rust;
@@ -26,6 +102,23 @@ pub fn generate_rust_methods(
         let wrapper_ident_cpp = idents.wrapper.cpp.to_string();
         let invokable_ident_rust = &idents.name.rust;
 
+        if invokable.is_static {
+            let fragment = generate_static_method(
+                invokable,
+                &idents,
+                qobject_idents,
+                namespace_idents,
+                qualified_mappings,
+            );
+            generated
+                .cxx_mod_contents
+                .append(&mut fragment.cxx_bridge_as_items()?);
+            generated
+                .cxx_qt_mod_contents
+                .append(&mut fragment.implementation_as_items()?);
+            continue;
+        }
+
         // TODO: once we aren't using qobject::T in the extern "RustQt"
         // we can just pass through the original ExternFn block and add the attribute?
         let cpp_struct = if invokable.mutable {
@@ -86,7 +179,7 @@ pub fn generate_rust_methods(
 mod tests {
     use super::*;
 
-    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::generator::naming::{qobject::tests::create_qobjectname, CombinedIdent};
     use crate::parser::parameter::ParsedFunctionParameter;
     use crate::tests::assert_tokens_eq;
     use quote::format_ident;
@@ -104,6 +197,10 @@ mod tests {
                 parameters: vec![],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                revision: None,
+                ident: CombinedIdent::from_rust_function(format_ident!("void_invokable")),
+                is_static: false,
+                qjs_error: None,
             },
             ParsedMethod {
                 method: parse_quote! { fn trivial_invokable(self: &MyObject, param: i32) -> i32; },
@@ -116,6 +213,10 @@ mod tests {
                 }],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                revision: None,
+                ident: CombinedIdent::from_rust_function(format_ident!("trivial_invokable")),
+                is_static: false,
+                qjs_error: None,
             },
             ParsedMethod {
                 method: parse_quote! { fn opaque_invokable(self: Pin<&mut MyObject>, param: &QColor) -> UniquePtr<QColor>; },
@@ -128,6 +229,10 @@ mod tests {
                 }],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                revision: None,
+                ident: CombinedIdent::from_rust_function(format_ident!("opaque_invokable")),
+                is_static: false,
+                qjs_error: None,
             },
             ParsedMethod {
                 method: parse_quote! { unsafe fn unsafe_invokable(self: &MyObject, param: *mut T) -> *mut T; },
@@ -140,11 +245,21 @@ mod tests {
                 }],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                revision: None,
+                ident: CombinedIdent::from_rust_function(format_ident!("unsafe_invokable")),
+                is_static: false,
+                qjs_error: None,
             },
         ];
         let qobject_idents = create_qobjectname();
 
-        let generated = generate_rust_methods(&invokables, &qobject_idents).unwrap();
+        let generated = generate_rust_methods(
+            &invokables,
+            &qobject_idents,
+            &NamespaceName::from_pair_str("cxx_qt", &format_ident!("MyObject")),
+            &BTreeMap::<Ident, Path>::default(),
+        )
+        .unwrap();
 
         assert_eq!(generated.cxx_mod_contents.len(), 4);
         assert_eq!(generated.cxx_qt_mod_contents.len(), 0);
@@ -197,4 +312,62 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_generate_rust_invokables_static() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn create_with(value: i32) -> UniquePtr<MyObject>; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![ParsedFunctionParameter {
+                ident: format_ident!("value"),
+                ty: parse_quote! { i32 },
+            }],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            revision: None,
+            ident: CombinedIdent::from_rust_function(format_ident!("create_with")),
+            is_static: true,
+            qjs_error: None,
+        }];
+        let qobject_idents = create_qobjectname();
+        let mut qualified_mappings = BTreeMap::<Ident, Path>::default();
+        qualified_mappings.insert(
+            format_ident!("MyObject"),
+            parse_quote! { qobject::MyObject },
+        );
+
+        let generated = generate_rust_methods(
+            &invokables,
+            &qobject_idents,
+            &NamespaceName::from_pair_str("cxx_qt", &format_ident!("MyObject")),
+            &qualified_mappings,
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[doc(hidden)]
+                    #[cxx_name = "createWithWrapper"]
+                    #[namespace = "cxx_qt::cxx_qt_my_object"]
+                    fn cxx_qt_ffi_my_object_create_with_wrapper(value: i32) -> UniquePtr<MyObject>;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                #[doc(hidden)]
+                pub fn cxx_qt_ffi_my_object_create_with_wrapper(value: i32) -> cxx::UniquePtr<qobject::MyObject> {
+                    qobject::MyObject::create_with(value)
+                }
+            },
+        );
+    }
 }
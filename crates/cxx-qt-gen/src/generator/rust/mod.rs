@@ -10,6 +10,8 @@ pub mod inherit;
 pub mod method;
 pub mod property;
 pub mod qenum;
+pub mod qmlattached;
+pub mod qmlsingleton;
 pub mod qobject;
 pub mod signals;
 pub mod threading;
@@ -0,0 +1,113 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::{
+    naming::{property::QPropertyName, qobject::QObjectName},
+    rust::fragment::RustFragmentPair,
+    utils::rust::syn_type_is_cxx_bridge_unsafe,
+};
+use quote::quote;
+use syn::Type;
+
+pub fn generate(
+    idents: &QPropertyName,
+    qobject_idents: &QObjectName,
+    cxx_ty: &Type,
+) -> RustFragmentPair {
+    let cpp_class_name_rust = &qobject_idents.cpp_class.rust;
+    let getter_cpp = idents.getter.cpp.to_string();
+    let getter_rust = &idents.getter.rust;
+    let setter_cpp = idents.setter.cpp.to_string();
+    let setter_rust = &idents.setter.rust;
+    let set_binding_cpp = idents.set_binding.cpp.to_string();
+    let set_binding_rust = &idents.set_binding.rust;
+    let ident_str = idents.name.rust.to_string();
+
+    // Determine if unsafe is required due to an unsafe type
+    let has_unsafe = if syn_type_is_cxx_bridge_unsafe(cxx_ty) {
+        quote! { unsafe }
+    } else {
+        quote! {}
+    };
+
+    // The value of a bindable property is stored in C++,
+    // so the getter and setters call the C++ methods.
+    RustFragmentPair {
+        cxx_bridge: vec![quote! {
+            unsafe extern "C++" {
+                #[doc = "Getter for the bindable Q_PROPERTY "]
+                #[doc = #ident_str]
+                #[doc = "\n"]
+                #[doc = "If the property has a binding, then the binding is evaluated when it is out of date."]
+                #[cxx_name = #getter_cpp]
+                #has_unsafe fn #getter_rust(self: &#cpp_class_name_rust) -> #cxx_ty;
+
+                #[doc = "Setter for the bindable Q_PROPERTY "]
+                #[doc = #ident_str]
+                #[doc = "\n"]
+                #[doc = "This removes any binding of the property."]
+                #[cxx_name = #setter_cpp]
+                #has_unsafe fn #setter_rust(self: Pin<&mut #cpp_class_name_rust>, value: #cxx_ty);
+
+                #[doc = "Set a binding for the bindable Q_PROPERTY "]
+                #[doc = #ident_str]
+                #[doc = "\n"]
+                #[doc = "The value of the property is then computed by the given function, any bindable properties read in the function are dependencies of the binding."]
+                #[cxx_name = #set_binding_cpp]
+                #has_unsafe fn #set_binding_rust(self: Pin<&mut #cpp_class_name_rust>, func: fn(&#cpp_class_name_rust) -> #cxx_ty);
+            }
+        }],
+        implementation: vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{generator::naming::qobject::tests::create_qobjectname, tests::assert_tokens_eq};
+    use quote::format_ident;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_rust_bindable_property() {
+        let generated = generate(
+            &QPropertyName::from(&format_ident!("total")),
+            &create_qobjectname(),
+            &parse_quote! { i32 },
+        );
+
+        assert_eq!(generated.cxx_bridge.len(), 1);
+        assert!(generated.implementation.is_empty());
+
+        assert_tokens_eq(
+            &generated.cxx_bridge_as_items().unwrap()[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc = "Getter for the bindable Q_PROPERTY "]
+                    #[doc = "total"]
+                    #[doc = "\n"]
+                    #[doc = "If the property has a binding, then the binding is evaluated when it is out of date."]
+                    #[cxx_name = "getTotal"]
+                    fn total(self: &MyObject) -> i32;
+
+                    #[doc = "Setter for the bindable Q_PROPERTY "]
+                    #[doc = "total"]
+                    #[doc = "\n"]
+                    #[doc = "This removes any binding of the property."]
+                    #[cxx_name = "setTotal"]
+                    fn set_total(self: Pin<&mut MyObject>, value: i32);
+
+                    #[doc = "Set a binding for the bindable Q_PROPERTY "]
+                    #[doc = "total"]
+                    #[doc = "\n"]
+                    #[doc = "The value of the property is then computed by the given function, any bindable properties read in the function are dependencies of the binding."]
+                    #[cxx_name = "setTotalBinding"]
+                    fn set_total_binding(self: Pin<&mut MyObject>, func: fn(&MyObject) -> i32);
+                }
+            },
+        );
+    }
+}
@@ -0,0 +1,258 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::{
+    naming::{
+        property::{QListPropertyName, QPropertyName},
+        qobject::QObjectName,
+    },
+    rust::fragment::RustFragmentPair,
+    utils::rust::{syn_ident_cxx_bridge_to_qualified_impl, syn_type_cxx_bridge_to_qualified},
+};
+use quote::quote;
+use std::collections::BTreeMap;
+use syn::{Ident, Path, Type};
+
+pub fn generate(
+    idents: &QPropertyName,
+    list_idents: &QListPropertyName,
+    qobject_idents: &QObjectName,
+    element_ty: &Type,
+    qualified_mappings: &BTreeMap<Ident, Path>,
+) -> RustFragmentPair {
+    let cpp_class_name_rust = &qobject_idents.cpp_class.rust;
+    let ident = &idents.name.rust;
+    let ident_str = ident.to_string();
+    let getter_rust = &idents.getter.rust;
+    let notify_ident = &idents.notify.rust;
+    let qualified_element_ty = syn_type_cxx_bridge_to_qualified(element_ty, qualified_mappings);
+    let qualified_impl =
+        syn_ident_cxx_bridge_to_qualified_impl(cpp_class_name_rust, qualified_mappings);
+
+    let append_rust = &list_idents.append.rust;
+    let append_wrapper_cpp = list_idents.append_wrapper.cpp.to_string();
+    let count_rust = &list_idents.count.rust;
+    let count_wrapper_cpp = list_idents.count_wrapper.cpp.to_string();
+    let at_rust = &list_idents.at.rust;
+    let at_wrapper_cpp = list_idents.at_wrapper.cpp.to_string();
+    let clear_rust = &list_idents.clear.rust;
+    let clear_wrapper_cpp = list_idents.clear_wrapper.cpp.to_string();
+    let replace_rust = &list_idents.replace.rust;
+    let replace_wrapper_cpp = list_idents.replace_wrapper.cpp.to_string();
+    let remove_last_rust = &list_idents.remove_last.rust;
+    let remove_last_wrapper_cpp = list_idents.remove_last_wrapper.cpp.to_string();
+
+    RustFragmentPair {
+        cxx_bridge: vec![quote! {
+            extern "Rust" {
+                #[cxx_name = #append_wrapper_cpp]
+                unsafe fn #append_rust(self: Pin<&mut #cpp_class_name_rust>, item: *mut #element_ty);
+
+                #[cxx_name = #count_wrapper_cpp]
+                fn #count_rust(self: &#cpp_class_name_rust) -> isize;
+
+                #[cxx_name = #at_wrapper_cpp]
+                fn #at_rust(self: &#cpp_class_name_rust, index: isize) -> *mut #element_ty;
+
+                #[cxx_name = #clear_wrapper_cpp]
+                fn #clear_rust(self: Pin<&mut #cpp_class_name_rust>);
+
+                #[cxx_name = #replace_wrapper_cpp]
+                unsafe fn #replace_rust(self: Pin<&mut #cpp_class_name_rust>, index: isize, item: *mut #element_ty);
+
+                #[cxx_name = #remove_last_wrapper_cpp]
+                fn #remove_last_rust(self: Pin<&mut #cpp_class_name_rust>);
+            }
+        }],
+        implementation: vec![quote! {
+            impl #qualified_impl {
+                #[doc = "Getter for the items of the QQmlListProperty "]
+                #[doc = #ident_str]
+                pub fn #getter_rust(&self) -> &Vec<*mut #qualified_element_ty> {
+                    &self.#ident
+                }
+
+                #[doc = "Append an item to the QQmlListProperty "]
+                #[doc = #ident_str]
+                pub fn #append_rust(mut self: core::pin::Pin<&mut Self>, item: *mut #qualified_element_ty) {
+                    use cxx_qt::CxxQtType;
+                    self.as_mut().rust_mut().#ident.push(item);
+                    self.as_mut().#notify_ident();
+                }
+
+                #[doc = "The number of items in the QQmlListProperty "]
+                #[doc = #ident_str]
+                pub fn #count_rust(&self) -> isize {
+                    self.#ident.len() as isize
+                }
+
+                #[doc = "The item at the given index of the QQmlListProperty "]
+                #[doc = #ident_str]
+                #[doc = ", this is null if the index is out of bounds"]
+                pub fn #at_rust(&self, index: isize) -> *mut #qualified_element_ty {
+                    usize::try_from(index)
+                        .ok()
+                        .and_then(|index| self.#ident.get(index))
+                        .copied()
+                        .unwrap_or(core::ptr::null_mut())
+                }
+
+                #[doc = "Remove all of the items from the QQmlListProperty "]
+                #[doc = #ident_str]
+                pub fn #clear_rust(mut self: core::pin::Pin<&mut Self>) {
+                    use cxx_qt::CxxQtType;
+                    if self.#ident.is_empty() {
+                        return;
+                    }
+                    self.as_mut().rust_mut().#ident.clear();
+                    self.as_mut().#notify_ident();
+                }
+
+                #[doc = "Replace the item at the given index of the QQmlListProperty "]
+                #[doc = #ident_str]
+                pub fn #replace_rust(mut self: core::pin::Pin<&mut Self>, index: isize, item: *mut #qualified_element_ty) {
+                    use cxx_qt::CxxQtType;
+                    {
+                        let mut rust = self.as_mut().rust_mut();
+                        match usize::try_from(index).ok().and_then(|index| rust.#ident.get_mut(index)) {
+                            Some(existing) => *existing = item,
+                            None => return,
+                        }
+                    }
+                    self.as_mut().#notify_ident();
+                }
+
+                #[doc = "Remove the last item from the QQmlListProperty "]
+                #[doc = #ident_str]
+                pub fn #remove_last_rust(mut self: core::pin::Pin<&mut Self>) {
+                    use cxx_qt::CxxQtType;
+                    if self.as_mut().rust_mut().#ident.pop().is_some() {
+                        self.as_mut().#notify_ident();
+                    }
+                }
+            }
+        }],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{generator::naming::qobject::tests::create_qobjectname, tests::assert_tokens_eq};
+    use quote::format_ident;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_rust_list_property() {
+        let ident = format_ident!("items");
+        let generated = generate(
+            &QPropertyName::from(&ident),
+            &QListPropertyName::from(&ident),
+            &create_qobjectname(),
+            &parse_quote! { Child },
+            &BTreeMap::<Ident, Path>::default(),
+        );
+
+        assert_eq!(generated.cxx_bridge.len(), 1);
+        assert_eq!(generated.implementation.len(), 1);
+
+        assert_tokens_eq(
+            &generated.cxx_bridge[0],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "itemsAppendWrapper"]
+                    unsafe fn items_append(self: Pin<&mut MyObject>, item: *mut Child);
+
+                    #[cxx_name = "itemsCountWrapper"]
+                    fn items_count(self: &MyObject) -> isize;
+
+                    #[cxx_name = "itemsAtWrapper"]
+                    fn items_at(self: &MyObject, index: isize) -> *mut Child;
+
+                    #[cxx_name = "itemsClearWrapper"]
+                    fn items_clear(self: Pin<&mut MyObject>);
+
+                    #[cxx_name = "itemsReplaceWrapper"]
+                    unsafe fn items_replace(self: Pin<&mut MyObject>, index: isize, item: *mut Child);
+
+                    #[cxx_name = "itemsRemoveLastWrapper"]
+                    fn items_remove_last(self: Pin<&mut MyObject>);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.implementation[0],
+            quote! {
+                impl MyObject {
+                    #[doc = "Getter for the items of the QQmlListProperty "]
+                    #[doc = "items"]
+                    pub fn items(&self) -> &Vec<*mut Child> {
+                        &self.items
+                    }
+
+                    #[doc = "Append an item to the QQmlListProperty "]
+                    #[doc = "items"]
+                    pub fn items_append(mut self: core::pin::Pin<&mut Self>, item: *mut Child) {
+                        use cxx_qt::CxxQtType;
+                        self.as_mut().rust_mut().items.push(item);
+                        self.as_mut().items_changed();
+                    }
+
+                    #[doc = "The number of items in the QQmlListProperty "]
+                    #[doc = "items"]
+                    pub fn items_count(&self) -> isize {
+                        self.items.len() as isize
+                    }
+
+                    #[doc = "The item at the given index of the QQmlListProperty "]
+                    #[doc = "items"]
+                    #[doc = ", this is null if the index is out of bounds"]
+                    pub fn items_at(&self, index: isize) -> *mut Child {
+                        usize::try_from(index)
+                            .ok()
+                            .and_then(|index| self.items.get(index))
+                            .copied()
+                            .unwrap_or(core::ptr::null_mut())
+                    }
+
+                    #[doc = "Remove all of the items from the QQmlListProperty "]
+                    #[doc = "items"]
+                    pub fn items_clear(mut self: core::pin::Pin<&mut Self>) {
+                        use cxx_qt::CxxQtType;
+                        if self.items.is_empty() {
+                            return;
+                        }
+                        self.as_mut().rust_mut().items.clear();
+                        self.as_mut().items_changed();
+                    }
+
+                    #[doc = "Replace the item at the given index of the QQmlListProperty "]
+                    #[doc = "items"]
+                    pub fn items_replace(mut self: core::pin::Pin<&mut Self>, index: isize, item: *mut Child) {
+                        use cxx_qt::CxxQtType;
+                        {
+                            let mut rust = self.as_mut().rust_mut();
+                            match usize::try_from(index).ok().and_then(|index| rust.items.get_mut(index)) {
+                                Some(existing) => *existing = item,
+                                None => return,
+                            }
+                        }
+                        self.as_mut().items_changed();
+                    }
+
+                    #[doc = "Remove the last item from the QQmlListProperty "]
+                    #[doc = "items"]
+                    pub fn items_remove_last(mut self: core::pin::Pin<&mut Self>) {
+                        use cxx_qt::CxxQtType;
+                        if self.as_mut().rust_mut().items.pop().is_some() {
+                            self.as_mut().items_changed();
+                        }
+                    }
+                }
+            },
+        );
+    }
+}
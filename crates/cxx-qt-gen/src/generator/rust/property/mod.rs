@@ -3,16 +3,21 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+pub mod bindable;
 pub mod getter;
+pub mod list;
 pub mod setter;
 pub mod signal;
 
 use crate::{
     generator::{
-        naming::{property::QPropertyName, qobject::QObjectName},
+        naming::{
+            property::{QListPropertyName, QPropertyName},
+            qobject::QObjectName,
+        },
         rust::qobject::GeneratedRustQObject,
     },
-    parser::property::ParsedQProperty,
+    parser::property::{ParsedQListProperty, ParsedQProperty},
 };
 use std::collections::BTreeMap;
 use syn::{Ident, Path, Result};
@@ -30,23 +35,72 @@ pub fn generate_rust_properties(
     for property in properties {
         let idents = QPropertyName::from(property);
 
-        // Getters
-        let getter = getter::generate(&idents, qobject_idents, &property.ty, qualified_mappings);
-        generated
-            .cxx_mod_contents
-            .append(&mut getter.cxx_bridge_as_items()?);
-        generated
-            .cxx_qt_mod_contents
-            .append(&mut getter.implementation_as_items()?);
+        // Bindable properties are stored in C++ rather than in the Rust struct
+        if property.bindable {
+            let bindable = bindable::generate(&idents, qobject_idents, &property.ty);
+            generated
+                .cxx_mod_contents
+                .append(&mut bindable.cxx_bridge_as_items()?);
+        } else {
+            // Getters
+            let getter =
+                getter::generate(&idents, qobject_idents, &property.ty, qualified_mappings);
+            generated
+                .cxx_mod_contents
+                .append(&mut getter.cxx_bridge_as_items()?);
+            generated
+                .cxx_qt_mod_contents
+                .append(&mut getter.implementation_as_items()?);
 
-        // Setters
-        let setter = setter::generate(&idents, qobject_idents, &property.ty, qualified_mappings);
+            // Setters
+            let setter =
+                setter::generate(&idents, qobject_idents, &property.ty, qualified_mappings);
+            generated
+                .cxx_mod_contents
+                .append(&mut setter.cxx_bridge_as_items()?);
+            generated
+                .cxx_qt_mod_contents
+                .append(&mut setter.implementation_as_items()?);
+        }
+
+        // Signals
+        signals.push(signal::generate(&idents, qobject_idents));
+    }
+
+    generated.append(&mut generate_rust_signals(
+        &signals,
+        qobject_idents,
+        qualified_mappings,
+    )?);
+
+    Ok(generated)
+}
+
+pub fn generate_rust_list_properties(
+    list_properties: &[ParsedQListProperty],
+    qobject_idents: &QObjectName,
+    qualified_mappings: &BTreeMap<Ident, Path>,
+) -> Result<GeneratedRustQObject> {
+    let mut generated = GeneratedRustQObject::default();
+    let mut signals = vec![];
+
+    for list_property in list_properties {
+        let idents = QPropertyName::from(&list_property.ident);
+        let list_idents = QListPropertyName::from(&list_property.ident);
+
+        let list = list::generate(
+            &idents,
+            &list_idents,
+            qobject_idents,
+            &list_property.element_ty,
+            qualified_mappings,
+        );
         generated
             .cxx_mod_contents
-            .append(&mut setter.cxx_bridge_as_items()?);
+            .append(&mut list.cxx_bridge_as_items()?);
         generated
             .cxx_qt_mod_contents
-            .append(&mut setter.implementation_as_items()?);
+            .append(&mut list.implementation_as_items()?);
 
         // Signals
         signals.push(signal::generate(&idents, qobject_idents));
@@ -75,14 +129,20 @@ mod tests {
             ParsedQProperty {
                 ident: format_ident!("trivial_property"),
                 ty: parse_quote! { i32 },
+                revision: None,
+                bindable: false,
             },
             ParsedQProperty {
                 ident: format_ident!("opaque_property"),
                 ty: parse_quote! { UniquePtr<QColor> },
+                revision: None,
+                bindable: false,
             },
             ParsedQProperty {
                 ident: format_ident!("unsafe_property"),
                 ty: parse_quote! { *mut T },
+                revision: None,
+                bindable: false,
             },
         ];
         let qobject_idents = create_qobjectname();
@@ -382,4 +442,98 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_generate_rust_properties_bindable() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("total"),
+            ty: parse_quote! { i32 },
+            revision: None,
+            bindable: true,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_idents,
+            &BTreeMap::<Ident, Path>::default(),
+        )
+        .unwrap();
+
+        // The C++ methods of the property and then the signal and its connect,
+        // there are no Rust wrappers as the value is stored in C++
+        assert_eq!(generated.cxx_mod_contents.len(), 3);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            parse_quote! {
+                unsafe extern "C++" {
+                    #[doc = "Getter for the bindable Q_PROPERTY "]
+                    #[doc = "total"]
+                    #[doc = "\n"]
+                    #[doc = "If the property has a binding, then the binding is evaluated when it is out of date."]
+                    #[cxx_name = "getTotal"]
+                    fn total(self: &MyObject) -> i32;
+
+                    #[doc = "Setter for the bindable Q_PROPERTY "]
+                    #[doc = "total"]
+                    #[doc = "\n"]
+                    #[doc = "This removes any binding of the property."]
+                    #[cxx_name = "setTotal"]
+                    fn set_total(self: Pin<&mut MyObject>, value: i32);
+
+                    #[doc = "Set a binding for the bindable Q_PROPERTY "]
+                    #[doc = "total"]
+                    #[doc = "\n"]
+                    #[doc = "The value of the property is then computed by the given function, any bindable properties read in the function are dependencies of the binding."]
+                    #[cxx_name = "setTotalBinding"]
+                    fn set_total_binding(self: Pin<&mut MyObject>, func: fn(&MyObject) -> i32);
+                }
+            },
+        );
+
+        // Signals
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            parse_quote! {
+                unsafe extern "C++" {
+                    #[doc = "Notify for the Q_PROPERTY"]
+                    #[rust_name = "total_changed"]
+                    fn totalChanged(self: Pin<&mut MyObject>, );
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[2],
+            parse_quote! {
+                unsafe extern "C++" {
+                    #[doc = "Connect the given function pointer to the signal "]
+                    #[doc = "totalChanged"]
+                    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+                    #[must_use]
+                    #[rust_name = "connect_total_changed"]
+                    fn totalChangedConnect(self: Pin <&mut MyObject>, func: fn(Pin<&mut MyObject>, ), conn_type : CxxQtConnectionType) -> CxxQtQMetaObjectConnection;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            parse_quote! {
+                impl MyObject {
+                    #[doc = "Connect the given function pointer to the signal "]
+                    #[doc = "totalChanged"]
+                    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+                    #[doc = "\n"]
+                    #[doc = "Note that this method uses a AutoConnection connection type."]
+                    #[must_use]
+                    pub fn on_total_changed(self: core::pin::Pin<&mut MyObject>, func: fn(core::pin::Pin<&mut MyObject>, )) -> cxx_qt_lib::QMetaObjectConnection
+                    {
+                        self.connect_total_changed(func, cxx_qt_lib::ConnectionType::AutoConnection)
+                    }
+                }
+            },
+        );
+    }
 }
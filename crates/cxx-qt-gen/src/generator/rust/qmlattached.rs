@@ -0,0 +1,110 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::collections::BTreeMap;
+
+use crate::generator::{
+    naming::{namespace::NamespaceName, qobject::QObjectName},
+    rust::{fragment::RustFragmentPair, qobject::GeneratedRustQObject},
+    utils::rust::syn_ident_cxx_bridge_to_qualified_impl,
+};
+use convert_case::{Case, Casing};
+use quote::{format_ident, quote};
+use syn::{Ident, Path, Result};
+
+pub fn generate(
+    attached: &Ident,
+    qobject_idents: &QObjectName,
+    namespace_idents: &NamespaceName,
+    qualified_mappings: &BTreeMap<Ident, Path>,
+) -> Result<GeneratedRustQObject> {
+    let mut blocks = GeneratedRustQObject::default();
+
+    let cpp_struct_ident = &qobject_idents.cpp_class.rust;
+    let rust_struct_ident = &qobject_idents.rust_struct.rust;
+    let qualified_impl =
+        syn_ident_cxx_bridge_to_qualified_impl(cpp_struct_ident, qualified_mappings);
+    let attached_qualified = syn_ident_cxx_bridge_to_qualified_impl(attached, qualified_mappings);
+    let namespace_internals = &namespace_idents.internal;
+    let qml_attached_properties_rs_ident = format_ident!(
+        "qml_attached_properties_rs_{object_name}",
+        object_name = rust_struct_ident.to_string().to_case(Case::Snake)
+    );
+
+    let fragment = RustFragmentPair {
+        cxx_bridge: vec![quote! {
+            extern "Rust" {
+                #[cxx_name = "qmlAttachedPropertiesRs"]
+                #[namespace = #namespace_internals]
+                fn #qml_attached_properties_rs_ident(object: Pin<&mut CxxQtQObject>) -> *mut #attached;
+            }
+        }],
+        implementation: vec![quote! {
+            #[doc(hidden)]
+            pub fn #qml_attached_properties_rs_ident(object: core::pin::Pin<&mut cxx_qt_lib::QObject>) -> *mut #attached_qualified {
+                <#qualified_impl as cxx_qt::QmlAttached<cxx_qt_lib::QObject, #attached_qualified>>::qml_attached_properties(object)
+            }
+        }],
+    };
+
+    blocks
+        .cxx_mod_contents
+        .append(&mut fragment.cxx_bridge_as_items()?);
+    blocks
+        .cxx_qt_mod_contents
+        .append(&mut fragment.implementation_as_items()?);
+
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::tests::assert_tokens_eq;
+
+    #[test]
+    fn test_generate_rust_qmlattached() {
+        let qobject_idents = create_qobjectname();
+        let namespace_idents = NamespaceName::from_pair_str("cxx_qt", &format_ident!("MyObject"));
+
+        let generated = generate(
+            &format_ident!("MyAttached"),
+            &qobject_idents,
+            &namespace_idents,
+            &BTreeMap::<Ident, Path>::default(),
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        // CXX bridges
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "qmlAttachedPropertiesRs"]
+                    #[namespace = "cxx_qt::cxx_qt_my_object"]
+                    fn qml_attached_properties_rs_my_object_rust(object: Pin<&mut CxxQtQObject>) -> *mut MyAttached;
+                }
+            },
+        );
+
+        // Implementation
+
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                #[doc(hidden)]
+                pub fn qml_attached_properties_rs_my_object_rust(object: core::pin::Pin<&mut cxx_qt_lib::QObject>) -> *mut MyAttached {
+                    <MyObject as cxx_qt::QmlAttached<cxx_qt_lib::QObject, MyAttached> >::qml_attached_properties(object)
+                }
+            },
+        );
+    }
+}
@@ -0,0 +1,160 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::collections::BTreeMap;
+
+use crate::generator::{
+    naming::{namespace::NamespaceName, qobject::QObjectName},
+    rust::{fragment::RustFragmentPair, qobject::GeneratedRustQObject},
+    utils::rust::{syn_ident_cxx_bridge_to_qualified_impl, syn_type_cxx_bridge_to_qualified},
+};
+use convert_case::{Case, Casing};
+use quote::{format_ident, quote};
+use syn::{Ident, Path, Result, Type};
+
+pub fn generate(
+    engine: &Type,
+    qobject_idents: &QObjectName,
+    namespace_idents: &NamespaceName,
+    qualified_mappings: &BTreeMap<Ident, Path>,
+    module_ident: &Ident,
+) -> Result<GeneratedRustQObject> {
+    let mut blocks = GeneratedRustQObject::default();
+
+    let cpp_struct_ident = &qobject_idents.cpp_class.rust;
+    let rust_struct_ident = &qobject_idents.rust_struct.rust;
+    let qualified_impl =
+        syn_ident_cxx_bridge_to_qualified_impl(cpp_struct_ident, qualified_mappings);
+    let engine_qualified = syn_type_cxx_bridge_to_qualified(engine, qualified_mappings);
+    let namespace_internals = &namespace_idents.internal;
+    let create_singleton_rs_ident = format_ident!(
+        "create_singleton_rs_{object_name}",
+        object_name = rust_struct_ident.to_string().to_case(Case::Snake)
+    );
+    let singleton_instance_ident = qobject_idents.cxx_qt_ffi_method("singleton_instance");
+
+    let fragment = RustFragmentPair {
+        cxx_bridge: vec![
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "createSingletonRs"]
+                    #[namespace = #namespace_internals]
+                    fn #create_singleton_rs_ident(engine: Pin<&mut #engine>) -> Box<#rust_struct_ident>;
+                }
+            },
+            quote! {
+                unsafe extern "C++" {
+                    #[cxx_name = "qmlSingletonInstance"]
+                    #[namespace = "rust::cxxqtlib1"]
+                    #[doc(hidden)]
+                    fn #singleton_instance_ident(engine: &#engine) -> *mut #cpp_struct_ident;
+                }
+            },
+        ],
+        implementation: vec![
+            quote! {
+                #[doc(hidden)]
+                pub fn #create_singleton_rs_ident(engine: core::pin::Pin<&mut #engine_qualified>) -> std::boxed::Box<#rust_struct_ident> {
+                    std::boxed::Box::new(<#qualified_impl as cxx_qt::QmlSingletonFactory<#engine_qualified>>::create(engine))
+                }
+            },
+            quote! {
+                impl #qualified_impl {
+                    #[doc = "Retrieve the instance of the QML singleton which was created for the given engine"]
+                    #[doc = "\n"]
+                    #[doc = "This is null if the singleton has not been used in the engine yet"]
+                    pub fn singleton_instance(engine: &#engine_qualified) -> *mut Self {
+                        #module_ident::#singleton_instance_ident(engine)
+                    }
+                }
+            },
+        ],
+    };
+
+    blocks
+        .cxx_mod_contents
+        .append(&mut fragment.cxx_bridge_as_items()?);
+    blocks
+        .cxx_qt_mod_contents
+        .append(&mut fragment.implementation_as_items()?);
+
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::tests::assert_tokens_eq;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_rust_qmlsingleton() {
+        let qobject_idents = create_qobjectname();
+        let namespace_idents = NamespaceName::from_pair_str("cxx_qt", &format_ident!("MyObject"));
+
+        let generated = generate(
+            &parse_quote! { QQmlEngine },
+            &qobject_idents,
+            &namespace_idents,
+            &BTreeMap::<Ident, Path>::default(),
+            &format_ident!("ffi"),
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 2);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+
+        // CXX bridges
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "createSingletonRs"]
+                    #[namespace = "cxx_qt::cxx_qt_my_object"]
+                    fn create_singleton_rs_my_object_rust(engine: Pin<&mut QQmlEngine>) -> Box<MyObjectRust>;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            quote! {
+                unsafe extern "C++" {
+                    #[cxx_name = "qmlSingletonInstance"]
+                    #[namespace = "rust::cxxqtlib1"]
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_my_object_singleton_instance(engine: &QQmlEngine) -> *mut MyObject;
+                }
+            },
+        );
+
+        // Implementation
+
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                #[doc(hidden)]
+                pub fn create_singleton_rs_my_object_rust(engine: core::pin::Pin<&mut QQmlEngine>) -> std::boxed::Box<MyObjectRust> {
+                    std::boxed::Box::new(<MyObject as cxx_qt::QmlSingletonFactory<QQmlEngine> >::create(engine))
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                impl MyObject {
+                    #[doc = "Retrieve the instance of the QML singleton which was created for the given engine"]
+                    #[doc = "\n"]
+                    #[doc = "This is null if the singleton has not been used in the engine yet"]
+                    pub fn singleton_instance(engine: &QQmlEngine) -> *mut Self {
+                        ffi::cxx_qt_ffi_my_object_singleton_instance(engine)
+                    }
+                }
+            },
+        );
+    }
+}
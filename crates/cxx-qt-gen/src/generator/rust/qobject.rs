@@ -9,9 +9,14 @@ use crate::{
     generator::{
         naming::{namespace::NamespaceName, qobject::QObjectName},
         rust::{
-            constructor, cxxqttype, fragment::RustFragmentPair, inherit,
-            method::generate_rust_methods, property::generate_rust_properties,
-            signals::generate_rust_signals, threading,
+            constructor, cxxqttype,
+            fragment::RustFragmentPair,
+            inherit,
+            method::generate_rust_methods,
+            property::{generate_rust_list_properties, generate_rust_properties},
+            qmlattached, qmlsingleton,
+            signals::generate_rust_signals,
+            threading,
         },
         utils::rust::syn_ident_cxx_bridge_to_qualified_impl,
     },
@@ -58,9 +63,16 @@ impl GeneratedRustQObject {
             &qobject_idents,
             qualified_mappings,
         )?);
+        generated.append(&mut generate_rust_list_properties(
+            &qobject.list_properties,
+            &qobject_idents,
+            qualified_mappings,
+        )?);
         generated.append(&mut generate_rust_methods(
             &qobject.methods,
             &qobject_idents,
+            &namespace_idents,
+            qualified_mappings,
         )?);
         generated.append(&mut generate_passthrough_impl(
             &qobject.passthrough_impl_items,
@@ -94,6 +106,31 @@ impl GeneratedRustQObject {
             }
         }
 
+        // If this QML element has an attached object then add generation
+        if let Some(attached) = qobject
+            .qml_metadata
+            .as_ref()
+            .and_then(|qml_metadata| qml_metadata.attached.as_ref())
+        {
+            generated.append(&mut qmlattached::generate(
+                attached,
+                &qobject_idents,
+                &namespace_idents,
+                qualified_mappings,
+            )?);
+        }
+
+        // If this QML singleton is created by a factory in Rust then add generation
+        if let Some(engine) = &qobject.qml_singleton_factory {
+            generated.append(&mut qmlsingleton::generate(
+                engine,
+                &qobject_idents,
+                &namespace_idents,
+                qualified_mappings,
+                module_ident,
+            )?);
+        }
+
         // If this type has threading enabled then add generation
         if qobject.threading {
             generated.append(&mut threading::generate(
@@ -118,17 +155,22 @@ impl GeneratedRustQObject {
             });
         }
 
-        generated.append(&mut constructor::generate(
-            &qobject.constructors,
-            &qobject_idents,
-            &namespace_idents,
-            qualified_mappings,
-            module_ident,
-        )?);
+        // A QML singleton created by a factory does not need a Default constructor,
+        // unless there are other constructors declared
+        if qobject.qml_singleton_factory.is_none() || !qobject.constructors.is_empty() {
+            generated.append(&mut constructor::generate(
+                &qobject.constructors,
+                &qobject_idents,
+                &namespace_idents,
+                qualified_mappings,
+                module_ident,
+            )?);
+        }
 
         generated.append(&mut cxxqttype::generate(
             &qobject_idents,
             qualified_mappings,
+            module_ident,
         )?);
 
         Ok(generated)
@@ -224,7 +266,7 @@ mod tests {
             &format_ident!("ffi"),
         )
         .unwrap();
-        assert_eq!(rust.cxx_mod_contents.len(), 6);
+        assert_eq!(rust.cxx_mod_contents.len(), 8);
         assert_tokens_eq(
             &rust.cxx_mod_contents[0],
             quote! {
@@ -266,7 +308,7 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &rust.cxx_mod_contents[4],
+            &rust.cxx_mod_contents[5],
             quote! {
                 unsafe extern "C++" {
                     #[cxx_name = "unsafeRust"]
@@ -276,7 +318,7 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &rust.cxx_mod_contents[5],
+            &rust.cxx_mod_contents[6],
             quote! {
                 unsafe extern "C++" {
                     #[cxx_name = "unsafeRustMut"]
@@ -286,4 +328,41 @@ mod tests {
             },
         );
     }
+    #[test]
+    fn test_generated_rust_qobject_blocks_singleton_factory() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qqmlengine.h");
+                    type QQmlEngine = cxx_qt_lib::QQmlEngine;
+                }
+
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_singleton]
+                    type MyObject = super::MyObjectRust;
+                }
+
+                impl cxx_qt::QmlSingletonFactory<QQmlEngine> for MyObject {}
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let rust = GeneratedRustQObject::from(
+            parser.cxx_qt_data.qobjects.values().next().unwrap(),
+            &BTreeMap::<Ident, Path>::default(),
+            &format_ident!("ffi"),
+        )
+        .unwrap();
+        // The singleton is created by the factory, so MyObjectRust does not need Default
+        assert!(!rust
+            .cxx_mod_contents
+            .iter()
+            .chain(rust.cxx_qt_mod_contents.iter())
+            .any(|item| quote! { #item }
+                .to_string()
+                .contains("create_rs_my_object_rust")));
+    }
 }
@@ -147,6 +147,7 @@ mod tests {
             },
             safe: true,
             inherit: false,
+            revision: None,
         };
         let qobject_idents = create_qobjectname();
 
@@ -226,6 +227,7 @@ mod tests {
             },
             safe: true,
             inherit: false,
+            revision: None,
         };
         let qobject_idents = create_qobjectname();
 
@@ -299,6 +301,7 @@ mod tests {
             },
             safe: false,
             inherit: false,
+            revision: None,
         };
         let qobject_idents = create_qobjectname();
 
@@ -369,6 +372,7 @@ mod tests {
             },
             safe: true,
             inherit: true,
+            revision: None,
         };
         let qobject_idents = create_qobjectname();
 
@@ -29,9 +29,9 @@ pub fn generate(
     let cpp_struct_ident = &qobject_ident.cpp_class.rust;
     let cxx_qt_thread_ident = &qobject_ident.cxx_qt_thread_class;
     let cxx_qt_thread_queued_fn_ident = &qobject_ident.cxx_qt_thread_queued_fn_struct;
-    let cxx_qt_thread_queue_fn = qobject_ident.cxx_qt_thread_method("queue_boxed_fn");
-    let cxx_qt_thread_clone = qobject_ident.cxx_qt_thread_method("threading_clone");
-    let cxx_qt_thread_drop = qobject_ident.cxx_qt_thread_method("threading_drop");
+    let cxx_qt_thread_queue_fn = qobject_ident.cxx_qt_ffi_method("queue_boxed_fn");
+    let cxx_qt_thread_clone = qobject_ident.cxx_qt_ffi_method("threading_clone");
+    let cxx_qt_thread_drop = qobject_ident.cxx_qt_ffi_method("threading_drop");
     let namespace_internals = &namespace_ident.internal;
     let cxx_qt_thread_ident_type_id_str =
         namespace_combine_ident(&namespace_ident.namespace, cxx_qt_thread_ident);
@@ -3,7 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::parser::mappings::ParsedCxxMappings;
+use crate::parser::{mappings::ParsedCxxMappings, qobject::QmlVersion};
 use syn::{
     spanned::Spanned, Error, Expr, GenericArgument, Lit, PathArguments, PathSegment, Result,
     ReturnType, Type, TypeArray, TypeBareFn, TypePtr, TypeReference, TypeSlice,
@@ -292,6 +292,53 @@ fn possible_built_in_template_base(ty: &str) -> Option<&str> {
     }
 }
 
+/// Generate a declaration for Qt 6 and Qt 5, which is chosen by the preprocessor
+///
+/// This is used for QML revisions as Qt 6 has a major and minor version,
+/// whereas Qt 5 only supports a single integer which is the minor version.
+pub(crate) fn qt_version_switch(qt6: &str, qt5: &str) -> String {
+    format!("#if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)\n{qt6}\n#else\n{qt5}\n#endif")
+}
+
+/// Generate a method or signal declaration with a Q_REVISION if there is a revision
+///
+/// Qt 5 only supports a single integer revision, which is the minor version,
+/// so the declaration is generated for both Qt 6 and Qt 5.
+pub(crate) fn generate_with_q_revision(
+    revision: &Option<QmlVersion>,
+    declaration: impl Fn(&str) -> String,
+) -> String {
+    if let Some(revision) = revision {
+        qt_version_switch(
+            &declaration(&format!(
+                "Q_REVISION({}, {}) ",
+                revision.major, revision.minor
+            )),
+            &declaration(&format!("Q_REVISION({}) ", revision.minor)),
+        )
+    } else {
+        declaration("")
+    }
+}
+
+/// Escape a string so that it can be used within a C++ string literal
+pub(crate) fn escape_cpp_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            // Use an octal escape as it is at most three digits, unlike a hex escape
+            c if c.is_ascii_control() => escaped.push_str(&format!("\\{:03o}", c as u8)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 /// A trait to allow indenting multi-line string
 /// This is specifically useful when using formatdoc! with a multi-line string argument.
 /// As the formatdoc! formatting doesn't support indenting multi-line arguments, we can indent
@@ -317,6 +364,30 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_qt_version_switch() {
+        assert_str_eq!(
+            qt_version_switch("Q_REVISION(1, 2)", "Q_REVISION(2)"),
+            indoc! {"
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+            Q_REVISION(1, 2)
+            #else
+            Q_REVISION(2)
+            #endif"}
+        );
+    }
+
+    #[test]
+    fn test_escape_cpp_string() {
+        assert_eq!(escape_cpp_string("plain value"), "plain value");
+        assert_eq!(
+            escape_cpp_string(r#"a "quoted" C:\path"#),
+            r#"a \"quoted\" C:\\path"#
+        );
+        assert_eq!(escape_cpp_string("line\nbreak"), r#"line\nbreak"#);
+        assert_eq!(escape_cpp_string("bell\u{7}a"), r#"bell\007a"#);
+    }
+
     #[test]
     fn test_syn_return_type_to_cpp_except_default() {
         let ty = parse_quote! {};
@@ -32,6 +32,18 @@ pub fn write_headers(directory: impl AsRef<Path>) {
             include_str!("../include/cxxqt_maybelockguard.h"),
             "cxxqt_maybelockguard.h",
         ),
+        (
+            include_str!("../include/cxxqt_qmlerror.h"),
+            "cxxqt_qmlerror.h",
+        ),
+        (
+            include_str!("../include/cxxqt_qmllistproperty.h"),
+            "cxxqt_qmllistproperty.h",
+        ),
+        (
+            include_str!("../include/cxxqt_qmlsingleton.h"),
+            "cxxqt_qmlsingleton.h",
+        ),
         (include_str!("../include/cxxqt_thread.h"), "cxxqt_thread.h"),
         (
             include_str!("../include/cxxqt_threading.h"),
@@ -202,6 +214,11 @@ mod tests {
         test_code_generation!("properties");
     }
 
+    #[test]
+    fn generates_bindable_properties() {
+        test_code_generation!("bindable_properties");
+    }
+
     #[test]
     fn generates_signals() {
         test_code_generation!("signals");
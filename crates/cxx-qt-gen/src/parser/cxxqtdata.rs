@@ -297,7 +297,13 @@ impl ParsedCxxQtData {
 mod tests {
     use super::*;
 
-    use crate::{generator::naming::CombinedIdent, parser::qobject::tests::create_parsed_qobject};
+    use crate::{
+        generator::naming::CombinedIdent,
+        parser::{
+            method::ParsedQJsError,
+            qobject::{tests::create_parsed_qobject, QmlVersion},
+        },
+    };
     use quote::format_ident;
     use syn::{parse_quote, ItemMod};
 
@@ -458,6 +464,165 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_impl_static_and_overloaded() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[Self = "MyObject"]
+                fn create_default() -> UniquePtr<MyObject>;
+
+                #[qinvokable]
+                #[cxx_name = "add"]
+                fn add_int(self: Pin<&mut MyObject>, value: i32);
+
+                #[qinvokable]
+                #[cxx_name = "add"]
+                fn add_double(self: Pin<&mut MyObject>, value: f64);
+            }
+        };
+        let result = cxx_qt_data.parse_cxx_qt_item(item).unwrap();
+        assert!(result.is_none());
+
+        let methods = &cxx_qt_data.qobjects[&qobject_ident()].methods;
+        assert_eq!(methods.len(), 3);
+        assert!(methods[0].is_static);
+        assert!(!methods[0].mutable);
+        assert!(methods[0].parameters.is_empty());
+        assert_eq!(methods[0].ident.cpp, format_ident!("createDefault"));
+        assert!(!methods[1].is_static);
+        assert_eq!(methods[1].ident.cpp, format_ident!("add"));
+        assert_eq!(methods[1].ident.rust, format_ident!("add_int"));
+        assert_eq!(methods[2].ident.cpp, format_ident!("add"));
+        assert_eq!(methods[2].ident.rust, format_ident!("add_double"));
+    }
+
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_impl_static_errors() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[Self = "MyObject"]
+                fn create(self: &MyObject);
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).is_err());
+
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[cxx_override]
+                #[Self = "MyObject"]
+                fn create();
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).is_err());
+    }
+
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_impl_qjs_error() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[qjs_error(RangeError)]
+                fn invokable(self: &MyObject) -> Result<i32>;
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).unwrap().is_none());
+        let methods = &cxx_qt_data.qobjects[&qobject_ident()].methods;
+        assert_eq!(
+            methods[0].qjs_error,
+            Some(ParsedQJsError {
+                error_type: format_ident!("RangeError"),
+                code: None,
+            })
+        );
+        assert!(methods[0].returns_result());
+
+        // With a code
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[qjs_error(TypeError, code = 42)]
+                fn invokable_code(self: &MyObject) -> Result<i32>;
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).unwrap().is_none());
+        let methods = &cxx_qt_data.qobjects[&qobject_ident()].methods;
+        assert_eq!(
+            methods[1].qjs_error,
+            Some(ParsedQJsError {
+                error_type: format_ident!("TypeError"),
+                code: Some(parse_quote! { 42 }),
+            })
+        );
+
+        // Code is not an integer or string
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[qjs_error(TypeError, code = 1.5)]
+                fn invokable(self: &MyObject) -> Result<i32>;
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).is_err());
+
+        // Unknown argument
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[qjs_error(TypeError, message = "error")]
+                fn invokable(self: &MyObject) -> Result<i32>;
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).is_err());
+
+        // A static invokable cannot return a Result
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[Self = "MyObject"]
+                fn static_invokable() -> Result<i32>;
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).is_err());
+
+        // Unknown error type
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[qjs_error(UnknownError)]
+                fn invokable(self: &MyObject) -> Result<i32>;
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).is_err());
+
+        // Does not return a Result
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[qjs_error(TypeError)]
+                fn invokable(self: &MyObject) -> i32;
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).is_err());
+
+        // Is not a qinvokable
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qjs_error(TypeError)]
+                fn invokable(self: &MyObject) -> Result<i32>;
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).is_err());
+    }
+
     #[test]
     fn test_find_and_merge_cxx_qt_item_impl_invalid_qobject() {
         let mut cxx_qt_data = create_parsed_cxx_qt_data();
@@ -825,6 +990,62 @@ mod tests {
         assert!(!signals[0].inherit);
     }
 
+    #[test]
+    fn test_parse_qml_revision() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+        let block: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[qml_revision(1, 2)]
+                fn invokable(self: &MyObject);
+
+                #[qinvokable]
+                fn invokable_without_revision(self: &MyObject);
+
+                #[qsignal]
+                #[qml_revision(1, 3)]
+                fn ready(self: Pin<&mut MyObject>);
+            }
+        };
+        cxxqtdata.parse_cxx_qt_item(block).unwrap();
+
+        let qobject = cxxqtdata.qobjects.get(&qobject_ident()).unwrap();
+        assert_eq!(
+            qobject.methods[0].revision,
+            Some(QmlVersion { major: 1, minor: 2 })
+        );
+        assert!(qobject.methods[0].method.attrs.is_empty());
+        assert!(qobject.methods[1].revision.is_none());
+        assert_eq!(
+            qobject.signals[0].revision,
+            Some(QmlVersion { major: 1, minor: 3 })
+        );
+    }
+
+    #[test]
+    fn test_parse_qml_revision_invalid() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+        let block: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qml_revision(1, 2)]
+                fn cpp_context(self: &MyObject);
+            }
+        };
+        // Only Q_INVOKABLE methods are visible to QML
+        assert!(cxxqtdata.parse_cxx_qt_item(block).is_err());
+
+        let block: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qsignal]
+                #[inherit]
+                #[qml_revision(1, 2)]
+                fn data_changed(self: Pin<&mut MyObject>);
+            }
+        };
+        // Inherited signals are declared in the base class
+        assert!(cxxqtdata.parse_cxx_qt_item(block).is_err());
+    }
+
     #[test]
     fn test_parse_threading() {
         let mut cxxqtdata = create_parsed_cxx_qt_data();
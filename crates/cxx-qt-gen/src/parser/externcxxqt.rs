@@ -4,10 +4,21 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::{
-    parser::signals::ParsedSignal,
+    parser::{
+        qobject::{ParsedQObject, QmlElementMetadata},
+        signals::ParsedSignal,
+    },
     syntax::{attribute::attribute_find_path, safety::Safety},
 };
-use syn::{Attribute, ForeignItem, ItemForeignMod, Result, Token};
+use syn::{Attribute, Error, ForeignItem, Ident, ItemForeignMod, Result, Token};
+
+/// A type in an extern "C++Qt" block which is registered with QML as a QML_FOREIGN
+pub struct ParsedQmlForeign {
+    /// The ident of the C++ type
+    pub ident: Ident,
+    /// The QML metadata to register the type with
+    pub qml_metadata: QmlElementMetadata,
+}
 
 /// Representation of an extern "C++Qt" block
 #[derive(Default)]
@@ -20,6 +31,8 @@ pub struct ParsedExternCxxQt {
     pub passthrough_items: Vec<ForeignItem>,
     /// Signals that need generation in the extern "C++Qt" block
     pub signals: Vec<ParsedSignal>,
+    /// Types that need to be registered with QML as a QML_FOREIGN
+    pub qml_foreign: Vec<ParsedQmlForeign>,
 }
 
 impl ParsedExternCxxQt {
@@ -36,8 +49,27 @@ impl ParsedExternCxxQt {
             Safety::Unsafe
         };
 
-        // Parse any signals, other items are passed through
-        for item in foreign_mod.items.drain(..) {
+        // Parse any signals and QML foreign types, other items are passed through
+        for mut item in foreign_mod.items.drain(..) {
+            if let ForeignItem::Type(foreign_ty) = &mut item {
+                // Test if the type is registered with QML
+                if let Some(qml_metadata) =
+                    ParsedQObject::parse_qml_metadata(&foreign_ty.ident, &mut foreign_ty.attrs)?
+                {
+                    if let Some(attached) = &qml_metadata.attached {
+                        return Err(Error::new_spanned(
+                            attached,
+                            "qml_attached is not supported for a type in an extern \"C++Qt\" block",
+                        ));
+                    }
+
+                    extern_cxx_block.qml_foreign.push(ParsedQmlForeign {
+                        ident: foreign_ty.ident.clone(),
+                        qml_metadata,
+                    });
+                }
+            }
+
             if let ForeignItem::Fn(foreign_fn) = &item {
                 // Test if the function is a signal
                 if let Some(index) = attribute_find_path(&foreign_fn.attrs, &["qsignal"]) {
@@ -83,4 +115,45 @@ mod tests {
         assert_eq!(extern_cxx_qt.signals.len(), 1);
         assert!(extern_cxx_qt.unsafety.is_some());
     }
+
+    #[test]
+    fn test_extern_cxx_qt_qml_foreign() {
+        let extern_cxx_qt = ParsedExternCxxQt::parse(parse_quote! {
+            unsafe extern "C++Qt" {
+                include!(<QtWidgets/QPushButton>);
+                #[qml_element = "PushButton"]
+                #[qml_extended(PushButtonExtension)]
+                type QPushButton;
+            }
+        })
+        .unwrap();
+
+        assert_eq!(extern_cxx_qt.passthrough_items.len(), 2);
+        assert_eq!(extern_cxx_qt.qml_foreign.len(), 1);
+        assert_eq!(extern_cxx_qt.qml_foreign[0].ident, "QPushButton");
+        assert_eq!(extern_cxx_qt.qml_foreign[0].qml_metadata.name, "PushButton");
+        assert_eq!(
+            extern_cxx_qt.qml_foreign[0].qml_metadata.extended,
+            Some(quote::format_ident!("PushButtonExtension"))
+        );
+
+        // The QML attributes are removed from the type
+        if let ForeignItem::Type(foreign_ty) = &extern_cxx_qt.passthrough_items[1] {
+            assert!(foreign_ty.attrs.is_empty());
+        } else {
+            panic!("Expected type");
+        }
+    }
+
+    #[test]
+    fn test_extern_cxx_qt_qml_foreign_attached() {
+        let extern_cxx_qt = ParsedExternCxxQt::parse(parse_quote! {
+            unsafe extern "C++Qt" {
+                #[qml_element]
+                #[qml_attached(Attached)]
+                type QPushButton;
+            }
+        });
+        assert!(extern_cxx_qt.is_err());
+    }
 }
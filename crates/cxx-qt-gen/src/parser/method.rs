@@ -4,11 +4,18 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::{
-    parser::parameter::ParsedFunctionParameter,
-    syntax::{attribute::attribute_take_path, foreignmod, safety::Safety, types},
+    generator::naming::CombinedIdent,
+    parser::{parameter::ParsedFunctionParameter, qobject::QmlVersion},
+    syntax::{
+        attribute::attribute_take_path, expr::expr_to_string, foreignmod, safety::Safety, types,
+    },
 };
+use quote::format_ident;
 use std::collections::HashSet;
-use syn::{spanned::Spanned, Error, ForeignItemFn, Ident, Result};
+use syn::{
+    parse::ParseStream, spanned::Spanned, Error, ForeignItemFn, Ident, Lit, Result, ReturnType,
+    Token, Type,
+};
 
 /// Describes a C++ specifier for the Q_INVOKABLE
 #[derive(Eq, Hash, PartialEq)]
@@ -28,6 +35,64 @@ impl ParsedQInvokableSpecifiers {
     }
 }
 
+/// The JavaScript error types that a QJSEngine can throw, see QJSValue::ErrorType
+const QJS_ERROR_TYPES: [&str; 7] = [
+    "GenericError",
+    "EvalError",
+    "RangeError",
+    "ReferenceError",
+    "SyntaxError",
+    "TypeError",
+    "URIError",
+];
+
+/// Describes the JavaScript error to throw when a Result invokable returns an error in QML
+#[derive(Debug, PartialEq)]
+pub struct ParsedQJsError {
+    /// The type of the JavaScript error, see QJSValue::ErrorType
+    pub error_type: Ident,
+    /// The code property of the JavaScript error object, if there is one
+    pub code: Option<Lit>,
+}
+
+impl ParsedQJsError {
+    /// Parse a `#[qjs_error(ErrorType)]` or `#[qjs_error(ErrorType, code = 1)]` attribute
+    fn parse(input: ParseStream) -> Result<Self> {
+        let error_type = input.parse::<Ident>()?;
+        if !QJS_ERROR_TYPES.contains(&error_type.to_string().as_str()) {
+            return Err(Error::new(
+                error_type.span(),
+                format!(
+                    "Unknown JavaScript error type, expected one of: {}",
+                    QJS_ERROR_TYPES.join(", ")
+                ),
+            ));
+        }
+
+        let code = if input.is_empty() {
+            None
+        } else {
+            let _comma = input.parse::<Token![,]>()?;
+            let key = input.parse::<Ident>()?;
+            if key != "code" {
+                return Err(Error::new(key.span(), "Unknown argument for #[qjs_error]"));
+            }
+            let _equals = input.parse::<Token![=]>()?;
+            match input.parse::<Lit>()? {
+                code @ (Lit::Int(_) | Lit::Str(_)) => Some(code),
+                code => {
+                    return Err(Error::new_spanned(
+                        code,
+                        "The code of a #[qjs_error] must be an integer or a string",
+                    ))
+                }
+            }
+        };
+
+        Ok(Self { error_type, code })
+    }
+}
+
 /// Describes a single method (which could be a Q_INVOKABLE) for a struct
 pub struct ParsedMethod {
     /// The original [syn::ImplItemFn] of the invokable
@@ -44,6 +109,14 @@ pub struct ParsedMethod {
     pub specifiers: HashSet<ParsedQInvokableSpecifiers>,
     /// Whether the method is qinvokable
     pub is_qinvokable: bool,
+    /// The QML revision of the invokable, if there is one
+    pub revision: Option<QmlVersion>,
+    /// The name of the method, multiple methods can share a C++ name to create overloads
+    pub ident: CombinedIdent,
+    /// Whether the method is static, in which case it has no self receiver
+    pub is_static: bool,
+    /// The JavaScript error to throw when a Result invokable returns an error in QML
+    pub qjs_error: Option<ParsedQJsError>,
 }
 
 impl ParsedMethod {
@@ -58,6 +131,17 @@ impl ParsedMethod {
         // Determine if the method is invokable
         let is_qinvokable = attribute_take_path(&mut method.attrs, &["qinvokable"]).is_some();
 
+        // Find the QML revision of the invokable
+        let revision = attribute_take_path(&mut method.attrs, &["qml_revision"])
+            .map(|attr| QmlVersion::parse_args(&attr))
+            .transpose()?;
+        if revision.is_some() && !is_qinvokable {
+            return Err(Error::new(
+                method.span(),
+                "#[qml_revision] can only be used on a #[qinvokable]",
+            ));
+        }
+
         // Parse any C++ specifiers
         let mut specifiers = HashSet::new();
         for specifier in [
@@ -70,12 +154,62 @@ impl ParsedMethod {
             }
         }
 
-        // Determine if the invokable is mutable
-        let self_receiver = foreignmod::self_type_from_foreign_fn(&method.sig)?;
-        let (qobject_ident, mutability) = types::extract_qobject_ident(&self_receiver.ty)?;
-        let mutable = mutability.is_some();
+        // A static method has no receiver, so the QObject is specified with #[Self = "T"]
+        let static_qobject = attribute_take_path(&mut method.attrs, &["Self"])
+            .map(|attr| {
+                let value = &attr.meta.require_name_value()?.value;
+                syn::parse_str::<Ident>(&expr_to_string(value)?)
+                    .map_err(|_| Error::new_spanned(value, "Expected the name of a QObject"))
+            })
+            .transpose()?;
+        let is_static = static_qobject.is_some();
+
+        let (qobject_ident, mutable, parameters) = if let Some(qobject_ident) = static_qobject {
+            if !specifiers.is_empty() {
+                return Err(Error::new(
+                    method.span(),
+                    "Static methods cannot be virtual, final or override",
+                ));
+            }
 
-        let parameters = ParsedFunctionParameter::parse_all_ignoring_receiver(&method.sig)?;
+            let parameters = ParsedFunctionParameter::parse_all_static(&method.sig)?;
+            (qobject_ident, false, parameters)
+        } else {
+            // Determine if the invokable is mutable
+            let self_receiver = foreignmod::self_type_from_foreign_fn(&method.sig)?;
+            let (qobject_ident, mutability) = types::extract_qobject_ident(&self_receiver.ty)?;
+
+            let parameters = ParsedFunctionParameter::parse_all_ignoring_receiver(&method.sig)?;
+            (qobject_ident, mutability.is_some(), parameters)
+        };
+
+        // Methods with different Rust names can share a C++ name to create overloads
+        let mut ident = CombinedIdent::from_rust_function(method.sig.ident.clone());
+        if let Some(attr) = attribute_take_path(&mut method.attrs, &["cxx_name"]) {
+            ident.cpp = format_ident!(
+                "{}",
+                expr_to_string(&attr.meta.require_name_value()?.value)?
+            );
+        }
+
+        // Find the JavaScript error type for errors of a Result invokable
+        let qjs_error = attribute_take_path(&mut method.attrs, &["qjs_error"])
+            .map(|attr| attr.parse_args_with(ParsedQJsError::parse))
+            .transpose()?;
+        if qjs_error.is_some() && (!is_qinvokable || !returns_result(&method)) {
+            return Err(Error::new(
+                method.span(),
+                "#[qjs_error] can only be used on a #[qinvokable] which returns a Result",
+            ));
+        }
+        // The error of a Result invokable is thrown into the QJSEngine of the QObject,
+        // a static invokable has no QObject so the error could not be caught in QML
+        if is_qinvokable && is_static && returns_result(&method) {
+            return Err(Error::new(
+                method.span(),
+                "A static #[qinvokable] cannot return a Result, as there is no QObject to find the QJSEngine to throw the error into",
+            ));
+        }
 
         let safe = method.sig.unsafety.is_none();
 
@@ -87,6 +221,27 @@ impl ParsedMethod {
             specifiers,
             safe,
             is_qinvokable,
+            revision,
+            ident,
+            is_static,
+            qjs_error,
         })
     }
+
+    /// Whether the method returns a Result, in which case C++ can see an exception
+    pub fn returns_result(&self) -> bool {
+        returns_result(&self.method)
+    }
+}
+
+fn returns_result(method: &ForeignItemFn) -> bool {
+    if let ReturnType::Type(_, ty) = &method.sig.output {
+        if let Type::Path(ty_path) = &**ty {
+            if let Some(segment) = ty_path.path.segments.first() {
+                return segment.ident == "Result";
+            }
+        }
+    }
+
+    false
 }
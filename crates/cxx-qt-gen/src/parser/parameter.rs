@@ -53,6 +53,27 @@ impl ParsedFunctionParameter {
         Self::parse_remaining(iter)
     }
 
+    /// This function parses the list of arguments of a static function,
+    /// which must not have a receiver
+    pub fn parse_all_static(signature: &Signature) -> Result<Vec<ParsedFunctionParameter>> {
+        if let Some(FnArg::Receiver(receiver)) = signature.inputs.iter().next() {
+            return Err(Error::new(
+                receiver.span(),
+                "Static methods cannot have a `self` receiver!",
+            ));
+        }
+
+        let parameters = Self::parse_remaining(signature.inputs.iter())?;
+        if parameters.len() != signature.inputs.len() {
+            return Err(Error::new_spanned(
+                &signature.inputs,
+                "Static methods cannot have a `self` parameter!",
+            ));
+        }
+
+        Ok(parameters)
+    }
+
     /// This function parses the list of arguments
     pub fn parse_all_without_receiver(
         signature: &Signature,
@@ -103,6 +124,38 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_parse_all_static() {
+        let function: ForeignItemFn = syn::parse_quote! {
+            fn foo(a: i32, b: String);
+        };
+
+        let parameters = ParsedFunctionParameter::parse_all_static(&function.sig).unwrap();
+        assert_eq!(parameters.len(), 2);
+        assert_eq!(parameters[0].ident, "a");
+        assert_eq!(parameters[1].ident, "b");
+
+        let function: ForeignItemFn = syn::parse_quote! {
+            fn foo();
+        };
+        assert!(ParsedFunctionParameter::parse_all_static(&function.sig)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_parse_all_static_invalid_self() {
+        fn assert_parse_error(function: ForeignItemFn) {
+            assert!(ParsedFunctionParameter::parse_all_static(&function.sig).is_err());
+        }
+        assert_parse_error(syn::parse_quote! {
+            fn foo(&self, a: i32);
+        });
+        assert_parse_error(syn::parse_quote! {
+            fn foo(self: &MyObject, a: i32);
+        });
+    }
+
     #[test]
     fn test_parse_all_without_receiver() {
         let function: ForeignItemFn = syn::parse_quote! {
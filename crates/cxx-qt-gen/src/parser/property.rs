@@ -3,7 +3,11 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use syn::{parse::ParseStream, Attribute, Ident, Result, Token, Type};
+use crate::{parser::qobject::QmlVersion, syntax::path::path_compare_str};
+use syn::{
+    parenthesized, parse::ParseStream, spanned::Spanned, Attribute, Error, GenericArgument, Ident,
+    PathArguments, Result, Token, Type,
+};
 
 /// Describes a single Q_PROPERTY for a struct
 pub struct ParsedQProperty {
@@ -11,6 +15,10 @@ pub struct ParsedQProperty {
     pub ident: Ident,
     /// The [syn::Type] of the property
     pub ty: Type,
+    /// The QML revision of the property, if there is one
+    pub revision: Option<QmlVersion>,
+    /// Whether the property is stored in C++ as a bindable property
+    pub bindable: bool,
 }
 
 impl ParsedQProperty {
@@ -22,12 +30,96 @@ impl ParsedQProperty {
 
             // TODO: later we'll need to parse setters and getters here
             // which are key-value, hence this not being parsed as a list
+            let mut revision = None;
+            let mut bindable = false;
+            while !input.is_empty() {
+                let _comma = input.parse::<Token![,]>()?;
+                let key = input.parse::<Ident>()?;
+                if key == "bindable" {
+                    bindable = true;
+                } else if key == "qml_revision" {
+                    let content;
+                    parenthesized!(content in input);
+                    revision = Some(QmlVersion::parse(&content)?);
+                } else {
+                    return Err(Error::new(key.span(), "Unknown argument for #[qproperty]"));
+                }
+            }
 
-            Ok(Self { ident, ty })
+            // A bindable property is stored in C++ as a QProperty<T> and
+            // is passed by value, so the type must be copyable
+            if bindable && !is_bindable_type(&ty) {
+                return Err(Error::new(
+                    ty.span(),
+                    "A bindable #[qproperty] must have a type that can be copied, such as a primitive or a Qt value type",
+                ));
+            }
+
+            Ok(Self {
+                ident,
+                ty,
+                revision,
+                bindable,
+            })
         })
     }
 }
 
+/// Whether the given type can be stored in a bindable property
+///
+/// Pointers, references and owning types such as UniquePtr<T> cannot be copied in and out of a QProperty<T>
+fn is_bindable_type(ty: &Type) -> bool {
+    match ty {
+        Type::Path(ty_path) => {
+            if let Some(segment) = ty_path.path.segments.last() {
+                !["UniquePtr", "SharedPtr", "WeakPtr", "Box"]
+                    .contains(&segment.ident.to_string().as_str())
+            } else {
+                true
+            }
+        }
+        Type::Group(group) => is_bindable_type(&group.elem),
+        Type::Paren(paren) => is_bindable_type(&paren.elem),
+        Type::Ptr(_) | Type::Reference(_) => false,
+        _ => true,
+    }
+}
+
+/// Describes a single Q_PROPERTY with a QQmlListProperty<T> type for a struct
+pub struct ParsedQListProperty {
+    /// The [syn::Ident] of the property
+    pub ident: Ident,
+    /// The [syn::Type] of the QObject elements in the list
+    pub element_ty: Type,
+    /// The QML revision of the property, if there is one
+    pub revision: Option<QmlVersion>,
+}
+
+impl ParsedQListProperty {
+    /// If the given property has a QQmlListProperty<T> type then return it as a list property
+    pub fn from_property(property: &ParsedQProperty) -> Option<Self> {
+        if let Type::Path(ty_path) = &property.ty {
+            if path_compare_str(&ty_path.path, &["QQmlListProperty"]) {
+                if let PathArguments::AngleBracketed(angles) =
+                    &ty_path.path.segments.last()?.arguments
+                {
+                    if let [GenericArgument::Type(element_ty)] =
+                        angles.args.iter().collect::<Vec<_>>()[..]
+                    {
+                        return Some(Self {
+                            ident: property.ident.clone(),
+                            element_ty: element_ty.clone(),
+                            revision: property.revision,
+                        });
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -44,6 +136,55 @@ mod tests {
         let property = ParsedQProperty::parse(input.attrs.remove(0)).unwrap();
         assert_eq!(property.ident, format_ident!("name"));
         assert_eq!(property.ty, parse_quote! { T });
+        assert!(property.revision.is_none());
+        assert!(!property.bindable);
+    }
+
+    #[test]
+    fn test_parse_property_revision() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, qml_revision(1, 2))]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0)).unwrap();
+        assert_eq!(property.ident, format_ident!("name"));
+        assert_eq!(property.revision, Some(QmlVersion { major: 1, minor: 2 }));
+    }
+
+    #[test]
+    fn test_parse_property_bindable() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, bindable, qml_revision(1, 2))]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0)).unwrap();
+        assert_eq!(property.ident, format_ident!("name"));
+        assert!(property.bindable);
+        assert_eq!(property.revision, Some(QmlVersion { major: 1, minor: 2 }));
+    }
+
+    #[test]
+    fn test_parse_property_bindable_not_copyable() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(UniquePtr<QColor>, name, bindable)]
+            #[qproperty(cxx::UniquePtr<QColor>, name, bindable)]
+            #[qproperty(*mut T, name, bindable)]
+            #[qproperty(&T, name, bindable)]
+            struct MyStruct;
+        };
+        for attr in input.attrs.drain(..) {
+            assert!(ParsedQProperty::parse(attr).is_err());
+        }
+    }
+
+    #[test]
+    fn test_parse_property_revision_invalid() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, qml_revision = 1)]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0));
+        assert!(property.is_err());
     }
 
     #[test]
@@ -85,4 +226,26 @@ mod tests {
         let property = ParsedQProperty::parse(input.attrs.remove(0));
         assert!(property.is_err());
     }
+
+    #[test]
+    fn test_parse_list_property() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(QQmlListProperty<Child>, items)]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0)).unwrap();
+        let list_property = ParsedQListProperty::from_property(&property).unwrap();
+        assert_eq!(list_property.ident, format_ident!("items"));
+        assert_eq!(list_property.element_ty, parse_quote! { Child });
+    }
+
+    #[test]
+    fn test_parse_list_property_not_list() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(QList<Child>, items)]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0)).unwrap();
+        assert!(ParsedQListProperty::from_property(&property).is_none());
+    }
 }
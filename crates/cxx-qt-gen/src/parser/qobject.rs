@@ -5,15 +5,44 @@
 
 use crate::{
     parser::{
-        constructor::Constructor, inherit::ParsedInheritedMethod, method::ParsedMethod,
-        property::ParsedQProperty, qenum::ParsedQEnum, signals::ParsedSignal,
+        constructor::Constructor,
+        inherit::ParsedInheritedMethod,
+        method::ParsedMethod,
+        property::{ParsedQListProperty, ParsedQProperty},
+        qenum::ParsedQEnum,
+        signals::ParsedSignal,
     },
     syntax::{
         attribute::attribute_take_path, expr::expr_to_string, foreignmod::ForeignTypeIdentAlias,
         path::path_compare_str,
     },
 };
-use syn::{Attribute, Error, Ident, ImplItem, Item, ItemImpl, Meta, Result};
+use syn::{
+    parse::ParseStream, AngleBracketedGenericArguments, Attribute, Error, GenericArgument, Ident,
+    ImplItem, Item, ItemImpl, LitInt, LitStr, Meta, PathArguments, Result, Token, Type,
+};
+
+/// A version of a QML module, used for QML_ADDED_IN_VERSION and revisions of members
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct QmlVersion {
+    pub major: u8,
+    pub minor: u8,
+}
+
+impl QmlVersion {
+    /// Parse a version from the arguments of an attribute, eg `#[qml_revision(1, 2)]`
+    pub fn parse_args(attr: &Attribute) -> Result<Self> {
+        attr.parse_args_with(Self::parse)
+    }
+
+    /// Parse a version from a `major, minor` pair
+    pub fn parse(input: ParseStream) -> Result<Self> {
+        let major = input.parse::<LitInt>()?.base10_parse()?;
+        input.parse::<Token![,]>()?;
+        let minor = input.parse::<LitInt>()?.base10_parse()?;
+        Ok(Self { major, minor })
+    }
+}
 
 /// Metadata for registering QML element
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -21,6 +50,12 @@ pub struct QmlElementMetadata {
     pub name: String,
     pub uncreatable: bool,
     pub singleton: bool,
+    /// The type of the QML attached object, if there is one
+    pub attached: Option<Ident>,
+    /// The type of the QML extension object, if there is one
+    pub extended: Option<Ident>,
+    /// The version of the QML module that the element was added in
+    pub added_in: Option<QmlVersion>,
 }
 
 /// A representation of a QObject within a CXX-Qt [syn::ItemMod]
@@ -51,10 +86,20 @@ pub struct ParsedQObject {
     pub passthrough_impl_items: Vec<ImplItem>,
     /// Any user-defined constructors
     pub constructors: Vec<Constructor>,
+    /// The QQmlEngine type of the factory if the QML singleton is created in Rust
+    pub qml_singleton_factory: Option<Type>,
     /// List of properties that need to be implemented on the C++ object
     ///
     /// These will be exposed as Q_PROPERTY on the C++ object
     pub properties: Vec<ParsedQProperty>,
+    /// List of properties with a QQmlListProperty type
+    ///
+    /// These will be exposed as a Q_PROPERTY backed by a Vec of QObject pointers
+    pub list_properties: Vec<ParsedQListProperty>,
+    /// The property which is the default property in QML
+    pub default_property: Option<Ident>,
+    /// List of key and value pairs to register as Q_CLASSINFO
+    pub class_info: Vec<(String, String)>,
     /// List of specifiers to register with in QML
     pub qml_metadata: Option<QmlElementMetadata>,
     /// Whether locking is enabled for this QObject
@@ -87,9 +132,59 @@ impl TryFrom<&ForeignTypeIdentAlias> for ParsedQObject {
             .transpose()?
             .unwrap_or_else(|| "".to_owned());
 
+        // Parse any class info in the type
+        // and remove the #[qclassinfo] attribute
+        let class_info = Self::parse_class_info_attributes(&mut qobject_ty.attrs)?;
+
         // Parse any properties in the type
         // and remove the #[qproperty] attribute
-        let properties = Self::parse_property_attributes(&mut qobject_ty.attrs)?;
+        let mut properties = Self::parse_property_attributes(&mut qobject_ty.attrs)?;
+
+        // Split out any QQmlListProperty properties as these generate different methods
+        if let Some(property) = properties.iter().find(|property| {
+            property.bindable && ParsedQListProperty::from_property(property).is_some()
+        }) {
+            return Err(Error::new_spanned(
+                &property.ident,
+                "A QQmlListProperty cannot be a bindable property",
+            ));
+        }
+        let mut list_properties = vec![];
+        properties.retain(|property| {
+            if let Some(list_property) = ParsedQListProperty::from_property(property) {
+                list_properties.push(list_property);
+                false
+            } else {
+                true
+            }
+        });
+
+        // Find if there is a default property and check that it exists
+        let default_property =
+            attribute_take_path(&mut qobject_ty.attrs, &["qml_default_property"])
+                .map(|attr| attr.parse_args::<Ident>())
+                .transpose()?;
+        if let Some(default_property) = &default_property {
+            if !properties
+                .iter()
+                .map(|property| &property.ident)
+                .chain(list_properties.iter().map(|property| &property.ident))
+                .any(|ident| ident == default_property)
+            {
+                return Err(Error::new_spanned(
+                    default_property,
+                    "The default property must be a #[qproperty] of the QObject",
+                ));
+            }
+
+            // The default property is itself a class info, so it cannot be set twice
+            if class_info.iter().any(|(key, _)| key == "DefaultProperty") {
+                return Err(Error::new_spanned(
+                    default_property,
+                    "#[qml_default_property] cannot be used with a #[qclassinfo] with the DefaultProperty key",
+                ));
+            }
+        }
 
         Ok(Self {
             base_class,
@@ -101,7 +196,11 @@ impl TryFrom<&ForeignTypeIdentAlias> for ParsedQObject {
             inherited_methods: vec![],
             passthrough_impl_items: vec![],
             constructors: vec![],
+            qml_singleton_factory: None,
             properties,
+            list_properties,
+            default_property,
+            class_info,
             qml_metadata,
             locking: true,
             threading: false,
@@ -111,10 +210,15 @@ impl TryFrom<&ForeignTypeIdentAlias> for ParsedQObject {
 }
 
 impl ParsedQObject {
-    fn parse_qml_metadata(
+    pub(crate) fn parse_qml_metadata(
         qobject_ident: &Ident,
         attrs: &mut Vec<Attribute>,
     ) -> Result<Option<QmlElementMetadata>> {
+        // Take the attributes which require a qml_element, so that they are not silently ignored
+        let attached_attr = attribute_take_path(attrs, &["qml_attached"]);
+        let extended_attr = attribute_take_path(attrs, &["qml_extended"]);
+        let added_in_attr = attribute_take_path(attrs, &["qml_added_in"]);
+
         // Find if there is a qml_element attribute
         if let Some(attr) = attribute_take_path(attrs, &["qml_element"]) {
             // Extract the name of the qml_element
@@ -129,13 +233,44 @@ impl ParsedQObject {
             // Determine if this element is a singleton
             let singleton = attribute_take_path(attrs, &["qml_singleton"]).is_some();
 
+            // Find the type of any attached object
+            let attached = attached_attr
+                .map(|attr| attr.parse_args::<Ident>())
+                .transpose()?;
+
+            // Find the type of any extension object
+            let extended = extended_attr
+                .map(|attr| attr.parse_args::<Ident>())
+                .transpose()?;
+
+            // Find the version of the module that this element was added in
+            let added_in = added_in_attr
+                .map(|attr| QmlVersion::parse_args(&attr))
+                .transpose()?;
+
             return Ok(Some(QmlElementMetadata {
                 name,
                 uncreatable,
                 singleton,
+                attached,
+                extended,
+                added_in,
             }));
         }
 
+        for (attr, name) in [
+            (attached_attr, "qml_attached"),
+            (extended_attr, "qml_extended"),
+            (added_in_attr, "qml_added_in"),
+        ] {
+            if let Some(attr) = attr {
+                return Err(Error::new_spanned(
+                    attr,
+                    format!("#[{name}] requires #[qml_element]"),
+                ));
+            }
+        }
+
         Ok(None)
     }
 
@@ -198,15 +333,93 @@ impl ParsedQObject {
         } else if path_compare_str(trait_path, &["cxx_qt", "Constructor"]) {
             self.constructors.push(Constructor::parse(imp)?);
             Ok(())
+        } else if path_compare_str(trait_path, &["cxx_qt", "QmlSingletonFactory"]) {
+            if not.is_some() {
+                return Err(Error::new_spanned(
+                    trait_path,
+                    "Negative impls for cxx_qt::QmlSingletonFactory are not allowed",
+                ));
+            }
+
+            // Check that this QObject is a QML singleton
+            if !matches!(&self.qml_metadata, Some(qml_metadata) if qml_metadata.singleton) {
+                return Err(Error::new_spanned(
+                    trait_path,
+                    "cxx_qt::QmlSingletonFactory can only be implemented for a #[qml_singleton]",
+                ));
+            }
+
+            if self.qml_singleton_factory.is_some() {
+                return Err(Error::new_spanned(
+                    trait_path,
+                    "cxx_qt::QmlSingletonFactory can only be implemented once",
+                ));
+            }
+
+            self.qml_singleton_factory = Some(Self::parse_singleton_factory_engine(&imp)?);
+            Ok(())
         } else {
             // TODO: Give suggestions on which trait might have been meant
             Err(Error::new_spanned(
                 trait_path,
-                "Unsupported trait!\nCXX-Qt currently only supports:\n- cxx_qt::Threading\n- cxx_qt::Constructor\n- cxx_qt::Locking\nNote that the trait must always be fully-qualified."
+                "Unsupported trait!\nCXX-Qt currently only supports:\n- cxx_qt::Threading\n- cxx_qt::Constructor\n- cxx_qt::Locking\n- cxx_qt::QmlSingletonFactory\nNote that the trait must always be fully-qualified."
             ))
         }
     }
 
+    /// Find the engine type from the generic argument of `cxx_qt::QmlSingletonFactory<Engine>`
+    fn parse_singleton_factory_engine(imp: &ItemImpl) -> Result<Type> {
+        if !imp.items.is_empty() {
+            return Err(Error::new_spanned(
+                &imp.items[0],
+                "cxx_qt::QmlSingletonFactory must only be declared, not implemented inside cxx_qt::bridge!",
+            ));
+        }
+
+        let (_, trait_path, _) = imp.trait_.as_ref().expect("Expected trait impl!");
+        if let Some(PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+            args, ..
+        })) = trait_path.segments.last().map(|segment| &segment.arguments)
+        {
+            if let [GenericArgument::Type(engine)] = *args.iter().collect::<Vec<_>>() {
+                return Ok(engine.clone());
+            }
+        }
+
+        Err(Error::new_spanned(
+            trait_path,
+            "cxx_qt::QmlSingletonFactory expects the QQmlEngine type as the generic argument, e.g. cxx_qt::QmlSingletonFactory<QQmlEngine>",
+        ))
+    }
+
+    fn parse_class_info_attributes(attrs: &mut Vec<Attribute>) -> Result<Vec<(String, String)>> {
+        let mut class_info = vec![];
+
+        // Each attribute can contain multiple key = "value" pairs,
+        // the key can be a string as Qt uses keys such as "D-Bus Interface"
+        while let Some(attr) = attribute_take_path(attrs, &["qclassinfo"]) {
+            attr.parse_args_with(|input: ParseStream| -> Result<()> {
+                while !input.is_empty() {
+                    let key = if input.peek(LitStr) {
+                        input.parse::<LitStr>()?.value()
+                    } else {
+                        input.parse::<Ident>()?.to_string()
+                    };
+                    input.parse::<Token![=]>()?;
+                    let value = input.parse::<LitStr>()?.value();
+                    class_info.push((key, value));
+
+                    if !input.is_empty() {
+                        input.parse::<Token![,]>()?;
+                    }
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(class_info)
+    }
+
     fn parse_property_attributes(attrs: &mut Vec<Attribute>) -> Result<Vec<ParsedQProperty>> {
         let mut properties = vec![];
 
@@ -227,6 +440,7 @@ pub mod tests {
     use super::*;
 
     use crate::parser::tests::f64_type;
+    use quote::format_ident;
     use syn::{parse_quote, ItemImpl};
 
     pub fn create_parsed_qobject() -> ParsedQObject {
@@ -327,6 +541,54 @@ pub mod tests {
         assert!(qobject.parse_trait_impl(item).is_err());
     }
 
+    #[test]
+    fn test_parse_trait_impl_singleton_factory() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_singleton]
+            type MyObject = super::MyObjectRust;
+        };
+        let mut qobject = ParsedQObject::try_from(&item).unwrap();
+        assert!(qobject.qml_singleton_factory.is_none());
+
+        // must have the engine type
+        let item: ItemImpl = parse_quote! {
+            impl cxx_qt::QmlSingletonFactory for MyObject {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_err());
+
+        // must be empty
+        let item: ItemImpl = parse_quote! {
+            impl cxx_qt::QmlSingletonFactory<QQmlEngine> for MyObject {
+                fn create() {}
+            }
+        };
+        assert!(qobject.parse_trait_impl(item).is_err());
+
+        let item: ItemImpl = parse_quote! {
+            impl cxx_qt::QmlSingletonFactory<QQmlEngine> for MyObject {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_ok());
+        assert_eq!(
+            qobject.qml_singleton_factory,
+            Some(parse_quote! { QQmlEngine })
+        );
+
+        // can only be declared once
+        let item: ItemImpl = parse_quote! {
+            impl cxx_qt::QmlSingletonFactory<QQmlEngine> for MyObject {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_err());
+
+        // must be a singleton
+        let mut qobject = create_parsed_qobject();
+        let item: ItemImpl = parse_quote! {
+            impl cxx_qt::QmlSingletonFactory<QQmlEngine> for MyObject {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_err());
+    }
+
     #[test]
     fn test_parse_struct_fields_valid() {
         let item: ForeignTypeIdentAlias = parse_quote! {
@@ -345,6 +607,98 @@ pub mod tests {
         assert_eq!(properties[1].ty, f64_type());
     }
 
+    #[test]
+    fn test_parse_list_properties() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qproperty(i32, value)]
+            #[qproperty(QQmlListProperty<Child>, items)]
+            #[qml_default_property(items)]
+            type T = super::TRust;
+        };
+        let qobject = ParsedQObject::try_from(&item).unwrap();
+        assert_eq!(qobject.properties.len(), 1);
+        assert_eq!(qobject.properties[0].ident, "value");
+
+        assert_eq!(qobject.list_properties.len(), 1);
+        assert_eq!(qobject.list_properties[0].ident, "items");
+        assert_eq!(
+            qobject.list_properties[0].element_ty,
+            parse_quote! { Child }
+        );
+
+        assert_eq!(qobject.default_property, Some(format_ident!("items")));
+    }
+
+    #[test]
+    fn test_parse_list_properties_bindable() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qproperty(QQmlListProperty<Child>, items, bindable)]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::try_from(&item).is_err());
+    }
+
+    #[test]
+    fn test_parse_default_property_missing() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qproperty(i32, value)]
+            #[qml_default_property(items)]
+            type T = super::TRust;
+        };
+        assert!(ParsedQObject::try_from(&item).is_err());
+    }
+
+    #[test]
+    fn test_parse_class_info() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qclassinfo(author = "KDAB", "D-Bus Interface" = "com.kdab.MyObject")]
+            #[qclassinfo(RegisterEnumClassesUnscoped = "false")]
+            type T = super::TRust;
+        };
+        let qobject = ParsedQObject::try_from(&item).unwrap();
+        assert_eq!(
+            qobject.class_info,
+            vec![
+                ("author".to_owned(), "KDAB".to_owned()),
+                ("D-Bus Interface".to_owned(), "com.kdab.MyObject".to_owned()),
+                ("RegisterEnumClassesUnscoped".to_owned(), "false".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_class_info_invalid() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qclassinfo(author = KDAB)]
+            type T = super::TRust;
+        };
+        assert!(ParsedQObject::try_from(&item).is_err());
+
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qclassinfo(author)]
+            type T = super::TRust;
+        };
+        assert!(ParsedQObject::try_from(&item).is_err());
+    }
+
+    #[test]
+    fn test_parse_class_info_default_property_conflict() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qproperty(i32, items)]
+            #[qml_default_property(items)]
+            #[qclassinfo(DefaultProperty = "other")]
+            type T = super::TRust;
+        };
+        assert!(ParsedQObject::try_from(&item).is_err());
+    }
+
     #[test]
     fn test_qml_metadata() {
         let item: ForeignTypeIdentAlias = parse_quote! {
@@ -359,6 +713,9 @@ pub mod tests {
                 name: "MyObject".to_string(),
                 uncreatable: false,
                 singleton: false,
+                attached: None,
+                extended: None,
+                added_in: None,
             })
         );
     }
@@ -377,6 +734,9 @@ pub mod tests {
                 name: "OtherName".to_string(),
                 uncreatable: false,
                 singleton: false,
+                attached: None,
+                extended: None,
+                added_in: None,
             })
         );
     }
@@ -396,6 +756,9 @@ pub mod tests {
                 name: "MyObject".to_string(),
                 uncreatable: false,
                 singleton: true,
+                attached: None,
+                extended: None,
+                added_in: None,
             })
         );
     }
@@ -415,7 +778,125 @@ pub mod tests {
                 name: "MyObject".to_string(),
                 uncreatable: true,
                 singleton: false,
+                attached: None,
+                extended: None,
+                added_in: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_qml_metadata_attached() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_attached(MyAttached)]
+            type MyObject = super::MyObjectRust;
+        };
+        let qobject = ParsedQObject::try_from(&item).unwrap();
+        assert_eq!(
+            qobject.qml_metadata,
+            Some(QmlElementMetadata {
+                name: "MyObject".to_string(),
+                uncreatable: false,
+                singleton: false,
+                attached: Some(format_ident!("MyAttached")),
+                extended: None,
+                added_in: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_qml_metadata_extended() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_extended(MyExtension)]
+            type MyObject = super::MyObjectRust;
+        };
+        let qobject = ParsedQObject::try_from(&item).unwrap();
+        assert_eq!(
+            qobject.qml_metadata,
+            Some(QmlElementMetadata {
+                name: "MyObject".to_string(),
+                uncreatable: false,
+                singleton: false,
+                attached: None,
+                extended: Some(format_ident!("MyExtension")),
+                added_in: None,
             })
         );
     }
+
+    #[test]
+    fn test_qml_metadata_added_in() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_added_in(1, 2)]
+            type MyObject = super::MyObjectRust;
+        };
+        let qobject = ParsedQObject::try_from(&item).unwrap();
+        assert_eq!(
+            qobject.qml_metadata.unwrap().added_in,
+            Some(QmlVersion { major: 1, minor: 2 })
+        );
+    }
+
+    #[test]
+    fn test_qml_metadata_added_in_invalid() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_added_in(1)]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::try_from(&item).is_err());
+
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_added_in(1, 256)]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::try_from(&item).is_err());
+    }
+
+    #[test]
+    fn test_qml_metadata_attached_invalid() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_attached = "MyAttached"]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::try_from(&item).is_err());
+    }
+    #[test]
+    fn test_qml_metadata_without_qml_element() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_attached(MyAttached)]
+            type MyObject = super::MyObjectRust;
+        };
+        let error = ParsedQObject::try_from(&item).err().unwrap();
+        assert_eq!(error.to_string(), "#[qml_attached] requires #[qml_element]");
+
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_extended(MyExtension)]
+            type MyObject = super::MyObjectRust;
+        };
+        let error = ParsedQObject::try_from(&item).err().unwrap();
+        assert_eq!(error.to_string(), "#[qml_extended] requires #[qml_element]");
+
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_added_in(1, 2)]
+            type MyObject = super::MyObjectRust;
+        };
+        let error = ParsedQObject::try_from(&item).err().unwrap();
+        assert_eq!(error.to_string(), "#[qml_added_in] requires #[qml_element]");
+    }
 }
@@ -4,6 +4,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::parser::parameter::ParsedFunctionParameter;
+use crate::parser::qobject::QmlVersion;
 use crate::syntax::attribute::attribute_take_path;
 use crate::syntax::expr::expr_to_string;
 use crate::syntax::foreignmod;
@@ -28,6 +29,8 @@ pub struct ParsedSignal {
     pub ident: CombinedIdent,
     /// If the signal is defined in the base class
     pub inherit: bool,
+    /// The QML revision of the signal, if there is one
+    pub revision: Option<QmlVersion>,
 }
 
 impl ParsedSignal {
@@ -45,6 +48,7 @@ impl ParsedSignal {
             parameters: vec![],
             ident,
             inherit: false,
+            revision: None,
         }
     }
 
@@ -78,6 +82,16 @@ impl ParsedSignal {
         }
 
         let inherit = attribute_take_path(&mut method.attrs, &["inherit"]).is_some();
+
+        let revision = attribute_take_path(&mut method.attrs, &["qml_revision"])
+            .map(|attr| QmlVersion::parse_args(&attr))
+            .transpose()?;
+        if revision.is_some() && inherit {
+            return Err(Error::new(
+                method.span(),
+                "#[qml_revision] cannot be used on an #[inherit] signal as it is declared in the base class",
+            ));
+        }
         let safe = method.sig.unsafety.is_none();
 
         Ok(Self {
@@ -88,6 +102,7 @@ impl ParsedSignal {
             ident,
             safe,
             inherit,
+            revision,
         })
     }
 }
@@ -173,6 +188,17 @@ mod tests {
         assert!(signal.inherit);
     }
 
+    #[test]
+    fn test_parse_signal_revision() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qml_revision(1, 2)]
+            fn ready(self: Pin<&mut MyObject>);
+        };
+        let signal = ParsedSignal::parse(method, Safety::Safe).unwrap();
+        assert!(signal.method.attrs.is_empty());
+        assert_eq!(signal.revision, Some(QmlVersion { major: 1, minor: 2 }));
+    }
+
     #[test]
     fn test_parse_signal_mutable_err() {
         let method: ForeignItemFn = parse_quote! {
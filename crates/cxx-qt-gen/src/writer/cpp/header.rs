@@ -104,6 +104,23 @@ fn qobjects_header(generated: &GeneratedCppBlocks) -> Vec<String> {
     }).collect::<Vec<String>>()
 }
 
+/// For a given GeneratedCppBlocks write the QML_FOREIGN registrations of extern C++Qt types
+fn qml_foreign_header(generated: &GeneratedCppBlocks) -> Vec<String> {
+    let qml_foreign = &generated.qml_foreign;
+    if qml_foreign.classes.is_empty() {
+        return vec![];
+    }
+
+    let (namespace_start, namespace_end) = namespace_start_and_end(&qml_foreign.namespace);
+    vec![formatdoc! { r#"
+        {namespace_start}
+        {classes}
+        {namespace_end}
+    "#,
+    classes = qml_foreign.classes.join("\n"),
+    }]
+}
+
 /// For a given GeneratedCppBlocks write this into a C++ header
 pub fn write_cpp_header(generated: &GeneratedCppBlocks) -> String {
     // Headers included:
@@ -121,7 +138,11 @@ pub fn write_cpp_header(generated: &GeneratedCppBlocks) -> String {
     "#,
     cxx_file_stem = generated.cxx_file_stem,
     forward_declare = forward_declare(generated).join("\n"),
-    qobjects = qobjects_header(generated).join("\n"),
+    qobjects = qobjects_header(generated)
+        .into_iter()
+        .chain(qml_foreign_header(generated))
+        .collect::<Vec<String>>()
+        .join("\n"),
     extern_cxx_qt = {
         let mut out = vec![];
         for block in &generated.extern_cxx_qt {
@@ -141,7 +162,8 @@ pub fn write_cpp_header(generated: &GeneratedCppBlocks) -> String {
     .fold(BTreeSet::<&String>::default(), |mut acc, qobject| {
         acc.extend(qobject.blocks.includes.iter());
         acc
-    }).into_iter().cloned().collect::<Vec<String>>().join("\n"),
+    }).into_iter().chain(generated.qml_foreign.includes.iter())
+    .collect::<BTreeSet<&String>>().into_iter().cloned().collect::<Vec<String>>().join("\n"),
     }
 }
 
@@ -202,4 +224,33 @@ mod tests {
         let output = write_cpp_header(&generated);
         assert_str_eq!(output, expected_header_no_namespace());
     }
+
+    #[test]
+    fn test_qml_foreign_header() {
+        let mut generated = create_generated_cpp();
+        assert!(qml_foreign_header(&generated).is_empty());
+
+        generated.qml_foreign.namespace = "rust::cxxqtgen1::ffi".to_owned();
+        generated.qml_foreign.classes = vec![
+            "class AQmlForeign\n{\n};\n".to_owned(),
+            "class BQmlForeign\n{\n};\n".to_owned(),
+        ];
+        let output = qml_foreign_header(&generated);
+        assert_eq!(output.len(), 1);
+        assert_str_eq!(
+            output[0],
+            indoc! {r#"
+            namespace rust::cxxqtgen1::ffi {
+            class AQmlForeign
+            {
+            };
+
+            class BQmlForeign
+            {
+            };
+
+            } // namespace rust::cxxqtgen1::ffi
+            "#}
+        );
+    }
 }
@@ -41,7 +41,10 @@ mod tests {
     use super::*;
 
     use crate::{
-        generator::cpp::qobject::{GeneratedCppQObject, GeneratedCppQObjectBlocks},
+        generator::cpp::{
+            qmlforeign::GeneratedCppQmlForeignBlocks,
+            qobject::{GeneratedCppQObject, GeneratedCppQObjectBlocks},
+        },
         tests::format_cpp,
     };
     use indoc::indoc;
@@ -53,6 +56,7 @@ mod tests {
             cxx_file_stem: "cxx_file_stem".to_owned(),
             namespace: "cxx_qt::my_object".to_owned(),
             extern_cxx_qt: vec![],
+            qml_foreign: GeneratedCppQmlForeignBlocks::default(),
             qobjects: vec![
                 GeneratedCppQObject {
                     ident: "MyObject".to_owned(),
@@ -174,6 +178,7 @@ mod tests {
             cxx_file_stem: "cxx_file_stem".to_owned(),
             namespace: "cxx_qt".to_owned(),
             extern_cxx_qt: vec![],
+            qml_foreign: GeneratedCppQmlForeignBlocks::default(),
             qobjects: vec![
                 GeneratedCppQObject {
                     ident: "FirstObject".to_owned(),
@@ -15,6 +15,21 @@ pub fn write_rust(generated: &GeneratedRustBlocks) -> TokenStream {
     let mut cxx_qt_mod_contents = generated.cxx_qt_mod_contents.clone();
     let namespace = &generated.namespace;
 
+    // The QObject type is only used for the upcasting and constructor helpers of QObjects
+    let qobject_type = if generated.qobjects.is_empty() {
+        None
+    } else {
+        Some(quote! {
+            include!("cxx-qt-lib/qobject.h");
+            #[doc(hidden)]
+            #[namespace = ""]
+            // Rename to CxxQtQObject so the developer can define it
+            // in their bridges without an invisible conflict
+            #[rust_name = "CxxQtQObject"]
+            type QObject = cxx_qt_lib::QObject;
+        })
+    };
+
     // Add common includes for all objects
     cxx_mod_contents.insert(
         0,
@@ -35,6 +50,8 @@ pub fn write_rust(generated: &GeneratedRustBlocks) -> TokenStream {
                 // in their bridges without an invisible conflict
                 #[rust_name = "CxxQtQMetaObjectConnection"]
                 type QMetaObjectConnection = cxx_qt_lib::QMetaObjectConnection;
+
+                #qobject_type
             }
         })
         .expect("Could not build CXX common block"),
@@ -208,6 +225,12 @@ mod tests {
                     #[namespace = "rust::cxxqtlib1"]
                     #[rust_name = "CxxQtQMetaObjectConnection"]
                     type QMetaObjectConnection = cxx_qt_lib::QMetaObjectConnection;
+
+                    include!("cxx-qt-lib/qobject.h");
+                    #[doc(hidden)]
+                    #[namespace = ""]
+                    #[rust_name = "CxxQtQObject"]
+                    type QObject = cxx_qt_lib::QObject;
                 }
 
                 unsafe extern "C++" {
@@ -257,6 +280,12 @@ mod tests {
                     #[namespace = "rust::cxxqtlib1"]
                     #[rust_name = "CxxQtQMetaObjectConnection"]
                     type QMetaObjectConnection = cxx_qt_lib::QMetaObjectConnection;
+
+                    include!("cxx-qt-lib/qobject.h");
+                    #[doc(hidden)]
+                    #[namespace = ""]
+                    #[rust_name = "CxxQtQObject"]
+                    type QObject = cxx_qt_lib::QObject;
                 }
 
                 unsafe extern "C++" {
@@ -317,4 +346,13 @@ mod tests {
         let result = write_rust(&generated);
         assert_str_eq!(result.to_string(), expected_rust_multi_qobjects());
     }
+    #[test]
+    fn test_write_rust_no_qobjects() {
+        let mut generated = create_generated_rust();
+        generated.qobjects.clear();
+        let result = write_rust(&generated).to_string();
+        assert!(!result.contains("qobject.h"));
+        assert!(!result.contains("CxxQtQObject"));
+        assert!(result.contains("CxxQtQMetaObjectConnection"));
+    }
 }
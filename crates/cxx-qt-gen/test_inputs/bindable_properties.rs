@@ -0,0 +1,9 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    extern "RustQt" {
+        #[qobject]
+        #[derive(Default)]
+        #[qproperty(i32, total, bindable)]
+        type MyObject = super::MyObjectRust;
+    }
+}
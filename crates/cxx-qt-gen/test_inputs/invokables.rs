@@ -47,6 +47,7 @@ mod ffi {
         fn invokable_result_tuple(self: &MyObject) -> Result<()>;
 
         #[qinvokable]
+        #[qjs_error(TypeError)]
         fn invokable_result_type(self: &MyObject) -> Result<String>;
     }
 
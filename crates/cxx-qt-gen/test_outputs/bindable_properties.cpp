@@ -0,0 +1,58 @@
+#include "cxx-qt-gen/ffi.cxxqt.h"
+
+namespace cxx_qt::my_object {
+
+::std::int32_t
+MyObject::getTotal() const
+{
+  const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+  return m_total.value();
+}
+
+void
+MyObject::setTotal(::std::int32_t value)
+{
+  const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+  m_total.setValue(::std::move(value));
+}
+
+QBindable<::std::int32_t>
+MyObject::bindableTotal()
+{
+  return QBindable<::std::int32_t>(&m_total);
+}
+
+void
+MyObject::setTotalBinding(::rust::Fn<::std::int32_t(MyObject const&)> func)
+{
+  const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+  m_total.setBinding([this, func = ::std::move(func)]() {
+    const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+    return func(*this);
+  });
+}
+
+::QMetaObject::Connection
+MyObject::totalChangedConnect(::rust::Fn<void(MyObject&)> func,
+                              ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    this,
+    &MyObject::totalChanged,
+    this,
+    [&, func = ::std::move(func)]() {
+      const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+      func(*this);
+    },
+    type);
+}
+
+MyObject::MyObject(QObject* parent)
+  : QObject(parent)
+  , ::rust::cxxqtlib1::CxxQtType<MyObjectRust>(
+      ::cxx_qt::my_object::cxx_qt_my_object::createRs())
+  , ::rust::cxxqtlib1::CxxQtLocking()
+{
+}
+
+} // namespace cxx_qt::my_object
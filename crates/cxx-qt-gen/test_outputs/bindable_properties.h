@@ -0,0 +1,54 @@
+#pragma once
+
+#include <QtCore/QtGlobal>
+#if QT_VERSION < QT_VERSION_CHECK(6, 0, 0)
+#error "A bindable #[qproperty] requires Qt 6"
+#endif
+#include <QtCore/QProperty>
+#include <cxx-qt-common/cxxqt_locking.h>
+#include <cxx-qt-common/cxxqt_maybelockguard.h>
+#include <cxx-qt-common/cxxqt_type.h>
+
+namespace cxx_qt::my_object {
+class MyObject;
+
+} // namespace cxx_qt::my_object
+
+#include "cxx-qt-gen/ffi.cxx.h"
+
+namespace cxx_qt::my_object {
+class MyObject
+  : public QObject
+  , public ::rust::cxxqtlib1::CxxQtType<MyObjectRust>
+  , public ::rust::cxxqtlib1::CxxQtLocking
+{
+  Q_OBJECT
+public:
+  Q_PROPERTY(::std::int32_t total READ getTotal WRITE setTotal NOTIFY
+               totalChanged BINDABLE bindableTotal)
+
+  virtual ~MyObject() = default;
+
+public:
+  ::std::int32_t getTotal() const;
+  Q_SLOT void setTotal(::std::int32_t value);
+  QBindable<::std::int32_t> bindableTotal();
+  void setTotalBinding(::rust::Fn<::std::int32_t(MyObject const&)> func);
+  Q_SIGNAL void totalChanged();
+  ::QMetaObject::Connection totalChangedConnect(
+    ::rust::Fn<void(MyObject&)> func,
+    ::Qt::ConnectionType type);
+  explicit MyObject(QObject* parent = nullptr);
+
+private:
+  Q_OBJECT_BINDABLE_PROPERTY(MyObject,
+                             ::std::int32_t,
+                             m_total,
+                             &MyObject::totalChanged)
+};
+
+static_assert(::std::is_base_of<QObject, MyObject>::value,
+              "MyObject must inherit from QObject");
+} // namespace cxx_qt::my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::MyObject*)
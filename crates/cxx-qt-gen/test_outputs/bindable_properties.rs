@@ -0,0 +1,160 @@
+#[cxx::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    unsafe extern "C++" {
+        include ! (< QtCore / QObject >);
+        include!("cxx-qt-lib/qt.h");
+        #[doc(hidden)]
+        #[namespace = "Qt"]
+        #[rust_name = "CxxQtConnectionType"]
+        type ConnectionType = cxx_qt_lib::ConnectionType;
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqtlib1"]
+        #[rust_name = "CxxQtQMetaObjectConnection"]
+        type QMetaObjectConnection = cxx_qt_lib::QMetaObjectConnection;
+        include!("cxx-qt-lib/qobject.h");
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQObject"]
+        type QObject = cxx_qt_lib::QObject;
+    }
+    unsafe extern "C++" {
+        include!("cxx-qt-gen/ffi.cxxqt.h");
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "MyObjectRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/qobject/generated-qobject.html>"]
+        type MyObject;
+    }
+    extern "Rust" {
+        type MyObjectRust;
+    }
+    unsafe extern "C++" {
+        #[doc = "Getter for the bindable Q_PROPERTY "]
+        #[doc = "total"]
+        #[doc = "\n"]
+        #[doc = "If the property has a binding, then the binding is evaluated when it is out of date."]
+        #[cxx_name = "getTotal"]
+        fn total(self: &MyObject) -> i32;
+        #[doc = "Setter for the bindable Q_PROPERTY "]
+        #[doc = "total"]
+        #[doc = "\n"]
+        #[doc = "This removes any binding of the property."]
+        #[cxx_name = "setTotal"]
+        fn set_total(self: Pin<&mut MyObject>, value: i32);
+        #[doc = "Set a binding for the bindable Q_PROPERTY "]
+        #[doc = "total"]
+        #[doc = "\n"]
+        #[doc = "The value of the property is then computed by the given function, any bindable properties read in the function are dependencies of the binding."]
+        #[cxx_name = "setTotalBinding"]
+        fn set_total_binding(self: Pin<&mut MyObject>, func: fn(&MyObject) -> i32);
+    }
+    unsafe extern "C++" {
+        #[doc = "Notify for the Q_PROPERTY"]
+        #[rust_name = "total_changed"]
+        fn totalChanged(self: Pin<&mut MyObject>);
+    }
+    unsafe extern "C++" {
+        #[doc = "Connect the given function pointer to the signal "]
+        #[doc = "totalChanged"]
+        #[doc = ", so that when the signal is emitted the function pointer is executed."]
+        #[must_use]
+        #[rust_name = "connect_total_changed"]
+        fn totalChangedConnect(
+            self: Pin<&mut MyObject>,
+            func: fn(Pin<&mut MyObject>),
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn create_rs_my_object_rust() -> Box<MyObjectRust>;
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "qobjectNew"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_new_0() -> UniquePtr<MyObject>;
+        #[cxx_name = "qobjectNewWithParent"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_new_with_parent_0(parent: Pin<&mut CxxQtQObject>) -> *mut MyObject;
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "unsafeRust"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_rust(self: &MyObject) -> &MyObjectRust;
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "unsafeRustMut"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_rust_mut(self: Pin<&mut MyObject>) -> Pin<&mut MyObjectRust>;
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "upcastQObject"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_upcast(object: &MyObject) -> &CxxQtQObject;
+        #[cxx_name = "upcastQObjectMut"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_upcast_mut(object: Pin<&mut MyObject>) -> Pin<&mut CxxQtQObject>;
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "totalChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[must_use]
+    pub fn on_total_changed(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        func: fn(core::pin::Pin<&mut ffi::MyObject>),
+    ) -> cxx_qt_lib::QMetaObjectConnection {
+        self.connect_total_changed(func, cxx_qt_lib::ConnectionType::AutoConnection)
+    }
+}
+impl cxx_qt::Locking for ffi::MyObject {}
+#[doc(hidden)]
+pub fn create_rs_my_object_rust() -> std::boxed::Box<MyObjectRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+impl ffi::MyObject {
+    #[doc = "Create a new instance of the QObject which is owned by the returned UniquePtr"]
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::cxx_qt_ffi_my_object_new_0()
+    }
+    #[doc = "Create a new instance of the QObject which is owned by the given parent"]
+    pub fn new_with_parent(parent: core::pin::Pin<&mut cxx_qt_lib::QObject>) -> *mut Self {
+        ffi::cxx_qt_ffi_my_object_new_with_parent_0(parent)
+    }
+}
+impl core::ops::Deref for ffi::MyObject {
+    type Target = MyObjectRust;
+    fn deref(&self) -> &Self::Target {
+        self.cxx_qt_ffi_rust()
+    }
+}
+impl cxx_qt::CxxQtType for ffi::MyObject {
+    type Rust = MyObjectRust;
+    fn rust(&self) -> &Self::Rust {
+        self.cxx_qt_ffi_rust()
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        self.cxx_qt_ffi_rust_mut()
+    }
+}
+impl cxx_qt_lib::Upcast<cxx_qt_lib::QObject> for ffi::MyObject {
+    fn upcast(&self) -> &cxx_qt_lib::QObject {
+        ffi::cxx_qt_ffi_my_object_upcast(self)
+    }
+    fn upcast_pin(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut cxx_qt_lib::QObject> {
+        ffi::cxx_qt_ffi_my_object_upcast_mut(self)
+    }
+}
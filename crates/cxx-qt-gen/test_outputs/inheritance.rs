@@ -18,6 +18,11 @@ mod inheritance {
         #[namespace = "rust::cxxqtlib1"]
         #[rust_name = "CxxQtQMetaObjectConnection"]
         type QMetaObjectConnection = cxx_qt_lib::QMetaObjectConnection;
+        include!("cxx-qt-lib/qobject.h");
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQObject"]
+        type QObject = cxx_qt_lib::QObject;
     }
     unsafe extern "C++" {
         include!("cxx-qt-gen/inheritance.cxxqt.h");
@@ -59,6 +64,16 @@ mod inheritance {
         #[namespace = "cxx_qt_my_object"]
         fn create_rs_my_object_rust() -> Box<MyObjectRust>;
     }
+    unsafe extern "C++" {
+        #[cxx_name = "qobjectNew"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_new_0() -> UniquePtr<MyObject>;
+        #[cxx_name = "qobjectNewWithParent"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_new_with_parent_0(parent: Pin<&mut CxxQtQObject>) -> *mut MyObject;
+    }
     unsafe extern "C++" {
         #[cxx_name = "unsafeRust"]
         #[doc(hidden)]
@@ -69,12 +84,32 @@ mod inheritance {
         #[doc(hidden)]
         fn cxx_qt_ffi_rust_mut(self: Pin<&mut MyObject>) -> Pin<&mut MyObjectRust>;
     }
+    unsafe extern "C++" {
+        #[cxx_name = "upcastQObject"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_upcast(object: &MyObject) -> &CxxQtQObject;
+        #[cxx_name = "upcastQObjectMut"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_upcast_mut(object: Pin<&mut MyObject>) -> Pin<&mut CxxQtQObject>;
+    }
 }
 impl cxx_qt::Locking for inheritance::MyObject {}
 #[doc(hidden)]
 pub fn create_rs_my_object_rust() -> std::boxed::Box<MyObjectRust> {
     std::boxed::Box::new(core::default::Default::default())
 }
+impl inheritance::MyObject {
+    #[doc = "Create a new instance of the QObject which is owned by the returned UniquePtr"]
+    pub fn new() -> cxx::UniquePtr<Self> {
+        inheritance::cxx_qt_ffi_my_object_new_0()
+    }
+    #[doc = "Create a new instance of the QObject which is owned by the given parent"]
+    pub fn new_with_parent(parent: core::pin::Pin<&mut cxx_qt_lib::QObject>) -> *mut Self {
+        inheritance::cxx_qt_ffi_my_object_new_with_parent_0(parent)
+    }
+}
 impl core::ops::Deref for inheritance::MyObject {
     type Target = MyObjectRust;
     fn deref(&self) -> &Self::Target {
@@ -90,3 +125,11 @@ impl cxx_qt::CxxQtType for inheritance::MyObject {
         self.cxx_qt_ffi_rust_mut()
     }
 }
+impl cxx_qt_lib::Upcast<cxx_qt_lib::QObject> for inheritance::MyObject {
+    fn upcast(&self) -> &cxx_qt_lib::QObject {
+        inheritance::cxx_qt_ffi_my_object_upcast(self)
+    }
+    fn upcast_pin(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut cxx_qt_lib::QObject> {
+        inheritance::cxx_qt_ffi_my_object_upcast_mut(self)
+    }
+}
@@ -71,14 +71,24 @@ void
 MyObject::invokableResultTuple() const
 {
   const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
-  invokableResultTupleWrapper();
+  try {
+    invokableResultTupleWrapper();
+  } catch (const ::rust::Error& error) {
+    ::rust::cxxqtlib1::qmlThrowError<void>(
+      *this, error, ::rust::cxxqtlib1::QmlErrorType::GenericError);
+  }
 }
 
 ::rust::String
 MyObject::invokableResultType() const
 {
   const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
-  return invokableResultTypeWrapper();
+  try {
+    return invokableResultTypeWrapper();
+  } catch (const ::rust::Error& error) {
+    return ::rust::cxxqtlib1::qmlThrowError<::rust::String>(
+      *this, error, ::rust::cxxqtlib1::QmlErrorType::TypeError);
+  }
 }
 
 static_assert(alignof(MyObjectCxxQtThread) <= alignof(::std::size_t),
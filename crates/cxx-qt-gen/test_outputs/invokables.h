@@ -1,6 +1,7 @@
 #pragma once
 
 #include <cxx-qt-common/cxxqt_maybelockguard.h>
+#include <cxx-qt-common/cxxqt_qmlerror.h>
 #include <cxx-qt-common/cxxqt_threading.h>
 #include <cxx-qt-common/cxxqt_type.h>
 
@@ -21,6 +21,11 @@ mod ffi {
         #[namespace = "rust::cxxqtlib1"]
         #[rust_name = "CxxQtQMetaObjectConnection"]
         type QMetaObjectConnection = cxx_qt_lib::QMetaObjectConnection;
+        include!("cxx-qt-lib/qobject.h");
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQObject"]
+        type QObject = cxx_qt_lib::QObject;
     }
     unsafe extern "C++" {
         include!("cxx-qt-gen/ffi.cxxqt.h");
@@ -209,6 +214,33 @@ mod ffi {
             args: CxxQtConstructorInitializeArgumentsMyObject1,
         );
     }
+    unsafe extern "C++" {
+        #[cxx_name = "qobjectNew"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        unsafe fn cxx_qt_ffi_my_object_new_0<'a>(
+            arg0: i32,
+            arg1: &'a QString,
+        ) -> UniquePtr<MyObject>;
+        #[cxx_name = "qobjectNewWithParent"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        unsafe fn cxx_qt_ffi_my_object_new_with_parent_0<'a>(
+            parent: Pin<&mut CxxQtQObject>,
+            arg0: i32,
+            arg1: &'a QString,
+        ) -> *mut MyObject;
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "qobjectNew"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_new_1() -> UniquePtr<MyObject>;
+        #[cxx_name = "qobjectNewWithParent"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_new_with_parent_1(parent: Pin<&mut CxxQtQObject>) -> *mut MyObject;
+    }
     unsafe extern "C++" {
         #[cxx_name = "unsafeRust"]
         #[doc(hidden)]
@@ -219,6 +251,16 @@ mod ffi {
         #[doc(hidden)]
         fn cxx_qt_ffi_rust_mut(self: Pin<&mut MyObject>) -> Pin<&mut MyObjectRust>;
     }
+    unsafe extern "C++" {
+        #[cxx_name = "upcastQObject"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_upcast(object: &MyObject) -> &CxxQtQObject;
+        #[cxx_name = "upcastQObjectMut"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_upcast_mut(object: Pin<&mut MyObject>) -> Pin<&mut CxxQtQObject>;
+    }
 }
 impl cxx_qt::Threading for ffi::MyObject {
     type BoxedQueuedFn = MyObjectCxxQtThreadQueuedFn;
@@ -329,6 +371,30 @@ pub fn initialize_my_object_1(
 ) {
     <ffi::MyObject as cxx_qt::Constructor<()>>::initialize(qobject, ());
 }
+impl ffi::MyObject {
+    #[doc = "Create a new instance of the QObject which is owned by the returned UniquePtr"]
+    pub unsafe fn new_i32_qstring<'a>(arg0: i32, arg1: &'a QString) -> cxx::UniquePtr<Self> {
+        ffi::cxx_qt_ffi_my_object_new_0(arg0, arg1)
+    }
+    #[doc = "Create a new instance of the QObject which is owned by the given parent"]
+    pub unsafe fn new_i32_qstring_with_parent<'a>(
+        parent: core::pin::Pin<&mut cxx_qt_lib::QObject>,
+        arg0: i32,
+        arg1: &'a QString,
+    ) -> *mut Self {
+        ffi::cxx_qt_ffi_my_object_new_with_parent_0(parent, arg0, arg1)
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Create a new instance of the QObject which is owned by the returned UniquePtr"]
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::cxx_qt_ffi_my_object_new_1()
+    }
+    #[doc = "Create a new instance of the QObject which is owned by the given parent"]
+    pub fn new_with_parent(parent: core::pin::Pin<&mut cxx_qt_lib::QObject>) -> *mut Self {
+        ffi::cxx_qt_ffi_my_object_new_with_parent_1(parent)
+    }
+}
 impl core::ops::Deref for ffi::MyObject {
     type Target = MyObjectRust;
     fn deref(&self) -> &Self::Target {
@@ -344,3 +410,11 @@ impl cxx_qt::CxxQtType for ffi::MyObject {
         self.cxx_qt_ffi_rust_mut()
     }
 }
+impl cxx_qt_lib::Upcast<cxx_qt_lib::QObject> for ffi::MyObject {
+    fn upcast(&self) -> &cxx_qt_lib::QObject {
+        ffi::cxx_qt_ffi_my_object_upcast(self)
+    }
+    fn upcast_pin(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut cxx_qt_lib::QObject> {
+        ffi::cxx_qt_ffi_my_object_upcast_mut(self)
+    }
+}
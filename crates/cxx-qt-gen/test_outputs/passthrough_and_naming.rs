@@ -57,6 +57,11 @@ pub mod ffi {
         #[namespace = "rust::cxxqtlib1"]
         #[rust_name = "CxxQtQMetaObjectConnection"]
         type QMetaObjectConnection = cxx_qt_lib::QMetaObjectConnection;
+        include!("cxx-qt-lib/qobject.h");
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQObject"]
+        type QObject = cxx_qt_lib::QObject;
     }
     unsafe extern "C++" {
         include!("cxx-qt-gen/multi_object.cxxqt.h");
@@ -124,6 +129,16 @@ pub mod ffi {
         #[namespace = "cxx_qt::multi_object::cxx_qt_my_object"]
         fn create_rs_my_object_rust() -> Box<MyObjectRust>;
     }
+    unsafe extern "C++" {
+        #[cxx_name = "qobjectNew"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_new_0() -> UniquePtr<MyObject>;
+        #[cxx_name = "qobjectNewWithParent"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_new_with_parent_0(parent: Pin<&mut CxxQtQObject>) -> *mut MyObject;
+    }
     unsafe extern "C++" {
         #[cxx_name = "unsafeRust"]
         #[doc(hidden)]
@@ -134,6 +149,16 @@ pub mod ffi {
         #[doc(hidden)]
         fn cxx_qt_ffi_rust_mut(self: Pin<&mut MyObject>) -> Pin<&mut MyObjectRust>;
     }
+    unsafe extern "C++" {
+        #[cxx_name = "upcastQObject"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_upcast(object: &MyObject) -> &CxxQtQObject;
+        #[cxx_name = "upcastQObjectMut"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_upcast_mut(object: Pin<&mut MyObject>) -> Pin<&mut CxxQtQObject>;
+    }
     unsafe extern "C++" {
         #[doc = "The C++ type for the QObject "]
         #[doc = "SecondObjectRust"]
@@ -198,6 +223,18 @@ pub mod ffi {
         #[namespace = "cxx_qt::multi_object::cxx_qt_second_object"]
         fn create_rs_second_object_rust() -> Box<SecondObjectRust>;
     }
+    unsafe extern "C++" {
+        #[cxx_name = "qobjectNew"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_second_object_new_0() -> UniquePtr<SecondObject>;
+        #[cxx_name = "qobjectNewWithParent"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_second_object_new_with_parent_0(
+            parent: Pin<&mut CxxQtQObject>,
+        ) -> *mut SecondObject;
+    }
     unsafe extern "C++" {
         #[cxx_name = "unsafeRust"]
         #[doc(hidden)]
@@ -208,6 +245,18 @@ pub mod ffi {
         #[doc(hidden)]
         fn cxx_qt_ffi_rust_mut(self: Pin<&mut SecondObject>) -> Pin<&mut SecondObjectRust>;
     }
+    unsafe extern "C++" {
+        #[cxx_name = "upcastQObject"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_second_object_upcast(object: &SecondObject) -> &CxxQtQObject;
+        #[cxx_name = "upcastQObjectMut"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_second_object_upcast_mut(
+            object: Pin<&mut SecondObject>,
+        ) -> Pin<&mut CxxQtQObject>;
+    }
 }
 impl ffi::MyObject {
     #[doc = "Getter for the Q_PROPERTY "]
@@ -261,6 +310,16 @@ impl cxx_qt::Locking for ffi::MyObject {}
 pub fn create_rs_my_object_rust() -> std::boxed::Box<MyObjectRust> {
     std::boxed::Box::new(core::default::Default::default())
 }
+impl ffi::MyObject {
+    #[doc = "Create a new instance of the QObject which is owned by the returned UniquePtr"]
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::cxx_qt_ffi_my_object_new_0()
+    }
+    #[doc = "Create a new instance of the QObject which is owned by the given parent"]
+    pub fn new_with_parent(parent: core::pin::Pin<&mut cxx_qt_lib::QObject>) -> *mut Self {
+        ffi::cxx_qt_ffi_my_object_new_with_parent_0(parent)
+    }
+}
 impl core::ops::Deref for ffi::MyObject {
     type Target = MyObjectRust;
     fn deref(&self) -> &Self::Target {
@@ -276,6 +335,14 @@ impl cxx_qt::CxxQtType for ffi::MyObject {
         self.cxx_qt_ffi_rust_mut()
     }
 }
+impl cxx_qt_lib::Upcast<cxx_qt_lib::QObject> for ffi::MyObject {
+    fn upcast(&self) -> &cxx_qt_lib::QObject {
+        ffi::cxx_qt_ffi_my_object_upcast(self)
+    }
+    fn upcast_pin(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut cxx_qt_lib::QObject> {
+        ffi::cxx_qt_ffi_my_object_upcast_mut(self)
+    }
+}
 impl ffi::SecondObject {
     #[doc = "Getter for the Q_PROPERTY "]
     #[doc = "property_name"]
@@ -327,6 +394,16 @@ impl ffi::SecondObject {
 pub fn create_rs_second_object_rust() -> std::boxed::Box<SecondObjectRust> {
     std::boxed::Box::new(core::default::Default::default())
 }
+impl ffi::SecondObject {
+    #[doc = "Create a new instance of the QObject which is owned by the returned UniquePtr"]
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::cxx_qt_ffi_second_object_new_0()
+    }
+    #[doc = "Create a new instance of the QObject which is owned by the given parent"]
+    pub fn new_with_parent(parent: core::pin::Pin<&mut cxx_qt_lib::QObject>) -> *mut Self {
+        ffi::cxx_qt_ffi_second_object_new_with_parent_0(parent)
+    }
+}
 impl core::ops::Deref for ffi::SecondObject {
     type Target = SecondObjectRust;
     fn deref(&self) -> &Self::Target {
@@ -342,3 +419,11 @@ impl cxx_qt::CxxQtType for ffi::SecondObject {
         self.cxx_qt_ffi_rust_mut()
     }
 }
+impl cxx_qt_lib::Upcast<cxx_qt_lib::QObject> for ffi::SecondObject {
+    fn upcast(&self) -> &cxx_qt_lib::QObject {
+        ffi::cxx_qt_ffi_second_object_upcast(self)
+    }
+    fn upcast_pin(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut cxx_qt_lib::QObject> {
+        ffi::cxx_qt_ffi_second_object_upcast_mut(self)
+    }
+}
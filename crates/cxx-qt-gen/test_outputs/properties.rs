@@ -17,6 +17,11 @@ mod ffi {
         #[namespace = "rust::cxxqtlib1"]
         #[rust_name = "CxxQtQMetaObjectConnection"]
         type QMetaObjectConnection = cxx_qt_lib::QMetaObjectConnection;
+        include!("cxx-qt-lib/qobject.h");
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQObject"]
+        type QObject = cxx_qt_lib::QObject;
     }
     unsafe extern "C++" {
         include!("cxx-qt-gen/ffi.cxxqt.h");
@@ -88,6 +93,16 @@ mod ffi {
         #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
         fn create_rs_my_object_rust() -> Box<MyObjectRust>;
     }
+    unsafe extern "C++" {
+        #[cxx_name = "qobjectNew"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_new_0() -> UniquePtr<MyObject>;
+        #[cxx_name = "qobjectNewWithParent"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_new_with_parent_0(parent: Pin<&mut CxxQtQObject>) -> *mut MyObject;
+    }
     unsafe extern "C++" {
         #[cxx_name = "unsafeRust"]
         #[doc(hidden)]
@@ -98,6 +113,16 @@ mod ffi {
         #[doc(hidden)]
         fn cxx_qt_ffi_rust_mut(self: Pin<&mut MyObject>) -> Pin<&mut MyObjectRust>;
     }
+    unsafe extern "C++" {
+        #[cxx_name = "upcastQObject"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_upcast(object: &MyObject) -> &CxxQtQObject;
+        #[cxx_name = "upcastQObjectMut"]
+        #[namespace = "rust::cxxqtlib1"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_upcast_mut(object: Pin<&mut MyObject>) -> Pin<&mut CxxQtQObject>;
+    }
 }
 impl ffi::MyObject {
     #[doc = "Getter for the Q_PROPERTY "]
@@ -170,6 +195,16 @@ impl cxx_qt::Locking for ffi::MyObject {}
 pub fn create_rs_my_object_rust() -> std::boxed::Box<MyObjectRust> {
     std::boxed::Box::new(core::default::Default::default())
 }
+impl ffi::MyObject {
+    #[doc = "Create a new instance of the QObject which is owned by the returned UniquePtr"]
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::cxx_qt_ffi_my_object_new_0()
+    }
+    #[doc = "Create a new instance of the QObject which is owned by the given parent"]
+    pub fn new_with_parent(parent: core::pin::Pin<&mut cxx_qt_lib::QObject>) -> *mut Self {
+        ffi::cxx_qt_ffi_my_object_new_with_parent_0(parent)
+    }
+}
 impl core::ops::Deref for ffi::MyObject {
     type Target = MyObjectRust;
     fn deref(&self) -> &Self::Target {
@@ -185,3 +220,11 @@ impl cxx_qt::CxxQtType for ffi::MyObject {
         self.cxx_qt_ffi_rust_mut()
     }
 }
+impl cxx_qt_lib::Upcast<cxx_qt_lib::QObject> for ffi::MyObject {
+    fn upcast(&self) -> &cxx_qt_lib::QObject {
+        ffi::cxx_qt_ffi_my_object_upcast(self)
+    }
+    fn upcast_pin(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut cxx_qt_lib::QObject> {
+        ffi::cxx_qt_ffi_my_object_upcast_mut(self)
+    }
+}
@@ -12,10 +12,15 @@
 
 #include <QtCore/QByteArray>
 #include <QtCore/QCoreApplication>
+#include <QtCore/QEvent>
 #include <QtCore/QString>
 #include <QtCore/QStringList>
 #include <QtCore/QVector>
 
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+
 namespace rust {
 namespace cxxqtlib1 {
 
@@ -37,88 +42,91 @@ private:
 ::std::unique_ptr<QCoreApplication>
 qcoreapplicationNew(const QVector<QByteArray>& args);
 
-template<typename T>
+// These are all static in Qt, so we need to create bindings until CXX
+// supports statics
 void
-qapplicationAddLibraryPath(T& app, const QString& path)
-{
-  app.addLibraryPath(path);
-}
-
-template<typename T>
+qcoreapplicationAddLibraryPath(QCoreApplication& app, const QString& path);
 QString
-qapplicationApplicationName(const T& app)
-{
-  return app.applicationName();
-}
-
-template<typename T>
+qcoreapplicationApplicationName(const QCoreApplication& app);
 QString
-qapplicationApplicationVersion(const T& app)
-{
-  return app.applicationVersion();
-}
-
-template<typename T>
+qcoreapplicationApplicationVersion(const QCoreApplication& app);
+QStringList
+qcoreapplicationArguments(const QCoreApplication& app);
 ::std::int32_t
-qapplicationExec(T& app)
-{
-  return static_cast<::std::int32_t>(app.exec());
-}
-
-template<typename T>
+qcoreapplicationExec(QCoreApplication& app);
+void
+qcoreapplicationExit(::std::int32_t returnCode);
+void
+qcoreapplicationExitQueued(::std::int32_t returnCode);
+QCoreApplication*
+qcoreapplicationInstance();
 QStringList
-qapplicationLibraryPaths(const T& app)
-{
-  return app.libraryPaths();
-}
-
-template<typename T>
+qcoreapplicationLibraryPaths(const QCoreApplication& app);
 QString
-qapplicationOrganizationDomain(const T& app)
-{
-  return app.organizationDomain();
-}
-
-template<typename T>
+qcoreapplicationOrganizationDomain(const QCoreApplication& app);
 QString
-qapplicationOrganizationName(const T& app)
-{
-  return app.organizationName();
-}
-
-template<typename T>
+qcoreapplicationOrganizationName(const QCoreApplication& app);
 void
-qapplicationSetApplicationName(T& app, const QString& name)
-{
-  app.setApplicationName(name);
-}
-
-template<typename T>
+qcoreapplicationPostEvent(QObject* receiver,
+                          ::std::unique_ptr<QEvent> event,
+                          ::std::int32_t priority);
 void
-qapplicationSetApplicationVersion(T& app, const QString& version)
-{
-  app.setApplicationVersion(version);
-}
-
-template<typename T>
+qcoreapplicationProcessEvents();
+void
+qcoreapplicationSendPostedEvents();
+void
+qcoreapplicationSetApplicationName(QCoreApplication& app, const QString& name);
+void
+qcoreapplicationSetApplicationVersion(QCoreApplication& app,
+                                      const QString& version);
+void
+qcoreapplicationSetAttribute(Qt::ApplicationAttribute attribute, bool on);
 void
-qapplicationSetLibraryPaths(T& app, const QStringList& paths)
+qcoreapplicationSetLibraryPaths(QCoreApplication& app,
+                                const QStringList& paths);
+void
+qcoreapplicationSetOrganizationDomain(QCoreApplication& app,
+                                      const QString& domain);
+void
+qcoreapplicationSetOrganizationName(QCoreApplication& app,
+                                    const QString& name);
+bool
+qcoreapplicationTestAttribute(Qt::ApplicationAttribute attribute);
+
+// The closure is a Rust type which is only declared after this header
+// in the generated bridge, so this is a template which is instantiated there.
+//
+// Note that the Box is wrapped in a shared_ptr as Qt may copy the functor.
+template<typename C>
+QMetaObjectConnection
+qcoreapplicationOnAboutToQuit(QCoreApplication& app,
+                              ::rust::Fn<void(C&)> func,
+                              ::rust::Box<C> closure)
 {
-  app.setLibraryPaths(paths);
+  auto shared = ::std::make_shared<::rust::Box<C>>(::std::move(closure));
+  return QObject::connect(
+    &app,
+    &QCoreApplication::aboutToQuit,
+    &app,
+    [func = ::std::move(func), shared = ::std::move(shared)]() {
+      func(**shared);
+    });
 }
 
+// These can be used to upcast any QCoreApplication subclass to a
+// QCoreApplication, such as QGuiApplication or QApplication.
 template<typename T>
-void
-qapplicationSetOrganizationDomain(T& app, const QString& domain)
+const QCoreApplication&
+upcastQCoreApplication(const T& object)
 {
-  app.setOrganizationDomain(domain);
+  return object;
 }
 
 template<typename T>
-void
-qapplicationSetOrganizationName(T& app, const QString& name)
+QCoreApplication&
+upcastQCoreApplicationMut(T& object)
 {
-  app.setOrganizationName(name);
+  return object;
 }
 
 }
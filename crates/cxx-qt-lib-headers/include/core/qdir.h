@@ -0,0 +1,67 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QDir>
+#include <QtCore/QString>
+#include <QtCore/QStringList>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QDir> : ::std::true_type
+{
+};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QDirFilter = QDir::Filter;
+using QDirSortFlag = QDir::SortFlag;
+
+QString
+qdirCleanPath(const QString& path);
+::rust::isize
+qdirCount(const QDir& dir);
+QString
+qdirCurrentPath();
+QStringList
+qdirEntryList(const QDir& dir, QDirFilter filters, QDirSortFlag sort);
+QStringList
+qdirEntryListWithNameFilters(const QDir& dir,
+                             const QStringList& nameFilters,
+                             QDirFilter filters,
+                             QDirSortFlag sort);
+QDirFilter
+qdirFilter(const QDir& dir);
+QString
+qdirFromNativeSeparators(const QString& pathName);
+QString
+qdirHomePath();
+QString
+qdirRootPath();
+bool
+qdirSetCurrent(const QString& path);
+void
+qdirSetFilter(QDir& dir, QDirFilter filters);
+void
+qdirSetSorting(QDir& dir, QDirSortFlag sort);
+QDirSortFlag
+qdirSorting(const QDir& dir);
+QString
+qdirTempPath();
+QString
+qdirToNativeSeparators(const QString& pathName);
+
+}
+}
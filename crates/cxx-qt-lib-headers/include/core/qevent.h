@@ -0,0 +1,25 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QEvent>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QEvent>
+qeventNew(::std::int32_t type);
+::std::int32_t
+qeventRegisterEventType(::std::int32_t hint);
+::std::int32_t
+qeventType(const QEvent& event);
+
+}
+}
@@ -0,0 +1,37 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QFile>
+#include <QtCore/QString>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+using QIODeviceOpenModeFlag = QIODeviceBase::OpenModeFlag;
+#else
+using QIODeviceOpenModeFlag = QIODevice::OpenModeFlag;
+#endif
+
+QString
+qfileDecodeName(const QByteArray& localFileName);
+QByteArray
+qfileEncodeName(const QString& fileName);
+::std::unique_ptr<QFile>
+qfileNew(const QString& name);
+bool
+qfileOpen(QFile& file, QIODeviceOpenModeFlag mode);
+::std::int64_t
+qfileSize(const QFile& file);
+
+}
+}
@@ -0,0 +1,38 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtCore/QDir>
+#include <QtCore/QFileInfo>
+#include <QtCore/QString>
+#include <QtCore/QUrl>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QFileInfo> : ::std::true_type
+{
+};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QFileInfo
+qfileinfoFromUrl(const QUrl& url);
+::std::int64_t
+qfileinfoSize(const QFileInfo& info);
+
+}
+}
@@ -0,0 +1,24 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QLineF>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QLineFIntersectionType = QLineF::IntersectionType;
+
+QLineF
+qlinefFromPolar(double length, double angle);
+QLineFIntersectionType
+qlinefIntersects(const QLineF& line,
+                 const QLineF& other,
+                 QPointF& intersectionPoint);
+
+}
+}
@@ -13,6 +13,8 @@
 #include <QtCore/QByteArray>
 #include <QtCore/QDate>
 #include <QtCore/QDateTime>
+#include <QtCore/QLine>
+#include <QtCore/QLineF>
 #include <QtCore/QMargins>
 #include <QtCore/QMarginsF>
 #include <QtCore/QPersistentModelIndex>
@@ -29,6 +31,11 @@
 
 #ifdef CXX_QT_GUI_FEATURE
 #include <QtGui/QColor>
+#include <QtGui/QMatrix4x4>
+#include <QtGui/QPolygon>
+#include <QtGui/QPolygonF>
+#include <QtGui/QQuaternion>
+#include <QtGui/QTransform>
 #endif
 
 #include "rust/cxx.h"
@@ -155,17 +162,30 @@ using QList_QColor = QList<::QColor>;
 #endif
 using QList_QDate = QList<::QDate>;
 using QList_QDateTime = QList<::QDateTime>;
+using QList_QLine = QList<::QLine>;
+using QList_QLineF = QList<::QLineF>;
 using QList_QMargins = QList<::QMargins>;
 using QList_QMarginsF = QList<::QMarginsF>;
+#ifdef CXX_QT_GUI_FEATURE
+using QList_QMatrix4x4 = QList<::QMatrix4x4>;
+#endif
 using QList_QPersistentModelIndex = QList<::QPersistentModelIndex>;
 using QList_QPoint = QList<::QPoint>;
 using QList_QPointF = QList<::QPointF>;
+#ifdef CXX_QT_GUI_FEATURE
+using QList_QPolygon = QList<::QPolygon>;
+using QList_QPolygonF = QList<::QPolygonF>;
+using QList_QQuaternion = QList<::QQuaternion>;
+#endif
 using QList_QRect = QList<::QRect>;
 using QList_QRectF = QList<::QRectF>;
 using QList_QSize = QList<::QSize>;
 using QList_QSizeF = QList<::QSizeF>;
 using QList_QString = QList<::QString>;
 using QList_QTime = QList<::QTime>;
+#ifdef CXX_QT_GUI_FEATURE
+using QList_QTransform = QList<::QTransform>;
+#endif
 using QList_QUrl = QList<::QUrl>;
 using QList_QVariant = QList<::QVariant>;
 using QList_u8 = QList<::std::uint8_t>;
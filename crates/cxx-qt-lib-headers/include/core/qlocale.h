@@ -0,0 +1,99 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cinttypes>
+
+#include <QtCore/QDate>
+#include <QtCore/QLocale>
+#include <QtCore/QString>
+#include <QtCore/QTime>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QLocale> : ::std::true_type
+{
+};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QLocaleCurrencySymbolFormat = ::QLocale::CurrencySymbolFormat;
+using QLocaleFormatType = ::QLocale::FormatType;
+using QLocaleMeasurementSystem = ::QLocale::MeasurementSystem;
+
+QLocale
+qlocaleC();
+QLocale
+qlocaleSystem();
+void
+qlocaleSetDefault(const QLocale& locale);
+
+// In Qt 5 these are a QChar, in Qt 6 they are a QString
+QString
+qlocaleDecimalPoint(const QLocale& locale);
+QString
+qlocaleGroupSeparator(const QLocale& locale);
+QString
+qlocaleNegativeSign(const QLocale& locale);
+QString
+qlocalePercent(const QLocale& locale);
+QString
+qlocalePositiveSign(const QLocale& locale);
+
+// Parse with the ok flag as a reference for CXX
+double
+qlocaleToDouble(const QLocale& locale, const QString& s, bool& ok);
+::std::int32_t
+qlocaleToInt(const QLocale& locale, const QString& s, bool& ok);
+::std::int64_t
+qlocaleToLongLong(const QLocale& locale, const QString& s, bool& ok);
+
+// CXX can't choose between toString and toCurrencyString overloads so use C++
+QString
+qlocaleToCurrencyStringF64(const QLocale& locale,
+                           double value,
+                           const QString& symbol);
+QString
+qlocaleToCurrencyStringI64(const QLocale& locale,
+                           ::std::int64_t value,
+                           const QString& symbol);
+QString
+qlocaleToStringDate(const QLocale& locale,
+                    QDate date,
+                    QLocaleFormatType format);
+QString
+qlocaleToStringDateFormat(const QLocale& locale,
+                          QDate date,
+                          const QString& format);
+QString
+qlocaleToStringF64(const QLocale& locale,
+                   double value,
+                   ::std::uint8_t format,
+                   ::std::int32_t precision);
+QString
+qlocaleToStringI64(const QLocale& locale, ::std::int64_t value);
+QString
+qlocaleToStringTime(const QLocale& locale,
+                    QTime time,
+                    QLocaleFormatType format);
+QString
+qlocaleToStringTimeFormat(const QLocale& locale,
+                          QTime time,
+                          const QString& format);
+QString
+qlocaleToStringU64(const QLocale& locale, ::std::uint64_t value);
+
+}
+}
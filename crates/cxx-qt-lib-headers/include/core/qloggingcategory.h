@@ -0,0 +1,36 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QLoggingCategory>
+#include <QtCore/QString>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/qtlogging.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QString
+qloggingcategoryCategoryName(const QLoggingCategory& category);
+void
+qloggingcategoryLog(const QLoggingCategory& category,
+                    QtMsgType type,
+                    ::rust::Str file,
+                    ::std::int32_t line,
+                    const QString& message);
+::std::unique_ptr<QLoggingCategory>
+qloggingcategoryNew(::rust::Str name);
+void
+qloggingcategorySetFilterRules(const QString& rules);
+
+}
+}
@@ -0,0 +1,71 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QObject>
+#include <QtCore/QString>
+#include <QtCore/QStringList>
+#include <QtCore/QVariant>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QObject*
+qobjectChildAt(const QObject& object, ::rust::isize index);
+::rust::isize
+qobjectChildrenLen(const QObject& object);
+QStringList
+qobjectDynamicPropertyNames(const QObject& object);
+QObject*
+qobjectFindChild(const QObject& object, const QString& name);
+QVariant
+qobjectProperty(const QObject& object, const QString& name);
+bool
+qobjectSetProperty(QObject& object,
+                   const QString& name,
+                   const QVariant& value);
+
+// These are used by the generated code of CXX-Qt to upcast
+// any QObject subclass to a QObject
+template<typename T>
+const QObject&
+upcastQObject(const T& object)
+{
+  return object;
+}
+
+template<typename T>
+QObject&
+upcastQObjectMut(T& object)
+{
+  return object;
+}
+
+// These are used by the generated code of CXX-Qt to create
+// any QObject subclass from Rust
+template<typename T, typename... Args>
+::std::unique_ptr<T>
+qobjectNew(Args... args)
+{
+  return ::std::make_unique<T>(::std::forward<Args>(args)...);
+}
+
+template<typename T, typename... Args>
+T*
+qobjectNewWithParent(QObject& parent, Args... args)
+{
+  auto* object = new T(::std::forward<Args>(args)...);
+  object->setParent(&parent);
+  return object;
+}
+
+}
+}
@@ -0,0 +1,39 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QObject>
+#include <QtCore/QPointer>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+// This has static asserts in the cpp file to ensure this is valid.
+template<>
+struct IsRelocatable<::QPointer<::QObject>> : ::std::true_type
+{
+};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QPointer_QObject = ::QPointer<::QObject>;
+
+QPointer_QObject
+qpointerNew(QObject& object);
+bool
+qpointerIsNull(const QPointer_QObject& pointer);
+QObject*
+qpointerUpgrade(const QPointer_QObject& pointer);
+
+}
+}
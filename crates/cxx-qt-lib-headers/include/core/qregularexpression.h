@@ -0,0 +1,56 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QRegularExpression>
+#include <QtCore/QString>
+#include <QtCore/QStringList>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QRegularExpression> : ::std::true_type
+{
+};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QRegularExpressionMatchType = ::QRegularExpression::MatchType;
+using QRegularExpressionPatternOption = ::QRegularExpression::PatternOption;
+
+QString
+qregularexpressionEscape(const QString& str);
+QRegularExpressionMatchIterator
+qregularexpressionGlobalMatch(const QRegularExpression& re,
+                              const QString& subject,
+                              ::rust::isize offset,
+                              QRegularExpressionMatchType matchType);
+QRegularExpression
+qregularexpressionInitFromPattern(const QString& pattern,
+                                  QRegularExpressionPatternOption options);
+QRegularExpressionMatch
+qregularexpressionMatch(const QRegularExpression& re,
+                        const QString& subject,
+                        ::rust::isize offset,
+                        QRegularExpressionMatchType matchType);
+::rust::isize
+qregularexpressionPatternErrorOffset(const QRegularExpression& re);
+QRegularExpressionPatternOption
+qregularexpressionPatternOptions(const QRegularExpression& re);
+void
+qregularexpressionSetPatternOptions(QRegularExpression& re,
+                                    QRegularExpressionPatternOption options);
+
+}
+}
@@ -0,0 +1,53 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QRegularExpressionMatch>
+#include <QtCore/QString>
+#include <QtCore/QStringList>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QRegularExpressionMatch> : ::std::true_type
+{
+};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// Qt 5 has an int Qt 6 has a qsizetype, so wrap these to always use isize
+QString
+qregularexpressionmatchCapturedName(const QRegularExpressionMatch& match,
+                                    const QString& name);
+::rust::isize
+qregularexpressionmatchCapturedEnd(const QRegularExpressionMatch& match,
+                                   ::std::int32_t nth);
+::rust::isize
+qregularexpressionmatchCapturedEndName(const QRegularExpressionMatch& match,
+                                       const QString& name);
+::rust::isize
+qregularexpressionmatchCapturedLength(const QRegularExpressionMatch& match,
+                                      ::std::int32_t nth);
+::rust::isize
+qregularexpressionmatchCapturedLengthName(const QRegularExpressionMatch& match,
+                                          const QString& name);
+::rust::isize
+qregularexpressionmatchCapturedStart(const QRegularExpressionMatch& match,
+                                     ::std::int32_t nth);
+::rust::isize
+qregularexpressionmatchCapturedStartName(const QRegularExpressionMatch& match,
+                                         const QString& name);
+
+}
+}
@@ -0,0 +1,58 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QSettings>
+#include <QtCore/QString>
+#include <QtCore/QVariant>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QSettingsFormat = QSettings::Format;
+using QSettingsScope = QSettings::Scope;
+using QSettingsStatus = QSettings::Status;
+
+// Qt 6 uses QAnyStringView for keys, so these need to be helpers
+// rather than binding to the member functions directly.
+void
+qsettingsBeginGroup(QSettings& settings, const QString& prefix);
+::std::int32_t
+qsettingsBeginReadArray(QSettings& settings, const QString& prefix);
+void
+qsettingsBeginWriteArray(QSettings& settings,
+                         const QString& prefix,
+                         ::std::int32_t size);
+bool
+qsettingsContains(const QSettings& settings, const QString& key);
+::std::unique_ptr<QSettings>
+qsettingsNew(const QString& organization, const QString& application);
+::std::unique_ptr<QSettings>
+qsettingsNewFromFile(const QString& fileName, QSettingsFormat format);
+::std::unique_ptr<QSettings>
+qsettingsNewWithFormat(QSettingsFormat format,
+                       QSettingsScope scope,
+                       const QString& organization,
+                       const QString& application);
+void
+qsettingsRemove(QSettings& settings, const QString& key);
+void
+qsettingsSetValue(QSettings& settings,
+                  const QString& key,
+                  const QVariant& value);
+QVariant
+qsettingsValue(const QSettings& settings, const QString& key);
+QVariant
+qsettingsValueOr(const QSettings& settings,
+                 const QString& key,
+                 const QVariant& defaultValue);
+
+}
+}
@@ -0,0 +1,41 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QStandardPaths>
+#include <QtCore/QString>
+#include <QtCore/QStringList>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QStandardPathsLocateOption = QStandardPaths::LocateOption;
+using QStandardPathsStandardLocation = QStandardPaths::StandardLocation;
+
+// QStandardPaths only has static methods, so these are all free functions
+QString
+qstandardpathsDisplayName(QStandardPathsStandardLocation type);
+QString
+qstandardpathsFindExecutable(const QString& executableName,
+                             const QStringList& paths);
+QString
+qstandardpathsLocate(QStandardPathsStandardLocation type,
+                     const QString& fileName,
+                     QStandardPathsLocateOption options);
+QStringList
+qstandardpathsLocateAll(QStandardPathsStandardLocation type,
+                        const QString& fileName,
+                        QStandardPathsLocateOption options);
+void
+qstandardpathsSetTestModeEnabled(bool testMode);
+QStringList
+qstandardpathsStandardLocations(QStandardPathsStandardLocation type);
+QString
+qstandardpathsWritableLocation(QStandardPathsStandardLocation type);
+
+}
+}
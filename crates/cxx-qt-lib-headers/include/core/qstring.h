@@ -8,6 +8,7 @@
 #pragma once
 
 #include <QtCore/QByteArray>
+#include <QtCore/QRegularExpression>
 #include <QtCore/QString>
 #include <QtCore/QStringList>
 #include <QtCore/Qt>
@@ -35,6 +36,9 @@ qstringToRustString(const QString& string);
 
 QString
 qstringArg(const QString& string, const QString& a);
+bool
+qstringContainsRegularExpression(const QString& string,
+                                 const QRegularExpression& re);
 ::rust::isize
 qstringIndexOf(const QString& string,
                const QString& str,
@@ -55,6 +59,10 @@ qstringSplit(const QString& string,
              const QString& sep,
              Qt::SplitBehaviorFlags behavior,
              Qt::CaseSensitivity cs);
+QStringList
+qstringSplitRegularExpression(const QString& string,
+                              const QRegularExpression& sep,
+                              Qt::SplitBehaviorFlags behavior);
 
 // If Q_COMPILER_REF_QUALIFIERS is set the definition of these is
 // T method() const& which CXX doesn't bind it.
@@ -0,0 +1,23 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QString>
+#include <QtCore/QTemporaryDir>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QTemporaryDir>
+qtemporarydirNew();
+::std::unique_ptr<QTemporaryDir>
+qtemporarydirNewWithTemplate(const QString& templatePath);
+
+}
+}
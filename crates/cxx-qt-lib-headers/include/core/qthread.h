@@ -0,0 +1,18 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QThread>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QThread*
+qthreadCurrentThread();
+
+}
+}
@@ -0,0 +1,61 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QTimer>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QTimer>
+qtimerNew();
+
+// The closure is a Rust type which is only declared after this header
+// in the generated bridge, so these are templates which are instantiated there.
+//
+// Note that the Box is wrapped in a shared_ptr as Qt may copy the functor.
+template<typename C>
+QMetaObjectConnection
+qtimerOnTimeout(QTimer& timer,
+                ::rust::Fn<void(C&)> func,
+                ::rust::Box<C> closure)
+{
+  auto shared = ::std::make_shared<::rust::Box<C>>(::std::move(closure));
+  return QObject::connect(
+    &timer,
+    &QTimer::timeout,
+    &timer,
+    [func = ::std::move(func), shared = ::std::move(shared)]() {
+      func(**shared);
+    });
+}
+
+template<typename C>
+void
+qtimerSingleShot(::std::int32_t msec,
+                 Qt::TimerType timerType,
+                 ::rust::Fn<void(C&)> func,
+                 ::rust::Box<C> closure)
+{
+  auto shared = ::std::make_shared<::rust::Box<C>>(::std::move(closure));
+  QTimer::singleShot(
+    static_cast<int>(msec),
+    timerType,
+    [func = ::std::move(func), shared = ::std::move(shared)]() {
+      func(**shared);
+    });
+}
+
+}
+}
@@ -0,0 +1,34 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtCore/QString>
+#include <QtCore/QtGlobal>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QtMsgType = ::QtMsgType;
+
+using QtMessageHandlerFn = ::rust::Fn<void(QtMsgType,
+                                           ::rust::Slice<const ::std::uint8_t>,
+                                           ::rust::Slice<const ::std::uint8_t>,
+                                           ::std::int32_t,
+                                           ::rust::Slice<const ::std::uint8_t>,
+                                           const QString&)>;
+
+void
+qtloggingInstallMessageHandler(QtMessageHandlerFn handler);
+void
+qtloggingRestoreMessageHandler();
+
+}
+}
@@ -14,6 +14,8 @@
 #include <QtCore/QByteArray>
 #include <QtCore/QDate>
 #include <QtCore/QDateTime>
+#include <QtCore/QLine>
+#include <QtCore/QLineF>
 #include <QtCore/QModelIndex>
 #include <QtCore/QPersistentModelIndex>
 #include <QtCore/QPoint>
@@ -29,6 +31,11 @@
 
 #ifdef CXX_QT_GUI_FEATURE
 #include <QtGui/QColor>
+#include <QtGui/QMatrix4x4>
+#include <QtGui/QPolygon>
+#include <QtGui/QPolygonF>
+#include <QtGui/QQuaternion>
+#include <QtGui/QTransform>
 #endif
 
 #include "rust/cxx.h"
@@ -80,10 +87,20 @@ CXX_QT_QVARIANT_CAN_CONVERT(QColor)
 #endif
 CXX_QT_QVARIANT_CAN_CONVERT(QDate)
 CXX_QT_QVARIANT_CAN_CONVERT(QDateTime)
+CXX_QT_QVARIANT_CAN_CONVERT(QLine)
+CXX_QT_QVARIANT_CAN_CONVERT(QLineF)
+#ifdef CXX_QT_GUI_FEATURE
+CXX_QT_QVARIANT_CAN_CONVERT(QMatrix4x4)
+#endif
 CXX_QT_QVARIANT_CAN_CONVERT(QModelIndex)
 CXX_QT_QVARIANT_CAN_CONVERT(QPersistentModelIndex)
 CXX_QT_QVARIANT_CAN_CONVERT(QPoint)
 CXX_QT_QVARIANT_CAN_CONVERT(QPointF)
+#ifdef CXX_QT_GUI_FEATURE
+CXX_QT_QVARIANT_CAN_CONVERT(QPolygon)
+CXX_QT_QVARIANT_CAN_CONVERT(QPolygonF)
+CXX_QT_QVARIANT_CAN_CONVERT(QQuaternion)
+#endif
 CXX_QT_QVARIANT_CAN_CONVERT(QRect)
 CXX_QT_QVARIANT_CAN_CONVERT(QRectF)
 CXX_QT_QVARIANT_CAN_CONVERT(QSize)
@@ -91,6 +108,9 @@ CXX_QT_QVARIANT_CAN_CONVERT(QSizeF)
 CXX_QT_QVARIANT_CAN_CONVERT(QString)
 CXX_QT_QVARIANT_CAN_CONVERT(QStringList)
 CXX_QT_QVARIANT_CAN_CONVERT(QTime)
+#ifdef CXX_QT_GUI_FEATURE
+CXX_QT_QVARIANT_CAN_CONVERT(QTransform)
+#endif
 CXX_QT_QVARIANT_CAN_CONVERT(QUrl)
 CXX_QT_QVARIANT_CAN_CONVERT(U8)
 CXX_QT_QVARIANT_CAN_CONVERT(U16)
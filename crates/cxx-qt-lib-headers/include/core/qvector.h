@@ -13,6 +13,8 @@
 #include <QtCore/QByteArray>
 #include <QtCore/QDate>
 #include <QtCore/QDateTime>
+#include <QtCore/QLine>
+#include <QtCore/QLineF>
 #include <QtCore/QMargins>
 #include <QtCore/QMarginsF>
 #include <QtCore/QPersistentModelIndex>
@@ -29,6 +31,11 @@
 
 #ifdef CXX_QT_GUI_FEATURE
 #include <QtGui/QColor>
+#include <QtGui/QMatrix4x4>
+#include <QtGui/QPolygon>
+#include <QtGui/QPolygonF>
+#include <QtGui/QQuaternion>
+#include <QtGui/QTransform>
 #endif
 
 #include "rust/cxx.h"
@@ -154,17 +161,30 @@ using QVector_QColor = QVector<::QColor>;
 #endif
 using QVector_QDate = QVector<::QDate>;
 using QVector_QDateTime = QVector<::QDateTime>;
+using QVector_QLine = QVector<::QLine>;
+using QVector_QLineF = QVector<::QLineF>;
 using QVector_QMargins = QVector<::QMargins>;
 using QVector_QMarginsF = QVector<::QMarginsF>;
+#ifdef CXX_QT_GUI_FEATURE
+using QVector_QMatrix4x4 = QVector<::QMatrix4x4>;
+#endif
 using QVector_QPersistentModelIndex = QVector<::QPersistentModelIndex>;
 using QVector_QPoint = QVector<::QPoint>;
 using QVector_QPointF = QVector<::QPointF>;
+#ifdef CXX_QT_GUI_FEATURE
+using QVector_QPolygon = QVector<::QPolygon>;
+using QVector_QPolygonF = QVector<::QPolygonF>;
+using QVector_QQuaternion = QVector<::QQuaternion>;
+#endif
 using QVector_QRect = QVector<::QRect>;
 using QVector_QRectF = QVector<::QRectF>;
 using QVector_QSize = QVector<::QSize>;
 using QVector_QSizeF = QVector<::QSizeF>;
 using QVector_QString = QVector<::QString>;
 using QVector_QTime = QVector<::QTime>;
+#ifdef CXX_QT_GUI_FEATURE
+using QVector_QTransform = QVector<::QTransform>;
+#endif
 using QVector_QUrl = QVector<::QUrl>;
 using QVector_QVariant = QVector<::QVariant>;
 using QVector_u8 = QVector<::std::uint8_t>;
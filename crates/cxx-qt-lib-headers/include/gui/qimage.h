@@ -0,0 +1,88 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_GUI_FEATURE
+#include <cstdint>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QString>
+#include <QtGui/QImage>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QImage> : ::std::true_type
+{
+};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QImageFormat = QImage::Format;
+
+::rust::Slice<const ::std::uint8_t>
+qimageAsBytes(const QImage& image);
+::rust::isize
+qimageBytesPerLine(const QImage& image);
+QImage
+qimageConvertToFormat(const QImage& image, QImageFormat format);
+
+// The buffer is a Rust type which is only declared after this header
+// in the generated bridge, so this is a template which is instantiated there.
+//
+// The image takes ownership of the buffer and drops it via the cleanup
+// function once the last copy of the image using the data is destroyed.
+template<typename T>
+QImage
+qimageInitFromBuffer(::rust::Box<T> buffer,
+                     ::std::uint8_t* data,
+                     ::std::int32_t width,
+                     ::std::int32_t height,
+                     ::std::int32_t bytesPerLine,
+                     QImageFormat format)
+{
+  auto info = new ::rust::Box<T>(::std::move(buffer));
+  QImage image(
+    data,
+    width,
+    height,
+    bytesPerLine,
+    format,
+    [](void* info) { delete static_cast<::rust::Box<T>*>(info); },
+    info);
+
+  // If the image is null then Qt does not take ownership of the buffer
+  if (image.isNull()) {
+    delete info;
+  }
+
+  return image;
+}
+
+QImage
+qimageInitFromData(::rust::Slice<const ::std::uint8_t> data,
+                   ::rust::Str format);
+bool
+qimageSave(const QImage& image, const QString& fileName);
+bool
+qimageSaveToData(const QImage& image, QByteArray& data, ::rust::Str format);
+QImage
+qimageScaled(const QImage& image,
+             ::std::int32_t width,
+             ::std::int32_t height,
+             Qt::AspectRatioMode mode);
+
+}
+}
+#endif
@@ -0,0 +1,23 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_GUI_FEATURE
+#include <QtGui/QMatrix4x4>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// Qt 6 uses double, Qt 5 uses float
+double
+qmatrix4x4Determinant(const QMatrix4x4& matrix);
+QMatrix4x4
+qmatrix4x4Inverted(const QMatrix4x4& matrix, bool& invertible);
+
+}
+}
+#endif
@@ -0,0 +1,28 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_GUI_FEATURE
+#include <QtCore/QRectF>
+#include <QtGui/QPainter>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QPainterRenderHint = QPainter::RenderHint;
+
+void
+qpainterDrawRoundedRect(QPainter& painter,
+                        const QRectF& rect,
+                        qreal xRadius,
+                        qreal yRadius);
+void
+qpainterSetRenderHint(QPainter& painter, QPainterRenderHint hint, bool on);
+
+}
+}
+#endif
@@ -0,0 +1,36 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_GUI_FEATURE
+#include <QtGui/QQuaternion>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+float
+qquaternionDotProduct(const QQuaternion& q1, const QQuaternion& q2);
+QQuaternion
+qquaternionFromAxisAndAngle(const QVector3D& axis, float angle);
+QQuaternion
+qquaternionFromDirection(const QVector3D& direction, const QVector3D& up);
+QQuaternion
+qquaternionFromEulerAngles(float pitch, float yaw, float roll);
+void
+qquaternionGetAxisAndAngle(const QQuaternion& quaternion,
+                           QVector3D& axis,
+                           float& angle);
+QQuaternion
+qquaternionNlerp(const QQuaternion& q1, const QQuaternion& q2, float t);
+QQuaternion
+qquaternionRotationTo(const QVector3D& from, const QVector3D& to);
+QQuaternion
+qquaternionSlerp(const QQuaternion& q1, const QQuaternion& q2, float t);
+
+}
+}
+#endif
@@ -0,0 +1,44 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_GUI_FEATURE
+#include <QtGui/QTransform>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+// Qt 5 has a user defined copy constructor so this is not trivially copyable
+template<>
+struct IsRelocatable<QTransform> : ::std::true_type
+{
+};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QTransformTransformationType = QTransform::TransformationType;
+
+QTransform
+qtransformFromScale(double dx, double dy);
+QTransform
+qtransformFromTranslate(double dx, double dy);
+QTransform
+qtransformInverted(const QTransform& transform, bool& invertible);
+void
+qtransformRotate(QTransform& transform, double a);
+void
+qtransformRotateRadians(QTransform& transform, double a);
+
+}
+}
+#endif
@@ -0,0 +1,45 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <memory>
+
+#include <QtCore/QVariant>
+#include <QtQml/QJSEngine>
+#include <QtQml/QJSValue>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QVariant
+qjsengineFromScriptValue(QJSEngine& engine, const QJSValue& value);
+::std::unique_ptr<QJSEngine>
+qjsengineNew();
+QJSValue
+qjsengineToScriptValue(QJSEngine& engine, const QVariant& value);
+
+// These are used to upcast the QML engines to a QJSEngine
+template<typename T>
+const QJSEngine&
+upcastQJSEngine(const T& engine)
+{
+  return engine;
+}
+
+template<typename T>
+QJSEngine&
+upcastQJSEngineMut(T& engine)
+{
+  return engine;
+}
+
+}
+}
+
+#endif
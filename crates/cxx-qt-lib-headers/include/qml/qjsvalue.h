@@ -0,0 +1,49 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <QtQml/QJSValue>
+
+#include "rust/cxx.h"
+
+// QJSValue has a single value member which does not point to itself,
+// so it is safe to relocate even though it has copy constructors.
+//
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QJSValue> : ::std::true_type
+{
+};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QJSValue
+qjsvalueCall(const QJSValue& value, ::rust::Slice<const QJSValue> args);
+QJSValue
+qjsvalueCallAsConstructor(const QJSValue& value,
+                          ::rust::Slice<const QJSValue> args);
+QJSValue
+qjsvalueCallWithInstance(const QJSValue& value,
+                         const QJSValue& instance,
+                         ::rust::Slice<const QJSValue> args);
+QJSValue
+qjsvalueInitNull();
+QJSValueList
+qjsvalueListFromSlice(::rust::Slice<const QJSValue> args);
+
+}
+}
+
+#endif
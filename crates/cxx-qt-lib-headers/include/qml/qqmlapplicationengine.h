@@ -10,13 +10,49 @@
 
 #include <memory>
 
+#include <QtCore/QUrl>
 #include <QtQml/QQmlApplicationEngine>
 
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+
 namespace rust {
 namespace cxxqtlib1 {
 
 ::std::unique_ptr<QQmlApplicationEngine>
 qqmlapplicationengineNew();
+QObject*
+qqmlapplicationengineRootObjectAt(const QQmlApplicationEngine& engine,
+                                  ::rust::isize index);
+::rust::isize
+qqmlapplicationengineRootObjectsLen(const QQmlApplicationEngine& engine);
+const QQmlEngine&
+qqmlapplicationengineUpcastQQmlEngine(const QQmlApplicationEngine& engine);
+QQmlEngine&
+qqmlapplicationengineUpcastQQmlEngineMut(QQmlApplicationEngine& engine);
+
+// The closure is a Rust type which is only declared after this header
+// in the generated bridge, so this is a template which is instantiated there.
+//
+// Note that the Box is wrapped in a shared_ptr as Qt may copy the functor.
+template<typename C>
+QMetaObjectConnection
+qqmlapplicationengineOnObjectCreated(
+  QQmlApplicationEngine& engine,
+  ::rust::Fn<void(C&, QObject*, const QUrl&)> func,
+  ::rust::Box<C> closure)
+{
+  auto shared = ::std::make_shared<::rust::Box<C>>(::std::move(closure));
+  return QObject::connect(
+    &engine,
+    &QQmlApplicationEngine::objectCreated,
+    &engine,
+    [func = ::std::move(func),
+     shared = ::std::move(shared)](QObject* object, const QUrl& url) {
+      func(**shared, object, url);
+    });
+}
 
 }
 }
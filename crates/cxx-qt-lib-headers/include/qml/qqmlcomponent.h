@@ -0,0 +1,39 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <memory>
+
+#include <QtCore/QStringList>
+#include <QtCore/QUrl>
+#include <QtCore/QVariantMap>
+#include <QtQml/QQmlComponent>
+#include <QtQml/QQmlEngine>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QQmlComponentStatus = QQmlComponent::Status;
+
+QObject*
+qqmlcomponentCreate(QQmlComponent& component);
+QObject*
+qqmlcomponentCreateWithInitialProperties(QQmlComponent& component,
+                                         const QVariantMap& properties);
+QStringList
+qqmlcomponentErrors(const QQmlComponent& component);
+::std::unique_ptr<QQmlComponent>
+qqmlcomponentNew(QQmlEngine& engine);
+::std::unique_ptr<QQmlComponent>
+qqmlcomponentNewFromUrl(QQmlEngine& engine, const QUrl& url);
+
+}
+}
+
+#endif
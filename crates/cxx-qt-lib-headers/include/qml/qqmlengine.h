@@ -10,7 +10,13 @@
 
 #include <memory>
 
+#include <QtCore/QStringList>
 #include <QtQml/QQmlEngine>
+#include <QtQml/QQmlError>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/qmetaobjectconnection.h"
 
 namespace rust {
 namespace cxxqtlib1 {
@@ -18,6 +24,31 @@ namespace cxxqtlib1 {
 ::std::unique_ptr<QQmlEngine>
 qqmlengineNew();
 
+// Converts the list of QQmlError into their string representation
+QStringList
+qqmlerrorsToQStringList(const QList<QQmlError>& errors);
+
+// The closure is a Rust type which is only declared after this header
+// in the generated bridge, so this is a template which is instantiated there.
+//
+// Note that the Box is wrapped in a shared_ptr as Qt may copy the functor.
+template<typename C>
+QMetaObjectConnection
+qqmlengineOnWarnings(QQmlEngine& engine,
+                     ::rust::Fn<void(C&, const QStringList&)> func,
+                     ::rust::Box<C> closure)
+{
+  auto shared = ::std::make_shared<::rust::Box<C>>(::std::move(closure));
+  return QObject::connect(
+    &engine,
+    &QQmlEngine::warnings,
+    &engine,
+    [func = ::std::move(func),
+     shared = ::std::move(shared)](const QList<QQmlError>& warnings) {
+      func(**shared, qqmlerrorsToQStringList(warnings));
+    });
+}
+
 }
 }
 
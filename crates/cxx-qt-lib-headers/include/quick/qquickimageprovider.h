@@ -0,0 +1,174 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QUICK_FEATURE
+
+#include <memory>
+
+#include <QtCore/QMetaObject>
+#include <QtCore/QRunnable>
+#include <QtCore/QSize>
+#include <QtCore/QString>
+#include <QtCore/QThreadPool>
+#include <QtGui/QImage>
+#include <QtQml/QQmlEngine>
+#include <QtQuick/QQuickImageProvider>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// The provider is a Rust type which is only declared after this header
+// in the generated bridge, so these are templates which are instantiated there.
+template<typename T>
+using QQuickImageProviderRequestFn =
+  ::rust::Fn<QImage(const T&, const QString&, const QSize&)>;
+
+template<typename T>
+class RustQQuickImageProvider : public QQuickImageProvider
+{
+public:
+  RustQQuickImageProvider(QQuickImageProviderRequestFn<T> func,
+                          ::rust::Box<T> provider)
+    : QQuickImageProvider(QQuickImageProvider::Image)
+    , m_func(::std::move(func))
+    , m_provider(::std::move(provider))
+  {
+  }
+
+  QImage requestImage(const QString& id,
+                      QSize* size,
+                      const QSize& requestedSize) override
+  {
+    QImage image = m_func(*m_provider, id, requestedSize);
+    if (size) {
+      *size = image.size();
+    }
+    return image;
+  }
+
+private:
+  QQuickImageProviderRequestFn<T> m_func;
+  ::rust::Box<T> m_provider;
+};
+
+// The response is run on the thread pool of the async provider and
+// emits finished once the Rust provider has returned the image.
+//
+// The provider is shared so that it stays alive for any running responses.
+template<typename T>
+class RustQQuickImageResponse
+  : public QQuickImageResponse
+  , public QRunnable
+{
+public:
+  RustQQuickImageResponse(QQuickImageProviderRequestFn<T> func,
+                          ::std::shared_ptr<::rust::Box<T>> provider,
+                          const QString& id,
+                          const QSize& requestedSize)
+    : m_func(::std::move(func))
+    , m_provider(::std::move(provider))
+    , m_id(id)
+    , m_requestedSize(requestedSize)
+  {
+    // The engine owns the response, so the thread pool must not delete it
+    setAutoDelete(false);
+  }
+
+  QString errorString() const override { return m_errorString; }
+
+  QQuickTextureFactory* textureFactory() const override
+  {
+    return QQuickTextureFactory::textureFactoryForImage(m_image);
+  }
+
+  void run() override
+  {
+    QImage image = m_func(**m_provider, m_id, m_requestedSize);
+
+    // Store the result and emit finished on the thread of the response
+    // so that the image is not written while it is being read
+    QMetaObject::invokeMethod(
+      this,
+      [this, image = ::std::move(image)]() {
+        m_image = image;
+        if (m_image.isNull()) {
+          m_errorString =
+            QStringLiteral("Could not provide image with id: %1").arg(m_id);
+        }
+        Q_EMIT finished();
+      },
+      Qt::QueuedConnection);
+  }
+
+private:
+  QQuickImageProviderRequestFn<T> m_func;
+  ::std::shared_ptr<::rust::Box<T>> m_provider;
+  QString m_id;
+  QSize m_requestedSize;
+  QImage m_image;
+  QString m_errorString;
+};
+
+template<typename T>
+class RustQQuickAsyncImageProvider : public QQuickAsyncImageProvider
+{
+public:
+  RustQQuickAsyncImageProvider(QQuickImageProviderRequestFn<T> func,
+                               ::rust::Box<T> provider)
+    : m_func(::std::move(func))
+    , m_provider(::std::make_shared<::rust::Box<T>>(::std::move(provider)))
+  {
+  }
+
+  QQuickImageResponse* requestImageResponse(
+    const QString& id,
+    const QSize& requestedSize) override
+  {
+    auto response =
+      new RustQQuickImageResponse<T>(m_func, m_provider, id, requestedSize);
+    m_pool.start(response);
+    return response;
+  }
+
+private:
+  QQuickImageProviderRequestFn<T> m_func;
+  ::std::shared_ptr<::rust::Box<T>> m_provider;
+  QThreadPool m_pool;
+};
+
+template<typename T>
+void
+qqmlengineAddAsyncImageProvider(QQmlEngine& engine,
+                                const QString& id,
+                                QQuickImageProviderRequestFn<T> func,
+                                ::rust::Box<T> provider)
+{
+  engine.addImageProvider(
+    id,
+    new RustQQuickAsyncImageProvider<T>(::std::move(func),
+                                        ::std::move(provider)));
+}
+
+template<typename T>
+void
+qqmlengineAddImageProvider(QQmlEngine& engine,
+                           const QString& id,
+                           QQuickImageProviderRequestFn<T> func,
+                           ::rust::Box<T> provider)
+{
+  engine.addImageProvider(
+    id,
+    new RustQQuickImageProvider<T>(::std::move(func), ::std::move(provider)));
+}
+
+}
+}
+
+#endif
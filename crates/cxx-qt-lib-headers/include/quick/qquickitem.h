@@ -0,0 +1,44 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QUICK_FEATURE
+
+#include <memory>
+
+#include <QtQuick/QQuickItem>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QQuickItemFlag = QQuickItem::Flag;
+
+bool
+qquickitemHasFlag(const QQuickItem& item, QQuickItemFlag flag);
+::std::unique_ptr<QQuickItem>
+qquickitemNew();
+
+// These can be used to upcast any QQuickItem subclass to a QQuickItem,
+// such as a CXX-Qt QObject with a base class of QQuickItem.
+template<typename T>
+const QQuickItem&
+upcastQQuickItem(const T& object)
+{
+  return object;
+}
+
+template<typename T>
+QQuickItem&
+upcastQQuickItemMut(T& object)
+{
+  return object;
+}
+
+}
+}
+
+#endif
@@ -0,0 +1,38 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QUICK_FEATURE
+
+#include <QtQuick/QQuickPaintedItem>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QQuickPaintedItemRenderTarget = QQuickPaintedItem::RenderTarget;
+
+// These can be used to upcast any QQuickPaintedItem subclass to a
+// QQuickPaintedItem, such as a CXX-Qt QObject with a base class of
+// QQuickPaintedItem.
+template<typename T>
+const QQuickPaintedItem&
+upcastQQuickPaintedItem(const T& object)
+{
+  return object;
+}
+
+template<typename T>
+QQuickPaintedItem&
+upcastQQuickPaintedItemMut(T& object)
+{
+  return object;
+}
+
+}
+}
+
+#endif
@@ -0,0 +1,36 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_WIDGETS_FEATURE
+
+#include <cstdint>
+#include <memory>
+
+#include <QtWidgets/QBoxLayout>
+#include <QtWidgets/QWidget>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QBoxLayoutDirection = QBoxLayout::Direction;
+
+void
+qboxlayoutAddLayout(QBoxLayout& layout,
+                    ::std::unique_ptr<QBoxLayout> child,
+                    ::std::int32_t stretch);
+void
+qboxlayoutAddWidget(QBoxLayout& layout,
+                    QWidget& widget,
+                    ::std::int32_t stretch);
+::std::unique_ptr<QBoxLayout>
+qboxlayoutNew(QBoxLayoutDirection direction);
+
+}
+}
+
+#endif
@@ -0,0 +1,40 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_WIDGETS_FEATURE
+
+#include <memory>
+
+#include <QtWidgets/QDialog>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QDialog>
+qdialogNew();
+
+// These can be used to upcast any QDialog subclass to a QDialog,
+// such as a CXX-Qt QObject with a base class of QDialog.
+template<typename T>
+const QDialog&
+upcastQDialog(const T& object)
+{
+  return object;
+}
+
+template<typename T>
+QDialog&
+upcastQDialogMut(T& object)
+{
+  return object;
+}
+
+}
+}
+
+#endif
@@ -0,0 +1,46 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_WIDGETS_FEATURE
+
+#include <memory>
+
+#include <QtGui/QPainter>
+#include <QtWidgets/QBoxLayout>
+#include <QtWidgets/QWidget>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QWidget>
+qwidgetNew();
+::std::unique_ptr<QPainter>
+qwidgetPainterNew(QWidget& widget);
+void
+qwidgetSetLayout(QWidget& widget, ::std::unique_ptr<QBoxLayout> layout);
+
+// These can be used to upcast any QWidget subclass to a QWidget,
+// such as a CXX-Qt QObject with a base class of QWidget.
+template<typename T>
+const QWidget&
+upcastQWidget(const T& object)
+{
+  return object;
+}
+
+template<typename T>
+QWidget&
+upcastQWidgetMut(T& object)
+{
+  return object;
+}
+
+}
+}
+
+#endif
@@ -24,13 +24,24 @@ pub fn write_headers(directory: impl AsRef<Path>) {
         ),
         (include_str!("../include/core/qdate.h"), "qdate.h"),
         (include_str!("../include/core/qdatetime.h"), "qdatetime.h"),
+        (include_str!("../include/core/qdir.h"), "qdir.h"),
+        (include_str!("../include/core/qevent.h"), "qevent.h"),
+        (include_str!("../include/core/qfile.h"), "qfile.h"),
+        (include_str!("../include/core/qfileinfo.h"), "qfileinfo.h"),
         (include_str!("../include/core/qhash.h"), "qhash.h"),
+        (include_str!("../include/core/qline.h"), "qline.h"),
+        (include_str!("../include/core/qlinef.h"), "qlinef.h"),
         (include_str!("../include/core/qlist.h"), "qlist.h"),
         (
             include_str!("../include/core/qlist_qvector.h"),
             "qlist_qvector.h",
         ),
+        (include_str!("../include/core/qlocale.h"), "qlocale.h"),
         (include_str!("../include/core/qmap.h"), "qmap.h"),
+        (
+            include_str!("../include/core/qloggingcategory.h"),
+            "qloggingcategory.h",
+        ),
         (include_str!("../include/core/qmargins.h"), "qmargins.h"),
         (include_str!("../include/core/qmarginsf.h"), "qmarginsf.h"),
         (
@@ -41,48 +52,150 @@ pub fn write_headers(directory: impl AsRef<Path>) {
             include_str!("../include/core/qmodelindex.h"),
             "qmodelindex.h",
         ),
+        (include_str!("../include/core/qobject.h"), "qobject.h"),
         (
             include_str!("../include/core/qpersistentmodelindex.h"),
             "qpersistentmodelindex.h",
         ),
         (include_str!("../include/core/qpoint.h"), "qpoint.h"),
+        (include_str!("../include/core/qpointer.h"), "qpointer.h"),
         (include_str!("../include/core/qpointf.h"), "qpointf.h"),
         (include_str!("../include/core/qrect.h"), "qrect.h"),
         (include_str!("../include/core/qrectf.h"), "qrectf.h"),
+        (
+            include_str!("../include/core/qregularexpression.h"),
+            "qregularexpression.h",
+        ),
+        (
+            include_str!("../include/core/qregularexpressionmatch.h"),
+            "qregularexpressionmatch.h",
+        ),
+        (
+            include_str!("../include/core/qregularexpressionmatchiterator.h"),
+            "qregularexpressionmatchiterator.h",
+        ),
         (include_str!("../include/core/qset.h"), "qset.h"),
+        (include_str!("../include/core/qsettings.h"), "qsettings.h"),
         (include_str!("../include/core/qsize.h"), "qsize.h"),
         (include_str!("../include/core/qsizef.h"), "qsizef.h"),
+        (
+            include_str!("../include/core/qstandardpaths.h"),
+            "qstandardpaths.h",
+        ),
         (include_str!("../include/core/qstring.h"), "qstring.h"),
         (
             include_str!("../include/core/qstringlist.h"),
             "qstringlist.h",
         ),
         (include_str!("../include/core/qt.h"), "qt.h"),
+        (
+            include_str!("../include/core/qtemporarydir.h"),
+            "qtemporarydir.h",
+        ),
+        (include_str!("../include/core/qthread.h"), "qthread.h"),
         (include_str!("../include/core/qtime.h"), "qtime.h"),
+        (include_str!("../include/core/qtimer.h"), "qtimer.h"),
+        (include_str!("../include/core/qtlogging.h"), "qtlogging.h"),
         (include_str!("../include/core/qtimezone.h"), "qtimezone.h"),
         (include_str!("../include/core/qurl.h"), "qurl.h"),
         (include_str!("../include/core/qvariant.h"), "qvariant.h"),
         (include_str!("../include/core/qvector.h"), "qvector.h"),
         #[cfg(feature = "qt_gui")]
+        (include_str!("../include/gui/qbrush.h"), "qbrush.h"),
+        #[cfg(feature = "qt_gui")]
         (include_str!("../include/gui/qcolor.h"), "qcolor.h"),
         #[cfg(feature = "qt_gui")]
+        (include_str!("../include/gui/qfont.h"), "qfont.h"),
+        #[cfg(feature = "qt_gui")]
         (
             include_str!("../include/gui/qguiapplication.h"),
             "qguiapplication.h",
         ),
         #[cfg(feature = "qt_gui")]
+        (include_str!("../include/gui/qimage.h"), "qimage.h"),
+        #[cfg(feature = "qt_gui")]
+        (include_str!("../include/gui/qmatrix4x4.h"), "qmatrix4x4.h"),
+        #[cfg(feature = "qt_gui")]
+        (include_str!("../include/gui/qpainter.h"), "qpainter.h"),
+        #[cfg(feature = "qt_gui")]
+        (
+            include_str!("../include/gui/qpainterpath.h"),
+            "qpainterpath.h",
+        ),
+        #[cfg(feature = "qt_gui")]
+        (
+            include_str!("../include/gui/qpaintevent.h"),
+            "qpaintevent.h",
+        ),
+        #[cfg(feature = "qt_gui")]
+        (include_str!("../include/gui/qpen.h"), "qpen.h"),
+        #[cfg(feature = "qt_gui")]
+        (include_str!("../include/gui/qpolygon.h"), "qpolygon.h"),
+        #[cfg(feature = "qt_gui")]
+        (include_str!("../include/gui/qpolygonf.h"), "qpolygonf.h"),
+        #[cfg(feature = "qt_gui")]
+        (
+            include_str!("../include/gui/qquaternion.h"),
+            "qquaternion.h",
+        ),
+        #[cfg(feature = "qt_gui")]
+        (include_str!("../include/gui/qtransform.h"), "qtransform.h"),
+        #[cfg(feature = "qt_gui")]
         (include_str!("../include/gui/qvector2d.h"), "qvector2d.h"),
         #[cfg(feature = "qt_gui")]
         (include_str!("../include/gui/qvector3d.h"), "qvector3d.h"),
         #[cfg(feature = "qt_gui")]
         (include_str!("../include/gui/qvector4d.h"), "qvector4d.h"),
         #[cfg(feature = "qt_qml")]
+        (include_str!("../include/qml/qjsengine.h"), "qjsengine.h"),
+        #[cfg(feature = "qt_qml")]
+        (include_str!("../include/qml/qjsvalue.h"), "qjsvalue.h"),
+        #[cfg(feature = "qt_qml")]
         (
             include_str!("../include/qml/qqmlapplicationengine.h"),
             "qqmlapplicationengine.h",
         ),
         #[cfg(feature = "qt_qml")]
+        (
+            include_str!("../include/qml/qqmlcomponent.h"),
+            "qqmlcomponent.h",
+        ),
+        #[cfg(feature = "qt_qml")]
+        (
+            include_str!("../include/qml/qqmlcontext.h"),
+            "qqmlcontext.h",
+        ),
+        #[cfg(feature = "qt_qml")]
         (include_str!("../include/qml/qqmlengine.h"), "qqmlengine.h"),
+        #[cfg(feature = "qt_quick")]
+        (
+            include_str!("../include/quick/qquickimageprovider.h"),
+            "qquickimageprovider.h",
+        ),
+        #[cfg(feature = "qt_quick")]
+        (
+            include_str!("../include/quick/qquickitem.h"),
+            "qquickitem.h",
+        ),
+        #[cfg(feature = "qt_quick")]
+        (
+            include_str!("../include/quick/qquickpainteditem.h"),
+            "qquickpainteditem.h",
+        ),
+        #[cfg(feature = "qt_widgets")]
+        (
+            include_str!("../include/widgets/qapplication.h"),
+            "qapplication.h",
+        ),
+        #[cfg(feature = "qt_widgets")]
+        (
+            include_str!("../include/widgets/qboxlayout.h"),
+            "qboxlayout.h",
+        ),
+        #[cfg(feature = "qt_widgets")]
+        (include_str!("../include/widgets/qdialog.h"), "qdialog.h"),
+        #[cfg(feature = "qt_widgets")]
+        (include_str!("../include/widgets/qwidget.h"), "qwidget.h"),
         (include_str!("../include/common.h"), "common.h"),
     ] {
         // Note that we do not need rerun-if-changed for these files
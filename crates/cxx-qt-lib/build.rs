@@ -6,6 +6,8 @@
 fn main() {
     let feature_qt_gui_enabled = std::env::var("CARGO_FEATURE_QT_GUI").is_ok();
     let feature_qt_qml_enabled = std::env::var("CARGO_FEATURE_QT_QML").is_ok();
+    let feature_qt_quick_enabled = std::env::var("CARGO_FEATURE_QT_QUICK").is_ok();
+    let feature_qt_widgets_enabled = std::env::var("CARGO_FEATURE_QT_WIDGETS").is_ok();
     let emscripten_targeted = match std::env::var("CARGO_CFG_TARGET_OS") {
         Ok(val) => val == "emscripten",
         Err(_) => false,
@@ -18,6 +20,12 @@ fn main() {
     if feature_qt_qml_enabled {
         qt_modules.push("Qml".to_owned());
     }
+    if feature_qt_quick_enabled {
+        qt_modules.push("Quick".to_owned());
+    }
+    if feature_qt_widgets_enabled {
+        qt_modules.push("Widgets".to_owned());
+    }
 
     let qtbuild = qt_build_utils::QtBuild::new(qt_modules).expect("Could not find Qt installation");
 
@@ -35,8 +43,14 @@ fn main() {
         "core/qbytearray",
         "core/qcoreapplication",
         "core/qdate",
+        "core/qdir",
+        "core/qevent",
+        "core/qfile",
+        "core/qfileinfo",
         "core/qhash/qhash_i32_qbytearray",
         "core/qhash/qhash_qstring_qvariant",
+        "core/qline",
+        "core/qlinef",
         "core/qlist/qlist_bool",
         "core/qlist/qlist_f32",
         "core/qlist/qlist_f64",
@@ -46,6 +60,8 @@ fn main() {
         "core/qlist/qlist_i64",
         "core/qlist/qlist_qbytearray",
         "core/qlist/qlist_qdate",
+        "core/qlist/qlist_qline",
+        "core/qlist/qlist_qlinef",
         "core/qlist/qlist_qmargins",
         "core/qlist/qlist_qmarginsf",
         "core/qlist/qlist_qpersistentmodelindex",
@@ -63,16 +79,23 @@ fn main() {
         "core/qlist/qlist_u16",
         "core/qlist/qlist_u32",
         "core/qlist/qlist_u64",
+        "core/qlocale",
         "core/qmap/qmap_qstring_qvariant",
+        "core/qloggingcategory",
         "core/qmargins",
         "core/qmarginsf",
         "core/qmetaobjectconnection",
         "core/qmodelindex",
+        "core/qobject",
         "core/qpersistentmodelindex",
         "core/qpoint",
+        "core/qpointer",
         "core/qpointf",
         "core/qrect",
         "core/qrectf",
+        "core/qregularexpression",
+        "core/qregularexpressionmatch",
+        "core/qregularexpressionmatchiterator",
         "core/qset/qset_bool",
         "core/qset/qset_f32",
         "core/qset/qset_f64",
@@ -90,12 +113,18 @@ fn main() {
         "core/qset/qset_u16",
         "core/qset/qset_u32",
         "core/qset/qset_u64",
+        "core/qsettings",
         "core/qsize",
         "core/qsizef",
+        "core/qstandardpaths",
         "core/qstring",
         "core/qstringlist",
         "core/qt",
+        "core/qtemporarydir",
+        "core/qthread",
         "core/qtime",
+        "core/qtimer",
+        "core/qtlogging",
         "core/qurl",
         "core/qvariant/mod",
         "core/qvariant/qvariant_bool",
@@ -107,6 +136,8 @@ fn main() {
         "core/qvariant/qvariant_i64",
         "core/qvariant/qvariant_qbytearray",
         "core/qvariant/qvariant_qdate",
+        "core/qvariant/qvariant_qline",
+        "core/qvariant/qvariant_qlinef",
         "core/qvariant/qvariant_qmodelindex",
         "core/qvariant/qvariant_qpersistentmodelindex",
         "core/qvariant/qvariant_qpoint",
@@ -132,6 +163,8 @@ fn main() {
         "core/qvector/qvector_i64",
         "core/qvector/qvector_qbytearray",
         "core/qvector/qvector_qdate",
+        "core/qvector/qvector_qline",
+        "core/qvector/qvector_qlinef",
         "core/qvector/qvector_qmargins",
         "core/qvector/qvector_qmarginsf",
         "core/qvector/qvector_qpersistentmodelindex",
@@ -154,10 +187,37 @@ fn main() {
     if feature_qt_gui_enabled {
         rust_bridges.extend([
             "core/qlist/qlist_qcolor",
+            "core/qlist/qlist_qmatrix4x4",
+            "core/qlist/qlist_qpolygon",
+            "core/qlist/qlist_qpolygonf",
+            "core/qlist/qlist_qquaternion",
+            "core/qlist/qlist_qtransform",
             "core/qvariant/qvariant_qcolor",
+            "core/qvariant/qvariant_qmatrix4x4",
+            "core/qvariant/qvariant_qpolygon",
+            "core/qvariant/qvariant_qpolygonf",
+            "core/qvariant/qvariant_qquaternion",
+            "core/qvariant/qvariant_qtransform",
             "core/qvector/qvector_qcolor",
+            "core/qvector/qvector_qmatrix4x4",
+            "core/qvector/qvector_qpolygon",
+            "core/qvector/qvector_qpolygonf",
+            "core/qvector/qvector_qquaternion",
+            "core/qvector/qvector_qtransform",
+            "gui/qbrush",
             "gui/qcolor",
+            "gui/qfont",
             "gui/qguiapplication",
+            "gui/qimage",
+            "gui/qmatrix4x4",
+            "gui/qpainter",
+            "gui/qpainterpath",
+            "gui/qpaintevent",
+            "gui/qpen",
+            "gui/qpolygon",
+            "gui/qpolygonf",
+            "gui/qquaternion",
+            "gui/qtransform",
             "gui/qvector2d",
             "gui/qvector3d",
             "gui/qvector4d",
@@ -165,7 +225,31 @@ fn main() {
     }
 
     if feature_qt_qml_enabled {
-        rust_bridges.extend(["qml/qqmlapplicationengine", "qml/qqmlengine"]);
+        rust_bridges.extend([
+            "qml/qjsengine",
+            "qml/qjsvalue",
+            "qml/qqmlapplicationengine",
+            "qml/qqmlcomponent",
+            "qml/qqmlcontext",
+            "qml/qqmlengine",
+        ]);
+    }
+
+    if feature_qt_quick_enabled {
+        rust_bridges.extend([
+            "quick/qquickimageprovider",
+            "quick/qquickitem",
+            "quick/qquickpainteditem",
+        ]);
+    }
+
+    if feature_qt_widgets_enabled {
+        rust_bridges.extend([
+            "widgets/qapplication",
+            "widgets/qboxlayout",
+            "widgets/qdialog",
+            "widgets/qwidget",
+        ]);
     }
 
     if !emscripten_targeted {
@@ -198,24 +282,43 @@ fn main() {
         "core/qbytearray",
         "core/qcoreapplication",
         "core/qdate",
+        "core/qdir",
+        "core/qevent",
+        "core/qfile",
+        "core/qfileinfo",
         "core/qhash/qhash",
+        "core/qline",
+        "core/qlinef",
         "core/qlist/qlist",
+        "core/qlocale",
         "core/qmap/qmap",
+        "core/qloggingcategory",
         "core/qmargins",
         "core/qmarginsf",
         "core/qmetaobjectconnection",
         "core/qmodelindex",
+        "core/qobject",
         "core/qpersistentmodelindex",
         "core/qpoint",
+        "core/qpointer",
         "core/qpointf",
         "core/qrect",
         "core/qrectf",
+        "core/qregularexpression",
+        "core/qregularexpressionmatch",
+        "core/qregularexpressionmatchiterator",
         "core/qset/qset",
+        "core/qsettings",
         "core/qsize",
         "core/qsizef",
+        "core/qstandardpaths",
         "core/qstring",
         "core/qstringlist",
+        "core/qtemporarydir",
+        "core/qthread",
         "core/qtime",
+        "core/qtimer",
+        "core/qtlogging",
         "core/qurl",
         "core/qvariant/qvariant",
         "core/qvector/qvector",
@@ -223,8 +326,19 @@ fn main() {
 
     if feature_qt_gui_enabled {
         cpp_files.extend([
+            "gui/qbrush",
             "gui/qcolor",
+            "gui/qfont",
             "gui/qguiapplication",
+            "gui/qimage",
+            "gui/qmatrix4x4",
+            "gui/qpainter",
+            "gui/qpainterpath",
+            "gui/qpen",
+            "gui/qpolygon",
+            "gui/qpolygonf",
+            "gui/qquaternion",
+            "gui/qtransform",
             "gui/qvector2d",
             "gui/qvector3d",
             "gui/qvector4d",
@@ -232,7 +346,26 @@ fn main() {
     }
 
     if feature_qt_qml_enabled {
-        cpp_files.extend(["qml/qqmlapplicationengine", "qml/qqmlengine"]);
+        cpp_files.extend([
+            "qml/qjsengine",
+            "qml/qjsvalue",
+            "qml/qqmlapplicationengine",
+            "qml/qqmlcomponent",
+            "qml/qqmlengine",
+        ]);
+    }
+
+    if feature_qt_quick_enabled {
+        cpp_files.extend(["quick/qquickitem"]);
+    }
+
+    if feature_qt_widgets_enabled {
+        cpp_files.extend([
+            "widgets/qapplication",
+            "widgets/qboxlayout",
+            "widgets/qdialog",
+            "widgets/qwidget",
+        ]);
     }
 
     if !emscripten_targeted {
@@ -262,6 +395,16 @@ fn main() {
         builder.define("CXX_QT_QML_FEATURE", None);
     }
 
+    // Enable Qt Quick in C++ if the feature is enabled
+    if feature_qt_quick_enabled {
+        builder.define("CXX_QT_QUICK_FEATURE", None);
+    }
+
+    // Enable Qt Widgets in C++ if the feature is enabled
+    if feature_qt_widgets_enabled {
+        builder.define("CXX_QT_WIDGETS_FEATURE", None);
+    }
+
     // Note, ensure our settings stay in sync across cxx-qt-build and cxx-qt-lib
     builder.cpp(true);
     // MSVC
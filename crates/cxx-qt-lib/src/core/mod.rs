@@ -7,7 +7,8 @@ mod qbytearray;
 pub use qbytearray::QByteArray;
 
 mod qcoreapplication;
-pub use qcoreapplication::QCoreApplication;
+pub(crate) use qcoreapplication::{application_args, impl_core_application_methods};
+pub use qcoreapplication::{CoreApplication, QCoreApplication};
 
 mod qdate;
 pub use qdate::QDate;
@@ -17,15 +18,41 @@ mod qdatetime;
 #[cfg(not(target_os = "emscripten"))]
 pub use qdatetime::QDateTime;
 
+mod qdir;
+pub use qdir::{QDir, QDirFilter, QDirSortFlag};
+
+mod qevent;
+pub use qevent::QEvent;
+
+mod qfile;
+pub use qfile::{QFile, QIODeviceOpenModeFlag};
+
+mod qfileinfo;
+pub use qfileinfo::QFileInfo;
+
 mod qhash;
 pub use qhash::{QHash, QHashPair, QHashPair_QString_QVariant, QHashPair_i32_QByteArray};
 
+mod qline;
+pub use qline::QLine;
+
+mod qlinef;
+pub use qlinef::{QLineF, QLineFIntersectionType};
+
 mod qlist;
 pub use qlist::{QList, QListElement};
 
+mod qlocale;
+pub use qlocale::{
+    QLocale, QLocaleCurrencySymbolFormat, QLocaleFormatType, QLocaleMeasurementSystem,
+};
+
 mod qmap;
 pub use qmap::{QMap, QMapPair, QMapPair_QString_QVariant};
 
+mod qloggingcategory;
+pub use qloggingcategory::QLoggingCategory;
+
 mod qmargins;
 pub use qmargins::QMargins;
 
@@ -38,6 +65,9 @@ pub use qmetaobjectconnection::QMetaObjectConnection;
 mod qmodelindex;
 pub use qmodelindex::QModelIndex;
 
+mod qobject;
+pub use qobject::{QObject, Upcast};
+
 mod qpersistentmodelindex;
 pub use qpersistentmodelindex::QPersistentModelIndex;
 
@@ -47,15 +77,38 @@ pub use qrect::QRect;
 mod qrectf;
 pub use qrectf::QRectF;
 
+mod qregularexpression;
+pub use qregularexpression::{
+    QRegularExpression, QRegularExpressionMatchType, QRegularExpressionPatternOption,
+};
+
+mod qregularexpressionmatch;
+pub use qregularexpressionmatch::QRegularExpressionMatch;
+
+mod qregularexpressionmatchiterator;
+pub use qregularexpressionmatchiterator::QRegularExpressionMatchIterator;
+
 mod qset;
 pub use qset::{QSet, QSetElement};
 
+mod qsettings;
+pub use qsettings::{QSettings, QSettingsFormat, QSettingsScope, QSettingsStatus};
+#[cfg(feature = "serde")]
+mod qsettings_serde;
+#[cfg(feature = "serde")]
+pub use qsettings_serde::QSettingsSerdeError;
+
 mod qsize;
 pub use qsize::QSize;
 
 mod qsizef;
 pub use qsizef::QSizeF;
 
+mod qstandardpaths;
+pub use qstandardpaths::{
+    QStandardPaths, QStandardPathsLocateOption, QStandardPathsStandardLocation,
+};
+
 mod qstring;
 pub use qstring::QString;
 
@@ -64,20 +117,41 @@ pub use qstringlist::QStringList;
 
 mod qt;
 pub use qt::{
-    AspectRatioMode, CaseSensitivity, ConnectionType, DateFormat, SplitBehaviorFlags, TimeSpec,
+    ApplicationAttribute, AspectRatioMode, BrushStyle, CaseSensitivity, ConnectionType, DateFormat,
+    DayOfWeek, FillRule, PenCapStyle, PenJoinStyle, PenStyle, SplitBehaviorFlags, TimeSpec,
+    TimerType,
 };
 
+mod qtemporarydir;
+pub use qtemporarydir::QTemporaryDir;
+
+mod qthread;
+pub use qthread::QThread;
+
 mod qtime;
 pub use qtime::QTime;
 
+mod qtimer;
+pub use qtimer::QTimer;
+
 #[cfg(not(target_os = "emscripten"))]
 mod qtimezone;
 #[cfg(not(target_os = "emscripten"))]
 pub use qtimezone::QTimeZone;
 
+mod qtlogging;
+#[cfg(feature = "log")]
+pub use qtlogging::log_message_handler;
+#[cfg(feature = "tracing")]
+pub use qtlogging::tracing_message_handler;
+pub use qtlogging::{q_install_message_handler, QMessageLogContext, QtMessageHandler, QtMsgType};
+
 mod qpoint;
 pub use qpoint::QPoint;
 
+mod qpointer;
+pub use qpointer::QPointer;
+
 mod qpointf;
 pub use qpointf::QPointF;
 
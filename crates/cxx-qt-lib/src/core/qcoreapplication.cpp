@@ -50,5 +50,152 @@ qcoreapplicationNew(const QVector<QByteArray>& args)
   return ptr;
 }
 
+void
+qcoreapplicationAddLibraryPath(QCoreApplication& app, const QString& path)
+{
+  app.addLibraryPath(path);
+}
+
+QString
+qcoreapplicationApplicationName(const QCoreApplication& app)
+{
+  return app.applicationName();
+}
+
+QString
+qcoreapplicationApplicationVersion(const QCoreApplication& app)
+{
+  return app.applicationVersion();
+}
+
+QStringList
+qcoreapplicationArguments(const QCoreApplication& app)
+{
+  return app.arguments();
+}
+
+::std::int32_t
+qcoreapplicationExec(QCoreApplication& app)
+{
+  return static_cast<::std::int32_t>(app.exec());
+}
+
+void
+qcoreapplicationExit(::std::int32_t returnCode)
+{
+  QCoreApplication::exit(static_cast<int>(returnCode));
+}
+
+void
+qcoreapplicationExitQueued(::std::int32_t returnCode)
+{
+  auto app = QCoreApplication::instance();
+  if (app == nullptr) {
+    return;
+  }
+
+  // Queue the exit on the thread of the application so that this can be
+  // called from any thread, and before the event loop has been entered
+  QMetaObject::invokeMethod(
+    app,
+    [returnCode]() {
+      QCoreApplication::exit(static_cast<int>(returnCode));
+    },
+    Qt::QueuedConnection);
+}
+
+QCoreApplication*
+qcoreapplicationInstance()
+{
+  return QCoreApplication::instance();
+}
+
+QStringList
+qcoreapplicationLibraryPaths(const QCoreApplication& app)
+{
+  return app.libraryPaths();
+}
+
+QString
+qcoreapplicationOrganizationDomain(const QCoreApplication& app)
+{
+  return app.organizationDomain();
+}
+
+QString
+qcoreapplicationOrganizationName(const QCoreApplication& app)
+{
+  return app.organizationName();
+}
+
+void
+qcoreapplicationPostEvent(QObject* receiver,
+                          ::std::unique_ptr<QEvent> event,
+                          ::std::int32_t priority)
+{
+  Q_ASSERT(receiver != nullptr);
+  // The event queue takes ownership of the event
+  QCoreApplication::postEvent(
+    receiver, event.release(), static_cast<int>(priority));
+}
+
+void
+qcoreapplicationProcessEvents()
+{
+  QCoreApplication::processEvents();
+}
+
+void
+qcoreapplicationSendPostedEvents()
+{
+  QCoreApplication::sendPostedEvents();
+}
+
+void
+qcoreapplicationSetApplicationName(QCoreApplication& app, const QString& name)
+{
+  app.setApplicationName(name);
+}
+
+void
+qcoreapplicationSetApplicationVersion(QCoreApplication& app,
+                                      const QString& version)
+{
+  app.setApplicationVersion(version);
+}
+
+void
+qcoreapplicationSetAttribute(Qt::ApplicationAttribute attribute, bool on)
+{
+  QCoreApplication::setAttribute(attribute, on);
+}
+
+void
+qcoreapplicationSetLibraryPaths(QCoreApplication& app,
+                                const QStringList& paths)
+{
+  app.setLibraryPaths(paths);
+}
+
+void
+qcoreapplicationSetOrganizationDomain(QCoreApplication& app,
+                                      const QString& domain)
+{
+  app.setOrganizationDomain(domain);
+}
+
+void
+qcoreapplicationSetOrganizationName(QCoreApplication& app,
+                                    const QString& name)
+{
+  app.setOrganizationName(name);
+}
+
+bool
+qcoreapplicationTestAttribute(Qt::ApplicationAttribute attribute)
+{
+  return QCoreApplication::testAttribute(attribute);
+}
+
 }
 }
@@ -4,14 +4,22 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::{QByteArray, QString, QStringList, QVector};
+use crate::{
+    ApplicationAttribute, QByteArray, QEvent, QMetaObjectConnection, QObject, QString, QStringList,
+    QVector, Upcast,
+};
 use core::pin::Pin;
+use std::ffi::OsStr;
 
 #[cxx::bridge]
 mod ffi {
     unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
         include!("cxx-qt-lib/qbytearray.h");
         type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qevent.h");
+        type QEvent = crate::QEvent;
         include!("cxx-qt-lib/qstring.h");
         type QString = crate::QString;
         include!("cxx-qt-lib/qstringlist.h");
@@ -20,14 +28,43 @@ mod ffi {
         type QVector_QByteArray = crate::QVector<QByteArray>;
 
         include!("cxx-qt-lib/qcoreapplication.h");
+        /// The QCoreApplication class provides an event loop for Qt applications without UI.
+        ///
+        /// The API which is shared with QGuiApplication and QApplication is in the [CoreApplication](crate::CoreApplication) trait.
         type QCoreApplication;
     }
 
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type ApplicationAttribute = crate::ApplicationAttribute;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QCoreApplicationClosure;
+    }
+
     #[namespace = "rust::cxxqtlib1"]
     unsafe extern "C++" {
         #[doc(hidden)]
         #[rust_name = "qcoreapplication_new"]
         fn qcoreapplicationNew(args: &QVector_QByteArray) -> UniquePtr<QCoreApplication>;
+
+        #[doc(hidden)]
+        #[rust_name = "qcoreapplication_on_about_to_quit"]
+        fn qcoreapplicationOnAboutToQuit(
+            app: Pin<&mut QCoreApplication>,
+            func: fn(&mut QCoreApplicationClosure),
+            closure: Box<QCoreApplicationClosure>,
+        ) -> QMetaObjectConnection;
     }
 
     // These are all static, so we need to create bindings until CXX supports statics
@@ -35,40 +72,84 @@ mod ffi {
     unsafe extern "C++" {
         #[doc(hidden)]
         #[rust_name = "qcoreapplication_add_library_path"]
-        fn qapplicationAddLibraryPath(app: Pin<&mut QCoreApplication>, path: &QString);
+        fn qcoreapplicationAddLibraryPath(app: Pin<&mut QCoreApplication>, path: &QString);
         #[doc(hidden)]
         #[rust_name = "qcoreapplication_application_name"]
-        fn qapplicationApplicationName(app: &QCoreApplication) -> QString;
+        fn qcoreapplicationApplicationName(app: &QCoreApplication) -> QString;
         #[doc(hidden)]
         #[rust_name = "qcoreapplication_application_version"]
-        fn qapplicationApplicationVersion(app: &QCoreApplication) -> QString;
+        fn qcoreapplicationApplicationVersion(app: &QCoreApplication) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qcoreapplication_arguments"]
+        fn qcoreapplicationArguments(app: &QCoreApplication) -> QStringList;
         #[doc(hidden)]
         #[rust_name = "qcoreapplication_exec"]
-        fn qapplicationExec(app: Pin<&mut QCoreApplication>) -> i32;
+        fn qcoreapplicationExec(app: Pin<&mut QCoreApplication>) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qcoreapplication_exit"]
+        fn qcoreapplicationExit(return_code: i32);
+        #[doc(hidden)]
+        #[rust_name = "qcoreapplication_exit_queued"]
+        fn qcoreapplicationExitQueued(return_code: i32);
+        #[doc(hidden)]
+        #[rust_name = "qcoreapplication_instance"]
+        fn qcoreapplicationInstance() -> *mut QCoreApplication;
         #[doc(hidden)]
         #[rust_name = "qcoreapplication_library_paths"]
-        fn qapplicationLibraryPaths(app: &QCoreApplication) -> QStringList;
+        fn qcoreapplicationLibraryPaths(app: &QCoreApplication) -> QStringList;
         #[doc(hidden)]
         #[rust_name = "qcoreapplication_organization_domain"]
-        fn qapplicationOrganizationDomain(app: &QCoreApplication) -> QString;
+        fn qcoreapplicationOrganizationDomain(app: &QCoreApplication) -> QString;
         #[doc(hidden)]
         #[rust_name = "qcoreapplication_organization_name"]
-        fn qapplicationOrganizationName(app: &QCoreApplication) -> QString;
+        fn qcoreapplicationOrganizationName(app: &QCoreApplication) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qcoreapplication_post_event"]
+        unsafe fn qcoreapplicationPostEvent(
+            receiver: *mut QObject,
+            event: UniquePtr<QEvent>,
+            priority: i32,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qcoreapplication_process_events"]
+        fn qcoreapplicationProcessEvents();
+        #[doc(hidden)]
+        #[rust_name = "qcoreapplication_send_posted_events"]
+        fn qcoreapplicationSendPostedEvents();
         #[doc(hidden)]
         #[rust_name = "qcoreapplication_set_application_name"]
-        fn qapplicationSetApplicationName(app: Pin<&mut QCoreApplication>, name: &QString);
+        fn qcoreapplicationSetApplicationName(app: Pin<&mut QCoreApplication>, name: &QString);
         #[doc(hidden)]
         #[rust_name = "qcoreapplication_set_application_version"]
-        fn qapplicationSetApplicationVersion(app: Pin<&mut QCoreApplication>, version: &QString);
+        fn qcoreapplicationSetApplicationVersion(
+            app: Pin<&mut QCoreApplication>,
+            version: &QString,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qcoreapplication_set_attribute"]
+        fn qcoreapplicationSetAttribute(attribute: ApplicationAttribute, on: bool);
         #[doc(hidden)]
         #[rust_name = "qcoreapplication_set_library_paths"]
-        fn qapplicationSetLibraryPaths(app: Pin<&mut QCoreApplication>, paths: &QStringList);
+        fn qcoreapplicationSetLibraryPaths(app: Pin<&mut QCoreApplication>, paths: &QStringList);
         #[doc(hidden)]
         #[rust_name = "qcoreapplication_set_organization_domain"]
-        fn qapplicationSetOrganizationDomain(app: Pin<&mut QCoreApplication>, domain: &QString);
+        fn qcoreapplicationSetOrganizationDomain(app: Pin<&mut QCoreApplication>, domain: &QString);
         #[doc(hidden)]
         #[rust_name = "qcoreapplication_set_organization_name"]
-        fn qapplicationSetOrganizationName(app: Pin<&mut QCoreApplication>, name: &QString);
+        fn qcoreapplicationSetOrganizationName(app: Pin<&mut QCoreApplication>, name: &QString);
+        #[doc(hidden)]
+        #[rust_name = "qcoreapplication_test_attribute"]
+        fn qcoreapplicationTestAttribute(attribute: ApplicationAttribute) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qcoreapplication_upcast"]
+        fn upcastQObject(object: &QCoreApplication) -> &QObject;
+        #[doc(hidden)]
+        #[rust_name = "qcoreapplication_upcast_mut"]
+        fn upcastQObjectMut(object: Pin<&mut QCoreApplication>) -> Pin<&mut QObject>;
     }
 
     // QCoreApplication is not a trivial to CXX and is not relocatable in Qt
@@ -80,92 +161,414 @@ mod ffi {
 
 pub use ffi::QCoreApplication;
 
-impl QCoreApplication {
+/// A Rust closure which is called from C++ when the application is about to quit.
+#[doc(hidden)]
+pub struct QCoreApplicationClosure {
+    closure: Box<dyn FnMut()>,
+}
+
+impl QCoreApplicationClosure {
+    fn new(closure: impl FnMut() + 'static) -> Box<Self> {
+        Box::new(Self {
+            closure: Box::new(closure),
+        })
+    }
+
+    fn call(&mut self) {
+        (self.closure)();
+    }
+}
+
+/// Build the owned arguments which are passed to the constructor of an application.
+///
+/// These are stored by the application as Qt requires argc and argv to outlive it.
+pub(crate) fn application_args<I, S>(args: I) -> QVector<QByteArray>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    let mut vector = QVector::<QByteArray>::default();
+
+    // Construct an owned QVector of the args
+    // as we need the args data to outlive this method
+    // so we pass a QVector to C++ which is then stored
+    for arg in args {
+        let arg = arg.as_ref();
+
+        // Unix OsStrings can be directly converted to bytes.
+        #[cfg(unix)]
+        use std::os::unix::ffi::OsStrExt;
+
+        // Windows OsStrings are WTF-8 encoded, so they need to be
+        // converted to UTF-8 Strings before being converted to bytes.
+        // https://simonsapin.github.io/wtf-8/
+        #[cfg(windows)]
+        let arg = arg.to_string_lossy();
+
+        vector.append(QByteArray::from(arg.as_bytes()));
+    }
+
+    vector
+}
+
+/// The API which is shared between [QCoreApplication], [QGuiApplication](crate::QGuiApplication)
+/// and [QApplication](crate::QApplication).
+///
+/// This is implemented for any type which can be upcast to a [QCoreApplication],
+/// as in Qt the methods are inherited from QCoreApplication.
+pub trait CoreApplication: Upcast<QCoreApplication> {
     /// Prepends path to the beginning of the library path list,
     /// ensuring that it is searched for libraries first.
     /// If path is empty or already in the path list, the path list is not changed.
-    pub fn add_library_path(self: Pin<&mut Self>, path: &QString) {
-        ffi::qcoreapplication_add_library_path(self, path);
+    fn add_library_path(self: Pin<&mut Self>, path: &QString) {
+        ffi::qcoreapplication_add_library_path(self.upcast_pin(), path);
     }
 
     /// The name of this application
-    pub fn application_name(&self) -> QString {
-        ffi::qcoreapplication_application_name(self)
+    fn application_name(&self) -> QString {
+        ffi::qcoreapplication_application_name(self.upcast())
     }
 
     /// The version of this application
-    pub fn application_version(&self) -> QString {
-        ffi::qcoreapplication_application_version(self)
+    fn application_version(&self) -> QString {
+        ffi::qcoreapplication_application_version(self.upcast())
+    }
+
+    /// Returns the list of command-line arguments.
+    ///
+    /// Usually the first argument is the program name, Qt specific arguments which were
+    /// handled by the application have been removed from the list.
+    fn arguments(&self) -> QStringList {
+        ffi::qcoreapplication_arguments(self.upcast())
     }
 
     /// Enters the main event loop and waits until exit() is called,
     /// and then returns the value that was set to exit() (which is 0 if exit() is called via quit()).
-    pub fn exec(self: Pin<&mut Self>) -> i32 {
-        ffi::qcoreapplication_exec(self)
+    fn exec(self: Pin<&mut Self>) -> i32 {
+        ffi::qcoreapplication_exec(self.upcast_pin())
     }
 
-    /// Returns a list of paths that the application will search when dynamically loading libraries.
-    pub fn library_paths(&self) -> QStringList {
-        ffi::qcoreapplication_library_paths(self)
+    /// Tells the application to exit with a return code.
+    ///
+    /// After this function has been called, the application leaves the main event loop
+    /// and returns from the call to exec(). If the event loop is not running, this function does nothing.
+    ///
+    /// This must be called from the thread of the application, use exit_queued() from other threads.
+    fn exit(return_code: i32) {
+        ffi::qcoreapplication_exit(return_code);
     }
 
-    /// Initializes the window system and constructs an application object with command line arguments in args.
-    pub fn new() -> cxx::UniquePtr<Self> {
-        let mut vector = QVector::<QByteArray>::default();
-
-        // Construct an owned QVector of the args
-        // as we need the args_os data to outlive this method
-        // so we pass a QVector to C++ which is then stored
-        for arg in std::env::args_os() {
-            // Unix OsStrings can be directly converted to bytes.
-            #[cfg(unix)]
-            use std::os::unix::ffi::OsStrExt;
-
-            // Windows OsStrings are WTF-8 encoded, so they need to be
-            // converted to UTF-8 Strings before being converted to bytes.
-            // https://simonsapin.github.io/wtf-8/
-            #[cfg(windows)]
-            let arg = arg.to_string_lossy();
-
-            vector.append(QByteArray::from(arg.as_bytes()));
-        }
+    /// Tells the application to exit with a return code once its event loop processes the request.
+    ///
+    /// The exit is queued to the thread of the application, so this can be called from any thread
+    /// and before the event loop has been entered.
+    fn exit_queued(return_code: i32) {
+        ffi::qcoreapplication_exit_queued(return_code);
+    }
+
+    /// Returns a pointer to the application's QCoreApplication instance,
+    /// or a null pointer if no instance has been constructed.
+    fn instance() -> *mut QCoreApplication {
+        ffi::qcoreapplication_instance()
+    }
 
-        ffi::qcoreapplication_new(&vector)
+    /// Returns a list of paths that the application will search when dynamically loading libraries.
+    fn library_paths(&self) -> QStringList {
+        ffi::qcoreapplication_library_paths(self.upcast())
+    }
+
+    /// Connect the given closure to the aboutToQuit signal of the application,
+    /// which is emitted when the application is about to quit the main event loop.
+    ///
+    /// The closure is called until the returned [QMetaObjectConnection] is dropped
+    /// or the application is destroyed.
+    fn on_about_to_quit<F>(self: Pin<&mut Self>, closure: F) -> QMetaObjectConnection
+    where
+        F: FnMut() + 'static,
+    {
+        ffi::qcoreapplication_on_about_to_quit(
+            self.upcast_pin(),
+            QCoreApplicationClosure::call,
+            QCoreApplicationClosure::new(closure),
+        )
     }
 
     /// The Internet domain of the organization that wrote this application
-    pub fn organization_domain(&self) -> QString {
-        ffi::qcoreapplication_organization_domain(self)
+    fn organization_domain(&self) -> QString {
+        ffi::qcoreapplication_organization_domain(self.upcast())
     }
 
     /// The name of the organization that wrote this application
-    pub fn organization_name(&self) -> QString {
-        ffi::qcoreapplication_organization_name(self)
+    fn organization_name(&self) -> QString {
+        ffi::qcoreapplication_organization_name(self.upcast())
+    }
+
+    /// Adds the event to an event queue with the given priority and returns immediately,
+    /// the event is delivered to the receiver once the event loop of its thread processes it.
+    ///
+    /// The event queue takes ownership of the event. This can be called from any thread.
+    ///
+    /// # Safety
+    ///
+    /// The receiver must be a valid pointer to a QObject and must not be destroyed
+    /// before the event is delivered.
+    unsafe fn post_event(receiver: *mut QObject, event: cxx::UniquePtr<QEvent>, priority: i32) {
+        ffi::qcoreapplication_post_event(receiver, event, priority);
+    }
+
+    /// Processes all pending events for the calling thread.
+    fn process_events() {
+        ffi::qcoreapplication_process_events();
+    }
+
+    /// Tells the application to exit with return code 0 (success).
+    ///
+    /// This is equivalent to calling exit(0).
+    fn quit() {
+        Self::exit(0);
+    }
+
+    /// Immediately dispatches all events which have been previously queued with post_event()
+    /// and which are for objects that live in the current thread.
+    fn send_posted_events() {
+        ffi::qcoreapplication_send_posted_events();
     }
 
     /// Set the name of this application
-    pub fn set_application_name(self: Pin<&mut Self>, name: &QString) {
-        ffi::qcoreapplication_set_application_name(self, name);
+    fn set_application_name(self: Pin<&mut Self>, name: &QString) {
+        ffi::qcoreapplication_set_application_name(self.upcast_pin(), name);
     }
 
     /// Set the version of this application
-    pub fn set_application_version(self: Pin<&mut Self>, version: &QString) {
-        ffi::qcoreapplication_set_application_version(self, version);
+    fn set_application_version(self: Pin<&mut Self>, version: &QString) {
+        ffi::qcoreapplication_set_application_version(self.upcast_pin(), version);
+    }
+
+    /// Sets the attribute if on is true; otherwise clears the attribute.
+    ///
+    /// Most attributes need to be set before the application is constructed.
+    fn set_attribute(attribute: ApplicationAttribute, on: bool) {
+        ffi::qcoreapplication_set_attribute(attribute, on);
     }
 
     /// Sets the list of directories to search when loading plugins with QLibrary to paths.
     /// All existing paths will be deleted and the path list will consist of the paths given in paths and the path to the application.
-    pub fn set_library_paths(self: Pin<&mut Self>, paths: &QStringList) {
-        ffi::qcoreapplication_set_library_paths(self, paths);
+    fn set_library_paths(self: Pin<&mut Self>, paths: &QStringList) {
+        ffi::qcoreapplication_set_library_paths(self.upcast_pin(), paths);
     }
 
     /// Sets the Internet domain of the organization that wrote this application
-    pub fn set_organization_domain(self: Pin<&mut Self>, domain: &QString) {
-        ffi::qcoreapplication_set_organization_domain(self, domain);
+    fn set_organization_domain(self: Pin<&mut Self>, domain: &QString) {
+        ffi::qcoreapplication_set_organization_domain(self.upcast_pin(), domain);
     }
 
     /// Sets the name of the organization that wrote this application
-    pub fn set_organization_name(self: Pin<&mut Self>, name: &QString) {
-        ffi::qcoreapplication_set_organization_name(self, name);
+    fn set_organization_name(self: Pin<&mut Self>, name: &QString) {
+        ffi::qcoreapplication_set_organization_name(self.upcast_pin(), name);
+    }
+
+    /// Returns true if the attribute is set; otherwise returns false.
+    fn test_attribute(attribute: ApplicationAttribute) -> bool {
+        ffi::qcoreapplication_test_attribute(attribute)
+    }
+}
+
+impl<T> CoreApplication for T where T: Upcast<QCoreApplication> {}
+
+/// Implement the methods of the [CoreApplication] trait as inherent methods of an application type,
+/// so that the trait does not need to be in scope to use them.
+macro_rules! impl_core_application_methods {
+    ($ty:ty) => {
+        impl $ty {
+            /// Prepends path to the beginning of the library path list,
+            /// ensuring that it is searched for libraries first.
+            /// If path is empty or already in the path list, the path list is not changed.
+            pub fn add_library_path(self: core::pin::Pin<&mut Self>, path: &$crate::QString) {
+                $crate::CoreApplication::add_library_path(self, path);
+            }
+
+            /// The name of this application
+            pub fn application_name(&self) -> $crate::QString {
+                $crate::CoreApplication::application_name(self)
+            }
+
+            /// The version of this application
+            pub fn application_version(&self) -> $crate::QString {
+                $crate::CoreApplication::application_version(self)
+            }
+
+            /// Returns the list of command-line arguments.
+            ///
+            /// Usually the first argument is the program name, Qt specific arguments which were
+            /// handled by the application have been removed from the list.
+            pub fn arguments(&self) -> $crate::QStringList {
+                $crate::CoreApplication::arguments(self)
+            }
+
+            /// Enters the main event loop and waits until exit() is called,
+            /// and then returns the value that was set to exit() (which is 0 if exit() is called via quit()).
+            pub fn exec(self: core::pin::Pin<&mut Self>) -> i32 {
+                $crate::CoreApplication::exec(self)
+            }
+
+            /// Tells the application to exit with a return code.
+            ///
+            /// This must be called from the thread of the application, use exit_queued() from other threads.
+            pub fn exit(return_code: i32) {
+                <Self as $crate::CoreApplication>::exit(return_code);
+            }
+
+            /// Tells the application to exit with a return code once its event loop processes the request.
+            ///
+            /// This can be called from any thread and before the event loop has been entered.
+            pub fn exit_queued(return_code: i32) {
+                <Self as $crate::CoreApplication>::exit_queued(return_code);
+            }
+
+            /// Returns a pointer to the application's QCoreApplication instance,
+            /// or a null pointer if no instance has been constructed.
+            pub fn instance() -> *mut $crate::QCoreApplication {
+                <Self as $crate::CoreApplication>::instance()
+            }
+
+            /// Returns a list of paths that the application will search when dynamically loading libraries.
+            pub fn library_paths(&self) -> $crate::QStringList {
+                $crate::CoreApplication::library_paths(self)
+            }
+
+            /// Connect the given closure to the aboutToQuit signal of the application,
+            /// which is emitted when the application is about to quit the main event loop.
+            pub fn on_about_to_quit<F>(
+                self: core::pin::Pin<&mut Self>,
+                closure: F,
+            ) -> $crate::QMetaObjectConnection
+            where
+                F: FnMut() + 'static,
+            {
+                $crate::CoreApplication::on_about_to_quit(self, closure)
+            }
+
+            /// The Internet domain of the organization that wrote this application
+            pub fn organization_domain(&self) -> $crate::QString {
+                $crate::CoreApplication::organization_domain(self)
+            }
+
+            /// The name of the organization that wrote this application
+            pub fn organization_name(&self) -> $crate::QString {
+                $crate::CoreApplication::organization_name(self)
+            }
+
+            /// Adds the event to an event queue with the given priority and returns immediately.
+            ///
+            /// # Safety
+            ///
+            /// The receiver must be a valid pointer to a QObject and must not be destroyed
+            /// before the event is delivered.
+            pub unsafe fn post_event(
+                receiver: *mut $crate::QObject,
+                event: cxx::UniquePtr<$crate::QEvent>,
+                priority: i32,
+            ) {
+                <Self as $crate::CoreApplication>::post_event(receiver, event, priority);
+            }
+
+            /// Processes all pending events for the calling thread.
+            pub fn process_events() {
+                <Self as $crate::CoreApplication>::process_events();
+            }
+
+            /// Tells the application to exit with return code 0 (success).
+            pub fn quit() {
+                <Self as $crate::CoreApplication>::quit();
+            }
+
+            /// Immediately dispatches all events which have been previously queued with post_event()
+            /// and which are for objects that live in the current thread.
+            pub fn send_posted_events() {
+                <Self as $crate::CoreApplication>::send_posted_events();
+            }
+
+            /// Set the name of this application
+            pub fn set_application_name(self: core::pin::Pin<&mut Self>, name: &$crate::QString) {
+                $crate::CoreApplication::set_application_name(self, name);
+            }
+
+            /// Set the version of this application
+            pub fn set_application_version(
+                self: core::pin::Pin<&mut Self>,
+                version: &$crate::QString,
+            ) {
+                $crate::CoreApplication::set_application_version(self, version);
+            }
+
+            /// Sets the attribute if on is true; otherwise clears the attribute.
+            pub fn set_attribute(attribute: $crate::ApplicationAttribute, on: bool) {
+                <Self as $crate::CoreApplication>::set_attribute(attribute, on);
+            }
+
+            /// Sets the list of directories to search when loading plugins with QLibrary to paths.
+            pub fn set_library_paths(self: core::pin::Pin<&mut Self>, paths: &$crate::QStringList) {
+                $crate::CoreApplication::set_library_paths(self, paths);
+            }
+
+            /// Sets the Internet domain of the organization that wrote this application
+            pub fn set_organization_domain(
+                self: core::pin::Pin<&mut Self>,
+                domain: &$crate::QString,
+            ) {
+                $crate::CoreApplication::set_organization_domain(self, domain);
+            }
+
+            /// Sets the name of the organization that wrote this application
+            pub fn set_organization_name(self: core::pin::Pin<&mut Self>, name: &$crate::QString) {
+                $crate::CoreApplication::set_organization_name(self, name);
+            }
+
+            /// Returns true if the attribute is set; otherwise returns false.
+            pub fn test_attribute(attribute: $crate::ApplicationAttribute) -> bool {
+                <Self as $crate::CoreApplication>::test_attribute(attribute)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_core_application_methods;
+
+impl_core_application_methods!(QCoreApplication);
+
+impl QCoreApplication {
+    /// Initializes the window system and constructs an application object with command line arguments in args.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        Self::new_with_args(std::env::args_os())
+    }
+
+    /// Constructs an application object with the given command line arguments,
+    /// the first argument is usually the program name.
+    pub fn new_with_args<I, S>(args: I) -> cxx::UniquePtr<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        ffi::qcoreapplication_new(&application_args(args))
+    }
+}
+
+impl Upcast<QCoreApplication> for QCoreApplication {
+    fn upcast(&self) -> &QCoreApplication {
+        self
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QCoreApplication> {
+        self
+    }
+}
+
+impl Upcast<QObject> for QCoreApplication {
+    fn upcast(&self) -> &QObject {
+        ffi::qcoreapplication_upcast(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QObject> {
+        ffi::qcoreapplication_upcast_mut(self)
     }
 }
@@ -0,0 +1,125 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qdir.h"
+
+#include "../assertion_utils.h"
+
+// QDir has a single QSharedDataPointer as its member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/io/qdir.h?h=v5.15.6-lts-lgpl#n255
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/io/qdir.h?h=v6.2.4#n248
+assert_alignment_and_size(QDir, alignof(::std::size_t), sizeof(::std::size_t));
+
+static_assert(!::std::is_trivially_copy_assignable<QDir>::value);
+static_assert(!::std::is_trivially_copy_constructible<QDir>::value);
+
+static_assert(!::std::is_trivially_destructible<QDir>::value);
+
+static_assert(QTypeInfo<QDir>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QString
+qdirCleanPath(const QString& path)
+{
+  return QDir::cleanPath(path);
+}
+
+::rust::isize
+qdirCount(const QDir& dir)
+{
+  // In Qt 5 the type was uint now it is qsizetype, so we need to ensure the
+  // type is the same for CXX
+  return static_cast<::rust::isize>(dir.count());
+}
+
+QString
+qdirCurrentPath()
+{
+  return QDir::currentPath();
+}
+
+QStringList
+qdirEntryList(const QDir& dir, QDirFilter filters, QDirSortFlag sort)
+{
+  return dir.entryList(QDir::Filters(filters), QDir::SortFlags(sort));
+}
+
+QStringList
+qdirEntryListWithNameFilters(const QDir& dir,
+                             const QStringList& nameFilters,
+                             QDirFilter filters,
+                             QDirSortFlag sort)
+{
+  return dir.entryList(
+    nameFilters, QDir::Filters(filters), QDir::SortFlags(sort));
+}
+
+QDirFilter
+qdirFilter(const QDir& dir)
+{
+  // Bitwise flags are passed to Rust as the enum type with combined values
+  return static_cast<QDirFilter>(static_cast<int>(dir.filter()));
+}
+
+QString
+qdirFromNativeSeparators(const QString& pathName)
+{
+  return QDir::fromNativeSeparators(pathName);
+}
+
+QString
+qdirHomePath()
+{
+  return QDir::homePath();
+}
+
+QString
+qdirRootPath()
+{
+  return QDir::rootPath();
+}
+
+bool
+qdirSetCurrent(const QString& path)
+{
+  return QDir::setCurrent(path);
+}
+
+void
+qdirSetFilter(QDir& dir, QDirFilter filters)
+{
+  dir.setFilter(QDir::Filters(filters));
+}
+
+void
+qdirSetSorting(QDir& dir, QDirSortFlag sort)
+{
+  dir.setSorting(QDir::SortFlags(sort));
+}
+
+QDirSortFlag
+qdirSorting(const QDir& dir)
+{
+  return static_cast<QDirSortFlag>(static_cast<int>(dir.sorting()));
+}
+
+QString
+qdirTempPath()
+{
+  return QDir::tempPath();
+}
+
+QString
+qdirToNativeSeparators(const QString& pathName)
+{
+  return QDir::toNativeSeparators(pathName);
+}
+
+}
+}
@@ -0,0 +1,505 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::path::{Path, PathBuf};
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the filtering options available to QDir; e.g. for entry_list().
+    ///
+    /// Multiple filters can be combined with the `|` operator.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QDirFilter {
+        /// List directories that match the filters.
+        Dirs = 0x001,
+        /// List files.
+        Files = 0x002,
+        /// List disk drives (ignored under Unix).
+        Drives = 0x004,
+        /// Do not list symbolic links (ignored by operating systems that don't support symbolic links).
+        NoSymLinks = 0x008,
+        /// List directories, files, drives and symlinks (this does not list broken symlinks unless you specify System).
+        AllEntries = 0x007,
+        /// Mask for the type filters (Dirs, Files, Drives and NoSymLinks).
+        TypeMask = 0x00f,
+        /// List files for which the application has read access.
+        Readable = 0x010,
+        /// List files for which the application has write access.
+        Writable = 0x020,
+        /// List files for which the application has execute access.
+        Executable = 0x040,
+        /// Mask for the permission filters (Readable, Writable and Executable).
+        PermissionMask = 0x070,
+        /// Only list files that have been modified (ignored on Unix).
+        Modified = 0x080,
+        /// List hidden files (on Unix, files starting with a ".").
+        Hidden = 0x100,
+        /// List system files (on Unix, FIFOs, sockets and device files are included; on Windows, .lnk files are included).
+        System = 0x200,
+        /// Mask for the access filters (Readable, Writable, Executable, Modified, Hidden and System).
+        AccessMask = 0x3F0,
+        /// List all directories; i.e. don't apply the filters to directory names.
+        AllDirs = 0x400,
+        /// The filter should be case sensitive.
+        CaseSensitive = 0x800,
+        /// Do not list the special entry ".".
+        NoDot = 0x2000,
+        /// Do not list the special entry "..".
+        NoDotDot = 0x4000,
+        /// Do not list the special entries "." and "..".
+        NoDotAndDotDot = 0x6000,
+        /// Use the filter of the QDir.
+        NoFilter = -1,
+    }
+
+    /// This enum describes the sort options available to QDir, e.g. for entry_list().
+    ///
+    /// Multiple flags can be combined with the `|` operator.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QDirSortFlag {
+        /// Sort by name.
+        Name = 0x00,
+        /// Sort by time (modification time).
+        Time = 0x01,
+        /// Sort by file size.
+        Size = 0x02,
+        /// Do not sort.
+        Unsorted = 0x03,
+        /// Put the directories first, then the files.
+        DirsFirst = 0x04,
+        /// Reverse the sort order.
+        Reversed = 0x08,
+        /// Sort case-insensitively.
+        IgnoreCase = 0x10,
+        /// Put the files first, then the directories.
+        DirsLast = 0x20,
+        /// Sort items appropriately using the current locale settings.
+        LocaleAware = 0x40,
+        /// Sort by file type (extension).
+        Type = 0x80,
+        /// Use the sorting of the QDir.
+        NoSort = -1,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qdir.h");
+        type QDirFilter;
+        type QDirSortFlag;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+
+        include!("cxx-qt-lib/qdir.h");
+        type QDir = super::QDir;
+
+        /// Returns the absolute path name of a file in the directory.
+        #[rust_name = "absolute_file_path"]
+        fn absoluteFilePath(self: &QDir, file_name: &QString) -> QString;
+
+        /// Returns the absolute path (a path that starts with "/" or with a drive specification), which may contain symbolic links,
+        /// but never contains redundant ".", ".." or multiple separators.
+        #[rust_name = "absolute_path"]
+        fn absolutePath(self: &QDir) -> QString;
+
+        /// Returns the canonical path, i.e. a path without symbolic links or redundant "." or ".." elements.
+        ///
+        /// If the directory does not exist, canonical_path() returns an empty string.
+        #[rust_name = "canonical_path"]
+        fn canonicalPath(self: &QDir) -> QString;
+
+        /// Changes the QDir's directory to dir_name.
+        ///
+        /// Returns true if the new directory exists; otherwise returns false.
+        /// Note that the logical cd() operation is not performed if the new directory does not exist.
+        fn cd(self: &mut QDir, dir_name: &QString) -> bool;
+
+        /// Changes directory by moving one directory up from the QDir's current directory.
+        ///
+        /// Returns true if the new directory exists; otherwise returns false.
+        #[rust_name = "cd_up"]
+        fn cdUp(self: &mut QDir) -> bool;
+
+        /// Returns the name of the directory; this is not the same as the path.
+        #[rust_name = "dir_name"]
+        fn dirName(self: &QDir) -> QString;
+
+        /// Returns true if the directory exists; otherwise returns false.
+        fn exists(self: &QDir) -> bool;
+
+        /// Returns true if the file called name exists; otherwise returns false.
+        ///
+        /// Unless name contains an absolute file path, the file name is assumed to be relative to the directory itself.
+        #[rust_name = "exists_entry"]
+        fn exists(self: &QDir, name: &QString) -> bool;
+
+        /// Returns the path name of a file in the directory.
+        #[rust_name = "file_path"]
+        fn filePath(self: &QDir, file_name: &QString) -> QString;
+
+        /// Returns true if the directory's path is absolute; otherwise returns false.
+        #[rust_name = "is_absolute"]
+        fn isAbsolute(self: &QDir) -> bool;
+
+        /// Returns true if the directory is readable and we can open files by name; otherwise returns false.
+        #[rust_name = "is_readable"]
+        fn isReadable(self: &QDir) -> bool;
+
+        /// Returns true if the directory path is relative; otherwise returns false.
+        #[rust_name = "is_relative"]
+        fn isRelative(self: &QDir) -> bool;
+
+        /// Returns true if the directory is the root directory; otherwise returns false.
+        #[rust_name = "is_root"]
+        fn isRoot(self: &QDir) -> bool;
+
+        /// Converts the directory path to an absolute path. If it is already absolute nothing happens.
+        ///
+        /// Returns true if the conversion succeeded; otherwise returns false.
+        #[rust_name = "make_absolute"]
+        fn makeAbsolute(self: &mut QDir) -> bool;
+
+        /// Creates a sub-directory called dir_name.
+        ///
+        /// Returns true on success; otherwise returns false.
+        /// If the directory already exists when this function is called, it will return false.
+        fn mkdir(self: &QDir, dir_name: &QString) -> bool;
+
+        /// Creates the directory path dir_path.
+        ///
+        /// The function will create all parent directories necessary to create the directory.
+        /// Returns true if successful; otherwise returns false.
+        fn mkpath(self: &QDir, dir_path: &QString) -> bool;
+
+        /// Returns the string list set by set_name_filters()
+        #[rust_name = "name_filters"]
+        fn nameFilters(self: &QDir) -> QStringList;
+
+        /// Returns the path. This may contain symbolic links, but never contains redundant ".", ".." or multiple separators.
+        fn path(self: &QDir) -> QString;
+
+        /// Refreshes the directory information.
+        fn refresh(self: &QDir);
+
+        /// Returns the path to file_name relative to the directory.
+        #[rust_name = "relative_file_path"]
+        fn relativeFilePath(self: &QDir, file_name: &QString) -> QString;
+
+        /// Removes the file, file_name.
+        ///
+        /// Returns true if the file is removed successfully; otherwise returns false.
+        fn remove(self: &mut QDir, file_name: &QString) -> bool;
+
+        /// Removes the directory, including all its contents.
+        ///
+        /// Returns true if successful, otherwise false.
+        #[rust_name = "remove_recursively"]
+        fn removeRecursively(self: &mut QDir) -> bool;
+
+        /// Renames a file or directory from old_name to new_name, and returns true if successful; otherwise returns false.
+        fn rename(self: &mut QDir, old_name: &QString, new_name: &QString) -> bool;
+
+        /// Removes the directory specified by dir_name.
+        ///
+        /// The directory must be empty for rmdir() to succeed.
+        fn rmdir(self: &QDir, dir_name: &QString) -> bool;
+
+        /// Removes the directory path dir_path.
+        ///
+        /// The function will remove all parent directories in dir_path, provided that they are empty.
+        fn rmpath(self: &QDir, dir_path: &QString) -> bool;
+
+        /// Sets the name filters used by entry_list() to name_filters.
+        ///
+        /// Each name filter is a wildcard (globbing) filter that understands * and ? wildcards.
+        #[rust_name = "set_name_filters"]
+        fn setNameFilters(self: &mut QDir, name_filters: &QStringList);
+
+        /// Sets the path of the directory to path.
+        #[rust_name = "set_path"]
+        fn setPath(self: &mut QDir, path: &QString);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qdir_clean_path"]
+        fn qdirCleanPath(path: &QString) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qdir_count"]
+        fn qdirCount(dir: &QDir) -> isize;
+        #[doc(hidden)]
+        #[rust_name = "qdir_current_path"]
+        fn qdirCurrentPath() -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qdir_entry_list"]
+        fn qdirEntryList(dir: &QDir, filters: QDirFilter, sort: QDirSortFlag) -> QStringList;
+        #[doc(hidden)]
+        #[rust_name = "qdir_entry_list_with_name_filters"]
+        fn qdirEntryListWithNameFilters(
+            dir: &QDir,
+            name_filters: &QStringList,
+            filters: QDirFilter,
+            sort: QDirSortFlag,
+        ) -> QStringList;
+        #[doc(hidden)]
+        #[rust_name = "qdir_filter"]
+        fn qdirFilter(dir: &QDir) -> QDirFilter;
+        #[doc(hidden)]
+        #[rust_name = "qdir_from_native_separators"]
+        fn qdirFromNativeSeparators(path_name: &QString) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qdir_home_path"]
+        fn qdirHomePath() -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qdir_root_path"]
+        fn qdirRootPath() -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qdir_set_current"]
+        fn qdirSetCurrent(path: &QString) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qdir_set_filter"]
+        fn qdirSetFilter(dir: &mut QDir, filters: QDirFilter);
+        #[doc(hidden)]
+        #[rust_name = "qdir_set_sorting"]
+        fn qdirSetSorting(dir: &mut QDir, sort: QDirSortFlag);
+        #[doc(hidden)]
+        #[rust_name = "qdir_sorting"]
+        fn qdirSorting(dir: &QDir) -> QDirSortFlag;
+        #[doc(hidden)]
+        #[rust_name = "qdir_temp_path"]
+        fn qdirTempPath() -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qdir_to_native_separators"]
+        fn qdirToNativeSeparators(path_name: &QString) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qdir_drop"]
+        fn drop(dir: &mut QDir);
+
+        #[doc(hidden)]
+        #[rust_name = "qdir_init_default"]
+        fn construct() -> QDir;
+        #[doc(hidden)]
+        #[rust_name = "qdir_init_from_qstring"]
+        fn construct(path: &QString) -> QDir;
+        #[doc(hidden)]
+        #[rust_name = "qdir_clone"]
+        fn construct(dir: &QDir) -> QDir;
+
+        #[doc(hidden)]
+        #[rust_name = "qdir_eq"]
+        fn operatorEq(a: &QDir, b: &QDir) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qdir_to_qstring"]
+        fn toQString(value: &QDir) -> QString;
+    }
+}
+
+pub use ffi::{QDirFilter, QDirSortFlag};
+
+/// The QDir class provides access to directory structures and their contents.
+///
+/// Note that paths starting with a colon, such as `:/images`, refer to resources.
+#[repr(C)]
+pub struct QDir {
+    _space: MaybeUninit<usize>,
+}
+
+impl QDir {
+    /// Returns path with directory separators normalized (that is, platform-native separators converted to "/")
+    /// and redundant ones removed, and "."s and ".."s resolved (as far as possible).
+    pub fn clean_path(path: &ffi::QString) -> ffi::QString {
+        ffi::qdir_clean_path(path)
+    }
+
+    /// Returns the total number of directories and files in the directory.
+    pub fn count(&self) -> isize {
+        ffi::qdir_count(self)
+    }
+
+    /// Returns the absolute path of the application's current directory.
+    pub fn current_path() -> ffi::QString {
+        ffi::qdir_current_path()
+    }
+
+    /// Returns a list of the names of all the files and directories in the directory,
+    /// ordered according to the name and attribute filters previously set with set_name_filters() and set_filter(),
+    /// and sorted according to the flags set with set_sorting().
+    ///
+    /// The filters and sort arguments can be used to override the filter and sorting of the directory,
+    /// QDirFilter::NoFilter and QDirSortFlag::NoSort use the filter and sorting of the directory.
+    pub fn entry_list(&self, filters: QDirFilter, sort: QDirSortFlag) -> ffi::QStringList {
+        ffi::qdir_entry_list(self, filters, sort)
+    }
+
+    /// Returns a list of the names of all the files and directories in the directory,
+    /// ordered according to the given name filters and attribute filters, and sorted according to sort.
+    pub fn entry_list_with_name_filters(
+        &self,
+        name_filters: &ffi::QStringList,
+        filters: QDirFilter,
+        sort: QDirSortFlag,
+    ) -> ffi::QStringList {
+        ffi::qdir_entry_list_with_name_filters(self, name_filters, filters, sort)
+    }
+
+    /// Returns the value set by set_filter()
+    pub fn filter(&self) -> QDirFilter {
+        ffi::qdir_filter(self)
+    }
+
+    /// Returns path_name using '/' as file separator.
+    pub fn from_native_separators(path_name: &ffi::QString) -> ffi::QString {
+        ffi::qdir_from_native_separators(path_name)
+    }
+
+    /// Returns the absolute path of the user's home directory.
+    pub fn home_path() -> ffi::QString {
+        ffi::qdir_home_path()
+    }
+
+    /// Returns the absolute path of the root directory.
+    pub fn root_path() -> ffi::QString {
+        ffi::qdir_root_path()
+    }
+
+    /// Sets the application's current working directory to path. Returns true if the directory was successfully changed; otherwise returns false.
+    pub fn set_current(path: &ffi::QString) -> bool {
+        ffi::qdir_set_current(path)
+    }
+
+    /// Sets the filter used by entry_list() to filters.
+    pub fn set_filter(&mut self, filters: QDirFilter) {
+        ffi::qdir_set_filter(self, filters)
+    }
+
+    /// Sets the sort order used by entry_list().
+    pub fn set_sorting(&mut self, sort: QDirSortFlag) {
+        ffi::qdir_set_sorting(self, sort)
+    }
+
+    /// Returns the value set by set_sorting()
+    pub fn sorting(&self) -> QDirSortFlag {
+        ffi::qdir_sorting(self)
+    }
+
+    /// Returns the absolute canonical path of the system's temporary directory.
+    pub fn temp_path() -> ffi::QString {
+        ffi::qdir_temp_path()
+    }
+
+    /// Returns path_name with the '/' separators converted to separators that are appropriate for the underlying operating system.
+    pub fn to_native_separators(path_name: &ffi::QString) -> ffi::QString {
+        ffi::qdir_to_native_separators(path_name)
+    }
+}
+
+impl Clone for QDir {
+    /// Constructs a QDir object that is a copy of the QDir object for directory dir.
+    fn clone(&self) -> Self {
+        ffi::qdir_clone(self)
+    }
+}
+
+impl Default for QDir {
+    /// Constructs a QDir pointing to the application's working directory (".").
+    fn default() -> Self {
+        ffi::qdir_init_default()
+    }
+}
+
+impl std::cmp::PartialEq for QDir {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qdir_eq(self, other)
+    }
+}
+
+impl std::cmp::Eq for QDir {}
+
+impl fmt::Display for QDir {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.path())
+    }
+}
+
+impl fmt::Debug for QDir {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qdir_to_qstring(self))
+    }
+}
+
+impl Drop for QDir {
+    /// Destroys the QDir object frees up its resources.
+    fn drop(&mut self) {
+        ffi::qdir_drop(self)
+    }
+}
+
+impl From<&ffi::QString> for QDir {
+    /// Constructs a QDir pointing to the given directory path.
+    /// If path is empty the program's working directory, ("."), is used.
+    fn from(path: &ffi::QString) -> Self {
+        ffi::qdir_init_from_qstring(path)
+    }
+}
+
+impl From<&Path> for QDir {
+    /// Constructs a QDir pointing to the given directory path.
+    fn from(path: &Path) -> Self {
+        ffi::qdir_init_from_qstring(&ffi::QString::from(path))
+    }
+}
+
+impl From<&QDir> for PathBuf {
+    /// Converts the path of the QDir, which may be absolute or relative, into a [PathBuf].
+    fn from(dir: &QDir) -> Self {
+        PathBuf::from(&dir.path())
+    }
+}
+
+impl std::ops::BitOr for QDirFilter {
+    type Output = Self;
+
+    /// Combine two filters together
+    fn bitor(self, other: Self) -> Self {
+        Self {
+            repr: self.repr | other.repr,
+        }
+    }
+}
+
+impl std::ops::BitOr for QDirSortFlag {
+    type Output = Self;
+
+    /// Combine two sort flags together
+    fn bitor(self, other: Self) -> Self {
+        Self {
+            repr: self.repr | other.repr,
+        }
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QDir {
+    type Id = type_id!("QDir");
+    type Kind = cxx::kind::Trivial;
+}
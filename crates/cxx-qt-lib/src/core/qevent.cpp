@@ -0,0 +1,32 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qevent.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QEvent>
+qeventNew(::std::int32_t type)
+{
+  return ::std::make_unique<QEvent>(static_cast<QEvent::Type>(type));
+}
+
+::std::int32_t
+qeventRegisterEventType(::std::int32_t hint)
+{
+  return static_cast<::std::int32_t>(
+    QEvent::registerEventType(static_cast<int>(hint)));
+}
+
+::std::int32_t
+qeventType(const QEvent& event)
+{
+  return static_cast<::std::int32_t>(event.type());
+}
+
+}
+}
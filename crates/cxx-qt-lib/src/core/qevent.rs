@@ -0,0 +1,77 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qevent.h");
+        /// The QEvent class is the base class of all event classes.
+        type QEvent;
+
+        /// Sets the accept flag of the event object, the receiver wants the event.
+        fn accept(self: Pin<&mut QEvent>);
+
+        /// Clears the accept flag of the event object, the receiver does not want the event.
+        fn ignore(self: Pin<&mut QEvent>);
+
+        /// Returns whether the accept flag of the event object is set.
+        #[rust_name = "is_accepted"]
+        fn isAccepted(self: &QEvent) -> bool;
+
+        /// Sets the accept flag of the event object.
+        #[rust_name = "set_accepted"]
+        fn setAccepted(self: Pin<&mut QEvent>, accepted: bool);
+
+        /// Returns true if the event originated outside the application (a system event); otherwise returns false.
+        fn spontaneous(self: &QEvent) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qevent_new"]
+        fn qeventNew(event_type: i32) -> UniquePtr<QEvent>;
+        #[doc(hidden)]
+        #[rust_name = "qevent_register_event_type"]
+        fn qeventRegisterEventType(hint: i32) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qevent_type"]
+        fn qeventType(event: &QEvent) -> i32;
+    }
+
+    // QEvent has a virtual destructor and is not copyable,
+    // so we need to use references or pointers.
+    impl UniquePtr<QEvent> {}
+}
+
+pub use ffi::QEvent;
+
+impl QEvent {
+    /// The first event type which is available for user defined events.
+    pub const USER: i32 = 1000;
+    /// The last event type which is available for user defined events.
+    pub const MAX_USER: i32 = 65535;
+
+    /// Contructs an event object of the given type.
+    ///
+    /// Custom events should use a type between [QEvent::USER] and [QEvent::MAX_USER],
+    /// ideally one returned from [QEvent::register_event_type].
+    pub fn new(event_type: i32) -> cxx::UniquePtr<Self> {
+        ffi::qevent_new(event_type)
+    }
+
+    /// Returns the event type.
+    pub fn event_type(&self) -> i32 {
+        ffi::qevent_type(self)
+    }
+
+    /// Registers and returns a custom event type, the hint provided will be used if it is available,
+    /// otherwise a value between [QEvent::USER] and [QEvent::MAX_USER] that has not yet been registered is returned.
+    ///
+    /// Use a hint of -1 if no specific value is wanted.
+    pub fn register_event_type(hint: i32) -> i32 {
+        ffi::qevent_register_event_type(hint)
+    }
+}
@@ -0,0 +1,49 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qfile.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QString
+qfileDecodeName(const QByteArray& localFileName)
+{
+  return QFile::decodeName(localFileName);
+}
+
+QByteArray
+qfileEncodeName(const QString& fileName)
+{
+  return QFile::encodeName(fileName);
+}
+
+::std::unique_ptr<QFile>
+qfileNew(const QString& name)
+{
+  return ::std::make_unique<QFile>(name);
+}
+
+bool
+qfileOpen(QFile& file, QIODeviceOpenModeFlag mode)
+{
+  // Bitwise flags are passed from Rust as the enum type with combined values
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return file.open(QIODeviceBase::OpenMode(mode));
+#else
+  return file.open(QIODevice::OpenMode(mode));
+#endif
+}
+
+::std::int64_t
+qfileSize(const QFile& file)
+{
+  // qint64 is not always the same type as std::int64_t
+  return static_cast<::std::int64_t>(file.size());
+}
+
+}
+}
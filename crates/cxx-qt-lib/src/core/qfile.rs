@@ -0,0 +1,225 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QByteArray, QObject, QString, Upcast};
+use core::pin::Pin;
+use std::path::{Path, PathBuf};
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum is used with open() to describe the mode in which a device is opened.
+    ///
+    /// Multiple modes can be combined with the `|` operator.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    enum QIODeviceOpenModeFlag {
+        /// The device is not open.
+        NotOpen = 0x0000,
+        /// The device is open for reading.
+        ReadOnly = 0x0001,
+        /// The device is open for writing.
+        WriteOnly = 0x0002,
+        /// The device is open for reading and writing.
+        ReadWrite = 0x0003,
+        /// The device is opened in append mode so that all data is written to the end of the file.
+        Append = 0x0004,
+        /// If possible, the device is truncated before it is opened. All earlier contents of the device are lost.
+        Truncate = 0x0008,
+        /// When reading, the end-of-line terminators are translated to '\n'.
+        /// When writing, the end-of-line terminators are translated to the local encoding.
+        Text = 0x0010,
+        /// Any buffer in the device is bypassed.
+        Unbuffered = 0x0020,
+        /// Fail if the file to be opened already exists. Create and open the file only if it does not exist.
+        NewOnly = 0x0040,
+        /// Fail if the file to be opened does not exist.
+        ExistingOnly = 0x0080,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        include!("cxx-qt-lib/qfile.h");
+        /// The QFile class provides an interface for reading from and writing to files.
+        ///
+        /// Files compiled into the application with `CxxQtBuilder::qrc` can be read
+        /// using a file name starting with a colon, such as `:/images/logo.png`.
+        type QFile;
+
+        /// Calls flush() and closes the file. Errors from flush are ignored.
+        fn close(self: Pin<&mut QFile>);
+
+        /// Copies the file named file_name() to new_name.
+        ///
+        /// Returns true if successful; otherwise returns false.
+        /// Note that if a file with the name new_name already exists, copy() returns false.
+        fn copy(self: Pin<&mut QFile>, new_name: &QString) -> bool;
+
+        /// Returns a human-readable description of the last device error that occurred.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QFile) -> QString;
+
+        /// Returns true if the file specified by file_name() exists; otherwise returns false.
+        fn exists(self: &QFile) -> bool;
+
+        /// Returns the name set by set_file_name() or to the QFile constructor.
+        #[rust_name = "file_name"]
+        fn fileName(self: &QFile) -> QString;
+
+        /// Returns true if the device is open; otherwise returns false.
+        #[rust_name = "is_open"]
+        fn isOpen(self: &QFile) -> bool;
+
+        /// Reads all remaining data from the device, and returns it as a byte array.
+        #[rust_name = "read_all"]
+        fn readAll(self: Pin<&mut QFile>) -> QByteArray;
+
+        /// Removes the file specified by file_name(). Returns true if successful; otherwise returns false.
+        ///
+        /// The file is closed before it is removed.
+        fn remove(self: Pin<&mut QFile>) -> bool;
+
+        /// Renames the file currently specified by file_name() to new_name. Returns true if successful; otherwise returns false.
+        fn rename(self: Pin<&mut QFile>, new_name: &QString) -> bool;
+
+        /// Sets the name of the file.
+        ///
+        /// Do not call this function if the file has already been opened.
+        #[rust_name = "set_file_name"]
+        fn setFileName(self: Pin<&mut QFile>, name: &QString);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QIODeviceOpenModeFlag;
+
+        #[doc(hidden)]
+        #[rust_name = "qfile_decode_name"]
+        fn qfileDecodeName(local_file_name: &QByteArray) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qfile_encode_name"]
+        fn qfileEncodeName(file_name: &QString) -> QByteArray;
+        #[doc(hidden)]
+        #[rust_name = "qfile_new"]
+        fn qfileNew(name: &QString) -> UniquePtr<QFile>;
+        #[doc(hidden)]
+        #[rust_name = "qfile_open"]
+        fn qfileOpen(file: Pin<&mut QFile>, mode: QIODeviceOpenModeFlag) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qfile_size"]
+        fn qfileSize(file: &QFile) -> i64;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qfile_upcast"]
+        fn upcastQObject(object: &QFile) -> &QObject;
+        #[doc(hidden)]
+        #[rust_name = "qfile_upcast_mut"]
+        fn upcastQObjectMut(object: Pin<&mut QFile>) -> Pin<&mut QObject>;
+    }
+
+    // QFile is a QObject so it is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QFile>::isRelocatable);
+    impl UniquePtr<QFile> {}
+}
+
+pub use ffi::{QFile, QIODeviceOpenModeFlag};
+
+impl QFile {
+    /// Constructs a new file object to represent the file with the given name.
+    pub fn new(name: &QString) -> cxx::UniquePtr<Self> {
+        ffi::qfile_new(name)
+    }
+
+    /// This does the reverse of encode_name() using local_file_name.
+    pub fn decode_name(local_file_name: &QByteArray) -> QString {
+        ffi::qfile_decode_name(local_file_name)
+    }
+
+    /// Converts file_name to an 8-bit encoding that you can use in native APIs.
+    ///
+    /// On Windows, the encoding is the one from active Windows (ANSI) codepage.
+    /// On other platforms, this is UTF-8, for macOS in decomposed form (NFD).
+    pub fn encode_name(file_name: &QString) -> QByteArray {
+        ffi::qfile_encode_name(file_name)
+    }
+
+    /// Opens the file using the given mode, returning true if successful; otherwise false.
+    pub fn open(self: Pin<&mut Self>, mode: QIODeviceOpenModeFlag) -> bool {
+        ffi::qfile_open(self, mode)
+    }
+
+    /// Returns the size of the file.
+    pub fn size(&self) -> i64 {
+        ffi::qfile_size(self)
+    }
+}
+
+impl Upcast<QObject> for QFile {
+    fn upcast(&self) -> &QObject {
+        ffi::qfile_upcast(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QObject> {
+        ffi::qfile_upcast_mut(self)
+    }
+}
+
+impl std::ops::BitOr for QIODeviceOpenModeFlag {
+    type Output = Self;
+
+    /// Combine two open modes together
+    fn bitor(self, other: Self) -> Self {
+        Self {
+            repr: self.repr | other.repr,
+        }
+    }
+}
+
+impl From<&QString> for PathBuf {
+    /// Converts a Qt file path into a [PathBuf].
+    ///
+    /// On Unix this uses QFile::encodeName so that file names which are not valid UTF-8 are preserved.
+    fn from(file_name: &QString) -> Self {
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStrExt;
+            let local_file_name = QFile::encode_name(file_name);
+            std::ffi::OsStr::from_bytes(local_file_name.as_slice()).into()
+        }
+
+        #[cfg(not(unix))]
+        {
+            PathBuf::from(String::from(file_name))
+        }
+    }
+}
+
+impl From<&Path> for QString {
+    /// Converts a [Path] into a Qt file path.
+    ///
+    /// On Unix this uses QFile::decodeName so that file names which are not valid UTF-8 are preserved.
+    fn from(path: &Path) -> Self {
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStrExt;
+            QFile::decode_name(&QByteArray::from(path.as_os_str().as_bytes()))
+        }
+
+        #[cfg(not(unix))]
+        {
+            QString::from(path.to_string_lossy().as_ref())
+        }
+    }
+}
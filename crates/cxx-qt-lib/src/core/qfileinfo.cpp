@@ -0,0 +1,55 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qfileinfo.h"
+
+#include "../assertion_utils.h"
+
+// QFileInfo has a single QSharedDataPointer as its member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/io/qfileinfo.h?h=v5.15.6-lts-lgpl#n167
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/io/qfileinfo.h?h=v6.2.4#n212
+assert_alignment_and_size(QFileInfo,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t));
+
+static_assert(!::std::is_trivially_copy_assignable<QFileInfo>::value);
+static_assert(!::std::is_trivially_copy_constructible<QFileInfo>::value);
+
+static_assert(!::std::is_trivially_destructible<QFileInfo>::value);
+
+static_assert(QTypeInfo<QFileInfo>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QFileInfo
+qfileinfoFromUrl(const QUrl& url)
+{
+  // Resources are referred to as qrc:/path in URLs but as :/path in file names
+  if (url.scheme().compare(QStringLiteral("qrc"), Qt::CaseInsensitive) == 0) {
+    if (url.authority().isEmpty()) {
+      return QFileInfo(QLatin1Char(':') + url.path());
+    }
+    return QFileInfo();
+  }
+
+  if (url.isLocalFile()) {
+    return QFileInfo(url.toLocalFile());
+  }
+
+  return QFileInfo();
+}
+
+::std::int64_t
+qfileinfoSize(const QFileInfo& info)
+{
+  // qint64 is not always the same type as std::int64_t
+  return static_cast<::std::int64_t>(info.size());
+}
+
+}
+}
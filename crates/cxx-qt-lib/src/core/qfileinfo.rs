@@ -0,0 +1,293 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::path::{Path, PathBuf};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qdir.h");
+        type QDir = crate::QDir;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = crate::QUrl;
+
+        include!("cxx-qt-lib/qfileinfo.h");
+        type QFileInfo = super::QFileInfo;
+
+        /// Returns the file's absolute path as a QDir object.
+        #[rust_name = "absolute_dir"]
+        fn absoluteDir(self: &QFileInfo) -> QDir;
+
+        /// Returns an absolute path including the file name.
+        #[rust_name = "absolute_file_path"]
+        fn absoluteFilePath(self: &QFileInfo) -> QString;
+
+        /// Returns a file's path absolute path. This doesn't include the file name.
+        #[rust_name = "absolute_path"]
+        fn absolutePath(self: &QFileInfo) -> QString;
+
+        /// Returns the base name of the file without the path.
+        ///
+        /// The base name consists of all characters in the file up to (but not including) the first '.' character.
+        #[rust_name = "base_name"]
+        fn baseName(self: &QFileInfo) -> QString;
+
+        /// Returns true if caching is enabled; otherwise returns false.
+        fn caching(self: &QFileInfo) -> bool;
+
+        /// Returns the canonical path including the file name, i.e. an absolute path without symbolic links or redundant "." or ".." elements.
+        ///
+        /// If the file does not exist, canonical_file_path() returns an empty string.
+        #[rust_name = "canonical_file_path"]
+        fn canonicalFilePath(self: &QFileInfo) -> QString;
+
+        /// Returns the file's path canonical path (excluding the file name), i.e. an absolute path without symbolic links or redundant "." or ".." elements.
+        ///
+        /// If the file does not exist, canonical_path() returns an empty string.
+        #[rust_name = "canonical_path"]
+        fn canonicalPath(self: &QFileInfo) -> QString;
+
+        /// Returns the complete base name of the file without the path.
+        ///
+        /// The complete base name consists of all characters in the file up to (but not including) the last '.' character.
+        #[rust_name = "complete_base_name"]
+        fn completeBaseName(self: &QFileInfo) -> QString;
+
+        /// Returns the complete suffix (extension) of the file.
+        ///
+        /// The complete suffix consists of all characters in the file after (but not including) the first '.'.
+        #[rust_name = "complete_suffix"]
+        fn completeSuffix(self: &QFileInfo) -> QString;
+
+        /// Returns the path of the object's parent directory as a QDir object.
+        fn dir(self: &QFileInfo) -> QDir;
+
+        /// Returns true if the file exists; otherwise returns false.
+        fn exists(self: &QFileInfo) -> bool;
+
+        /// Returns the name of the file, excluding the path.
+        #[rust_name = "file_name"]
+        fn fileName(self: &QFileInfo) -> QString;
+
+        /// Returns the file name, including the path (which may be absolute or relative).
+        #[rust_name = "file_path"]
+        fn filePath(self: &QFileInfo) -> QString;
+
+        /// Returns true if the file path is absolute, otherwise returns false (i.e. the path is relative).
+        #[rust_name = "is_absolute"]
+        fn isAbsolute(self: &QFileInfo) -> bool;
+
+        /// Returns true if this object points to a directory or to a symbolic link to a directory.
+        #[rust_name = "is_dir"]
+        fn isDir(self: &QFileInfo) -> bool;
+
+        /// Returns true if the file is executable; otherwise returns false.
+        #[rust_name = "is_executable"]
+        fn isExecutable(self: &QFileInfo) -> bool;
+
+        /// Returns true if this object points to a file or to a symbolic link to a file.
+        #[rust_name = "is_file"]
+        fn isFile(self: &QFileInfo) -> bool;
+
+        /// Returns true if this is a "hidden" file; otherwise returns false.
+        #[rust_name = "is_hidden"]
+        fn isHidden(self: &QFileInfo) -> bool;
+
+        /// Returns true if the user can read the file; otherwise returns false.
+        #[rust_name = "is_readable"]
+        fn isReadable(self: &QFileInfo) -> bool;
+
+        /// Returns true if the file path is relative, otherwise returns false (i.e. the path is absolute).
+        ///
+        /// Paths starting with a colon (:) are always considered absolute, as they denote a resource.
+        #[rust_name = "is_relative"]
+        fn isRelative(self: &QFileInfo) -> bool;
+
+        /// Returns true if the object points to a directory or to a symbolic link to a directory, and that directory is the root directory.
+        #[rust_name = "is_root"]
+        fn isRoot(self: &QFileInfo) -> bool;
+
+        /// Returns true if this object points to a symbolic link, shortcut, or alias; otherwise returns false.
+        #[rust_name = "is_sym_link"]
+        fn isSymLink(self: &QFileInfo) -> bool;
+
+        /// Returns true if the user can write to the file; otherwise returns false.
+        #[rust_name = "is_writable"]
+        fn isWritable(self: &QFileInfo) -> bool;
+
+        /// Converts the file's path to an absolute path if it is not already in that form.
+        ///
+        /// Returns true to indicate that the path was changed; otherwise returns false to indicate that the path was already absolute.
+        #[rust_name = "make_absolute"]
+        fn makeAbsolute(self: &mut QFileInfo) -> bool;
+
+        /// Returns the file's path. This doesn't include the file name.
+        fn path(self: &QFileInfo) -> QString;
+
+        /// Refreshes the information about the file, i.e. reads in information from the file system the next time a cached property is fetched.
+        fn refresh(self: &mut QFileInfo);
+
+        /// If enable is true, enables caching of file information. If enable is false caching is disabled.
+        #[rust_name = "set_caching"]
+        fn setCaching(self: &mut QFileInfo, enable: bool);
+
+        /// Sets the file that the QFileInfo provides information about to file.
+        ///
+        /// The file can also include an absolute or relative path.
+        #[rust_name = "set_file"]
+        fn setFile(self: &mut QFileInfo, file: &QString);
+
+        /// Returns the suffix (extension) of the file.
+        ///
+        /// The suffix consists of all characters in the file after (but not including) the last '.'.
+        fn suffix(self: &QFileInfo) -> QString;
+
+        /// Returns the absolute path to the file or directory a symbolic link points to,
+        /// or an empty string if the object isn't a symbolic link.
+        #[rust_name = "sym_link_target"]
+        fn symLinkTarget(self: &QFileInfo) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_from_url"]
+        fn qfileinfoFromUrl(url: &QUrl) -> QFileInfo;
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_size"]
+        fn qfileinfoSize(info: &QFileInfo) -> i64;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_drop"]
+        fn drop(info: &mut QFileInfo);
+
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_init_default"]
+        fn construct() -> QFileInfo;
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_init_from_qstring"]
+        fn construct(file: &QString) -> QFileInfo;
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_clone"]
+        fn construct(info: &QFileInfo) -> QFileInfo;
+
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_eq"]
+        fn operatorEq(a: &QFileInfo, b: &QFileInfo) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_to_qstring"]
+        fn toQString(value: &QFileInfo) -> QString;
+    }
+}
+
+/// The QFileInfo class provides system-independent file information.
+///
+/// Note that file names starting with a colon, such as `:/images/logo.png`, refer to resources.
+#[repr(C)]
+pub struct QFileInfo {
+    _space: MaybeUninit<usize>,
+}
+
+impl QFileInfo {
+    /// Constructs a QFileInfo for the file a URL points to.
+    ///
+    /// Both `file:` URLs, for example from a QML `FileDialog`, and `qrc:` resource URLs are supported,
+    /// for any other URL None is returned.
+    pub fn from_url(url: &ffi::QUrl) -> Option<Self> {
+        let info = ffi::qfileinfo_from_url(url);
+        if info.file_path().is_empty() {
+            None
+        } else {
+            Some(info)
+        }
+    }
+
+    /// Returns the file size in bytes.
+    ///
+    /// If the file does not exist or cannot be fetched, 0 is returned.
+    pub fn size(&self) -> i64 {
+        ffi::qfileinfo_size(self)
+    }
+}
+
+impl Clone for QFileInfo {
+    /// Constructs a new QFileInfo that is a copy of the given fileinfo.
+    fn clone(&self) -> Self {
+        ffi::qfileinfo_clone(self)
+    }
+}
+
+impl Default for QFileInfo {
+    /// Constructs an empty QFileInfo object.
+    fn default() -> Self {
+        ffi::qfileinfo_init_default()
+    }
+}
+
+impl std::cmp::PartialEq for QFileInfo {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qfileinfo_eq(self, other)
+    }
+}
+
+impl std::cmp::Eq for QFileInfo {}
+
+impl fmt::Display for QFileInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.file_path())
+    }
+}
+
+impl fmt::Debug for QFileInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qfileinfo_to_qstring(self))
+    }
+}
+
+impl Drop for QFileInfo {
+    /// Destroys the QFileInfo and frees its resources.
+    fn drop(&mut self) {
+        ffi::qfileinfo_drop(self)
+    }
+}
+
+impl From<&ffi::QString> for QFileInfo {
+    /// Constructs a QFileInfo that gives information about the given file.
+    fn from(file: &ffi::QString) -> Self {
+        ffi::qfileinfo_init_from_qstring(file)
+    }
+}
+
+impl From<&Path> for QFileInfo {
+    /// Constructs a QFileInfo that gives information about the given path.
+    fn from(path: &Path) -> Self {
+        ffi::qfileinfo_init_from_qstring(&ffi::QString::from(path))
+    }
+}
+
+impl From<&QFileInfo> for PathBuf {
+    /// Converts the file path of the QFileInfo, which may be absolute or relative, into a [PathBuf].
+    fn from(info: &QFileInfo) -> Self {
+        PathBuf::from(&info.file_path())
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QFileInfo {
+    type Id = type_id!("QFileInfo");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,20 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qline.h"
+
+#include "../assertion_utils.h"
+
+// QLine has two QPoint members - pt1 and pt2
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/tools/qline.h?h=v5.15.6-lts-lgpl#n88
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/tools/qline.h?h=v6.2.4#n90
+assert_alignment_and_size(QLine,
+                          alignof(::std::int32_t),
+                          sizeof(::std::int32_t[4]));
+
+static_assert(::std::is_trivially_copyable<QLine>::value,
+              "QLine should be trivially copyable");
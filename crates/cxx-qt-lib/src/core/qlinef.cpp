@@ -0,0 +1,38 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qlinef.h"
+
+#include "../assertion_utils.h"
+
+// QLineF has two QPointF members - pt1 and pt2
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/tools/qline.h?h=v5.15.6-lts-lgpl#n285
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/tools/qline.h?h=v6.2.4#n277
+assert_alignment_and_size(QLineF, alignof(double), sizeof(double[4]));
+
+static_assert(::std::is_trivially_copyable<QLineF>::value,
+              "QLineF should be trivially copyable");
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QLineF
+qlinefFromPolar(double length, double angle)
+{
+  return QLineF::fromPolar(length, angle);
+}
+
+QLineFIntersectionType
+qlinefIntersects(const QLineF& line,
+                 const QLineF& other,
+                 QPointF& intersectionPoint)
+{
+  return line.intersects(other, &intersectionPoint);
+}
+
+}
+}
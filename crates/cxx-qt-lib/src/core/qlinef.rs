@@ -0,0 +1,252 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use std::fmt;
+
+#[cxx::bridge]
+mod ffi {
+    /// Describes the intersection between two lines.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    enum QLineFIntersectionType {
+        /// Indicates that the lines do not intersect; i.e. they are parallel.
+        NoIntersection,
+        /// The two lines intersect with each other within the start and end points of each line.
+        BoundedIntersection,
+        /// The two lines intersect, but not within the range defined by their lengths.
+        UnboundedIntersection,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qlinef.h");
+        type QLineF = super::QLineF;
+        include!("cxx-qt-lib/qline.h");
+        type QLine = crate::QLine;
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = crate::QPointF;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// Returns the angle of the line in degrees.
+        ///
+        /// The return value will be in the range of values from 0.0 up to but not including 360.0.
+        /// The angles are measured counter-clockwise from a point on the x-axis to the right of the origin (x > 0).
+        fn angle(self: &QLineF) -> f64;
+
+        /// Returns the angle (in degrees) from this line to the given line, taking the direction of the lines into account.
+        #[rust_name = "angle_to"]
+        fn angleTo(self: &QLineF, line: &QLineF) -> f64;
+
+        /// Returns the center point of this line. This is equivalent to 0.5 * p1() + 0.5 * p2().
+        fn center(self: &QLineF) -> QPointF;
+
+        /// Returns the horizontal component of the line's vector.
+        fn dx(self: &QLineF) -> f64;
+
+        /// Returns the vertical component of the line's vector.
+        fn dy(self: &QLineF) -> f64;
+
+        /// Returns true if the line does not have distinct start and end points; otherwise returns false.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QLineF) -> bool;
+
+        /// Returns the length of the line.
+        fn length(self: &QLineF) -> f64;
+
+        /// Returns a line that is perpendicular to this line with the same starting point and length.
+        #[rust_name = "normal_vector"]
+        fn normalVector(self: &QLineF) -> QLineF;
+
+        /// Returns the line's start point.
+        fn p1(self: &QLineF) -> QPointF;
+
+        /// Returns the line's end point.
+        fn p2(self: &QLineF) -> QPointF;
+
+        /// Returns the point at the position specified by finite parameter t.
+        /// The function returns the line's start point if t = 0, and its end point if t = 1.
+        #[rust_name = "point_at"]
+        fn pointAt(self: &QLineF, t: f64) -> QPointF;
+
+        /// Sets the angle of the line to the given angle (in degrees).
+        /// This will change the position of the second point of the line such that the line has the given angle.
+        #[rust_name = "set_angle"]
+        fn setAngle(self: &mut QLineF, angle: f64);
+
+        /// Sets the length of the line to the given finite length.
+        /// QLineF will move the end point - p2() - of the line to give the line its new length, unless length was previously zero,
+        /// in which case no scaling is attempted.
+        #[rust_name = "set_length"]
+        fn setLength(self: &mut QLineF, length: f64);
+
+        /// Sets this line to the start in x1, y1 and end in x2, y2.
+        #[rust_name = "set_line"]
+        fn setLine(self: &mut QLineF, x1: f64, y1: f64, x2: f64, y2: f64);
+
+        /// Sets the starting point of this line to p1.
+        #[rust_name = "set_p1"]
+        fn setP1(self: &mut QLineF, p1: &QPointF);
+
+        /// Sets the end point of this line to p2.
+        #[rust_name = "set_p2"]
+        fn setP2(self: &mut QLineF, p2: &QPointF);
+
+        /// Sets the start point of this line to p1 and the end point of this line to p2.
+        #[rust_name = "set_points"]
+        fn setPoints(self: &mut QLineF, p1: &QPointF, p2: &QPointF);
+
+        /// Returns an integer based copy of this line.
+        ///
+        /// Note that the returned line's start and end points are rounded to the nearest integer.
+        #[rust_name = "to_line"]
+        fn toLine(self: &QLineF) -> QLine;
+
+        /// Translates this line by the given offset.
+        fn translate(self: &mut QLineF, offset: &QPointF);
+
+        /// Returns this line translated by the given offset.
+        fn translated(self: &QLineF, offset: &QPointF) -> QLineF;
+
+        /// Returns the unit vector for this line, i.e a line starting at the same point as this line with a length of 1.0,
+        /// provided the line is non-null.
+        #[rust_name = "unit_vector"]
+        fn unitVector(self: &QLineF) -> QLineF;
+
+        /// Returns the x-coordinate of the line's start point.
+        fn x1(self: &QLineF) -> f64;
+
+        /// Returns the x-coordinate of the line's end point.
+        fn x2(self: &QLineF) -> f64;
+
+        /// Returns the y-coordinate of the line's start point.
+        fn y1(self: &QLineF) -> f64;
+
+        /// Returns the y-coordinate of the line's end point.
+        fn y2(self: &QLineF) -> f64;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qlinef_from_polar"]
+        fn qlinefFromPolar(length: f64, angle: f64) -> QLineF;
+        #[doc(hidden)]
+        #[rust_name = "qlinef_intersects"]
+        fn qlinefIntersects(
+            line: &QLineF,
+            other: &QLineF,
+            intersection_point: &mut QPointF,
+        ) -> QLineFIntersectionType;
+
+        #[doc(hidden)]
+        #[rust_name = "qlinef_init_default"]
+        fn construct() -> QLineF;
+        #[doc(hidden)]
+        #[rust_name = "qlinef_init"]
+        fn construct(p1: &QPointF, p2: &QPointF) -> QLineF;
+        #[doc(hidden)]
+        #[rust_name = "qlinef_init_from_coordinates"]
+        fn construct(x1: f64, y1: f64, x2: f64, y2: f64) -> QLineF;
+        #[doc(hidden)]
+        #[rust_name = "qlinef_from_qline"]
+        fn construct(line: &QLine) -> QLineF;
+        #[doc(hidden)]
+        #[rust_name = "qlinef_to_qstring"]
+        fn toQString(value: &QLineF) -> QString;
+    }
+}
+
+pub use ffi::QLineFIntersectionType;
+
+/// The QLineF class provides a two-dimensional vector using floating point precision.
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct QLineF {
+    pt1: ffi::QPointF,
+    pt2: ffi::QPointF,
+}
+
+impl QLineF {
+    /// Returns a QLineF with the given length and angle.
+    ///
+    /// The first point of the line will be on the origin.
+    pub fn from_polar(length: f64, angle: f64) -> Self {
+        ffi::qlinef_from_polar(length, angle)
+    }
+
+    /// Constructs a line object that represents the line between (x1, y1) and (x2, y2).
+    pub fn from_coordinates(x1: f64, y1: f64, x2: f64, y2: f64) -> Self {
+        ffi::qlinef_init_from_coordinates(x1, y1, x2, y2)
+    }
+
+    /// Returns a value indicating whether or not this line intersects with the given line,
+    /// and the intersection point if the lines are not parallel.
+    pub fn intersects(&self, line: &QLineF) -> (QLineFIntersectionType, ffi::QPointF) {
+        let mut point = ffi::QPointF::default();
+        let intersection_type = ffi::qlinef_intersects(self, line, &mut point);
+        (intersection_type, point)
+    }
+
+    /// Constructs a line object that represents the line between p1 and p2.
+    pub fn new(p1: &ffi::QPointF, p2: &ffi::QPointF) -> Self {
+        ffi::qlinef_init(p1, p2)
+    }
+}
+
+impl Default for QLineF {
+    /// Constructs a null line.
+    fn default() -> Self {
+        ffi::qlinef_init_default()
+    }
+}
+
+impl fmt::Display for QLineF {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qlinef_to_qstring(self))
+    }
+}
+
+impl From<&ffi::QLine> for QLineF {
+    /// Construct a QLineF object from the given integer-based line.
+    fn from(line: &ffi::QLine) -> Self {
+        ffi::qlinef_from_qline(line)
+    }
+}
+
+impl From<QLineF> for ffi::QLine {
+    /// Returns an integer based copy of this line, rounding the start and end points.
+    fn from(line: QLineF) -> Self {
+        line.to_line()
+    }
+}
+
+impl std::ops::Add<ffi::QPointF> for QLineF {
+    type Output = Self;
+
+    /// Returns this line translated by the given offset.
+    fn add(self, offset: ffi::QPointF) -> Self {
+        self.translated(&offset)
+    }
+}
+
+impl std::ops::Sub<ffi::QPointF> for QLineF {
+    type Output = Self;
+
+    /// Returns this line translated by the negative of the given offset.
+    fn sub(self, offset: ffi::QPointF) -> Self {
+        self.translated(&ffi::QPointF::new(-offset.x(), -offset.y()))
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side ensure that QLineF is trivial.
+unsafe impl ExternType for QLineF {
+    type Id = type_id!("QLineF");
+    type Kind = cxx::kind::Trivial;
+}
@@ -3,14 +3,14 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-#[cfg(feature = "qt_gui")]
-use crate::QColor;
 #[cfg(not(target_os = "emscripten"))]
 use crate::QDateTime;
 use crate::{
-    QByteArray, QDate, QMargins, QMarginsF, QPersistentModelIndex, QPoint, QPointF, QRect, QRectF,
-    QSize, QSizeF, QString, QTime, QUrl, QVariant,
+    QByteArray, QDate, QLine, QLineF, QMargins, QMarginsF, QPersistentModelIndex, QPoint, QPointF,
+    QRect, QRectF, QSize, QSizeF, QString, QTime, QUrl, QVariant,
 };
+#[cfg(feature = "qt_gui")]
+use crate::{QColor, QMatrix4x4, QPolygon, QPolygonF, QQuaternion, QTransform};
 use core::{marker::PhantomData, mem::MaybeUninit};
 use cxx::{type_id, ExternType};
 
@@ -355,8 +355,12 @@ impl_qlist_element!(QColor, qlist_qcolor, "QList_QColor");
 impl_qlist_element!(QDate, qlist_qdate, "QList_QDate");
 #[cfg(not(target_os = "emscripten"))]
 impl_qlist_element!(QDateTime, qlist_qdatetime, "QList_QDateTime");
+impl_qlist_element!(QLine, qlist_qline, "QList_QLine");
+impl_qlist_element!(QLineF, qlist_qlinef, "QList_QLineF");
 impl_qlist_element!(QMargins, qlist_qmargins, "QList_QMargins");
 impl_qlist_element!(QMarginsF, qlist_qmarginsf, "QList_QMarginsF");
+#[cfg(feature = "qt_gui")]
+impl_qlist_element!(QMatrix4x4, qlist_qmatrix4x4, "QList_QMatrix4x4");
 impl_qlist_element!(
     QPersistentModelIndex,
     qlist_qpersistentmodelindex,
@@ -364,12 +368,20 @@ impl_qlist_element!(
 );
 impl_qlist_element!(QPoint, qlist_qpoint, "QList_QPoint");
 impl_qlist_element!(QPointF, qlist_qpointf, "QList_QPointF");
+#[cfg(feature = "qt_gui")]
+impl_qlist_element!(QPolygon, qlist_qpolygon, "QList_QPolygon");
+#[cfg(feature = "qt_gui")]
+impl_qlist_element!(QPolygonF, qlist_qpolygonf, "QList_QPolygonF");
+#[cfg(feature = "qt_gui")]
+impl_qlist_element!(QQuaternion, qlist_qquaternion, "QList_QQuaternion");
 impl_qlist_element!(QRect, qlist_qrect, "QList_QRect");
 impl_qlist_element!(QRectF, qlist_qrectf, "QList_QRectF");
 impl_qlist_element!(QSize, qlist_qsize, "QList_QSize");
 impl_qlist_element!(QSizeF, qlist_qsizef, "QList_QSizeF");
 impl_qlist_element!(QString, qlist_qstring, "QList_QString");
 impl_qlist_element!(QTime, qlist_qtime, "QList_QTime");
+#[cfg(feature = "qt_gui")]
+impl_qlist_element!(QTransform, qlist_qtransform, "QList_QTransform");
 impl_qlist_element!(QUrl, qlist_qurl, "QList_QUrl");
 impl_qlist_element!(QVariant, qlist_qvariant, "QList_QVariant");
 impl_qlist_element!(u8, qlist_u8, "QList_u8");
@@ -43,17 +43,30 @@ CXX_QT_QLIST_ASSERTS(::QColor, QColor);
 #endif
 CXX_QT_QLIST_ASSERTS(::QDate, QDate);
 CXX_QT_QLIST_ASSERTS(::QDateTime, QDateTime);
+CXX_QT_QLIST_ASSERTS(::QLine, QLine);
+CXX_QT_QLIST_ASSERTS(::QLineF, QLineF);
 CXX_QT_QLIST_ASSERTS(::QMargins, QMargins);
 CXX_QT_QLIST_ASSERTS(::QMarginsF, QMarginsF);
+#ifdef CXX_QT_GUI_FEATURE
+CXX_QT_QLIST_ASSERTS(::QMatrix4x4, QMatrix4x4);
+#endif
 CXX_QT_QLIST_ASSERTS(::QPersistentModelIndex, QPersistentModelIndex);
 CXX_QT_QLIST_ASSERTS(::QPoint, QPoint);
 CXX_QT_QLIST_ASSERTS(::QPointF, QPointF);
+#ifdef CXX_QT_GUI_FEATURE
+CXX_QT_QLIST_ASSERTS(::QPolygon, QPolygon);
+CXX_QT_QLIST_ASSERTS(::QPolygonF, QPolygonF);
+CXX_QT_QLIST_ASSERTS(::QQuaternion, QQuaternion);
+#endif
 CXX_QT_QLIST_ASSERTS(::QRect, QRect);
 CXX_QT_QLIST_ASSERTS(::QRectF, QRectF);
 CXX_QT_QLIST_ASSERTS(::QSize, QSize);
 CXX_QT_QLIST_ASSERTS(::QSizeF, QSizeF);
 CXX_QT_QLIST_ASSERTS(::QString, QString);
 CXX_QT_QLIST_ASSERTS(::QTime, QTime);
+#ifdef CXX_QT_GUI_FEATURE
+CXX_QT_QLIST_ASSERTS(::QTransform, QTransform);
+#endif
 CXX_QT_QLIST_ASSERTS(::QUrl, QUrl);
 CXX_QT_QLIST_ASSERTS(::QVariant, QVariant);
 CXX_QT_QLIST_ASSERTS(::std::uint8_t, u8);
@@ -84,10 +97,18 @@ static const int register_QList_QDate =
   qRegisterMetaType<::QList_QDate>("QList_QDate");
 static const int register_QList_QDateTime =
   qRegisterMetaType<::QList_QDateTime>("QList_QDateTime");
+static const int register_QList_QLine =
+  qRegisterMetaType<::QList_QLine>("QList_QLine");
+static const int register_QList_QLineF =
+  qRegisterMetaType<::QList_QLineF>("QList_QLineF");
 static const int register_QList_QMargins =
   qRegisterMetaType<::QList_QMargins>("QList_QMargins");
 static const int register_QList_QMarginsF =
   qRegisterMetaType<::QList_QMarginsF>("QList_QMarginsF");
+#ifdef CXX_QT_GUI_FEATURE
+static const int register_QList_QMatrix4x4 =
+  qRegisterMetaType<::QList_QMatrix4x4>("QList_QMatrix4x4");
+#endif
 static const int register_QList_QPersistentModelIndex =
   qRegisterMetaType<::QList_QPersistentModelIndex>(
     "QList_QPersistentModelIndex");
@@ -95,6 +116,14 @@ static const int register_QList_QPoint =
   qRegisterMetaType<::QList_QPoint>("QList_QPoint");
 static const int register_QList_QPointF =
   qRegisterMetaType<::QList_QPointF>("QList_QPointF");
+#ifdef CXX_QT_GUI_FEATURE
+static const int register_QList_QPolygon =
+  qRegisterMetaType<::QList_QPolygon>("QList_QPolygon");
+static const int register_QList_QPolygonF =
+  qRegisterMetaType<::QList_QPolygonF>("QList_QPolygonF");
+static const int register_QList_QQuaternion =
+  qRegisterMetaType<::QList_QQuaternion>("QList_QQuaternion");
+#endif
 static const int register_QList_QRect =
   qRegisterMetaType<::QList_QRect>("QList_QRect");
 static const int register_QList_QRectF =
@@ -107,6 +136,10 @@ static const int register_QList_QString =
   qRegisterMetaType<::QList_QString>("QList_QString");
 static const int register_QList_QTime =
   qRegisterMetaType<::QList_QTime>("QList_QTime");
+#ifdef CXX_QT_GUI_FEATURE
+static const int register_QList_QTransform =
+  qRegisterMetaType<::QList_QTransform>("QList_QTransform");
+#endif
 static const int register_QList_QUrl =
   qRegisterMetaType<::QList_QUrl>("QList_QUrl");
 // Ensure that QList<QVariant> (aka QVariantList) is registered
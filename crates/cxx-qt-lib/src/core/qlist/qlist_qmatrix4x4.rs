@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmatrix4x4.h");
+        type QMatrix4x4 = crate::QMatrix4x4;
+
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QMatrix4x4 = crate::QList<QMatrix4x4>;
+    }
+
+    unsafe extern "C++" {
+        #[rust_name = "cxx_clear"]
+        fn clear(self: &mut QList_QMatrix4x4);
+        #[rust_name = "cxx_contains"]
+        fn contains(self: &QList_QMatrix4x4, _: &QMatrix4x4) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qlist_clone_QMatrix4x4"]
+        fn construct(_: &QList_QMatrix4x4) -> QList_QMatrix4x4;
+        #[rust_name = "qlist_default_QMatrix4x4"]
+        fn construct() -> QList_QMatrix4x4;
+        #[rust_name = "qlist_drop_QMatrix4x4"]
+        fn drop(_: &mut QList_QMatrix4x4);
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "reserve_QMatrix4x4"]
+        fn qlistReserve(_: &mut QList_QMatrix4x4, size: isize);
+        #[rust_name = "append_QMatrix4x4"]
+        fn qlistAppend(_: &mut QList_QMatrix4x4, _: &QMatrix4x4);
+        #[rust_name = "get_unchecked_QMatrix4x4"]
+        unsafe fn qlistGetUnchecked(set: &QList_QMatrix4x4, pos: isize) -> &QMatrix4x4;
+        #[rust_name = "index_of_QMatrix4x4"]
+        fn qlistIndexOf(_: &QList_QMatrix4x4, _: &QMatrix4x4) -> isize;
+        #[rust_name = "insert_QMatrix4x4"]
+        fn qlistInsert(_: &mut QList_QMatrix4x4, _: isize, _: &QMatrix4x4);
+        #[rust_name = "remove_QMatrix4x4"]
+        fn qlistRemove(_: &mut QList_QMatrix4x4, _: isize);
+        #[rust_name = "len_QMatrix4x4"]
+        fn qlistLen(_: &QList_QMatrix4x4) -> isize;
+    }
+}
+
+pub(crate) fn reserve(v: &mut ffi::QList_QMatrix4x4, size: isize) {
+    ffi::reserve_QMatrix4x4(v, size);
+}
+
+pub(crate) fn append(v: &mut ffi::QList_QMatrix4x4, value: &ffi::QMatrix4x4) {
+    ffi::append_QMatrix4x4(v, value);
+}
+
+pub(crate) fn clone(s: &ffi::QList_QMatrix4x4) -> ffi::QList_QMatrix4x4 {
+    ffi::qlist_clone_QMatrix4x4(s)
+}
+
+pub(crate) fn default() -> ffi::QList_QMatrix4x4 {
+    ffi::qlist_default_QMatrix4x4()
+}
+
+pub(crate) fn drop(s: &mut ffi::QList_QMatrix4x4) {
+    ffi::qlist_drop_QMatrix4x4(s);
+}
+
+pub(crate) unsafe fn get_unchecked(s: &ffi::QList_QMatrix4x4, pos: isize) -> &ffi::QMatrix4x4 {
+    ffi::get_unchecked_QMatrix4x4(s, pos)
+}
+
+pub(crate) fn index_of(v: &ffi::QList_QMatrix4x4, value: &ffi::QMatrix4x4) -> isize {
+    ffi::index_of_QMatrix4x4(v, value)
+}
+
+pub(crate) fn insert(s: &mut ffi::QList_QMatrix4x4, pos: isize, value: &ffi::QMatrix4x4) {
+    ffi::insert_QMatrix4x4(s, pos, value);
+}
+
+pub(crate) fn len(s: &ffi::QList_QMatrix4x4) -> isize {
+    ffi::len_QMatrix4x4(s)
+}
+
+pub(crate) fn remove(s: &mut ffi::QList_QMatrix4x4, pos: isize) {
+    ffi::remove_QMatrix4x4(s, pos);
+}
@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpolygon.h");
+        type QPolygon = crate::QPolygon;
+
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QPolygon = crate::QList<QPolygon>;
+    }
+
+    unsafe extern "C++" {
+        #[rust_name = "cxx_clear"]
+        fn clear(self: &mut QList_QPolygon);
+        #[rust_name = "cxx_contains"]
+        fn contains(self: &QList_QPolygon, _: &QPolygon) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qlist_clone_QPolygon"]
+        fn construct(_: &QList_QPolygon) -> QList_QPolygon;
+        #[rust_name = "qlist_default_QPolygon"]
+        fn construct() -> QList_QPolygon;
+        #[rust_name = "qlist_drop_QPolygon"]
+        fn drop(_: &mut QList_QPolygon);
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "reserve_QPolygon"]
+        fn qlistReserve(_: &mut QList_QPolygon, size: isize);
+        #[rust_name = "append_QPolygon"]
+        fn qlistAppend(_: &mut QList_QPolygon, _: &QPolygon);
+        #[rust_name = "get_unchecked_QPolygon"]
+        unsafe fn qlistGetUnchecked(set: &QList_QPolygon, pos: isize) -> &QPolygon;
+        #[rust_name = "index_of_QPolygon"]
+        fn qlistIndexOf(_: &QList_QPolygon, _: &QPolygon) -> isize;
+        #[rust_name = "insert_QPolygon"]
+        fn qlistInsert(_: &mut QList_QPolygon, _: isize, _: &QPolygon);
+        #[rust_name = "remove_QPolygon"]
+        fn qlistRemove(_: &mut QList_QPolygon, _: isize);
+        #[rust_name = "len_QPolygon"]
+        fn qlistLen(_: &QList_QPolygon) -> isize;
+    }
+}
+
+pub(crate) fn reserve(v: &mut ffi::QList_QPolygon, size: isize) {
+    ffi::reserve_QPolygon(v, size);
+}
+
+pub(crate) fn append(v: &mut ffi::QList_QPolygon, value: &ffi::QPolygon) {
+    ffi::append_QPolygon(v, value);
+}
+
+pub(crate) fn clone(s: &ffi::QList_QPolygon) -> ffi::QList_QPolygon {
+    ffi::qlist_clone_QPolygon(s)
+}
+
+pub(crate) fn default() -> ffi::QList_QPolygon {
+    ffi::qlist_default_QPolygon()
+}
+
+pub(crate) fn drop(s: &mut ffi::QList_QPolygon) {
+    ffi::qlist_drop_QPolygon(s);
+}
+
+pub(crate) unsafe fn get_unchecked(s: &ffi::QList_QPolygon, pos: isize) -> &ffi::QPolygon {
+    ffi::get_unchecked_QPolygon(s, pos)
+}
+
+pub(crate) fn index_of(v: &ffi::QList_QPolygon, value: &ffi::QPolygon) -> isize {
+    ffi::index_of_QPolygon(v, value)
+}
+
+pub(crate) fn insert(s: &mut ffi::QList_QPolygon, pos: isize, value: &ffi::QPolygon) {
+    ffi::insert_QPolygon(s, pos, value);
+}
+
+pub(crate) fn len(s: &ffi::QList_QPolygon) -> isize {
+    ffi::len_QPolygon(s)
+}
+
+pub(crate) fn remove(s: &mut ffi::QList_QPolygon, pos: isize) {
+    ffi::remove_QPolygon(s, pos);
+}
@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpolygonf.h");
+        type QPolygonF = crate::QPolygonF;
+
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QPolygonF = crate::QList<QPolygonF>;
+    }
+
+    unsafe extern "C++" {
+        #[rust_name = "cxx_clear"]
+        fn clear(self: &mut QList_QPolygonF);
+        #[rust_name = "cxx_contains"]
+        fn contains(self: &QList_QPolygonF, _: &QPolygonF) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qlist_clone_QPolygonF"]
+        fn construct(_: &QList_QPolygonF) -> QList_QPolygonF;
+        #[rust_name = "qlist_default_QPolygonF"]
+        fn construct() -> QList_QPolygonF;
+        #[rust_name = "qlist_drop_QPolygonF"]
+        fn drop(_: &mut QList_QPolygonF);
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "reserve_QPolygonF"]
+        fn qlistReserve(_: &mut QList_QPolygonF, size: isize);
+        #[rust_name = "append_QPolygonF"]
+        fn qlistAppend(_: &mut QList_QPolygonF, _: &QPolygonF);
+        #[rust_name = "get_unchecked_QPolygonF"]
+        unsafe fn qlistGetUnchecked(set: &QList_QPolygonF, pos: isize) -> &QPolygonF;
+        #[rust_name = "index_of_QPolygonF"]
+        fn qlistIndexOf(_: &QList_QPolygonF, _: &QPolygonF) -> isize;
+        #[rust_name = "insert_QPolygonF"]
+        fn qlistInsert(_: &mut QList_QPolygonF, _: isize, _: &QPolygonF);
+        #[rust_name = "remove_QPolygonF"]
+        fn qlistRemove(_: &mut QList_QPolygonF, _: isize);
+        #[rust_name = "len_QPolygonF"]
+        fn qlistLen(_: &QList_QPolygonF) -> isize;
+    }
+}
+
+pub(crate) fn reserve(v: &mut ffi::QList_QPolygonF, size: isize) {
+    ffi::reserve_QPolygonF(v, size);
+}
+
+pub(crate) fn append(v: &mut ffi::QList_QPolygonF, value: &ffi::QPolygonF) {
+    ffi::append_QPolygonF(v, value);
+}
+
+pub(crate) fn clone(s: &ffi::QList_QPolygonF) -> ffi::QList_QPolygonF {
+    ffi::qlist_clone_QPolygonF(s)
+}
+
+pub(crate) fn default() -> ffi::QList_QPolygonF {
+    ffi::qlist_default_QPolygonF()
+}
+
+pub(crate) fn drop(s: &mut ffi::QList_QPolygonF) {
+    ffi::qlist_drop_QPolygonF(s);
+}
+
+pub(crate) unsafe fn get_unchecked(s: &ffi::QList_QPolygonF, pos: isize) -> &ffi::QPolygonF {
+    ffi::get_unchecked_QPolygonF(s, pos)
+}
+
+pub(crate) fn index_of(v: &ffi::QList_QPolygonF, value: &ffi::QPolygonF) -> isize {
+    ffi::index_of_QPolygonF(v, value)
+}
+
+pub(crate) fn insert(s: &mut ffi::QList_QPolygonF, pos: isize, value: &ffi::QPolygonF) {
+    ffi::insert_QPolygonF(s, pos, value);
+}
+
+pub(crate) fn len(s: &ffi::QList_QPolygonF) -> isize {
+    ffi::len_QPolygonF(s)
+}
+
+pub(crate) fn remove(s: &mut ffi::QList_QPolygonF, pos: isize) {
+    ffi::remove_QPolygonF(s, pos);
+}
@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qquaternion.h");
+        type QQuaternion = crate::QQuaternion;
+
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QQuaternion = crate::QList<QQuaternion>;
+    }
+
+    unsafe extern "C++" {
+        #[rust_name = "cxx_clear"]
+        fn clear(self: &mut QList_QQuaternion);
+        #[rust_name = "cxx_contains"]
+        fn contains(self: &QList_QQuaternion, _: &QQuaternion) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qlist_clone_QQuaternion"]
+        fn construct(_: &QList_QQuaternion) -> QList_QQuaternion;
+        #[rust_name = "qlist_default_QQuaternion"]
+        fn construct() -> QList_QQuaternion;
+        #[rust_name = "qlist_drop_QQuaternion"]
+        fn drop(_: &mut QList_QQuaternion);
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "reserve_QQuaternion"]
+        fn qlistReserve(_: &mut QList_QQuaternion, size: isize);
+        #[rust_name = "append_QQuaternion"]
+        fn qlistAppend(_: &mut QList_QQuaternion, _: &QQuaternion);
+        #[rust_name = "get_unchecked_QQuaternion"]
+        unsafe fn qlistGetUnchecked(set: &QList_QQuaternion, pos: isize) -> &QQuaternion;
+        #[rust_name = "index_of_QQuaternion"]
+        fn qlistIndexOf(_: &QList_QQuaternion, _: &QQuaternion) -> isize;
+        #[rust_name = "insert_QQuaternion"]
+        fn qlistInsert(_: &mut QList_QQuaternion, _: isize, _: &QQuaternion);
+        #[rust_name = "remove_QQuaternion"]
+        fn qlistRemove(_: &mut QList_QQuaternion, _: isize);
+        #[rust_name = "len_QQuaternion"]
+        fn qlistLen(_: &QList_QQuaternion) -> isize;
+    }
+}
+
+pub(crate) fn reserve(v: &mut ffi::QList_QQuaternion, size: isize) {
+    ffi::reserve_QQuaternion(v, size);
+}
+
+pub(crate) fn append(v: &mut ffi::QList_QQuaternion, value: &ffi::QQuaternion) {
+    ffi::append_QQuaternion(v, value);
+}
+
+pub(crate) fn clone(s: &ffi::QList_QQuaternion) -> ffi::QList_QQuaternion {
+    ffi::qlist_clone_QQuaternion(s)
+}
+
+pub(crate) fn default() -> ffi::QList_QQuaternion {
+    ffi::qlist_default_QQuaternion()
+}
+
+pub(crate) fn drop(s: &mut ffi::QList_QQuaternion) {
+    ffi::qlist_drop_QQuaternion(s);
+}
+
+pub(crate) unsafe fn get_unchecked(s: &ffi::QList_QQuaternion, pos: isize) -> &ffi::QQuaternion {
+    ffi::get_unchecked_QQuaternion(s, pos)
+}
+
+pub(crate) fn index_of(v: &ffi::QList_QQuaternion, value: &ffi::QQuaternion) -> isize {
+    ffi::index_of_QQuaternion(v, value)
+}
+
+pub(crate) fn insert(s: &mut ffi::QList_QQuaternion, pos: isize, value: &ffi::QQuaternion) {
+    ffi::insert_QQuaternion(s, pos, value);
+}
+
+pub(crate) fn len(s: &ffi::QList_QQuaternion) -> isize {
+    ffi::len_QQuaternion(s)
+}
+
+pub(crate) fn remove(s: &mut ffi::QList_QQuaternion, pos: isize) {
+    ffi::remove_QQuaternion(s, pos);
+}
@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qtransform.h");
+        type QTransform = crate::QTransform;
+
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QTransform = crate::QList<QTransform>;
+    }
+
+    unsafe extern "C++" {
+        #[rust_name = "cxx_clear"]
+        fn clear(self: &mut QList_QTransform);
+        #[rust_name = "cxx_contains"]
+        fn contains(self: &QList_QTransform, _: &QTransform) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qlist_clone_QTransform"]
+        fn construct(_: &QList_QTransform) -> QList_QTransform;
+        #[rust_name = "qlist_default_QTransform"]
+        fn construct() -> QList_QTransform;
+        #[rust_name = "qlist_drop_QTransform"]
+        fn drop(_: &mut QList_QTransform);
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "reserve_QTransform"]
+        fn qlistReserve(_: &mut QList_QTransform, size: isize);
+        #[rust_name = "append_QTransform"]
+        fn qlistAppend(_: &mut QList_QTransform, _: &QTransform);
+        #[rust_name = "get_unchecked_QTransform"]
+        unsafe fn qlistGetUnchecked(set: &QList_QTransform, pos: isize) -> &QTransform;
+        #[rust_name = "index_of_QTransform"]
+        fn qlistIndexOf(_: &QList_QTransform, _: &QTransform) -> isize;
+        #[rust_name = "insert_QTransform"]
+        fn qlistInsert(_: &mut QList_QTransform, _: isize, _: &QTransform);
+        #[rust_name = "remove_QTransform"]
+        fn qlistRemove(_: &mut QList_QTransform, _: isize);
+        #[rust_name = "len_QTransform"]
+        fn qlistLen(_: &QList_QTransform) -> isize;
+    }
+}
+
+pub(crate) fn reserve(v: &mut ffi::QList_QTransform, size: isize) {
+    ffi::reserve_QTransform(v, size);
+}
+
+pub(crate) fn append(v: &mut ffi::QList_QTransform, value: &ffi::QTransform) {
+    ffi::append_QTransform(v, value);
+}
+
+pub(crate) fn clone(s: &ffi::QList_QTransform) -> ffi::QList_QTransform {
+    ffi::qlist_clone_QTransform(s)
+}
+
+pub(crate) fn default() -> ffi::QList_QTransform {
+    ffi::qlist_default_QTransform()
+}
+
+pub(crate) fn drop(s: &mut ffi::QList_QTransform) {
+    ffi::qlist_drop_QTransform(s);
+}
+
+pub(crate) unsafe fn get_unchecked(s: &ffi::QList_QTransform, pos: isize) -> &ffi::QTransform {
+    ffi::get_unchecked_QTransform(s, pos)
+}
+
+pub(crate) fn index_of(v: &ffi::QList_QTransform, value: &ffi::QTransform) -> isize {
+    ffi::index_of_QTransform(v, value)
+}
+
+pub(crate) fn insert(s: &mut ffi::QList_QTransform, pos: isize, value: &ffi::QTransform) {
+    ffi::insert_QTransform(s, pos, value);
+}
+
+pub(crate) fn len(s: &ffi::QList_QTransform) -> isize {
+    ffi::len_QTransform(s)
+}
+
+pub(crate) fn remove(s: &mut ffi::QList_QTransform, pos: isize) {
+    ffi::remove_QTransform(s, pos);
+}
@@ -0,0 +1,166 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qlocale.h"
+
+#include "../assertion_utils.h"
+
+// QLocale has a single QSharedDataPointer as its member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/text/qlocale.h?h=v5.15.6-lts-lgpl#n1137
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/text/qlocale.h?h=v6.2.4#n1122
+assert_alignment_and_size(QLocale,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t));
+
+static_assert(!::std::is_trivially_copy_assignable<QLocale>::value);
+static_assert(!::std::is_trivially_copy_constructible<QLocale>::value);
+
+static_assert(!::std::is_trivially_destructible<QLocale>::value);
+
+static_assert(QTypeInfo<QLocale>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QLocale
+qlocaleC()
+{
+  return QLocale::c();
+}
+
+QLocale
+qlocaleSystem()
+{
+  return QLocale::system();
+}
+
+void
+qlocaleSetDefault(const QLocale& locale)
+{
+  QLocale::setDefault(locale);
+}
+
+QString
+qlocaleDecimalPoint(const QLocale& locale)
+{
+  return QString(locale.decimalPoint());
+}
+
+QString
+qlocaleGroupSeparator(const QLocale& locale)
+{
+  return QString(locale.groupSeparator());
+}
+
+QString
+qlocaleNegativeSign(const QLocale& locale)
+{
+  return QString(locale.negativeSign());
+}
+
+QString
+qlocalePercent(const QLocale& locale)
+{
+  return QString(locale.percent());
+}
+
+QString
+qlocalePositiveSign(const QLocale& locale)
+{
+  return QString(locale.positiveSign());
+}
+
+double
+qlocaleToDouble(const QLocale& locale, const QString& s, bool& ok)
+{
+  return locale.toDouble(s, &ok);
+}
+
+::std::int32_t
+qlocaleToInt(const QLocale& locale, const QString& s, bool& ok)
+{
+  return static_cast<::std::int32_t>(locale.toInt(s, &ok));
+}
+
+::std::int64_t
+qlocaleToLongLong(const QLocale& locale, const QString& s, bool& ok)
+{
+  return static_cast<::std::int64_t>(locale.toLongLong(s, &ok));
+}
+
+QString
+qlocaleToCurrencyStringF64(const QLocale& locale,
+                           double value,
+                           const QString& symbol)
+{
+  return locale.toCurrencyString(value, symbol);
+}
+
+QString
+qlocaleToCurrencyStringI64(const QLocale& locale,
+                           ::std::int64_t value,
+                           const QString& symbol)
+{
+  return locale.toCurrencyString(static_cast<qlonglong>(value), symbol);
+}
+
+QString
+qlocaleToStringDate(const QLocale& locale,
+                    QDate date,
+                    QLocaleFormatType format)
+{
+  return locale.toString(date, format);
+}
+
+QString
+qlocaleToStringDateFormat(const QLocale& locale,
+                          QDate date,
+                          const QString& format)
+{
+  return locale.toString(date, format);
+}
+
+QString
+qlocaleToStringF64(const QLocale& locale,
+                   double value,
+                   ::std::uint8_t format,
+                   ::std::int32_t precision)
+{
+  return locale.toString(
+    value, static_cast<char>(format), static_cast<int>(precision));
+}
+
+QString
+qlocaleToStringI64(const QLocale& locale, ::std::int64_t value)
+{
+  return locale.toString(static_cast<qlonglong>(value));
+}
+
+QString
+qlocaleToStringTime(const QLocale& locale,
+                    QTime time,
+                    QLocaleFormatType format)
+{
+  return locale.toString(time, format);
+}
+
+QString
+qlocaleToStringTimeFormat(const QLocale& locale,
+                          QTime time,
+                          const QString& format)
+{
+  return locale.toString(time, format);
+}
+
+QString
+qlocaleToStringU64(const QLocale& locale, ::std::uint64_t value)
+{
+  return locale.toString(static_cast<qulonglong>(value));
+}
+
+}
+}
@@ -0,0 +1,436 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// Specifies the format of the currency symbol.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QLocaleCurrencySymbolFormat {
+        /// A ISO-4217 code of the currency.
+        CurrencyIsoCode,
+        /// A currency symbol.
+        CurrencySymbol,
+        /// A user readable name of the currency.
+        CurrencyDisplayName,
+    }
+
+    /// Specifies the format of a date or time when converting to a string.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QLocaleFormatType {
+        /// The long version of day and month names.
+        LongFormat,
+        /// The short version of day and month names.
+        ShortFormat,
+        /// A special version of day and month names for use when space is limited.
+        NarrowFormat,
+    }
+
+    /// Specifies the measurement system of a locale.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QLocaleMeasurementSystem {
+        /// This value indicates metric units, such as meters, centimeters and millimeters.
+        MetricSystem,
+        /// This value indicates imperial units, such as inches and miles as they are used in the United States.
+        ImperialUSSystem,
+        /// This value indicates imperial units, such as inches and miles as they are used in the United Kingdom.
+        ImperialUKSystem,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qlocale.h");
+        type QLocaleCurrencySymbolFormat;
+        type QLocaleFormatType;
+        type QLocaleMeasurementSystem;
+    }
+
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type DayOfWeek = crate::DayOfWeek;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qdate.h");
+        type QDate = crate::QDate;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+        include!("cxx-qt-lib/qtime.h");
+        type QTime = crate::QTime;
+
+        include!("cxx-qt-lib/qlocale.h");
+        type QLocale = super::QLocale;
+
+        /// Returns the localized name of the "AM" suffix for times specified using the conventions of the 12-hour clock.
+        #[rust_name = "am_text"]
+        fn amText(self: &QLocale) -> QString;
+
+        /// Returns the BCP47 field names joined with dashes.
+        ///
+        /// This combines as many of language, script and country (and possibly other BCP47 fields)
+        /// as are needed to uniquely specify the locale.
+        #[rust_name = "bcp47_name"]
+        fn bcp47Name(self: &QLocale) -> QString;
+
+        /// Returns a currency symbol according to the format.
+        #[rust_name = "currency_symbol"]
+        fn currencySymbol(self: &QLocale, format: QLocaleCurrencySymbolFormat) -> QString;
+
+        /// Returns the date format used for the current locale.
+        #[rust_name = "date_format"]
+        fn dateFormat(self: &QLocale, format: QLocaleFormatType) -> QString;
+
+        /// Returns the date time format used for the current locale.
+        #[rust_name = "date_time_format"]
+        fn dateTimeFormat(self: &QLocale, format: QLocaleFormatType) -> QString;
+
+        /// Returns the first day of the week according to the current locale.
+        #[rust_name = "first_day_of_week"]
+        fn firstDayOfWeek(self: &QLocale) -> DayOfWeek;
+
+        /// Returns the measurement system for the locale.
+        #[rust_name = "measurement_system"]
+        fn measurementSystem(self: &QLocale) -> QLocaleMeasurementSystem;
+
+        /// Returns the language and country of this locale as a string of the form "language_country",
+        /// where language is a lowercase, two-letter ISO 639 language code,
+        /// and country is an uppercase, two- or three-letter ISO 3166 country code.
+        fn name(self: &QLocale) -> QString;
+
+        /// Returns a native name of the language for the locale.
+        /// For example "Schweizer Hochdeutsch" for the Swiss-German locale.
+        #[rust_name = "native_language_name"]
+        fn nativeLanguageName(self: &QLocale) -> QString;
+
+        /// Returns the localized name of the "PM" suffix for times specified using the conventions of the 12-hour clock.
+        #[rust_name = "pm_text"]
+        fn pmText(self: &QLocale) -> QString;
+
+        /// Returns the time format used for the current locale.
+        #[rust_name = "time_format"]
+        fn timeFormat(self: &QLocale, format: QLocaleFormatType) -> QString;
+
+        /// Returns an ordered list of locale names for translation purposes in preference order (like "en-Latn-US", "en-US", "en").
+        #[rust_name = "ui_languages"]
+        fn uiLanguages(self: &QLocale) -> QStringList;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qlocale_c"]
+        fn qlocaleC() -> QLocale;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_system"]
+        fn qlocaleSystem() -> QLocale;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_set_default"]
+        fn qlocaleSetDefault(locale: &QLocale);
+
+        #[doc(hidden)]
+        #[rust_name = "qlocale_decimal_point"]
+        fn qlocaleDecimalPoint(locale: &QLocale) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_group_separator"]
+        fn qlocaleGroupSeparator(locale: &QLocale) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_negative_sign"]
+        fn qlocaleNegativeSign(locale: &QLocale) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_percent"]
+        fn qlocalePercent(locale: &QLocale) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_positive_sign"]
+        fn qlocalePositiveSign(locale: &QLocale) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_double"]
+        fn qlocaleToDouble(locale: &QLocale, s: &QString, ok: &mut bool) -> f64;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_int"]
+        fn qlocaleToInt(locale: &QLocale, s: &QString, ok: &mut bool) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_long_long"]
+        fn qlocaleToLongLong(locale: &QLocale, s: &QString, ok: &mut bool) -> i64;
+
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_currency_string_f64"]
+        fn qlocaleToCurrencyStringF64(locale: &QLocale, value: f64, symbol: &QString) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_currency_string_i64"]
+        fn qlocaleToCurrencyStringI64(locale: &QLocale, value: i64, symbol: &QString) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_string_date"]
+        fn qlocaleToStringDate(locale: &QLocale, date: QDate, format: QLocaleFormatType)
+            -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_string_date_format"]
+        fn qlocaleToStringDateFormat(locale: &QLocale, date: QDate, format: &QString) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_string_f64"]
+        fn qlocaleToStringF64(locale: &QLocale, value: f64, format: u8, precision: i32) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_string_i64"]
+        fn qlocaleToStringI64(locale: &QLocale, value: i64) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_string_time"]
+        fn qlocaleToStringTime(locale: &QLocale, time: QTime, format: QLocaleFormatType)
+            -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_string_time_format"]
+        fn qlocaleToStringTimeFormat(locale: &QLocale, time: QTime, format: &QString) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_string_u64"]
+        fn qlocaleToStringU64(locale: &QLocale, value: u64) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qlocale_drop"]
+        fn drop(locale: &mut QLocale);
+
+        #[doc(hidden)]
+        #[rust_name = "qlocale_init_default"]
+        fn construct() -> QLocale;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_init_from_qstring"]
+        fn construct(name: &QString) -> QLocale;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_clone"]
+        fn construct(locale: &QLocale) -> QLocale;
+
+        #[doc(hidden)]
+        #[rust_name = "qlocale_eq"]
+        fn operatorEq(a: &QLocale, b: &QLocale) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_qstring"]
+        fn toQString(value: &QLocale) -> QString;
+    }
+}
+
+pub use ffi::{QLocaleCurrencySymbolFormat, QLocaleFormatType, QLocaleMeasurementSystem};
+
+/// The QLocale class converts between numbers and their string representations in various languages.
+///
+/// This matches the locale used by `Qt.locale()` in QML, so values formatted in Rust
+/// are consistent with the values formatted in the UI.
+#[repr(C)]
+pub struct QLocale {
+    _space: MaybeUninit<usize>,
+}
+
+impl QLocale {
+    /// Returns a QLocale object initialized to the "C" locale.
+    ///
+    /// This locale is based on en_US but with various quirks of its own,
+    /// such as simplified number formatting and its own date formatting.
+    pub fn c() -> Self {
+        ffi::qlocale_c()
+    }
+
+    /// Returns the decimal point character of this locale.
+    pub fn decimal_point(&self) -> ffi::QString {
+        ffi::qlocale_decimal_point(self)
+    }
+
+    /// Returns the digit-grouping separator for this locale.
+    pub fn group_separator(&self) -> ffi::QString {
+        ffi::qlocale_group_separator(self)
+    }
+
+    /// Returns the negative sign indicator of this locale.
+    pub fn negative_sign(&self) -> ffi::QString {
+        ffi::qlocale_negative_sign(self)
+    }
+
+    /// Returns the percent marker of this locale.
+    pub fn percent(&self) -> ffi::QString {
+        ffi::qlocale_percent(self)
+    }
+
+    /// Returns the positive sign indicator of this locale.
+    pub fn positive_sign(&self) -> ffi::QString {
+        ffi::qlocale_positive_sign(self)
+    }
+
+    /// Sets the global default locale to locale.
+    ///
+    /// These values are used when a QLocale object is constructed with no arguments.
+    pub fn set_default(locale: &QLocale) {
+        ffi::qlocale_set_default(locale)
+    }
+
+    /// Returns a QLocale object initialized to the system locale.
+    pub fn system() -> Self {
+        ffi::qlocale_system()
+    }
+
+    /// Returns a localized string representation of value as a currency.
+    ///
+    /// If symbol is empty the currency symbol of this locale is used.
+    pub fn to_currency_string_f64(&self, value: f64, symbol: &ffi::QString) -> ffi::QString {
+        ffi::qlocale_to_currency_string_f64(self, value, symbol)
+    }
+
+    /// Returns a localized string representation of value as a currency.
+    ///
+    /// If symbol is empty the currency symbol of this locale is used.
+    pub fn to_currency_string_i64(&self, value: i64, symbol: &ffi::QString) -> ffi::QString {
+        ffi::qlocale_to_currency_string_i64(self, value, symbol)
+    }
+
+    /// Returns the double represented by the localized string s, or None if the conversion failed.
+    pub fn to_double(&self, s: &ffi::QString) -> Option<f64> {
+        let mut ok = false;
+        let value = ffi::qlocale_to_double(self, s, &mut ok);
+        if ok {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the int represented by the localized string s, or None if the conversion failed.
+    pub fn to_int(&self, s: &ffi::QString) -> Option<i32> {
+        let mut ok = false;
+        let value = ffi::qlocale_to_int(self, s, &mut ok);
+        if ok {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the long long int represented by the localized string s, or None if the conversion failed.
+    pub fn to_long_long(&self, s: &ffi::QString) -> Option<i64> {
+        let mut ok = false;
+        let value = ffi::qlocale_to_long_long(self, s, &mut ok);
+        if ok {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a localized string representation of the given date in the specified format.
+    pub fn to_string_date(&self, date: &ffi::QDate, format: QLocaleFormatType) -> ffi::QString {
+        ffi::qlocale_to_string_date(self, date.clone(), format)
+    }
+
+    /// Returns a localized string representation of the given date according to the format string.
+    pub fn to_string_date_format(&self, date: &ffi::QDate, format: &ffi::QString) -> ffi::QString {
+        ffi::qlocale_to_string_date_format(self, date.clone(), format)
+    }
+
+    /// Returns a string representing the floating-point number value.
+    ///
+    /// The format is one of the C printf format characters such as b'e', b'f' or b'g'
+    /// and precision is the number of digits after the decimal point (or the number of significant digits for b'g').
+    pub fn to_string_f64(&self, value: f64, format: u8, precision: i32) -> ffi::QString {
+        ffi::qlocale_to_string_f64(self, value, format, precision)
+    }
+
+    /// Returns a localized string representation of value.
+    pub fn to_string_i64(&self, value: i64) -> ffi::QString {
+        ffi::qlocale_to_string_i64(self, value)
+    }
+
+    /// Returns a localized string representation of the given time in the specified format.
+    pub fn to_string_time(&self, time: &ffi::QTime, format: QLocaleFormatType) -> ffi::QString {
+        ffi::qlocale_to_string_time(self, time.clone(), format)
+    }
+
+    /// Returns a localized string representation of the given time according to the format string.
+    pub fn to_string_time_format(&self, time: &ffi::QTime, format: &ffi::QString) -> ffi::QString {
+        ffi::qlocale_to_string_time_format(self, time.clone(), format)
+    }
+
+    /// Returns a localized string representation of value.
+    pub fn to_string_u64(&self, value: u64) -> ffi::QString {
+        ffi::qlocale_to_string_u64(self, value)
+    }
+}
+
+impl Clone for QLocale {
+    /// Constructs a QLocale object as a copy of other.
+    fn clone(&self) -> Self {
+        ffi::qlocale_clone(self)
+    }
+}
+
+impl Default for QLocale {
+    /// Constructs a QLocale object initialized with the default locale.
+    ///
+    /// If no default locale was set using set_default(), this locale will be the same as the one returned by system().
+    fn default() -> Self {
+        ffi::qlocale_init_default()
+    }
+}
+
+impl std::cmp::PartialEq for QLocale {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qlocale_eq(self, other)
+    }
+}
+
+impl std::cmp::Eq for QLocale {}
+
+impl fmt::Display for QLocale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.bcp47_name())
+    }
+}
+
+impl fmt::Debug for QLocale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qlocale_to_qstring(self))
+    }
+}
+
+impl Drop for QLocale {
+    /// Destructor
+    fn drop(&mut self) {
+        ffi::qlocale_drop(self)
+    }
+}
+
+impl From<&ffi::QString> for QLocale {
+    /// Constructs a QLocale object with the specified name.
+    ///
+    /// The name has the format "language[_script][_territory][.codeset][@modifier]" or "C",
+    /// BCP47 names such as "de-DE" are also accepted.
+    /// If the string violates the locale format, or language is not a valid ISO 639 code,
+    /// the "C" locale is used instead.
+    fn from(name: &ffi::QString) -> Self {
+        ffi::qlocale_init_from_qstring(name)
+    }
+}
+
+impl From<&str> for QLocale {
+    /// Constructs a QLocale object with the specified name from a Rust string.
+    fn from(name: &str) -> Self {
+        ffi::qlocale_init_from_qstring(&ffi::QString::from(name))
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QLocale {
+    type Id = type_id!("QLocale");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,90 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qloggingcategory.h"
+
+#include <set>
+#include <string>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QMutex>
+#include <QtCore/QMutexLocker>
+
+namespace {
+
+// QLoggingCategory only stores a pointer to the name, so the names are
+// interned for the lifetime of the program as categories are usually static.
+//
+// Note that the nodes of a std::set are stable so the c_str remains valid.
+const char*
+internCategoryName(::rust::Str name)
+{
+  static QMutex mutex;
+  static ::std::set<::std::string> names;
+
+  QMutexLocker locker(&mutex);
+  return names.emplace(name.data(), name.size()).first->c_str();
+}
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QString
+qloggingcategoryCategoryName(const QLoggingCategory& category)
+{
+  return QString::fromUtf8(category.categoryName());
+}
+
+void
+qloggingcategoryLog(const QLoggingCategory& category,
+                    QtMsgType type,
+                    ::rust::Str file,
+                    ::std::int32_t line,
+                    const QString& message)
+{
+  if (!category.isEnabled(type)) {
+    return;
+  }
+
+  const QByteArray fileName(file.data(), static_cast<int>(file.size()));
+  const QMessageLogger logger(fileName.constData(),
+                              static_cast<int>(line),
+                              nullptr,
+                              category.categoryName());
+  switch (type) {
+    case QtDebugMsg:
+      logger.debug("%s", qUtf8Printable(message));
+      break;
+    case QtInfoMsg:
+      logger.info("%s", qUtf8Printable(message));
+      break;
+    case QtWarningMsg:
+      logger.warning("%s", qUtf8Printable(message));
+      break;
+    case QtCriticalMsg:
+      logger.critical("%s", qUtf8Printable(message));
+      break;
+    case QtFatalMsg:
+      logger.fatal("%s", qUtf8Printable(message));
+  }
+}
+
+::std::unique_ptr<QLoggingCategory>
+qloggingcategoryNew(::rust::Str name)
+{
+  return ::std::make_unique<QLoggingCategory>(internCategoryName(name));
+}
+
+void
+qloggingcategorySetFilterRules(const QString& rules)
+{
+  QLoggingCategory::setFilterRules(rules);
+}
+
+}
+}
@@ -0,0 +1,143 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QString, QtMsgType};
+use core::panic::Location;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        include!("cxx-qt-lib/qloggingcategory.h");
+        /// The QLoggingCategory class represents a category, or 'area' in the logging infrastructure.
+        ///
+        /// Messages logged to a category from Rust can be filtered with the same rules
+        /// as categories used from C++ or a LoggingCategory in QML.
+        type QLoggingCategory;
+
+        /// Returns true if critical messages should be shown for this category; otherwise returns false.
+        #[rust_name = "is_critical_enabled"]
+        fn isCriticalEnabled(self: &QLoggingCategory) -> bool;
+
+        /// Returns true if debug messages should be shown for this category; otherwise returns false.
+        #[rust_name = "is_debug_enabled"]
+        fn isDebugEnabled(self: &QLoggingCategory) -> bool;
+
+        /// Returns true if a message of type msgtype for the category should be shown; otherwise returns false.
+        #[rust_name = "is_enabled"]
+        fn isEnabled(self: &QLoggingCategory, msg_type: QtMsgType) -> bool;
+
+        /// Returns true if informational messages should be shown for this category; otherwise returns false.
+        #[rust_name = "is_info_enabled"]
+        fn isInfoEnabled(self: &QLoggingCategory) -> bool;
+
+        /// Returns true if warning messages should be shown for this category; otherwise returns false.
+        #[rust_name = "is_warning_enabled"]
+        fn isWarningEnabled(self: &QLoggingCategory) -> bool;
+
+        /// Changes the message type type for the category to enable.
+        ///
+        /// Note that changes are overridden when the filter rules are changed.
+        #[rust_name = "set_enabled"]
+        fn setEnabled(self: Pin<&mut QLoggingCategory>, msg_type: QtMsgType, enable: bool);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qtlogging.h");
+        type QtMsgType = crate::QtMsgType;
+
+        #[doc(hidden)]
+        #[rust_name = "qloggingcategory_category_name"]
+        fn qloggingcategoryCategoryName(category: &QLoggingCategory) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qloggingcategory_log"]
+        fn qloggingcategoryLog(
+            category: &QLoggingCategory,
+            msg_type: QtMsgType,
+            file: &str,
+            line: i32,
+            message: &QString,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qloggingcategory_new"]
+        fn qloggingcategoryNew(name: &str) -> UniquePtr<QLoggingCategory>;
+        #[doc(hidden)]
+        #[rust_name = "qloggingcategory_set_filter_rules"]
+        fn qloggingcategorySetFilterRules(rules: &QString);
+    }
+
+    // QLoggingCategory is not copyable and only stores a pointer to its name,
+    // so we need to use references or pointers.
+    impl UniquePtr<QLoggingCategory> {}
+}
+
+pub use ffi::QLoggingCategory;
+
+impl QLoggingCategory {
+    /// Constructs a category with the given name, for example "kdab.app".
+    ///
+    /// Categories are usually created once and live for the duration of the program,
+    /// the name is kept alive for the duration of the program as Qt only stores a pointer to it.
+    pub fn new(name: &str) -> cxx::UniquePtr<Self> {
+        ffi::qloggingcategory_new(name)
+    }
+
+    /// Returns the name of the category.
+    pub fn category_name(&self) -> QString {
+        ffi::qloggingcategory_category_name(self)
+    }
+
+    /// Logs a critical message to the category, if critical messages are enabled.
+    #[track_caller]
+    pub fn critical(&self, message: &QString) {
+        self.log_at(QtMsgType::QtCriticalMsg, message, Location::caller());
+    }
+
+    /// Logs a debug message to the category, if debug messages are enabled.
+    #[track_caller]
+    pub fn debug(&self, message: &QString) {
+        self.log_at(QtMsgType::QtDebugMsg, message, Location::caller());
+    }
+
+    /// Logs an informational message to the category, if informational messages are enabled.
+    #[track_caller]
+    pub fn info(&self, message: &QString) {
+        self.log_at(QtMsgType::QtInfoMsg, message, Location::caller());
+    }
+
+    /// Logs a message of the given type to the category, if the type is enabled.
+    ///
+    /// The message is passed to the installed Qt message handler with the file and line of the caller.
+    /// Note that a message of type [QtMsgType::QtFatalMsg] aborts the program.
+    #[track_caller]
+    pub fn log(&self, msg_type: QtMsgType, message: &QString) {
+        self.log_at(msg_type, message, Location::caller());
+    }
+
+    /// Configures which categories and message types should be enabled through a set of rules,
+    /// for example "kdab.app.debug=false".
+    pub fn set_filter_rules(rules: &QString) {
+        ffi::qloggingcategory_set_filter_rules(rules);
+    }
+
+    /// Logs a warning message to the category, if warning messages are enabled.
+    #[track_caller]
+    pub fn warning(&self, message: &QString) {
+        self.log_at(QtMsgType::QtWarningMsg, message, Location::caller());
+    }
+
+    fn log_at(&self, msg_type: QtMsgType, message: &QString, location: &Location) {
+        ffi::qloggingcategory_log(
+            self,
+            msg_type,
+            location.file(),
+            i32::try_from(location.line()).unwrap_or(i32::MAX),
+            message,
+        );
+    }
+}
@@ -0,0 +1,55 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qobject.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QObject*
+qobjectChildAt(const QObject& object, ::rust::isize index)
+{
+  Q_ASSERT(index >= 0);
+  Q_ASSERT(index < static_cast<::rust::isize>(object.children().size()));
+  return object.children().at(static_cast<int>(index));
+}
+
+::rust::isize
+qobjectChildrenLen(const QObject& object)
+{
+  return static_cast<::rust::isize>(object.children().size());
+}
+
+QStringList
+qobjectDynamicPropertyNames(const QObject& object)
+{
+  QStringList names;
+  for (const auto& name : object.dynamicPropertyNames()) {
+    names.append(QString::fromUtf8(name));
+  }
+  return names;
+}
+
+QObject*
+qobjectFindChild(const QObject& object, const QString& name)
+{
+  return object.findChild<QObject*>(name);
+}
+
+QVariant
+qobjectProperty(const QObject& object, const QString& name)
+{
+  return object.property(name.toUtf8().constData());
+}
+
+bool
+qobjectSetProperty(QObject& object, const QString& name, const QVariant& value)
+{
+  return object.setProperty(name.toUtf8().constData(), value);
+}
+
+}
+}
@@ -0,0 +1,151 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QString, QStringList, QVariant};
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+        include!("cxx-qt-lib/qthread.h");
+        type QThread = crate::QThread;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        include!("cxx-qt-lib/qobject.h");
+        type QObject;
+
+        /// Schedules this object for deletion.
+        ///
+        /// The object will be deleted when control returns to the event loop.
+        #[rust_name = "delete_later"]
+        fn deleteLater(self: Pin<&mut QObject>);
+
+        /// Returns the name of this object.
+        #[rust_name = "object_name"]
+        fn objectName(self: &QObject) -> QString;
+
+        /// Returns a pointer to the parent object.
+        fn parent(self: &QObject) -> *mut QObject;
+
+        /// Sets the name of this object.
+        #[rust_name = "set_object_name"]
+        fn setObjectName(self: Pin<&mut QObject>, name: &QString);
+
+        #[doc(hidden)]
+        #[rust_name = "set_parent_raw"]
+        unsafe fn setParent(self: Pin<&mut QObject>, parent: *mut QObject);
+
+        /// Returns true if signals are blocked; otherwise returns false.
+        #[rust_name = "signals_blocked"]
+        fn signalsBlocked(self: &QObject) -> bool;
+
+        /// Returns the thread in which the object lives.
+        fn thread(self: &QObject) -> *mut QThread;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qobject_child_at"]
+        fn qobjectChildAt(object: &QObject, index: isize) -> *mut QObject;
+        #[doc(hidden)]
+        #[rust_name = "qobject_children_len"]
+        fn qobjectChildrenLen(object: &QObject) -> isize;
+        #[doc(hidden)]
+        #[rust_name = "qobject_dynamic_property_names"]
+        fn qobjectDynamicPropertyNames(object: &QObject) -> QStringList;
+        #[doc(hidden)]
+        #[rust_name = "qobject_find_child"]
+        fn qobjectFindChild(object: &QObject, name: &QString) -> *mut QObject;
+        #[doc(hidden)]
+        #[rust_name = "qobject_property"]
+        fn qobjectProperty(object: &QObject, name: &QString) -> QVariant;
+        #[doc(hidden)]
+        #[rust_name = "qobject_set_property"]
+        fn qobjectSetProperty(object: Pin<&mut QObject>, name: &QString, value: &QVariant) -> bool;
+    }
+}
+
+pub use ffi::QObject;
+
+/// This trait is automatically implemented for all types which are marked as `#[qobject]`,
+/// and for the QObject subclasses in this crate.
+/// It allows for safely casting the object to one of its base classes, eg [QObject].
+///
+/// ```ignore
+/// use cxx_qt_lib::Upcast;
+///
+/// let qobject: &cxx_qt_lib::QObject = my_object.upcast();
+/// println!("Object name: {}", qobject.object_name());
+/// ```
+pub trait Upcast<T> {
+    /// Cast a reference to this object into a reference of the base class
+    fn upcast(&self) -> &T;
+
+    /// Cast a pinned mutable reference to this object into a pinned mutable reference of the base class
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut T>;
+}
+
+impl Upcast<QObject> for QObject {
+    fn upcast(&self) -> &QObject {
+        self
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QObject> {
+        self
+    }
+}
+
+impl QObject {
+    /// Returns a list of child objects.
+    pub fn children(&self) -> Vec<*mut QObject> {
+        (0..ffi::qobject_children_len(self))
+            .map(|index| ffi::qobject_child_at(self, index))
+            .collect()
+    }
+
+    /// Returns the names of all properties that were dynamically added to the object using set_property().
+    pub fn dynamic_property_names(&self) -> QStringList {
+        ffi::qobject_dynamic_property_names(self)
+    }
+
+    /// Returns the child of this object that has the given object name, searching recursively.
+    ///
+    /// If there is no such object a null pointer is returned.
+    pub fn find_child(&self, name: &QString) -> *mut QObject {
+        ffi::qobject_find_child(self, name)
+    }
+
+    /// Returns the value of the object's name property.
+    ///
+    /// If no such property exists, the returned variant is invalid.
+    /// Both properties declared with Q_PROPERTY and dynamic properties are supported.
+    pub fn property(&self, name: &QString) -> QVariant {
+        ffi::qobject_property(self, name)
+    }
+
+    /// Makes the object a child of parent, a null pointer removes the parent.
+    ///
+    /// # Safety
+    ///
+    /// The parent must be a valid pointer to a QObject or a null pointer,
+    /// note that the parent takes ownership of this object.
+    pub unsafe fn set_parent(self: Pin<&mut Self>, parent: *mut QObject) {
+        self.set_parent_raw(parent);
+    }
+
+    /// Sets the value of the object's name property to value.
+    ///
+    /// If the property is defined in the class using Q_PROPERTY then true is returned on success and false otherwise.
+    /// If the property is not defined using Q_PROPERTY then it is added as a dynamic property and false is returned.
+    pub fn set_property(self: Pin<&mut Self>, name: &QString, value: &QVariant) -> bool {
+        ffi::qobject_set_property(self, name, value)
+    }
+}
@@ -0,0 +1,58 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qpointer.h"
+
+#include <QtCore/QThread>
+
+#include "../assertion_utils.h"
+
+// QPointer has one member which is a QWeakPointer, this has two pointers
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/kernel/qpointer.h?h=v5.15.6-lts-lgpl#n57
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/kernel/qpointer.h?h=v6.2.4#n57
+assert_alignment_and_size(::rust::cxxqtlib1::QPointer_QObject,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t) * 2);
+
+static_assert(!::std::is_trivially_copy_assignable<
+              ::rust::cxxqtlib1::QPointer_QObject>::value);
+static_assert(!::std::is_trivially_copy_constructible<
+              ::rust::cxxqtlib1::QPointer_QObject>::value);
+
+static_assert(!::std::is_trivially_destructible<
+              ::rust::cxxqtlib1::QPointer_QObject>::value);
+
+static_assert(QTypeInfo<::rust::cxxqtlib1::QPointer_QObject>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QPointer_QObject
+qpointerNew(QObject& object)
+{
+  return QPointer_QObject(&object);
+}
+
+bool
+qpointerIsNull(const QPointer_QObject& pointer)
+{
+  return pointer.isNull();
+}
+
+QObject*
+qpointerUpgrade(const QPointer_QObject& pointer)
+{
+  QObject* object = pointer.data();
+  // Only allow for access from the thread that the object lives in
+  if (object != nullptr && object->thread() != QThread::currentThread()) {
+    return nullptr;
+  }
+
+  return object;
+}
+
+}
+}
@@ -0,0 +1,185 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QObject, Upcast};
+use core::{marker::PhantomData, pin::Pin};
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpointer.h");
+        #[doc(hidden)]
+        type QPointer_QObject = super::QPointerQObject;
+
+        #[doc(hidden)]
+        #[rust_name = "qpointer_new"]
+        fn qpointerNew(object: Pin<&mut QObject>) -> QPointer_QObject;
+        #[doc(hidden)]
+        #[rust_name = "qpointer_is_null"]
+        fn qpointerIsNull(pointer: &QPointer_QObject) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qpointer_upgrade"]
+        fn qpointerUpgrade(pointer: &QPointer_QObject) -> *mut QObject;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qpointer_clone"]
+        fn construct(pointer: &QPointer_QObject) -> QPointer_QObject;
+        #[doc(hidden)]
+        #[rust_name = "qpointer_default"]
+        fn construct() -> QPointer_QObject;
+        #[doc(hidden)]
+        #[rust_name = "qpointer_drop"]
+        fn drop(pointer: &mut QPointer_QObject);
+    }
+}
+
+/// The C++ `QPointer<QObject>` which backs a [QPointer].
+#[doc(hidden)]
+#[repr(C)]
+pub struct QPointerQObject {
+    _space: MaybeUninit<[usize; 2]>,
+}
+
+impl Drop for QPointerQObject {
+    fn drop(&mut self) {
+        ffi::qpointer_drop(self);
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QPointerQObject {
+    type Id = type_id!("rust::cxxqtlib1::QPointer_QObject");
+    type Kind = cxx::kind::Trivial;
+}
+
+/// A guarded pointer to a QObject, which is similar to a weak reference.
+///
+/// The pointer is automatically cleared when the referenced object is destroyed,
+/// this works for any QObject from cxx-qt-lib and any `#[qobject]` generated by CXX-Qt.
+///
+/// Note that the object can only be accessed from the thread that it lives in,
+/// to modify the object from a background thread use the `CxxQtThread` of the object instead.
+pub struct QPointer<T>
+where
+    T: Upcast<QObject>,
+{
+    pointer: QPointerQObject,
+    object: *mut T,
+    _phantom: PhantomData<T>,
+}
+
+impl<T> QPointer<T>
+where
+    T: Upcast<QObject>,
+{
+    /// Constructs a guarded pointer that points to the given object.
+    pub fn new(mut object: Pin<&mut T>) -> Self {
+        // Safety: the pointer is only used as a mutable reference again
+        // when the QPointer reports that the object is still alive.
+        let ptr = unsafe { object.as_mut().get_unchecked_mut() as *mut T };
+        Self {
+            pointer: ffi::qpointer_new(object.upcast_pin()),
+            object: ptr,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Constructs a guarded pointer from a raw pointer, a null pointer creates a null guarded pointer.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be a null pointer or point to a valid object.
+    pub unsafe fn from_raw(object: *mut T) -> Self {
+        if let Some(object) = object.as_mut() {
+            Self::new(Pin::new_unchecked(object))
+        } else {
+            Self::default()
+        }
+    }
+
+    /// Returns true if the referenced object has been destroyed or if there is no referenced object;
+    /// otherwise returns false.
+    pub fn is_null(&self) -> bool {
+        ffi::qpointer_is_null(&self.pointer)
+    }
+
+    /// Returns a pinned mutable reference to the referenced object.
+    ///
+    /// If the object has been destroyed or this is called from a thread
+    /// other than the one that the object lives in [None] is returned.
+    ///
+    /// # Safety
+    ///
+    /// The QPointer only knows whether the object is alive at the time of the call,
+    /// so the caller must ensure that while the returned reference is live
+    ///
+    /// - no other reference to the object exists, eg from a clone of this QPointer,
+    ///   from the owner of the object or from a reference passed to the current function
+    /// - the object is not destroyed, eg by a nested event loop or a `deleteLater`
+    pub unsafe fn upgrade(&mut self) -> Option<Pin<&mut T>> {
+        if ffi::qpointer_upgrade(&self.pointer).is_null() {
+            return None;
+        }
+
+        // Safety: the object is still alive and we are on the thread that it lives in,
+        // the caller upholds that this is the only reference to the object
+        self.object
+            .as_mut()
+            .map(|object| Pin::new_unchecked(object))
+    }
+
+    /// Returns a raw pointer to the referenced object,
+    /// or a null pointer if the object has been destroyed.
+    pub fn as_ptr(&self) -> *mut T {
+        if self.is_null() {
+            std::ptr::null_mut()
+        } else {
+            self.object
+        }
+    }
+}
+
+impl<T> Clone for QPointer<T>
+where
+    T: Upcast<QObject>,
+{
+    /// Constructs a copy of the guarded pointer, which references the same object.
+    fn clone(&self) -> Self {
+        Self {
+            pointer: ffi::qpointer_clone(&self.pointer),
+            object: self.object,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for QPointer<T>
+where
+    T: Upcast<QObject>,
+{
+    /// Constructs a null guarded pointer.
+    fn default() -> Self {
+        Self {
+            pointer: ffi::qpointer_default(),
+            object: std::ptr::null_mut(),
+            _phantom: PhantomData,
+        }
+    }
+}
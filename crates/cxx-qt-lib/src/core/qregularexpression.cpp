@@ -0,0 +1,96 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qregularexpression.h"
+
+#include "../assertion_utils.h"
+
+// QRegularExpression has a single QExplicitlySharedDataPointer as its member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/text/qregularexpression.h?h=v5.15.6-lts-lgpl#n171
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/text/qregularexpression.h?h=v6.2.4#n183
+assert_alignment_and_size(QRegularExpression,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t));
+
+static_assert(!::std::is_trivially_copy_assignable<QRegularExpression>::value);
+static_assert(
+  !::std::is_trivially_copy_constructible<QRegularExpression>::value);
+
+static_assert(!::std::is_trivially_destructible<QRegularExpression>::value);
+
+static_assert(QTypeInfo<QRegularExpression>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QString
+qregularexpressionEscape(const QString& str)
+{
+  return QRegularExpression::escape(str);
+}
+
+QRegularExpressionMatchIterator
+qregularexpressionGlobalMatch(const QRegularExpression& re,
+                              const QString& subject,
+                              ::rust::isize offset,
+                              QRegularExpressionMatchType matchType)
+{
+  // Qt 5 has an int Qt 6 has a qsizetype
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return re.globalMatch(subject, static_cast<qsizetype>(offset), matchType);
+#else
+  return re.globalMatch(subject, static_cast<int>(offset), matchType);
+#endif
+}
+
+QRegularExpression
+qregularexpressionInitFromPattern(const QString& pattern,
+                                  QRegularExpressionPatternOption options)
+{
+  return QRegularExpression(pattern,
+                            QRegularExpression::PatternOptions(options));
+}
+
+QRegularExpressionMatch
+qregularexpressionMatch(const QRegularExpression& re,
+                        const QString& subject,
+                        ::rust::isize offset,
+                        QRegularExpressionMatchType matchType)
+{
+  // Qt 5 has an int Qt 6 has a qsizetype
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return re.match(subject, static_cast<qsizetype>(offset), matchType);
+#else
+  return re.match(subject, static_cast<int>(offset), matchType);
+#endif
+}
+
+::rust::isize
+qregularexpressionPatternErrorOffset(const QRegularExpression& re)
+{
+  // In Qt 5 the type was int now it is qsizetype, so we need to ensure the type
+  // is the same for CXX
+  return static_cast<::rust::isize>(re.patternErrorOffset());
+}
+
+QRegularExpressionPatternOption
+qregularexpressionPatternOptions(const QRegularExpression& re)
+{
+  // Bitwise flags are passed to Rust as the enum type with combined values
+  return static_cast<QRegularExpressionPatternOption>(
+    static_cast<int>(re.patternOptions()));
+}
+
+void
+qregularexpressionSetPatternOptions(QRegularExpression& re,
+                                    QRegularExpressionPatternOption options)
+{
+  re.setPatternOptions(QRegularExpression::PatternOptions(options));
+}
+
+}
+}
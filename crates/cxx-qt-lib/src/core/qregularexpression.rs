@@ -0,0 +1,335 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// The match type defines how a subject string is matched against a regular expression.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QRegularExpressionMatchType {
+        /// A normal match is done.
+        NormalMatch,
+        /// The pattern string is matched partially against the subject string.
+        /// If a partial match is found, then it is recorded, and other matching alternatives are tried as usual.
+        /// If a complete match is then found, then it's preferred to the partial match.
+        PartialPreferCompleteMatch,
+        /// The pattern string is matched partially against the subject string.
+        /// If a partial match is found, then matching stops, and the partial match is reported.
+        PartialPreferFirstMatch,
+        /// No matching is done. This value is returned as the match type by a default constructed match.
+        NoMatch,
+    }
+
+    /// The pattern option flags which change the way a pattern string is interpreted.
+    ///
+    /// Multiple options can be combined with the `|` operator.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QRegularExpressionPatternOption {
+        /// No pattern options are set.
+        NoPatternOption = 0x0000,
+        /// The pattern should match against the subject string in a case insensitive way.
+        CaseInsensitiveOption = 0x0001,
+        /// The dot metacharacter (.) in the pattern string is allowed to match any character in the subject string,
+        /// including newlines (normally, the dot does not match newlines).
+        DotMatchesEverythingOption = 0x0002,
+        /// The caret (^) and the dollar ($) metacharacters in the pattern string are allowed to match,
+        /// respectively, immediately after and immediately before any newline in the subject string,
+        /// as well as at the very beginning and at the very end of the subject string.
+        MultilineOption = 0x0004,
+        /// Any whitespace in the pattern string which is not escaped and outside a character class is ignored.
+        /// Moreover, an unescaped sharp (#) outside a character class causes all the following characters,
+        /// until the first newline (included), to be ignored.
+        ExtendedPatternSyntaxOption = 0x0008,
+        /// The greediness of the quantifiers is inverted: *, +, ?, {m,n}, etc. become lazy,
+        /// while their lazy versions (*?, +?, ??, {m,n}?, etc.) become greedy.
+        InvertedGreedinessOption = 0x0010,
+        /// The non-named capturing groups do not capture substrings; named capturing groups still work as intended.
+        DontCaptureOption = 0x0020,
+        /// The meaning of the \w, \d, etc., character classes, as well as the meaning of their counterparts (\W, \D, etc.),
+        /// is changed from matching ASCII characters only to matching any character with the corresponding Unicode property.
+        UseUnicodePropertiesOption = 0x0040,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qregularexpression.h");
+        type QRegularExpressionMatchType;
+        type QRegularExpressionPatternOption;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qregularexpressionmatch.h");
+        type QRegularExpressionMatch = crate::QRegularExpressionMatch;
+        include!("cxx-qt-lib/qregularexpressionmatchiterator.h");
+        type QRegularExpressionMatchIterator = crate::QRegularExpressionMatchIterator;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+
+        include!("cxx-qt-lib/qregularexpression.h");
+        type QRegularExpression = super::QRegularExpression;
+
+        /// Returns the number of capturing groups inside the pattern string, or -1 if the regular expression is not valid.
+        #[rust_name = "capture_count"]
+        fn captureCount(self: &QRegularExpression) -> i32;
+
+        /// Returns a textual description of the error found when checking the validity of the regular expression,
+        /// or "no error" if no error was found.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QRegularExpression) -> QString;
+
+        /// Returns true if the regular expression is a valid regular expression (that is, it contains no syntax errors, etc.),
+        /// or false otherwise. Use error_string() to obtain a textual description of the error.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QRegularExpression) -> bool;
+
+        /// Returns a list of capturing group names, one for each capturing group in the pattern string.
+        /// The list is sorted such that the element of the list at position i is the name of the i-th capturing group,
+        /// if it has a name, or an empty string if that capturing group is unnamed.
+        #[rust_name = "named_capture_groups"]
+        fn namedCaptureGroups(self: &QRegularExpression) -> QStringList;
+
+        /// Returns the pattern string of the regular expression.
+        fn pattern(self: &QRegularExpression) -> QString;
+
+        /// Sets the pattern string of the regular expression to pattern. The pattern options are left unchanged.
+        #[rust_name = "set_pattern"]
+        fn setPattern(self: &mut QRegularExpression, pattern: &QString);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qregularexpression_escape"]
+        fn qregularexpressionEscape(str: &QString) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qregularexpression_global_match"]
+        fn qregularexpressionGlobalMatch(
+            re: &QRegularExpression,
+            subject: &QString,
+            offset: isize,
+            match_type: QRegularExpressionMatchType,
+        ) -> QRegularExpressionMatchIterator;
+        #[doc(hidden)]
+        #[rust_name = "qregularexpression_init_from_pattern"]
+        fn qregularexpressionInitFromPattern(
+            pattern: &QString,
+            options: QRegularExpressionPatternOption,
+        ) -> QRegularExpression;
+        #[doc(hidden)]
+        #[rust_name = "qregularexpression_match"]
+        fn qregularexpressionMatch(
+            re: &QRegularExpression,
+            subject: &QString,
+            offset: isize,
+            match_type: QRegularExpressionMatchType,
+        ) -> QRegularExpressionMatch;
+        #[doc(hidden)]
+        #[rust_name = "qregularexpression_pattern_error_offset"]
+        fn qregularexpressionPatternErrorOffset(re: &QRegularExpression) -> isize;
+        #[doc(hidden)]
+        #[rust_name = "qregularexpression_pattern_options"]
+        fn qregularexpressionPatternOptions(
+            re: &QRegularExpression,
+        ) -> QRegularExpressionPatternOption;
+        #[doc(hidden)]
+        #[rust_name = "qregularexpression_set_pattern_options"]
+        fn qregularexpressionSetPatternOptions(
+            re: &mut QRegularExpression,
+            options: QRegularExpressionPatternOption,
+        );
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qregularexpression_drop"]
+        fn drop(re: &mut QRegularExpression);
+
+        #[doc(hidden)]
+        #[rust_name = "qregularexpression_init_default"]
+        fn construct() -> QRegularExpression;
+        #[doc(hidden)]
+        #[rust_name = "qregularexpression_init_from_qstring"]
+        fn construct(pattern: &QString) -> QRegularExpression;
+        #[doc(hidden)]
+        #[rust_name = "qregularexpression_clone"]
+        fn construct(re: &QRegularExpression) -> QRegularExpression;
+
+        #[doc(hidden)]
+        #[rust_name = "qregularexpression_eq"]
+        fn operatorEq(a: &QRegularExpression, b: &QRegularExpression) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qregularexpression_to_qstring"]
+        fn toQString(value: &QRegularExpression) -> QString;
+    }
+}
+
+pub use ffi::{QRegularExpressionMatchType, QRegularExpressionPatternOption};
+
+/// The QRegularExpression class provides pattern matching using regular expressions.
+///
+/// Note that the pattern syntax is Perl-compatible (PCRE2), matching the behaviour of
+/// `RegularExpressionValidator` in QML.
+#[repr(C)]
+pub struct QRegularExpression {
+    _space: MaybeUninit<usize>,
+}
+
+impl QRegularExpression {
+    /// Escapes all characters of str so that they no longer have any special meaning
+    /// when used as a regular expression pattern string, and returns the escaped string.
+    pub fn escape(str: &ffi::QString) -> ffi::QString {
+        ffi::qregularexpression_escape(str)
+    }
+
+    /// Attempts to perform a global match of the regular expression against the given subject string,
+    /// starting at the position offset inside the subject, using a match of type match_type.
+    ///
+    /// The returned iterator is positioned before the first match result (if any)
+    /// and implements [Iterator] over each [QRegularExpressionMatch](crate::QRegularExpressionMatch).
+    pub fn global_match(
+        &self,
+        subject: &ffi::QString,
+        offset: isize,
+        match_type: QRegularExpressionMatchType,
+    ) -> ffi::QRegularExpressionMatchIterator {
+        ffi::qregularexpression_global_match(self, subject, offset, match_type)
+    }
+
+    /// Attempts to match the regular expression against the given subject string,
+    /// starting at the position offset inside the subject, using a match of type match_type.
+    pub fn match_at(
+        &self,
+        subject: &ffi::QString,
+        offset: isize,
+        match_type: QRegularExpressionMatchType,
+    ) -> ffi::QRegularExpressionMatch {
+        ffi::qregularexpression_match(self, subject, offset, match_type)
+    }
+
+    /// Constructs a QRegularExpression object using the given pattern as pattern and the options as the pattern options.
+    pub fn new(pattern: &ffi::QString, options: QRegularExpressionPatternOption) -> Self {
+        ffi::qregularexpression_init_from_pattern(pattern, options)
+    }
+
+    /// Returns the offset, inside the pattern string, at which an error was found
+    /// when checking the validity of the regular expression.
+    ///
+    /// If no error was found, then None is returned.
+    pub fn pattern_error_offset(&self) -> Option<isize> {
+        let offset = ffi::qregularexpression_pattern_error_offset(self);
+        if offset < 0 {
+            None
+        } else {
+            Some(offset)
+        }
+    }
+
+    /// Returns the pattern options for the regular expression.
+    pub fn pattern_options(&self) -> QRegularExpressionPatternOption {
+        ffi::qregularexpression_pattern_options(self)
+    }
+
+    /// Sets the given options as the pattern options of the regular expression. The pattern string is left unchanged.
+    pub fn set_pattern_options(&mut self, options: QRegularExpressionPatternOption) {
+        ffi::qregularexpression_set_pattern_options(self, options)
+    }
+
+    /// Returns an error string if the regular expression is not valid, otherwise returns the regular expression.
+    ///
+    /// This is useful to surface validation errors, for example when the pattern comes from user input.
+    pub fn validate(self) -> Result<Self, ffi::QString> {
+        if self.is_valid() {
+            Ok(self)
+        } else {
+            Err(self.error_string())
+        }
+    }
+}
+
+impl Clone for QRegularExpression {
+    /// Constructs a QRegularExpression object as a copy of re.
+    fn clone(&self) -> Self {
+        ffi::qregularexpression_clone(self)
+    }
+}
+
+impl Default for QRegularExpression {
+    /// Constructs a QRegularExpression object with an empty pattern and no pattern options.
+    fn default() -> Self {
+        ffi::qregularexpression_init_default()
+    }
+}
+
+impl std::cmp::PartialEq for QRegularExpression {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qregularexpression_eq(self, other)
+    }
+}
+
+impl std::cmp::Eq for QRegularExpression {}
+
+impl fmt::Display for QRegularExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.pattern())
+    }
+}
+
+impl fmt::Debug for QRegularExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qregularexpression_to_qstring(self))
+    }
+}
+
+impl Drop for QRegularExpression {
+    /// Destroys the QRegularExpression object.
+    fn drop(&mut self) {
+        ffi::qregularexpression_drop(self)
+    }
+}
+
+impl From<&ffi::QString> for QRegularExpression {
+    /// Constructs a QRegularExpression object using the given pattern as pattern and no pattern options.
+    fn from(pattern: &ffi::QString) -> Self {
+        ffi::qregularexpression_init_from_qstring(pattern)
+    }
+}
+
+impl From<&str> for QRegularExpression {
+    /// Constructs a QRegularExpression object from a Rust string pattern and no pattern options.
+    ///
+    /// Note that this converts from UTF-8 to UTF-16
+    fn from(pattern: &str) -> Self {
+        ffi::qregularexpression_init_from_qstring(&ffi::QString::from(pattern))
+    }
+}
+
+impl std::ops::BitOr for QRegularExpressionPatternOption {
+    type Output = Self;
+
+    /// Combine two pattern options together
+    fn bitor(self, other: Self) -> Self {
+        Self {
+            repr: self.repr | other.repr,
+        }
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QRegularExpression {
+    type Id = type_id!("QRegularExpression");
+    type Kind = cxx::kind::Trivial;
+}
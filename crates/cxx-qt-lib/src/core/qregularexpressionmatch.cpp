@@ -0,0 +1,85 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qregularexpressionmatch.h"
+
+#include "../assertion_utils.h"
+
+// QRegularExpressionMatch has a single QSharedDataPointer as its member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/text/qregularexpression.h?h=v5.15.6-lts-lgpl#n249
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/text/qregularexpression.h?h=v6.2.4#n276
+assert_alignment_and_size(QRegularExpressionMatch,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t));
+
+static_assert(
+  !::std::is_trivially_copy_assignable<QRegularExpressionMatch>::value);
+static_assert(
+  !::std::is_trivially_copy_constructible<QRegularExpressionMatch>::value);
+
+static_assert(
+  !::std::is_trivially_destructible<QRegularExpressionMatch>::value);
+
+static_assert(QTypeInfo<QRegularExpressionMatch>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QString
+qregularexpressionmatchCapturedName(const QRegularExpressionMatch& match,
+                                    const QString& name)
+{
+  // CXX can't choose between captured overloads so use C++
+  return match.captured(name);
+}
+
+::rust::isize
+qregularexpressionmatchCapturedEnd(const QRegularExpressionMatch& match,
+                                   ::std::int32_t nth)
+{
+  return static_cast<::rust::isize>(match.capturedEnd(static_cast<int>(nth)));
+}
+
+::rust::isize
+qregularexpressionmatchCapturedEndName(const QRegularExpressionMatch& match,
+                                       const QString& name)
+{
+  return static_cast<::rust::isize>(match.capturedEnd(name));
+}
+
+::rust::isize
+qregularexpressionmatchCapturedLength(const QRegularExpressionMatch& match,
+                                      ::std::int32_t nth)
+{
+  return static_cast<::rust::isize>(
+    match.capturedLength(static_cast<int>(nth)));
+}
+
+::rust::isize
+qregularexpressionmatchCapturedLengthName(const QRegularExpressionMatch& match,
+                                          const QString& name)
+{
+  return static_cast<::rust::isize>(match.capturedLength(name));
+}
+
+::rust::isize
+qregularexpressionmatchCapturedStart(const QRegularExpressionMatch& match,
+                                     ::std::int32_t nth)
+{
+  return static_cast<::rust::isize>(
+    match.capturedStart(static_cast<int>(nth)));
+}
+
+::rust::isize
+qregularexpressionmatchCapturedStartName(const QRegularExpressionMatch& match,
+                                         const QString& name)
+{
+  return static_cast<::rust::isize>(match.capturedStart(name));
+}
+
+}
+}
@@ -0,0 +1,227 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qregularexpression.h");
+        type QRegularExpressionMatchType = crate::QRegularExpressionMatchType;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qregularexpression.h");
+        type QRegularExpression = crate::QRegularExpression;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+
+        include!("cxx-qt-lib/qregularexpressionmatch.h");
+        type QRegularExpressionMatch = super::QRegularExpressionMatch;
+
+        /// Returns the substring captured by the nth capturing group.
+        ///
+        /// If the nth capturing group did not capture a string, or if there is no such capturing group, returns a null QString.
+        ///
+        /// Note that the implicit capturing group number 0 captures the substring matched by the entire pattern.
+        fn captured(self: &QRegularExpressionMatch, nth: i32) -> QString;
+
+        /// Returns a list of all strings captured by capturing groups, in the order the groups themselves appear in the pattern string.
+        /// The list includes the implicit capturing group number 0, capturing the substring matched by the entire pattern.
+        #[rust_name = "captured_texts"]
+        fn capturedTexts(self: &QRegularExpressionMatch) -> QStringList;
+
+        /// Returns true if the regular expression matched against the subject string, or false otherwise.
+        #[rust_name = "has_match"]
+        fn hasMatch(self: &QRegularExpressionMatch) -> bool;
+
+        /// Returns true if the regular expression partially matched against the subject string, or false otherwise.
+        #[rust_name = "has_partial_match"]
+        fn hasPartialMatch(self: &QRegularExpressionMatch) -> bool;
+
+        /// Returns true if the match object was obtained as a result from the match_at() function
+        /// invoked on a valid QRegularExpression object; returns false if the QRegularExpression was invalid.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QRegularExpressionMatch) -> bool;
+
+        /// Returns the index of the last capturing group that captured something, including the implicit capturing group 0.
+        /// This can be used to extract all the substrings that were captured.
+        #[rust_name = "last_captured_index"]
+        fn lastCapturedIndex(self: &QRegularExpressionMatch) -> i32;
+
+        /// Returns the match type that was used to get this QRegularExpressionMatch object.
+        #[rust_name = "match_type"]
+        fn matchType(self: &QRegularExpressionMatch) -> QRegularExpressionMatchType;
+
+        /// Returns the QRegularExpression object whose match() function returned this object.
+        #[rust_name = "regular_expression"]
+        fn regularExpression(self: &QRegularExpressionMatch) -> QRegularExpression;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qregularexpressionmatch_captured_name"]
+        fn qregularexpressionmatchCapturedName(
+            match_: &QRegularExpressionMatch,
+            name: &QString,
+        ) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qregularexpressionmatch_captured_end"]
+        fn qregularexpressionmatchCapturedEnd(match_: &QRegularExpressionMatch, nth: i32) -> isize;
+        #[doc(hidden)]
+        #[rust_name = "qregularexpressionmatch_captured_end_name"]
+        fn qregularexpressionmatchCapturedEndName(
+            match_: &QRegularExpressionMatch,
+            name: &QString,
+        ) -> isize;
+        #[doc(hidden)]
+        #[rust_name = "qregularexpressionmatch_captured_length"]
+        fn qregularexpressionmatchCapturedLength(
+            match_: &QRegularExpressionMatch,
+            nth: i32,
+        ) -> isize;
+        #[doc(hidden)]
+        #[rust_name = "qregularexpressionmatch_captured_length_name"]
+        fn qregularexpressionmatchCapturedLengthName(
+            match_: &QRegularExpressionMatch,
+            name: &QString,
+        ) -> isize;
+        #[doc(hidden)]
+        #[rust_name = "qregularexpressionmatch_captured_start"]
+        fn qregularexpressionmatchCapturedStart(
+            match_: &QRegularExpressionMatch,
+            nth: i32,
+        ) -> isize;
+        #[doc(hidden)]
+        #[rust_name = "qregularexpressionmatch_captured_start_name"]
+        fn qregularexpressionmatchCapturedStartName(
+            match_: &QRegularExpressionMatch,
+            name: &QString,
+        ) -> isize;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qregularexpressionmatch_drop"]
+        fn drop(match_: &mut QRegularExpressionMatch);
+
+        #[doc(hidden)]
+        #[rust_name = "qregularexpressionmatch_init_default"]
+        fn construct() -> QRegularExpressionMatch;
+        #[doc(hidden)]
+        #[rust_name = "qregularexpressionmatch_clone"]
+        fn construct(match_: &QRegularExpressionMatch) -> QRegularExpressionMatch;
+
+        #[doc(hidden)]
+        #[rust_name = "qregularexpressionmatch_to_qstring"]
+        fn toQString(value: &QRegularExpressionMatch) -> QString;
+    }
+}
+
+/// The QRegularExpressionMatch class provides the results of a matching a QRegularExpression against a string.
+#[repr(C)]
+pub struct QRegularExpressionMatch {
+    _space: MaybeUninit<usize>,
+}
+
+impl QRegularExpressionMatch {
+    /// Returns the substring captured by the capturing group named name.
+    ///
+    /// If the named capturing group name did not capture a string, or if there is no capturing group named name, returns None.
+    pub fn captured_name(&self, name: &ffi::QString) -> Option<ffi::QString> {
+        let captured = ffi::qregularexpressionmatch_captured_name(self, name);
+        if captured.is_null() {
+            None
+        } else {
+            Some(captured)
+        }
+    }
+
+    /// Returns the offset inside the subject string immediately after the ending position
+    /// of the substring captured by the nth capturing group.
+    /// If the nth capturing group did not capture a string or doesn't exist, returns -1.
+    pub fn captured_end(&self, nth: i32) -> isize {
+        ffi::qregularexpressionmatch_captured_end(self, nth)
+    }
+
+    /// Returns the offset inside the subject string immediately after the ending position
+    /// of the substring captured by the capturing group named name.
+    /// If the capturing group named name did not capture a string or doesn't exist, returns -1.
+    pub fn captured_end_name(&self, name: &ffi::QString) -> isize {
+        ffi::qregularexpressionmatch_captured_end_name(self, name)
+    }
+
+    /// Returns the length of the substring captured by the nth capturing group.
+    ///
+    /// Note: This function returns 0 if the nth capturing group did not capture a string or doesn't exist.
+    pub fn captured_length(&self, nth: i32) -> isize {
+        ffi::qregularexpressionmatch_captured_length(self, nth)
+    }
+
+    /// Returns the length of the substring captured by the capturing group named name.
+    ///
+    /// Note: This function returns 0 if the capturing group named name did not capture a string or doesn't exist.
+    pub fn captured_length_name(&self, name: &ffi::QString) -> isize {
+        ffi::qregularexpressionmatch_captured_length_name(self, name)
+    }
+
+    /// Returns the start offset inside the subject string corresponding to the starting position
+    /// of the substring captured by the nth capturing group.
+    /// If the nth capturing group did not capture a string or doesn't exist, returns -1.
+    pub fn captured_start(&self, nth: i32) -> isize {
+        ffi::qregularexpressionmatch_captured_start(self, nth)
+    }
+
+    /// Returns the start offset inside the subject string corresponding to the starting position
+    /// of the substring captured by the capturing group named name.
+    /// If the capturing group named name did not capture a string or doesn't exist, returns -1.
+    pub fn captured_start_name(&self, name: &ffi::QString) -> isize {
+        ffi::qregularexpressionmatch_captured_start_name(self, name)
+    }
+}
+
+impl Clone for QRegularExpressionMatch {
+    /// Constructs a match result by copying the result of the given match.
+    fn clone(&self) -> Self {
+        ffi::qregularexpressionmatch_clone(self)
+    }
+}
+
+impl Default for QRegularExpressionMatch {
+    /// Constructs a valid, empty QRegularExpressionMatch object.
+    /// The regular expression is set to a default-constructed one; the match type to NoMatch.
+    fn default() -> Self {
+        ffi::qregularexpressionmatch_init_default()
+    }
+}
+
+impl fmt::Debug for QRegularExpressionMatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qregularexpressionmatch_to_qstring(self))
+    }
+}
+
+impl Drop for QRegularExpressionMatch {
+    /// Destroys the match result.
+    fn drop(&mut self) {
+        ffi::qregularexpressionmatch_drop(self)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QRegularExpressionMatch {
+    type Id = type_id!("QRegularExpressionMatch");
+    type Kind = cxx::kind::Trivial;
+}
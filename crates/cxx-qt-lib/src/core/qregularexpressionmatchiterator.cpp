@@ -0,0 +1,27 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qregularexpressionmatchiterator.h"
+
+#include "../assertion_utils.h"
+
+// QRegularExpressionMatchIterator has a single QSharedDataPointer as its member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/text/qregularexpression.h?h=v5.15.6-lts-lgpl#n301
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/text/qregularexpression.h?h=v6.2.4#n350
+assert_alignment_and_size(QRegularExpressionMatchIterator,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t));
+
+static_assert(!::std::is_trivially_copy_assignable<
+              QRegularExpressionMatchIterator>::value);
+static_assert(!::std::is_trivially_copy_constructible<
+              QRegularExpressionMatchIterator>::value);
+
+static_assert(
+  !::std::is_trivially_destructible<QRegularExpressionMatchIterator>::value);
+
+static_assert(QTypeInfo<QRegularExpressionMatchIterator>::isRelocatable);
@@ -0,0 +1,122 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qregularexpression.h");
+        type QRegularExpressionMatchType = crate::QRegularExpressionMatchType;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qregularexpression.h");
+        type QRegularExpression = crate::QRegularExpression;
+        include!("cxx-qt-lib/qregularexpressionmatch.h");
+        type QRegularExpressionMatch = crate::QRegularExpressionMatch;
+
+        include!("cxx-qt-lib/qregularexpressionmatchiterator.h");
+        type QRegularExpressionMatchIterator = super::QRegularExpressionMatchIterator;
+
+        /// Returns true if there is at least one match result ahead of the iterator; otherwise it returns false.
+        #[rust_name = "has_next"]
+        fn hasNext(self: &QRegularExpressionMatchIterator) -> bool;
+
+        /// Returns true if the iterator object was obtained as a result from the global_match() function
+        /// invoked on a valid QRegularExpression object; returns false if the QRegularExpression was invalid.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QRegularExpressionMatchIterator) -> bool;
+
+        /// Returns the match type that was used to get this iterator.
+        #[rust_name = "match_type"]
+        fn matchType(self: &QRegularExpressionMatchIterator) -> QRegularExpressionMatchType;
+
+        /// Returns the next match result and advances the iterator by one position.
+        ///
+        /// Note that calling this function when the iterator is at the end of the result set leads to undefined results.
+        #[rust_name = "next_match"]
+        fn next(self: &mut QRegularExpressionMatchIterator) -> QRegularExpressionMatch;
+
+        /// Returns the next match result without moving the iterator.
+        ///
+        /// Note that calling this function when the iterator is at the end of the result set leads to undefined results.
+        #[rust_name = "peek_next"]
+        fn peekNext(self: &QRegularExpressionMatchIterator) -> QRegularExpressionMatch;
+
+        /// Returns the QRegularExpression object whose global_match() function returned this object.
+        #[rust_name = "regular_expression"]
+        fn regularExpression(self: &QRegularExpressionMatchIterator) -> QRegularExpression;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qregularexpressionmatchiterator_drop"]
+        fn drop(iterator: &mut QRegularExpressionMatchIterator);
+
+        #[doc(hidden)]
+        #[rust_name = "qregularexpressionmatchiterator_init_default"]
+        fn construct() -> QRegularExpressionMatchIterator;
+        #[doc(hidden)]
+        #[rust_name = "qregularexpressionmatchiterator_clone"]
+        fn construct(iterator: &QRegularExpressionMatchIterator)
+            -> QRegularExpressionMatchIterator;
+    }
+}
+
+/// The QRegularExpressionMatchIterator class provides an iterator on the results of a global match
+/// of a QRegularExpression object against a string.
+///
+/// This implements [Iterator], so the results can be used in a `for` loop.
+#[repr(C)]
+pub struct QRegularExpressionMatchIterator {
+    _space: MaybeUninit<usize>,
+}
+
+impl Clone for QRegularExpressionMatchIterator {
+    /// Constructs a QRegularExpressionMatchIterator object as a copy of iterator.
+    fn clone(&self) -> Self {
+        ffi::qregularexpressionmatchiterator_clone(self)
+    }
+}
+
+impl Default for QRegularExpressionMatchIterator {
+    /// Constructs an empty, valid QRegularExpressionMatchIterator object.
+    /// The regular expression is set to a default-constructed one; the match type to NoMatch.
+    fn default() -> Self {
+        ffi::qregularexpressionmatchiterator_init_default()
+    }
+}
+
+impl Drop for QRegularExpressionMatchIterator {
+    /// Destroys the QRegularExpressionMatchIterator object.
+    fn drop(&mut self) {
+        ffi::qregularexpressionmatchiterator_drop(self)
+    }
+}
+
+impl Iterator for QRegularExpressionMatchIterator {
+    type Item = ffi::QRegularExpressionMatch;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.has_next() {
+            Some(self.next_match())
+        } else {
+            None
+        }
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QRegularExpressionMatchIterator {
+    type Id = type_id!("QRegularExpressionMatchIterator");
+    type Kind = cxx::kind::Trivial;
+}
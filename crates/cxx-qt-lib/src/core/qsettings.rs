@@ -0,0 +1,279 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QObject, QString, QVariant, Upcast};
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum type specifies the storage format used by QSettings.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    enum QSettingsFormat {
+        /// Store the settings using the most appropriate storage format for the platform.
+        NativeFormat = 0,
+        /// Store the settings in INI files.
+        IniFormat = 1,
+    }
+
+    /// This enum specifies whether settings are user-specific or shared by all users of the same system.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    enum QSettingsScope {
+        /// Store settings in a location specific to the current user.
+        UserScope,
+        /// Store settings in a global location, so that all users on the same machine access the same set of settings.
+        SystemScope,
+    }
+
+    /// The following status values are possible.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    enum QSettingsStatus {
+        /// No error occurred.
+        NoError,
+        /// An access error occurred (e.g. trying to write to a read-only file).
+        AccessError,
+        /// A format error occurred (e.g. loading a malformed INI file).
+        FormatError,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        include!("cxx-qt-lib/qsettings.h");
+        /// The QSettings class provides persistent platform-independent application settings.
+        type QSettings;
+
+        /// Returns a list of all keys, including subkeys, that can be read using the QSettings object.
+        #[rust_name = "all_keys"]
+        fn allKeys(self: &QSettings) -> QStringList;
+
+        /// Returns the application name used for storing the settings.
+        #[rust_name = "application_name"]
+        fn applicationName(self: &QSettings) -> QString;
+
+        /// Returns a list of all key top-level groups that contain keys that can be read using the QSettings object.
+        #[rust_name = "child_groups"]
+        fn childGroups(self: &QSettings) -> QStringList;
+
+        /// Returns a list of all top-level keys that can be read using the QSettings object.
+        #[rust_name = "child_keys"]
+        fn childKeys(self: &QSettings) -> QStringList;
+
+        /// Removes all entries in the primary location associated to this QSettings object.
+        fn clear(self: Pin<&mut QSettings>);
+
+        /// Closes the array that was started using begin_read_array() or begin_write_array().
+        #[rust_name = "end_array"]
+        fn endArray(self: Pin<&mut QSettings>);
+
+        /// Resets the group to what it was before the corresponding begin_group() call.
+        #[rust_name = "end_group"]
+        fn endGroup(self: Pin<&mut QSettings>);
+
+        /// Returns the path where settings written using this QSettings object are stored.
+        #[rust_name = "file_name"]
+        fn fileName(self: &QSettings) -> QString;
+
+        /// Returns the format used for storing the settings.
+        fn format(self: &QSettings) -> QSettingsFormat;
+
+        /// Returns the current group.
+        fn group(self: &QSettings) -> QString;
+
+        /// Returns true if settings can be written using this QSettings object; returns false otherwise.
+        #[rust_name = "is_writable"]
+        fn isWritable(self: &QSettings) -> bool;
+
+        /// Returns the organization name used for storing the settings.
+        #[rust_name = "organization_name"]
+        fn organizationName(self: &QSettings) -> QString;
+
+        /// Returns the scope used for storing the settings.
+        fn scope(self: &QSettings) -> QSettingsScope;
+
+        /// Sets the current array index to i.
+        ///
+        /// Calls to functions such as set_value(), value(), remove(), and contains()
+        /// will operate on the array entry at that index.
+        #[rust_name = "set_array_index"]
+        fn setArrayIndex(self: Pin<&mut QSettings>, i: i32);
+
+        /// Returns a status code indicating the first error that was met by QSettings,
+        /// or QSettingsStatus::NoError if no error occurred.
+        fn status(self: &QSettings) -> QSettingsStatus;
+
+        /// Writes any unsaved changes to permanent storage,
+        /// and reloads any settings that have been changed in the meantime by another application.
+        fn sync(self: Pin<&mut QSettings>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QSettingsFormat;
+        type QSettingsScope;
+        type QSettingsStatus;
+
+        #[doc(hidden)]
+        #[rust_name = "qsettings_begin_group"]
+        fn qsettingsBeginGroup(settings: Pin<&mut QSettings>, prefix: &QString);
+        #[doc(hidden)]
+        #[rust_name = "qsettings_begin_read_array"]
+        fn qsettingsBeginReadArray(settings: Pin<&mut QSettings>, prefix: &QString) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qsettings_begin_write_array"]
+        fn qsettingsBeginWriteArray(settings: Pin<&mut QSettings>, prefix: &QString, size: i32);
+        #[doc(hidden)]
+        #[rust_name = "qsettings_contains"]
+        fn qsettingsContains(settings: &QSettings, key: &QString) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qsettings_new"]
+        fn qsettingsNew(organization: &QString, application: &QString) -> UniquePtr<QSettings>;
+        #[doc(hidden)]
+        #[rust_name = "qsettings_new_from_file"]
+        fn qsettingsNewFromFile(
+            file_name: &QString,
+            format: QSettingsFormat,
+        ) -> UniquePtr<QSettings>;
+        #[doc(hidden)]
+        #[rust_name = "qsettings_new_with_format"]
+        fn qsettingsNewWithFormat(
+            format: QSettingsFormat,
+            scope: QSettingsScope,
+            organization: &QString,
+            application: &QString,
+        ) -> UniquePtr<QSettings>;
+        #[doc(hidden)]
+        #[rust_name = "qsettings_remove"]
+        fn qsettingsRemove(settings: Pin<&mut QSettings>, key: &QString);
+        #[doc(hidden)]
+        #[rust_name = "qsettings_set_value"]
+        fn qsettingsSetValue(settings: Pin<&mut QSettings>, key: &QString, value: &QVariant);
+        #[doc(hidden)]
+        #[rust_name = "qsettings_value"]
+        fn qsettingsValue(settings: &QSettings, key: &QString) -> QVariant;
+        #[doc(hidden)]
+        #[rust_name = "qsettings_value_or"]
+        fn qsettingsValueOr(
+            settings: &QSettings,
+            key: &QString,
+            default_value: &QVariant,
+        ) -> QVariant;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsettings_upcast"]
+        fn upcastQObject(object: &QSettings) -> &QObject;
+        #[doc(hidden)]
+        #[rust_name = "qsettings_upcast_mut"]
+        fn upcastQObjectMut(object: Pin<&mut QSettings>) -> Pin<&mut QObject>;
+    }
+
+    // QSettings is a QObject so it is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QSettings>::isRelocatable);
+    impl UniquePtr<QSettings> {}
+}
+
+pub use ffi::{QSettings, QSettingsFormat, QSettingsScope, QSettingsStatus};
+
+impl QSettings {
+    /// Constructs a QSettings object for accessing settings of the application called application
+    /// from the organization called organization, using the native format in the user scope.
+    pub fn new(organization: &QString, application: &QString) -> cxx::UniquePtr<Self> {
+        ffi::qsettings_new(organization, application)
+    }
+
+    /// Constructs a QSettings object for accessing the settings stored in the file called file_name.
+    ///
+    /// If the file doesn't already exist, it is created when the settings are written.
+    /// Using QSettingsFormat::IniFormat allows for the same file to be used on all platforms.
+    pub fn from_file(file_name: &QString, format: QSettingsFormat) -> cxx::UniquePtr<Self> {
+        ffi::qsettings_new_from_file(file_name, format)
+    }
+
+    /// Constructs a QSettings object for accessing settings of the application called application
+    /// from the organization called organization, using the given format and scope.
+    pub fn new_with_format(
+        format: QSettingsFormat,
+        scope: QSettingsScope,
+        organization: &QString,
+        application: &QString,
+    ) -> cxx::UniquePtr<Self> {
+        ffi::qsettings_new_with_format(format, scope, organization, application)
+    }
+
+    /// Appends prefix to the current group, until end_group() is called.
+    pub fn begin_group(self: Pin<&mut Self>, prefix: &QString) {
+        ffi::qsettings_begin_group(self, prefix);
+    }
+
+    /// Adds prefix to the current group and starts reading from an array. Returns the size of the array.
+    ///
+    /// Use set_array_index() to select an entry and end_array() once finished.
+    pub fn begin_read_array(self: Pin<&mut Self>, prefix: &QString) -> i32 {
+        ffi::qsettings_begin_read_array(self, prefix)
+    }
+
+    /// Adds prefix to the current group and starts writing an array of size size.
+    ///
+    /// If size is -1 the size is determined from the indexes of the entries written.
+    pub fn begin_write_array(self: Pin<&mut Self>, prefix: &QString, size: i32) {
+        ffi::qsettings_begin_write_array(self, prefix, size);
+    }
+
+    /// Returns true if there exists a setting called key; returns false otherwise.
+    ///
+    /// The key is relative to the current group.
+    pub fn contains(&self, key: &QString) -> bool {
+        ffi::qsettings_contains(self, key)
+    }
+
+    /// Removes the setting key and any sub-settings of key.
+    ///
+    /// If key is empty all keys in the current group are removed.
+    pub fn remove(self: Pin<&mut Self>, key: &QString) {
+        ffi::qsettings_remove(self, key);
+    }
+
+    /// Sets the value of setting key to value. If the key already exists, the previous value is overwritten.
+    pub fn set_value(self: Pin<&mut Self>, key: &QString, value: &QVariant) {
+        ffi::qsettings_set_value(self, key, value);
+    }
+
+    /// Returns the value for setting key. If the setting doesn't exist, returns an invalid QVariant.
+    ///
+    /// Note that the INI format does not store the type of values,
+    /// so use [QVariant::value] to convert the value to the type that is expected.
+    pub fn value(&self, key: &QString) -> QVariant {
+        ffi::qsettings_value(self, key)
+    }
+
+    /// Returns the value for setting key. If the setting doesn't exist, returns default_value.
+    pub fn value_or(&self, key: &QString, default_value: &QVariant) -> QVariant {
+        ffi::qsettings_value_or(self, key, default_value)
+    }
+}
+
+impl Upcast<QObject> for QSettings {
+    fn upcast(&self) -> &QObject {
+        ffi::qsettings_upcast(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QObject> {
+        ffi::qsettings_upcast_mut(self)
+    }
+}
@@ -0,0 +1,630 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Maps Rust types which implement serde's Serialize and Deserialize onto a group of a QSettings.
+//!
+//! Structs are stored as groups with a key per field, sequences as QSettings arrays
+//! and primitives as values. Primitives inside a sequence are stored with the key "value".
+
+use crate::{QSettings, QString, QStringList, QVariant, QVariantValue};
+use core::pin::Pin;
+use serde::{
+    de::{self, IntoDeserializer},
+    ser, Deserialize, Serialize,
+};
+use std::fmt::Display;
+
+/// The key used to store primitives which are elements of a sequence.
+const ELEMENT_KEY: &str = "value";
+
+/// An error which occurred while serializing to or deserializing from a QSettings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QSettingsSerdeError(String);
+
+impl Display for QSettingsSerdeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for QSettingsSerdeError {}
+
+impl ser::Error for QSettingsSerdeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+impl de::Error for QSettingsSerdeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+type Result<T> = std::result::Result<T, QSettingsSerdeError>;
+
+impl QSettings {
+    /// Writes the value into the given group, replacing any existing settings in the group.
+    ///
+    /// Structs are stored as groups with a key per field, sequences as QSettings arrays,
+    /// and `None` values remove the key. Maps and enums with data are not supported.
+    pub fn serialize_group<T>(mut self: Pin<&mut Self>, group: &QString, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.as_mut().remove(group);
+        value.serialize(Serializer {
+            settings: &mut self,
+            key: Some(group.clone()),
+        })
+    }
+
+    /// Reads a value from the given group, as written by [QSettings::serialize_group].
+    ///
+    /// Missing fields of type `Option` are read as `None`, other missing fields are an error
+    /// unless they have a serde default.
+    pub fn deserialize_group<T>(mut self: Pin<&mut Self>, group: &QString) -> Result<T>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        T::deserialize(Deserializer {
+            settings: &mut self,
+            key: Some(group.clone()),
+        })
+    }
+}
+
+fn contains_in(list: &QStringList, key: &QString) -> bool {
+    list.contains(key, crate::CaseSensitivity::CaseSensitive)
+}
+
+/// Returns if the key exists as a value or a group in the current group
+fn settings_has_key(settings: &QSettings, key: &QString) -> bool {
+    settings.contains(key) || contains_in(&settings.child_groups(), key)
+}
+
+/// Serializes a value to the given key of the current group,
+/// a key of None means that the value is an element of an array.
+struct Serializer<'a, 'b> {
+    settings: &'a mut Pin<&'b mut QSettings>,
+    key: Option<QString>,
+}
+
+impl<'a, 'b> Serializer<'a, 'b> {
+    fn set_value<T: QVariantValue>(self, value: &T) -> Result<()> {
+        let key = self.key.unwrap_or_else(|| QString::from(ELEMENT_KEY));
+        self.settings
+            .as_mut()
+            .set_value(&key, &QVariant::from(value));
+        Ok(())
+    }
+}
+
+impl<'a, 'b> ser::Serializer for Serializer<'a, 'b> {
+    type Ok = ();
+    type Error = QSettingsSerdeError;
+
+    type SerializeSeq = SeqSerializer<'a, 'b>;
+    type SerializeTuple = SeqSerializer<'a, 'b>;
+    type SerializeTupleStruct = SeqSerializer<'a, 'b>;
+    type SerializeTupleVariant = ser::Impossible<(), QSettingsSerdeError>;
+    type SerializeMap = ser::Impossible<(), QSettingsSerdeError>;
+    type SerializeStruct = StructSerializer<'a, 'b>;
+    type SerializeStructVariant = ser::Impossible<(), QSettingsSerdeError>;
+
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        self.set_value(&v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<()> {
+        self.set_value(&v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<()> {
+        self.set_value(&v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<()> {
+        self.set_value(&v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<()> {
+        self.set_value(&v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<()> {
+        self.set_value(&v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<()> {
+        self.set_value(&v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<()> {
+        self.set_value(&v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<()> {
+        self.set_value(&v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<()> {
+        self.set_value(&v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<()> {
+        self.set_value(&v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<()> {
+        self.set_value(&QString::from(v.encode_utf8(&mut [0; 4]) as &str))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<()> {
+        self.set_value(&QString::from(v))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        self.set_value(&crate::QByteArray::from(v))
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        let key = self.key.unwrap_or_else(|| QString::from(ELEMENT_KEY));
+        self.settings.as_mut().remove(&key);
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        self.serialize_none()
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        self.serialize_none()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<()> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _value: &T,
+    ) -> Result<()> {
+        Err(ser::Error::custom(format!(
+            "enum variants with data are not supported: {name}::{variant}"
+        )))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqSerializer<'a, 'b>> {
+        let key = self.key.ok_or_else(|| {
+            <QSettingsSerdeError as ser::Error>::custom("nested sequences are not supported")
+        })?;
+        let len = len.and_then(|len| i32::try_from(len).ok()).unwrap_or(-1);
+        self.settings.as_mut().begin_write_array(&key, len);
+        Ok(SeqSerializer {
+            settings: self.settings,
+            index: 0,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqSerializer<'a, 'b>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer<'a, 'b>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(ser::Error::custom(format!(
+            "enum variants with data are not supported: {name}::{variant}"
+        )))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Err(ser::Error::custom("maps are not supported"))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<StructSerializer<'a, 'b>> {
+        // Elements of an array store their fields directly at the array index
+        let grouped = if let Some(key) = &self.key {
+            self.settings.as_mut().begin_group(key);
+            true
+        } else {
+            false
+        };
+
+        Ok(StructSerializer {
+            settings: self.settings,
+            grouped,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(ser::Error::custom(format!(
+            "enum variants with data are not supported: {name}::{variant}"
+        )))
+    }
+}
+
+struct SeqSerializer<'a, 'b> {
+    settings: &'a mut Pin<&'b mut QSettings>,
+    index: i32,
+}
+
+impl<'a, 'b> ser::SerializeSeq for SeqSerializer<'a, 'b> {
+    type Ok = ();
+    type Error = QSettingsSerdeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.settings.as_mut().set_array_index(self.index);
+        self.index += 1;
+        value.serialize(Serializer {
+            settings: &mut *self.settings,
+            key: None,
+        })
+    }
+
+    fn end(self) -> Result<()> {
+        self.settings.as_mut().end_array();
+        Ok(())
+    }
+}
+
+impl<'a, 'b> ser::SerializeTuple for SeqSerializer<'a, 'b> {
+    type Ok = ();
+    type Error = QSettingsSerdeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<()> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl<'a, 'b> ser::SerializeTupleStruct for SeqSerializer<'a, 'b> {
+    type Ok = ();
+    type Error = QSettingsSerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<()> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+struct StructSerializer<'a, 'b> {
+    settings: &'a mut Pin<&'b mut QSettings>,
+    grouped: bool,
+}
+
+impl<'a, 'b> ser::SerializeStruct for StructSerializer<'a, 'b> {
+    type Ok = ();
+    type Error = QSettingsSerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(Serializer {
+            settings: &mut *self.settings,
+            key: Some(QString::from(key)),
+        })
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<()> {
+        self.settings.as_mut().remove(&QString::from(key));
+        Ok(())
+    }
+
+    fn end(self) -> Result<()> {
+        if self.grouped {
+            self.settings.as_mut().end_group();
+        }
+        Ok(())
+    }
+}
+
+/// Deserializes a value from the given key of the current group,
+/// a key of None means that the value is an element of an array.
+struct Deserializer<'a, 'b> {
+    settings: &'a mut Pin<&'b mut QSettings>,
+    key: Option<QString>,
+}
+
+impl<'a, 'b> Deserializer<'a, 'b> {
+    fn value<T: QVariantValue>(&self) -> Result<T> {
+        let key = self
+            .key
+            .clone()
+            .unwrap_or_else(|| QString::from(ELEMENT_KEY));
+        if !self.settings.contains(&key) {
+            return Err(de::Error::custom(format!("missing setting: {key}")));
+        }
+
+        self.settings
+            .value(&key)
+            .value::<T>()
+            .ok_or_else(|| de::Error::custom(format!("invalid type for setting: {key}")))
+    }
+
+    fn string(&self) -> Result<String> {
+        Ok(self.value::<QString>()?.to_string())
+    }
+}
+
+macro_rules! deserialize_value {
+    ($method:ident, $visit:ident, $type:ty) => {
+        fn $method<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+            visitor.$visit(self.value::<$type>()?)
+        }
+    };
+}
+
+impl<'de, 'a, 'b> de::Deserializer<'de> for Deserializer<'a, 'b> {
+    type Error = QSettingsSerdeError;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        // The INI format does not store types, so anything that is not a group is a string
+        match &self.key {
+            Some(key) if contains_in(&self.settings.child_groups(), key) => Err(de::Error::custom(
+                format!("cannot infer the type of group: {key}"),
+            )),
+            _ => visitor.visit_string(self.string()?),
+        }
+    }
+
+    deserialize_value!(deserialize_bool, visit_bool, bool);
+    deserialize_value!(deserialize_i8, visit_i8, i8);
+    deserialize_value!(deserialize_i16, visit_i16, i16);
+    deserialize_value!(deserialize_i32, visit_i32, i32);
+    deserialize_value!(deserialize_i64, visit_i64, i64);
+    deserialize_value!(deserialize_u8, visit_u8, u8);
+    deserialize_value!(deserialize_u16, visit_u16, u16);
+    deserialize_value!(deserialize_u32, visit_u32, u32);
+    deserialize_value!(deserialize_u64, visit_u64, u64);
+    deserialize_value!(deserialize_f32, visit_f32, f32);
+    deserialize_value!(deserialize_f64, visit_f64, f64);
+
+    fn deserialize_char<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let string = self.string()?;
+        let mut chars = string.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => visitor.visit_char(c),
+            _ => visitor.visit_string(string),
+        }
+    }
+
+    fn deserialize_str<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_string(self.string()?)
+    }
+
+    fn deserialize_string<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_string(self.string()?)
+    }
+
+    fn deserialize_bytes<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let bytes = self.value::<crate::QByteArray>()?;
+        visitor.visit_byte_buf(bytes.as_slice().to_vec())
+    }
+
+    fn deserialize_byte_buf<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let key = self
+            .key
+            .clone()
+            .unwrap_or_else(|| QString::from(ELEMENT_KEY));
+        if settings_has_key(self.settings, &key) {
+            visitor.visit_some(self)
+        } else {
+            visitor.visit_none()
+        }
+    }
+
+    fn deserialize_unit<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let key = self.key.ok_or_else(|| {
+            <QSettingsSerdeError as de::Error>::custom("nested sequences are not supported")
+        })?;
+        let len = self.settings.as_mut().begin_read_array(&key);
+        let value = visitor.visit_seq(SeqAccess {
+            settings: &mut *self.settings,
+            index: 0,
+            len,
+        });
+        self.settings.as_mut().end_array();
+        value
+    }
+
+    fn deserialize_tuple<V: de::Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: de::Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(de::Error::custom("maps are not supported"))
+    }
+
+    fn deserialize_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        if let Some(key) = &self.key {
+            self.settings.as_mut().begin_group(key);
+        }
+
+        // Only visit the fields which exist so that missing fields can use defaults
+        let fields = fields
+            .iter()
+            .copied()
+            .filter(|field| settings_has_key(self.settings, &QString::from(*field)))
+            .collect::<Vec<_>>()
+            .into_iter();
+        let value = visitor.visit_map(StructAccess {
+            settings: &mut *self.settings,
+            fields,
+            current: None,
+        });
+
+        if self.key.is_some() {
+            self.settings.as_mut().end_group();
+        }
+        value
+    }
+
+    fn deserialize_enum<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_enum(self.string()?.into_deserializer())
+    }
+
+    fn deserialize_identifier<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_ignored_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+}
+
+struct SeqAccess<'a, 'b> {
+    settings: &'a mut Pin<&'b mut QSettings>,
+    index: i32,
+    len: i32,
+}
+
+impl<'de, 'a, 'b> de::SeqAccess<'de> for SeqAccess<'a, 'b> {
+    type Error = QSettingsSerdeError;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>> {
+        if self.index >= self.len {
+            return Ok(None);
+        }
+
+        self.settings.as_mut().set_array_index(self.index);
+        self.index += 1;
+        seed.deserialize(Deserializer {
+            settings: &mut *self.settings,
+            key: None,
+        })
+        .map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        usize::try_from(self.len - self.index).ok()
+    }
+}
+
+struct StructAccess<'a, 'b> {
+    settings: &'a mut Pin<&'b mut QSettings>,
+    fields: std::vec::IntoIter<&'static str>,
+    current: Option<&'static str>,
+}
+
+impl<'de, 'a, 'b> de::MapAccess<'de> for StructAccess<'a, 'b> {
+    type Error = QSettingsSerdeError;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        self.current = self.fields.next();
+        match self.current {
+            Some(field) => seed.deserialize(field.into_deserializer()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let field = self
+            .current
+            .take()
+            .ok_or_else(|| <QSettingsSerdeError as de::Error>::custom("value is missing a key"))?;
+        seed.deserialize(Deserializer {
+            settings: &mut *self.settings,
+            key: Some(QString::from(field)),
+        })
+    }
+}
@@ -0,0 +1,62 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qstandardpaths.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QString
+qstandardpathsDisplayName(QStandardPathsStandardLocation type)
+{
+  return QStandardPaths::displayName(type);
+}
+
+QString
+qstandardpathsFindExecutable(const QString& executableName,
+                             const QStringList& paths)
+{
+  return QStandardPaths::findExecutable(executableName, paths);
+}
+
+QString
+qstandardpathsLocate(QStandardPathsStandardLocation type,
+                     const QString& fileName,
+                     QStandardPathsLocateOption options)
+{
+  return QStandardPaths::locate(
+    type, fileName, QStandardPaths::LocateOptions(options));
+}
+
+QStringList
+qstandardpathsLocateAll(QStandardPathsStandardLocation type,
+                        const QString& fileName,
+                        QStandardPathsLocateOption options)
+{
+  return QStandardPaths::locateAll(
+    type, fileName, QStandardPaths::LocateOptions(options));
+}
+
+void
+qstandardpathsSetTestModeEnabled(bool testMode)
+{
+  QStandardPaths::setTestModeEnabled(testMode);
+}
+
+QStringList
+qstandardpathsStandardLocations(QStandardPathsStandardLocation type)
+{
+  return QStandardPaths::standardLocations(type);
+}
+
+QString
+qstandardpathsWritableLocation(QStandardPathsStandardLocation type)
+{
+  return QStandardPaths::writableLocation(type);
+}
+
+}
+}
@@ -0,0 +1,189 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QString, QStringList};
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the different flags that can be used for controlling the behavior of QStandardPaths::locate and QStandardPaths::locate_all.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    enum QStandardPathsLocateOption {
+        /// Return only files.
+        LocateFile = 0x0,
+        /// Return only directories.
+        LocateDirectory = 0x1,
+    }
+
+    /// This enum describes the different locations that can be queried using methods such as QStandardPaths::writable_location,
+    /// QStandardPaths::standard_locations, and QStandardPaths::display_name.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    enum QStandardPathsStandardLocation {
+        /// Returns the user's desktop directory.
+        DesktopLocation = 0,
+        /// Returns the directory containing user document files.
+        DocumentsLocation = 1,
+        /// Returns the directory containing user's fonts.
+        FontsLocation = 2,
+        /// Returns the directory containing the user applications (either executables, application bundles, or shortcuts to them).
+        ApplicationsLocation = 3,
+        /// Returns the directory containing the user's music or other audio files.
+        MusicLocation = 4,
+        /// Returns the directory containing the user's movies and videos.
+        MoviesLocation = 5,
+        /// Returns the directory containing the user's pictures or photos.
+        PicturesLocation = 6,
+        /// Returns a directory where temporary files can be stored.
+        TempLocation = 7,
+        /// Returns the user's home directory (the same as QDir::home_path()).
+        HomeLocation = 8,
+        /// Returns a directory location where persistent application data can be stored.
+        /// This is an application-specific directory.
+        AppLocalDataLocation = 9,
+        /// Returns a directory location where user-specific non-essential (cached) data should be written.
+        /// This is an application-specific directory.
+        CacheLocation = 10,
+        /// Returns a directory location where persistent data shared across applications can be stored.
+        GenericDataLocation = 11,
+        /// Returns a directory location where runtime communication files should be written, like Unix local sockets.
+        RuntimeLocation = 12,
+        /// Returns a directory location where user-specific configuration files should be written.
+        ConfigLocation = 13,
+        /// Returns a directory for user's downloaded files.
+        DownloadLocation = 14,
+        /// Returns a directory location where user-specific non-essential (cached) data, shared across applications, should be written.
+        GenericCacheLocation = 15,
+        /// Returns a directory location where user-specific configuration files shared between multiple applications should be written.
+        GenericConfigLocation = 16,
+        /// Returns a directory location where persistent application data can be stored.
+        /// This is an application-specific directory, on Windows this is the roaming directory.
+        AppDataLocation = 17,
+        /// Returns a directory location where user-specific configuration files should be written.
+        /// This is an application-specific directory.
+        AppConfigLocation = 18,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstandardpaths.h");
+        type QStandardPathsLocateOption;
+        type QStandardPathsStandardLocation;
+
+        #[doc(hidden)]
+        #[rust_name = "qstandardpaths_display_name"]
+        fn qstandardpathsDisplayName(location: QStandardPathsStandardLocation) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qstandardpaths_find_executable"]
+        fn qstandardpathsFindExecutable(executable_name: &QString, paths: &QStringList) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qstandardpaths_locate"]
+        fn qstandardpathsLocate(
+            location: QStandardPathsStandardLocation,
+            file_name: &QString,
+            options: QStandardPathsLocateOption,
+        ) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qstandardpaths_locate_all"]
+        fn qstandardpathsLocateAll(
+            location: QStandardPathsStandardLocation,
+            file_name: &QString,
+            options: QStandardPathsLocateOption,
+        ) -> QStringList;
+        #[doc(hidden)]
+        #[rust_name = "qstandardpaths_set_test_mode_enabled"]
+        fn qstandardpathsSetTestModeEnabled(test_mode: bool);
+        #[doc(hidden)]
+        #[rust_name = "qstandardpaths_standard_locations"]
+        fn qstandardpathsStandardLocations(location: QStandardPathsStandardLocation)
+            -> QStringList;
+        #[doc(hidden)]
+        #[rust_name = "qstandardpaths_writable_location"]
+        fn qstandardpathsWritableLocation(location: QStandardPathsStandardLocation) -> QString;
+    }
+}
+
+pub use ffi::{QStandardPathsLocateOption, QStandardPathsStandardLocation};
+
+/// The QStandardPaths class provides methods for accessing standard paths.
+///
+/// Note that this only has associated functions, as in Qt all the methods are static.
+pub struct QStandardPaths;
+
+impl QStandardPaths {
+    /// Returns a localized display name for the given location type or an empty QString if no relevant location can be found.
+    pub fn display_name(location: QStandardPathsStandardLocation) -> QString {
+        ffi::qstandardpaths_display_name(location)
+    }
+
+    /// Finds the executable named executable_name in the specified paths, or the system paths if paths is empty.
+    ///
+    /// Returns None if the executable could not be found, otherwise the absolute file path to the executable.
+    pub fn find_executable(executable_name: &QString, paths: &QStringList) -> Option<QString> {
+        let path = ffi::qstandardpaths_find_executable(executable_name, paths);
+        if path.is_empty() {
+            None
+        } else {
+            Some(path)
+        }
+    }
+
+    /// Tries to find a file or directory called file_name in the standard locations for location.
+    ///
+    /// Returns the full path to the first file or directory found, otherwise None.
+    pub fn locate(
+        location: QStandardPathsStandardLocation,
+        file_name: &QString,
+        options: QStandardPathsLocateOption,
+    ) -> Option<QString> {
+        let path = ffi::qstandardpaths_locate(location, file_name, options);
+        if path.is_empty() {
+            None
+        } else {
+            Some(path)
+        }
+    }
+
+    /// Tries to find all files or directories called file_name in the standard locations for location.
+    pub fn locate_all(
+        location: QStandardPathsStandardLocation,
+        file_name: &QString,
+        options: QStandardPathsLocateOption,
+    ) -> QStringList {
+        ffi::qstandardpaths_locate_all(location, file_name, options)
+    }
+
+    /// If test_mode is true, this enables a special "test mode" in QStandardPaths,
+    /// which changes writable locations to point to test directories.
+    pub fn set_test_mode_enabled(test_mode: bool) {
+        ffi::qstandardpaths_set_test_mode_enabled(test_mode)
+    }
+
+    /// Returns all the directories where files of location belong.
+    ///
+    /// The list of directories is sorted from high to low priority, starting with writable_location() if it can be determined.
+    pub fn standard_locations(location: QStandardPathsStandardLocation) -> QStringList {
+        ffi::qstandardpaths_standard_locations(location)
+    }
+
+    /// Returns the directory where files of location should be written to, or None if the location cannot be determined.
+    ///
+    /// Note that the directory may not exist yet, it can be created with QDir::mkpath.
+    pub fn writable_location(location: QStandardPathsStandardLocation) -> Option<QString> {
+        let path = ffi::qstandardpaths_writable_location(location);
+        if path.is_empty() {
+            None
+        } else {
+            Some(path)
+        }
+    }
+}
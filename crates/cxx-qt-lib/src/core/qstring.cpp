@@ -61,6 +61,14 @@ qstringArg(const QString& string, const QString& a)
   return string.arg(a);
 }
 
+bool
+qstringContainsRegularExpression(const QString& string,
+                                 const QRegularExpression& re)
+{
+  // CXX can't bind the overload with the optional match output, so use C++
+  return string.contains(re);
+}
+
 ::rust::isize
 qstringIndexOf(const QString& string,
                const QString& str,
@@ -144,6 +152,14 @@ qstringSplit(const QString& string,
   return string.split(sep, behavior, cs);
 }
 
+QStringList
+qstringSplitRegularExpression(const QString& string,
+                              const QRegularExpression& sep,
+                              Qt::SplitBehaviorFlags behavior)
+{
+  return string.split(sep, behavior);
+}
+
 QString
 qstringSimplified(const QString& string)
 {
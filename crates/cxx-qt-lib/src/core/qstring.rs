@@ -20,6 +20,8 @@ mod ffi {
     unsafe extern "C++" {
         include!("cxx-qt-lib/qbytearray.h");
         type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qregularexpression.h");
+        type QRegularExpression = crate::QRegularExpression;
         include!("cxx-qt-lib/qstring.h");
         type QString = super::QString;
         include!("cxx-qt-lib/qstringlist.h");
@@ -78,6 +80,17 @@ mod ffi {
             cs: CaseSensitivity,
         ) -> &'a mut QString;
 
+        /// Replaces every occurrence of the regular expression re in the string with after.
+        /// Returns a reference to the string.
+        ///
+        /// In after, back-references to capturing groups are expanded, e.g. \1 is replaced with the first captured substring.
+        #[rust_name = "replace_regex"]
+        fn replace<'a>(
+            self: &'a mut QString,
+            re: &QRegularExpression,
+            after: &QString,
+        ) -> &'a mut QString;
+
         /// Returns true if the string starts with s; otherwise returns false.
         #[rust_name = "starts_with"]
         fn startsWith(self: &QString, s: &QString, cs: CaseSensitivity) -> bool;
@@ -116,6 +129,9 @@ mod ffi {
         #[rust_name = "qstring_arg"]
         fn qstringArg(string: &QString, a: &QString) -> QString;
         #[doc(hidden)]
+        #[rust_name = "qstring_contains_regex"]
+        fn qstringContainsRegularExpression(string: &QString, re: &QRegularExpression) -> bool;
+        #[doc(hidden)]
         #[rust_name = "qstring_index_of"]
         fn qstringIndexOf(
             string: &QString,
@@ -151,6 +167,13 @@ mod ffi {
             cs: CaseSensitivity,
         ) -> QStringList;
         #[doc(hidden)]
+        #[rust_name = "qstring_split_regex"]
+        fn qstringSplitRegularExpression(
+            string: &QString,
+            sep: &QRegularExpression,
+            behavior: SplitBehaviorFlags,
+        ) -> QStringList;
+        #[doc(hidden)]
         #[rust_name = "qstring_to_latin1"]
         fn qstringToLatin1(string: &QString) -> QByteArray;
         #[doc(hidden)]
@@ -294,6 +317,11 @@ impl QString {
         0.cmp(&self.compare_i32(other, cs))
     }
 
+    /// Returns true if the regular expression re matches somewhere in this string; otherwise returns false.
+    pub fn contains_regex(&self, re: &ffi::QRegularExpression) -> bool {
+        ffi::qstring_contains_regex(self, re)
+    }
+
     /// Returns the index position of the first occurrence of the string str in this string,
     /// searching forward from index position from. Returns -1 if str is not found.
     pub fn index_of(&self, str: &QString, from: isize, cs: ffi::CaseSensitivity) -> isize {
@@ -342,6 +370,16 @@ impl QString {
         ffi::qstring_split(self, sep, behavior, cs)
     }
 
+    /// Splits the string into substrings wherever the regular expression re matches, and returns the list of those strings.
+    /// If re does not match anywhere in the string, split_regex() returns a single-element list containing this string.
+    pub fn split_regex(
+        &self,
+        re: &ffi::QRegularExpression,
+        behavior: ffi::SplitBehaviorFlags,
+    ) -> ffi::QStringList {
+        ffi::qstring_split_regex(self, re, behavior)
+    }
+
     /// Returns a Latin-1 representation of the string as a QByteArray.
     pub fn to_latin1(&self) -> ffi::QByteArray {
         ffi::qstring_to_latin1(self)
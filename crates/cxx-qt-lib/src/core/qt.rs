@@ -5,6 +5,61 @@
 
 #[cxx::bridge(namespace = "Qt")]
 mod ffi {
+    /// This enum describes attributes that change the behavior of application-wide features.
+    ///
+    /// Note that most attributes need to be set before the application is constructed.
+    #[repr(i32)]
+    enum ApplicationAttribute {
+        /// Menus do not show icons.
+        AA_DontShowIconsInMenus = 2,
+        /// Ensures that widgets have native windows.
+        AA_NativeWindows = 3,
+        /// Ensures that siblings of native widgets stay non-native.
+        AA_DontCreateNativeWidgetSiblings = 4,
+        /// Indicates that Qt is used to author a plugin.
+        AA_PluginApplication = 5,
+        /// Menu bars are not used as a native menubar on platforms that support it.
+        AA_DontUseNativeMenuBar = 6,
+        /// On macOS the Control and Meta (Command) keys are not swapped.
+        AA_MacDontSwapCtrlAndMeta = 7,
+        /// Assume the screen has a resolution of 96 DPI rather than using the resolution of the operating system.
+        AA_Use96Dpi = 8,
+        /// Unhandled mouse events are translated to touch events.
+        AA_SynthesizeTouchForUnhandledMouseEvents = 11,
+        /// Unhandled touch events are translated to mouse events.
+        AA_SynthesizeMouseForUnhandledTouchEvents = 12,
+        /// Make QWidget always use raster rendering.
+        AA_ForceRasterWidgets = 14,
+        /// Forces the usage of desktop OpenGL on platforms that use dynamic loading of the OpenGL implementation.
+        AA_UseDesktopOpenGL = 15,
+        /// Forces the usage of OpenGL ES 2.0 or higher on platforms that use dynamic loading of the OpenGL implementation.
+        AA_UseOpenGLES = 16,
+        /// Forces the usage of a software based OpenGL implementation on platforms that use dynamic loading of the OpenGL implementation.
+        AA_UseSoftwareOpenGL = 17,
+        /// Enables resource sharing between the OpenGL contexts used by classes like QOpenGLWidget and QQuickWidget.
+        AA_ShareOpenGLContexts = 18,
+        /// Indicates whether a palette was explicitly set on the QGuiApplication.
+        AA_SetPalette = 19,
+        /// Widget style sheet propagation follows the same rules as regular palette and font propagation.
+        AA_UseStyleSheetPropagationInWidgetStyles = 22,
+        /// Native dialogs provided by the platform are not used.
+        AA_DontUseNativeDialogs = 23,
+        /// Unhandled tablet events are translated to mouse events.
+        AA_SynthesizeMouseForUnhandledTabletEvents = 24,
+        /// Mouse move, touch update and similar high frequency events are compressed.
+        AA_CompressHighFrequencyEvents = 25,
+        /// Disables the check that a QOpenGLContext is only made current on the thread it was created in.
+        AA_DontCheckOpenGLContextThreadAffinity = 26,
+        /// Disables caching of shader program binaries on disk.
+        AA_DisableShaderDiskCache = 27,
+        /// Keyboard shortcuts are not shown in context menus.
+        AA_DontShowShortcutsInContextMenus = 28,
+        /// Tablet move events are compressed like mouse move events.
+        AA_CompressTabletEvents = 29,
+        /// Disables the QSessionManager.
+        AA_DisableSessionManager = 31,
+    }
+
     /// This enum type defines what happens to the aspect ratio when scaling an rectangle.
     #[repr(i32)]
     enum AspectRatioMode {
@@ -16,6 +71,50 @@ mod ffi {
         KeepAspectRatioByExpanding,
     }
 
+    /// This enum type defines the brush styles supported by Qt,
+    /// i.e. the fill pattern of shapes drawn using QPainter.
+    #[repr(i32)]
+    enum BrushStyle {
+        /// No brush pattern.
+        NoBrush = 0,
+        /// Uniform color.
+        SolidPattern = 1,
+        /// Extremely dense brush pattern.
+        Dense1Pattern = 2,
+        /// Very dense brush pattern.
+        Dense2Pattern = 3,
+        /// Somewhat dense brush pattern.
+        Dense3Pattern = 4,
+        /// Half dense brush pattern.
+        Dense4Pattern = 5,
+        /// Somewhat sparse brush pattern.
+        Dense5Pattern = 6,
+        /// Very sparse brush pattern.
+        Dense6Pattern = 7,
+        /// Extremely sparse brush pattern.
+        Dense7Pattern = 8,
+        /// Horizontal lines.
+        HorPattern = 9,
+        /// Vertical lines.
+        VerPattern = 10,
+        /// Crossing horizontal and vertical lines.
+        CrossPattern = 11,
+        /// Backward diagonal lines.
+        BDiagPattern = 12,
+        /// Forward diagonal lines.
+        FDiagPattern = 13,
+        /// Crossing diagonal lines.
+        DiagCrossPattern = 14,
+        /// Linear gradient (set using a dedicated QBrush constructor).
+        LinearGradientPattern = 15,
+        /// Radial gradient (set using a dedicated QBrush constructor).
+        RadialGradientPattern = 16,
+        /// Conical gradient (set using a dedicated QBrush constructor).
+        ConicalGradientPattern = 17,
+        /// Custom pattern (see QBrush::setTexture()).
+        TexturePattern = 24,
+    }
+
     #[repr(i32)]
     enum CaseSensitivity {
         CaseInsensitive,
@@ -49,6 +148,72 @@ mod ffi {
         RFC2822Date = 8,
     }
 
+    /// This enum type defines the days of the week.
+    #[repr(i32)]
+    enum DayOfWeek {
+        Monday = 1,
+        Tuesday = 2,
+        Wednesday = 3,
+        Thursday = 4,
+        Friday = 5,
+        Saturday = 6,
+        Sunday = 7,
+    }
+
+    /// Specifies which method should be used to fill the paths and polygons.
+    #[repr(i32)]
+    enum FillRule {
+        /// Specifies that the region is filled using the odd even fill rule.
+        OddEvenFill,
+        /// Specifies that the region is filled using the non zero winding rule.
+        WindingFill,
+    }
+
+    /// This enum type defines the pen cap styles supported by Qt,
+    /// i.e. the line end caps that can be drawn using QPainter.
+    #[repr(i32)]
+    enum PenCapStyle {
+        /// A square line end that does not cover the end point of the line.
+        FlatCap = 0x00,
+        /// A square line end that covers the end point and extends beyond it by half the line width.
+        SquareCap = 0x10,
+        /// A rounded line end.
+        RoundCap = 0x20,
+    }
+
+    /// This enum type defines the pen join styles supported by Qt,
+    /// i.e. which joins between two connected lines can be drawn using QPainter.
+    #[repr(i32)]
+    enum PenJoinStyle {
+        /// The outer edges of the lines are extended to meet at an angle, and this area is filled.
+        MiterJoin = 0x00,
+        /// The triangular notch between the two lines is filled.
+        BevelJoin = 0x40,
+        /// A circular arc between the two lines is filled.
+        RoundJoin = 0x80,
+        /// A miter join corresponding to the definition of a miter join in the SVG 1.2 Tiny specification.
+        SvgMiterJoin = 0x100,
+    }
+
+    /// This enum type defines the pen styles that can be drawn using QPainter.
+    #[repr(i32)]
+    enum PenStyle {
+        /// No line at all.
+        NoPen,
+        /// A plain line.
+        SolidLine,
+        /// Dashes separated by a few pixels.
+        DashLine,
+        /// Dots separated by a few pixels.
+        DotLine,
+        /// Alternate dots and dashes.
+        DashDotLine,
+        /// One dash, two dots, one dash, two dots.
+        DashDotDotLine,
+        /// A custom pattern defined using QPainterPathStroker::setDashPattern().
+        CustomDashLine,
+    }
+
     #[repr(i32)]
     enum SplitBehaviorFlags {
         KeepEmptyParts,
@@ -67,17 +232,38 @@ mod ffi {
         TimeZone,
     }
 
+    /// The timer type indicates how accurate a timer can be.
+    #[repr(i32)]
+    enum TimerType {
+        /// Precise timers try to keep millisecond accuracy
+        PreciseTimer,
+        /// Coarse timers try to keep accuracy within 5% of the desired interval
+        CoarseTimer,
+        /// Very coarse timers only keep full second accuracy
+        VeryCoarseTimer,
+    }
+
     unsafe extern "C++" {
         include!("cxx-qt-lib/qt.h");
+        type ApplicationAttribute;
         type AspectRatioMode;
+        type BrushStyle;
         type CaseSensitivity;
         type ConnectionType;
         type DateFormat;
+        type DayOfWeek;
+        type FillRule;
+        type PenCapStyle;
+        type PenJoinStyle;
+        type PenStyle;
         type SplitBehaviorFlags;
         type TimeSpec;
+        type TimerType;
     }
 }
 
 pub use ffi::{
-    AspectRatioMode, CaseSensitivity, ConnectionType, DateFormat, SplitBehaviorFlags, TimeSpec,
+    ApplicationAttribute, AspectRatioMode, BrushStyle, CaseSensitivity, ConnectionType, DateFormat,
+    DayOfWeek, FillRule, PenCapStyle, PenJoinStyle, PenStyle, SplitBehaviorFlags, TimeSpec,
+    TimerType,
 };
@@ -0,0 +1,25 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qtemporarydir.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QTemporaryDir>
+qtemporarydirNew()
+{
+  return ::std::make_unique<QTemporaryDir>();
+}
+
+::std::unique_ptr<QTemporaryDir>
+qtemporarydirNewWithTemplate(const QString& templatePath)
+{
+  return ::std::make_unique<QTemporaryDir>(templatePath);
+}
+
+}
+}
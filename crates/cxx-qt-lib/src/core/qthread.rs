@@ -0,0 +1,76 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QObject, Upcast};
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qthread.h");
+        type QThread;
+
+        /// Returns true if the thread is finished; otherwise returns false.
+        #[rust_name = "is_finished"]
+        fn isFinished(self: &QThread) -> bool;
+
+        /// Return true if the task running on this thread should be stopped.
+        /// An interruption can be requested by request_interruption().
+        #[rust_name = "is_interruption_requested"]
+        fn isInterruptionRequested(self: &QThread) -> bool;
+
+        /// Returns true if the thread is running; otherwise returns false.
+        #[rust_name = "is_running"]
+        fn isRunning(self: &QThread) -> bool;
+
+        /// Tells the thread's event loop to exit with return code 0 (success).
+        ///
+        /// This function does nothing if the thread does not have an event loop.
+        fn quit(self: Pin<&mut QThread>);
+
+        /// Request the interruption of the thread.
+        /// That request is advisory and it is up to code running on the thread to decide if and how it should act upon such request.
+        #[rust_name = "request_interruption"]
+        fn requestInterruption(self: Pin<&mut QThread>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qthread_current_thread"]
+        fn qthreadCurrentThread() -> *mut QThread;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qthread_upcast"]
+        fn upcastQObject(object: &QThread) -> &QObject;
+        #[doc(hidden)]
+        #[rust_name = "qthread_upcast_mut"]
+        fn upcastQObjectMut(object: Pin<&mut QThread>) -> Pin<&mut QObject>;
+    }
+}
+
+pub use ffi::QThread;
+
+impl QThread {
+    /// Returns a pointer to a QThread which manages the currently executing thread.
+    pub fn current_thread() -> *mut Self {
+        ffi::qthread_current_thread()
+    }
+}
+
+impl Upcast<QObject> for QThread {
+    fn upcast(&self) -> &QObject {
+        ffi::qthread_upcast(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QObject> {
+        ffi::qthread_upcast_mut(self)
+    }
+}
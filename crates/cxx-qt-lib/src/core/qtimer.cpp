@@ -0,0 +1,19 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qtimer.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QTimer>
+qtimerNew()
+{
+  return ::std::make_unique<QTimer>();
+}
+
+}
+}
@@ -0,0 +1,216 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QMetaObjectConnection, QObject, TimerType, Upcast};
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+    }
+
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type TimerType = crate::TimerType;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qtimer.h");
+        type QTimer;
+
+        /// Returns the timeout interval in milliseconds.
+        fn interval(self: &QTimer) -> i32;
+
+        /// Returns true if the timer is running (pending); otherwise returns false.
+        #[rust_name = "is_active"]
+        fn isActive(self: &QTimer) -> bool;
+
+        /// Returns whether the timer is a single-shot timer.
+        #[rust_name = "is_single_shot"]
+        fn isSingleShot(self: &QTimer) -> bool;
+
+        /// Returns the timer's remaining value in milliseconds left until the timeout.
+        /// If the timer is inactive, the returned value will be -1.
+        /// If the timer is overdue, the returned value will be 0.
+        #[rust_name = "remaining_time"]
+        fn remainingTime(self: &QTimer) -> i32;
+
+        /// Sets the timeout interval in milliseconds.
+        ///
+        /// The default value for this property is 0.
+        /// A QTimer with a timeout interval of 0 will time out as soon as all the events in the window system's event queue have been processed.
+        #[rust_name = "set_interval"]
+        fn setInterval(self: Pin<&mut QTimer>, msec: i32);
+
+        /// Sets whether the timer is a single-shot timer.
+        ///
+        /// A single-shot timer fires only once, non-single-shot timers fire every interval milliseconds.
+        #[rust_name = "set_single_shot"]
+        fn setSingleShot(self: Pin<&mut QTimer>, single_shot: bool);
+
+        /// Sets the accuracy of the timer.
+        #[rust_name = "set_timer_type"]
+        fn setTimerType(self: Pin<&mut QTimer>, atype: TimerType);
+
+        /// Starts or restarts the timer with the timeout specified in interval.
+        ///
+        /// If the timer is already running, it will be stopped and restarted.
+        fn start(self: Pin<&mut QTimer>);
+
+        /// Starts or restarts the timer with a timeout interval of msec milliseconds.
+        ///
+        /// If the timer is already running, it will be stopped and restarted.
+        #[rust_name = "start_msec"]
+        fn start(self: Pin<&mut QTimer>, msec: i32);
+
+        /// Stops the timer.
+        fn stop(self: Pin<&mut QTimer>);
+
+        /// Returns the ID of the timer if the timer is running; otherwise returns -1.
+        #[rust_name = "timer_id"]
+        fn timerId(self: &QTimer) -> i32;
+
+        /// Returns the accuracy of the timer.
+        #[rust_name = "timer_type"]
+        fn timerType(self: &QTimer) -> TimerType;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QTimerClosure;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qtimer_new"]
+        fn qtimerNew() -> UniquePtr<QTimer>;
+
+        #[doc(hidden)]
+        #[rust_name = "qtimer_on_timeout"]
+        fn qtimerOnTimeout(
+            timer: Pin<&mut QTimer>,
+            func: fn(&mut QTimerClosure),
+            closure: Box<QTimerClosure>,
+        ) -> QMetaObjectConnection;
+
+        #[doc(hidden)]
+        #[rust_name = "qtimer_single_shot"]
+        fn qtimerSingleShot(
+            msec: i32,
+            timer_type: TimerType,
+            func: fn(&mut QTimerClosure),
+            closure: Box<QTimerClosure>,
+        );
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qtimer_upcast"]
+        fn upcastQObject(object: &QTimer) -> &QObject;
+        #[doc(hidden)]
+        #[rust_name = "qtimer_upcast_mut"]
+        fn upcastQObjectMut(object: Pin<&mut QTimer>) -> Pin<&mut QObject>;
+    }
+
+    // QTimer is a QObject so it is not trivial or relocatable,
+    // therefore we need to use references or pointers.
+    impl UniquePtr<QTimer> {}
+}
+
+pub use ffi::QTimer;
+
+/// A Rust closure which is called from C++ when a timer times out.
+#[doc(hidden)]
+pub struct QTimerClosure {
+    closure: Box<dyn FnMut()>,
+}
+
+impl QTimerClosure {
+    fn new(closure: impl FnMut() + 'static) -> Box<Self> {
+        Box::new(Self {
+            closure: Box::new(closure),
+        })
+    }
+
+    fn call(&mut self) {
+        (self.closure)();
+    }
+}
+
+impl QTimer {
+    /// Constructs a timer.
+    ///
+    /// The timer lives in the thread that it is created in,
+    /// so it should be created and used from the Qt thread with a running event loop.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qtimer_new()
+    }
+
+    /// Connect the given closure to the timeout signal of the timer.
+    ///
+    /// The closure is called every time the timer times out, until the returned
+    /// [QMetaObjectConnection] is dropped or the timer is destroyed.
+    ///
+    /// As the closure is always called on the thread of the timer it does not need to be [Send].
+    /// To update a QObject from the closure capture its `CxxQtThread` and queue the change.
+    pub fn on_timeout<F>(self: Pin<&mut Self>, closure: F) -> QMetaObjectConnection
+    where
+        F: FnMut() + 'static,
+    {
+        ffi::qtimer_on_timeout(self, QTimerClosure::call, QTimerClosure::new(closure))
+    }
+
+    /// Calls the given closure once after msec milliseconds have elapsed.
+    ///
+    /// This is a convenience to avoid creating and keeping a [QTimer] around for a one-off timeout,
+    /// the closure is called on the thread which called this method once its event loop runs.
+    pub fn single_shot<F>(msec: i32, closure: F)
+    where
+        F: FnOnce() + 'static,
+    {
+        Self::single_shot_with_type(msec, TimerType::CoarseTimer, closure);
+    }
+
+    /// Calls the given closure once after msec milliseconds have elapsed,
+    /// using the given timer type for the accuracy of the timer.
+    pub fn single_shot_with_type<F>(msec: i32, timer_type: TimerType, closure: F)
+    where
+        F: FnOnce() + 'static,
+    {
+        let mut closure = Some(closure);
+        ffi::qtimer_single_shot(
+            msec,
+            timer_type,
+            QTimerClosure::call,
+            QTimerClosure::new(move || {
+                if let Some(closure) = closure.take() {
+                    closure();
+                }
+            }),
+        );
+    }
+}
+
+impl Upcast<QObject> for QTimer {
+    fn upcast(&self) -> &QObject {
+        ffi::qtimer_upcast(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QObject> {
+        ffi::qtimer_upcast_mut(self)
+    }
+}
@@ -0,0 +1,73 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qtlogging.h"
+
+#include <atomic>
+#include <cstring>
+
+namespace {
+
+// The handler is only ever set once to the same Rust function,
+// so it is never freed as messages could be logged from any thread.
+::std::atomic<rust::cxxqtlib1::QtMessageHandlerFn*> rustMessageHandler{
+  nullptr
+};
+
+::rust::Slice<const ::std::uint8_t>
+toSlice(const char* string)
+{
+  if (string == nullptr) {
+    return ::rust::Slice<const ::std::uint8_t>();
+  }
+
+  // The strings from the context are not guaranteed to be UTF-8,
+  // so pass the bytes and let Rust convert them lossily
+  return ::rust::Slice<const ::std::uint8_t>(
+    reinterpret_cast<const ::std::uint8_t*>(string), ::std::strlen(string));
+}
+
+void
+messageHandler(QtMsgType type,
+               const QMessageLogContext& context,
+               const QString& message)
+{
+  const auto handler = rustMessageHandler.load();
+  if (handler == nullptr) {
+    return;
+  }
+
+  (*handler)(type,
+             toSlice(context.category),
+             toSlice(context.file),
+             static_cast<::std::int32_t>(context.line),
+             toSlice(context.function),
+             message);
+}
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qtloggingInstallMessageHandler(QtMessageHandlerFn handler)
+{
+  if (rustMessageHandler.load() == nullptr) {
+    rustMessageHandler.store(new QtMessageHandlerFn(handler));
+  }
+
+  qInstallMessageHandler(messageHandler);
+}
+
+void
+qtloggingRestoreMessageHandler()
+{
+  qInstallMessageHandler(nullptr);
+}
+
+}
+}
@@ -0,0 +1,244 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::QString;
+use std::sync::RwLock;
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the messages that can be sent to a message handler.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    enum QtMsgType {
+        /// A message generated by the qDebug() function.
+        QtDebugMsg = 0,
+        /// A message generated by the qWarning() function.
+        QtWarningMsg = 1,
+        /// A message generated by the qCritical() function.
+        QtCriticalMsg = 2,
+        /// A message generated by the qFatal() function.
+        QtFatalMsg = 3,
+        /// A message generated by the qInfo() function.
+        QtInfoMsg = 4,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qtlogging.h");
+        type QtMsgType;
+
+        #[doc(hidden)]
+        #[rust_name = "qtlogging_install_message_handler"]
+        #[allow(clippy::type_complexity)]
+        fn qtloggingInstallMessageHandler(
+            handler: fn(QtMsgType, &[u8], &[u8], i32, &[u8], &QString),
+        );
+        #[doc(hidden)]
+        #[rust_name = "qtlogging_restore_message_handler"]
+        fn qtloggingRestoreMessageHandler();
+    }
+}
+
+pub use ffi::QtMsgType;
+
+/// A Qt message handler, which is called with the type, context and message of any message logged via Qt.
+pub type QtMessageHandler = fn(QtMsgType, &QMessageLogContext, &QString);
+
+/// Additional information about a message logged via Qt.
+///
+/// Note that the file, line and function are only available when Qt was built with
+/// QT_MESSAGELOGCONTEXT defined or the message was logged from a debug build,
+/// otherwise they are empty or zero.
+#[derive(Debug)]
+pub struct QMessageLogContext<'a> {
+    /// The name of the logging category, for example "default", "qml" or "js"
+    pub category: &'a str,
+    /// The file the message was logged from
+    pub file: &'a str,
+    /// The function the message was logged from
+    pub function: &'a str,
+    /// The line the message was logged from
+    pub line: i32,
+}
+
+static MESSAGE_HANDLER: RwLock<Option<QtMessageHandler>> = RwLock::new(None);
+
+fn message_handler(
+    msg_type: QtMsgType,
+    category: &[u8],
+    file: &[u8],
+    line: i32,
+    function: &[u8],
+    message: &QString,
+) {
+    let handler = MESSAGE_HANDLER.read().ok().and_then(|handler| *handler);
+    if let Some(handler) = handler {
+        let category = String::from_utf8_lossy(category);
+        let file = String::from_utf8_lossy(file);
+        let function = String::from_utf8_lossy(function);
+        let context = QMessageLogContext {
+            category: &category,
+            file: &file,
+            function: &function,
+            line,
+        };
+        handler(msg_type, &context, message);
+    }
+}
+
+/// Installs a message handler which is called for any message logged via Qt,
+/// such as qDebug() and qWarning() from C++ or console.log() from QML.
+///
+/// Passing `None` restores the default message handler of Qt, which prints to stderr.
+///
+/// When the `log` or `tracing` feature is enabled [log_message_handler] or
+/// [tracing_message_handler] can be used to forward Qt messages to those crates.
+pub fn q_install_message_handler(handler: Option<QtMessageHandler>) {
+    if let Ok(mut message_handler) = MESSAGE_HANDLER.write() {
+        *message_handler = handler;
+    }
+
+    if handler.is_some() {
+        ffi::qtlogging_install_message_handler(message_handler);
+    } else {
+        ffi::qtlogging_restore_message_handler();
+    }
+}
+
+/// A [QtMessageHandler] which forwards Qt messages to the `log` crate.
+///
+/// The category of the message is used as the target, the file and line of the context are also forwarded.
+/// Critical and fatal messages are logged as errors.
+///
+/// ```ignore
+/// cxx_qt_lib::q_install_message_handler(Some(cxx_qt_lib::log_message_handler));
+/// ```
+#[cfg(feature = "log")]
+pub fn log_message_handler(msg_type: QtMsgType, context: &QMessageLogContext, message: &QString) {
+    let level = match msg_type {
+        QtMsgType::QtDebugMsg => log::Level::Debug,
+        QtMsgType::QtInfoMsg => log::Level::Info,
+        QtMsgType::QtWarningMsg => log::Level::Warn,
+        _ => log::Level::Error,
+    };
+    if level > log::max_level() {
+        return;
+    }
+
+    let target = if context.category.is_empty() {
+        "qt"
+    } else {
+        context.category
+    };
+    log::logger().log(
+        &log::Record::builder()
+            .args(format_args!("{message}"))
+            .level(level)
+            .target(target)
+            .file(Some(context.file).filter(|file| !file.is_empty()))
+            .line(u32::try_from(context.line).ok().filter(|line| *line > 0))
+            .build(),
+    );
+}
+
+/// A [QtMessageHandler] which forwards Qt messages to the `tracing` crate.
+///
+/// Events use a target of "qt", with the category, file, line and function of the context as fields.
+/// Critical and fatal messages are logged as errors.
+///
+/// ```ignore
+/// cxx_qt_lib::q_install_message_handler(Some(cxx_qt_lib::tracing_message_handler));
+/// ```
+#[cfg(feature = "tracing")]
+pub fn tracing_message_handler(
+    msg_type: QtMsgType,
+    context: &QMessageLogContext,
+    message: &QString,
+) {
+    // The level of a tracing event needs to be a constant
+    macro_rules! event {
+        ($level:expr) => {
+            tracing::event!(
+                target: "qt",
+                $level,
+                category = context.category,
+                file = context.file,
+                line = context.line,
+                function = context.function,
+                "{}",
+                message
+            )
+        };
+    }
+
+    match msg_type {
+        QtMsgType::QtDebugMsg => event!(tracing::Level::DEBUG),
+        QtMsgType::QtInfoMsg => event!(tracing::Level::INFO),
+        QtMsgType::QtWarningMsg => event!(tracing::Level::WARN),
+        _ => event!(tracing::Level::ERROR),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "log")]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    struct TestLogger;
+
+    type TestRecord = (log::Level, String, String, Option<u32>);
+
+    static RECORDS: Mutex<Vec<TestRecord>> = Mutex::new(Vec::new());
+
+    impl log::Log for TestLogger {
+        fn enabled(&self, _: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            RECORDS.lock().unwrap().push((
+                record.level(),
+                record.target().to_owned(),
+                record.args().to_string(),
+                record.line(),
+            ));
+        }
+
+        fn flush(&self) {}
+    }
+
+    #[test]
+    fn log_message_handler_forwards_context() {
+        log::set_logger(&TestLogger).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+
+        let context = QMessageLogContext {
+            category: "kdab.test",
+            file: "main.cpp",
+            function: "main",
+            line: 42,
+        };
+        log_message_handler(QtMsgType::QtWarningMsg, &context, &QString::from("hello"));
+        log_message_handler(QtMsgType::QtCriticalMsg, &context, &QString::from("world"));
+
+        let records = RECORDS.lock().unwrap();
+        assert_eq!(
+            records[0],
+            (
+                log::Level::Warn,
+                "kdab.test".to_owned(),
+                "hello".to_owned(),
+                Some(42)
+            )
+        );
+        assert_eq!(records[1].0, log::Level::Error);
+    }
+}
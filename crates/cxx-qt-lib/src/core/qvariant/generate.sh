@@ -104,10 +104,16 @@ generate_bridge_qt "QByteArray" "qbytearray"
 generate_bridge_qt "QColor" "qcolor"
 generate_bridge_qt "QDate" "qdate"
 generate_bridge_qt "QDateTime" "qdatetime"
+generate_bridge_qt "QLine" "qline"
+generate_bridge_qt "QLineF" "qlinef"
+generate_bridge_qt "QMatrix4x4" "qmatrix4x4"
 generate_bridge_qt "QModelIndex" "qmodelindex"
 generate_bridge_qt "QPersistentModelIndex" "qpersistentmodelindex"
 generate_bridge_qt "QPoint" "qpoint"
 generate_bridge_qt "QPointF" "qpointf"
+generate_bridge_qt "QPolygon" "qpolygon"
+generate_bridge_qt "QPolygonF" "qpolygonf"
+generate_bridge_qt "QQuaternion" "qquaternion"
 generate_bridge_qt "QRect" "qrect"
 generate_bridge_qt "QRectF" "qrectf"
 generate_bridge_qt "QSize" "qsize"
@@ -115,6 +121,7 @@ generate_bridge_qt "QSizeF" "qsizef"
 generate_bridge_qt "QString" "qstring"
 generate_bridge_qt "QStringList" "qstringlist"
 generate_bridge_qt "QTime" "qtime"
+generate_bridge_qt "QTransform" "qtransform"
 generate_bridge_qt "QUrl" "qurl"
 generate_bridge_primitive "u8" "U8"
 generate_bridge_primitive "u16" "U16"
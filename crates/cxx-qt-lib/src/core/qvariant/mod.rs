@@ -169,10 +169,20 @@ impl_qvariant_value!(crate::QColor, qvariant_qcolor);
 impl_qvariant_value!(crate::QDate, qvariant_qdate);
 #[cfg(not(target_os = "emscripten"))]
 impl_qvariant_value!(crate::QDateTime, qvariant_qdatetime);
+impl_qvariant_value!(crate::QLine, qvariant_qline);
+impl_qvariant_value!(crate::QLineF, qvariant_qlinef);
+#[cfg(feature = "qt_gui")]
+impl_qvariant_value!(crate::QMatrix4x4, qvariant_qmatrix4x4);
 impl_qvariant_value!(crate::QModelIndex, qvariant_qmodelindex);
 impl_qvariant_value!(crate::QPersistentModelIndex, qvariant_qpersistentmodelindex);
 impl_qvariant_value!(crate::QPoint, qvariant_qpoint);
 impl_qvariant_value!(crate::QPointF, qvariant_qpointf);
+#[cfg(feature = "qt_gui")]
+impl_qvariant_value!(crate::QPolygon, qvariant_qpolygon);
+#[cfg(feature = "qt_gui")]
+impl_qvariant_value!(crate::QPolygonF, qvariant_qpolygonf);
+#[cfg(feature = "qt_gui")]
+impl_qvariant_value!(crate::QQuaternion, qvariant_qquaternion);
 impl_qvariant_value!(crate::QRect, qvariant_qrect);
 impl_qvariant_value!(crate::QRectF, qvariant_qrectf);
 impl_qvariant_value!(crate::QSize, qvariant_qsize);
@@ -180,6 +190,8 @@ impl_qvariant_value!(crate::QSizeF, qvariant_qsizef);
 impl_qvariant_value!(crate::QString, qvariant_qstring);
 impl_qvariant_value!(crate::QStringList, qvariant_qstringlist);
 impl_qvariant_value!(crate::QTime, qvariant_qtime);
+#[cfg(feature = "qt_gui")]
+impl_qvariant_value!(crate::QTransform, qvariant_qtransform);
 impl_qvariant_value!(crate::QUrl, qvariant_qurl);
 impl_qvariant_value!(u8, qvariant_u8);
 impl_qvariant_value!(u16, qvariant_u16);
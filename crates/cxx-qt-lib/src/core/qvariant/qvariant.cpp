@@ -88,10 +88,20 @@ CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QColor, QColor)
 #endif
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QDate, QDate)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QDateTime, QDateTime)
+CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QLine, QLine)
+CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QLineF, QLineF)
+#ifdef CXX_QT_GUI_FEATURE
+CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QMatrix4x4, QMatrix4x4)
+#endif
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QModelIndex, QModelIndex)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QPersistentModelIndex, QPersistentModelIndex)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QPoint, QPoint)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QPointF, QPointF)
+#ifdef CXX_QT_GUI_FEATURE
+CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QPolygon, QPolygon)
+CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QPolygonF, QPolygonF)
+CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QQuaternion, QQuaternion)
+#endif
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QRect, QRect)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QRectF, QRectF)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QSize, QSize)
@@ -99,6 +109,9 @@ CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QSizeF, QSizeF)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QString, QString)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QStringList, QStringList)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QTime, QTime)
+#ifdef CXX_QT_GUI_FEATURE
+CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QTransform, QTransform)
+#endif
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QUrl, QUrl)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::std::uint8_t, U8)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::std::uint16_t, U16)
@@ -0,0 +1,37 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmatrix4x4.h");
+        type QMatrix4x4 = crate::QMatrix4x4;
+
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvariant"]
+    unsafe extern "C++" {
+        #[rust_name = "can_convert_QMatrix4x4"]
+        fn qvariantCanConvertQMatrix4x4(variant: &QVariant) -> bool;
+        #[rust_name = "construct_QMatrix4x4"]
+        fn qvariantConstruct(value: &QMatrix4x4) -> QVariant;
+        #[rust_name = "value_or_default_QMatrix4x4"]
+        fn qvariantValueOrDefault(variant: &QVariant) -> QMatrix4x4;
+    }
+}
+
+pub(crate) fn can_convert(variant: &ffi::QVariant) -> bool {
+    ffi::can_convert_QMatrix4x4(variant)
+}
+
+pub(crate) fn construct(value: &ffi::QMatrix4x4) -> ffi::QVariant {
+    ffi::construct_QMatrix4x4(value)
+}
+
+pub(crate) fn value_or_default(variant: &ffi::QVariant) -> ffi::QMatrix4x4 {
+    ffi::value_or_default_QMatrix4x4(variant)
+}
@@ -0,0 +1,37 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qtransform.h");
+        type QTransform = crate::QTransform;
+
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvariant"]
+    unsafe extern "C++" {
+        #[rust_name = "can_convert_QTransform"]
+        fn qvariantCanConvertQTransform(variant: &QVariant) -> bool;
+        #[rust_name = "construct_QTransform"]
+        fn qvariantConstruct(value: &QTransform) -> QVariant;
+        #[rust_name = "value_or_default_QTransform"]
+        fn qvariantValueOrDefault(variant: &QVariant) -> QTransform;
+    }
+}
+
+pub(crate) fn can_convert(variant: &ffi::QVariant) -> bool {
+    ffi::can_convert_QTransform(variant)
+}
+
+pub(crate) fn construct(value: &ffi::QTransform) -> ffi::QVariant {
+    ffi::construct_QTransform(value)
+}
+
+pub(crate) fn value_or_default(variant: &ffi::QVariant) -> ffi::QTransform {
+    ffi::value_or_default_QTransform(variant)
+}
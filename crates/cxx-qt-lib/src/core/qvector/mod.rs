@@ -3,14 +3,14 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-#[cfg(feature = "qt_gui")]
-use crate::QColor;
 #[cfg(not(target_os = "emscripten"))]
 use crate::QDateTime;
 use crate::{
-    QByteArray, QDate, QMargins, QMarginsF, QPersistentModelIndex, QPoint, QPointF, QRect, QRectF,
-    QSize, QSizeF, QString, QTime, QUrl, QVariant,
+    QByteArray, QDate, QLine, QLineF, QMargins, QMarginsF, QPersistentModelIndex, QPoint, QPointF,
+    QRect, QRectF, QSize, QSizeF, QString, QTime, QUrl, QVariant,
 };
+#[cfg(feature = "qt_gui")]
+use crate::{QColor, QMatrix4x4, QPolygon, QPolygonF, QQuaternion, QTransform};
 use core::{marker::PhantomData, mem::MaybeUninit};
 use cxx::{type_id, ExternType};
 
@@ -355,8 +355,12 @@ impl_qvector_element!(QColor, qvector_qcolor, "QVector_QColor");
 impl_qvector_element!(QDate, qvector_qdate, "QVector_QDate");
 #[cfg(not(target_os = "emscripten"))]
 impl_qvector_element!(QDateTime, qvector_qdatetime, "QVector_QDateTime");
+impl_qvector_element!(QLine, qvector_qline, "QVector_QLine");
+impl_qvector_element!(QLineF, qvector_qlinef, "QVector_QLineF");
 impl_qvector_element!(QMargins, qvector_qmargins, "QVector_QMargins");
 impl_qvector_element!(QMarginsF, qvector_qmarginsf, "QVector_QMarginsF");
+#[cfg(feature = "qt_gui")]
+impl_qvector_element!(QMatrix4x4, qvector_qmatrix4x4, "QVector_QMatrix4x4");
 impl_qvector_element!(
     QPersistentModelIndex,
     qvector_qpersistentmodelindex,
@@ -364,12 +368,20 @@ impl_qvector_element!(
 );
 impl_qvector_element!(QPoint, qvector_qpoint, "QVector_QPoint");
 impl_qvector_element!(QPointF, qvector_qpointf, "QVector_QPointF");
+#[cfg(feature = "qt_gui")]
+impl_qvector_element!(QPolygon, qvector_qpolygon, "QVector_QPolygon");
+#[cfg(feature = "qt_gui")]
+impl_qvector_element!(QPolygonF, qvector_qpolygonf, "QVector_QPolygonF");
+#[cfg(feature = "qt_gui")]
+impl_qvector_element!(QQuaternion, qvector_qquaternion, "QVector_QQuaternion");
 impl_qvector_element!(QRect, qvector_qrect, "QVector_QRect");
 impl_qvector_element!(QRectF, qvector_qrectf, "QVector_QRectF");
 impl_qvector_element!(QSize, qvector_qsize, "QVector_QSize");
 impl_qvector_element!(QSizeF, qvector_qsizef, "QVector_QSizeF");
 impl_qvector_element!(QString, qvector_qstring, "QVector_QString");
 impl_qvector_element!(QTime, qvector_qtime, "QVector_QTime");
+#[cfg(feature = "qt_gui")]
+impl_qvector_element!(QTransform, qvector_qtransform, "QVector_QTransform");
 impl_qvector_element!(QUrl, qvector_qurl, "QVector_QUrl");
 impl_qvector_element!(QVariant, qvector_qvariant, "QVector_QVariant");
 impl_qvector_element!(u8, qvector_u8, "QVector_u8");
@@ -44,17 +44,30 @@ CXX_QT_QVECTOR_ASSERTS(::QColor, QColor);
 #endif
 CXX_QT_QVECTOR_ASSERTS(::QDate, QDate);
 CXX_QT_QVECTOR_ASSERTS(::QDateTime, QDateTime);
+CXX_QT_QVECTOR_ASSERTS(::QLine, QLine);
+CXX_QT_QVECTOR_ASSERTS(::QLineF, QLineF);
 CXX_QT_QVECTOR_ASSERTS(::QMargins, QMargins);
 CXX_QT_QVECTOR_ASSERTS(::QMarginsF, QMarginsF);
+#ifdef CXX_QT_GUI_FEATURE
+CXX_QT_QVECTOR_ASSERTS(::QMatrix4x4, QMatrix4x4);
+#endif
 CXX_QT_QVECTOR_ASSERTS(::QPersistentModelIndex, QPersistentModelIndex);
 CXX_QT_QVECTOR_ASSERTS(::QPoint, QPoint);
 CXX_QT_QVECTOR_ASSERTS(::QPointF, QPointF);
+#ifdef CXX_QT_GUI_FEATURE
+CXX_QT_QVECTOR_ASSERTS(::QPolygon, QPolygon);
+CXX_QT_QVECTOR_ASSERTS(::QPolygonF, QPolygonF);
+CXX_QT_QVECTOR_ASSERTS(::QQuaternion, QQuaternion);
+#endif
 CXX_QT_QVECTOR_ASSERTS(::QRect, QRect);
 CXX_QT_QVECTOR_ASSERTS(::QRectF, QRectF);
 CXX_QT_QVECTOR_ASSERTS(::QSize, QSize);
 CXX_QT_QVECTOR_ASSERTS(::QSizeF, QSizeF);
 CXX_QT_QVECTOR_ASSERTS(::QString, QString);
 CXX_QT_QVECTOR_ASSERTS(::QTime, QTime);
+#ifdef CXX_QT_GUI_FEATURE
+CXX_QT_QVECTOR_ASSERTS(::QTransform, QTransform);
+#endif
 CXX_QT_QVECTOR_ASSERTS(::QUrl, QUrl);
 CXX_QT_QVECTOR_ASSERTS(::QVariant, QVariant);
 CXX_QT_QVECTOR_ASSERTS(::std::uint8_t, u8);
@@ -86,10 +99,18 @@ static const int register_QVector_QDate =
   qRegisterMetaType<::QVector_QDate>("QVector_QDate");
 static const int register_QVector_QDateTime =
   qRegisterMetaType<::QVector_QDateTime>("QVector_QDateTime");
+static const int register_QVector_QLine =
+  qRegisterMetaType<::QVector_QLine>("QVector_QLine");
+static const int register_QVector_QLineF =
+  qRegisterMetaType<::QVector_QLineF>("QVector_QLineF");
 static const int register_QVector_QMargins =
   qRegisterMetaType<::QVector_QMargins>("QVector_QMargins");
 static const int register_QVector_QMarginsF =
   qRegisterMetaType<::QVector_QMarginsF>("QVector_QMarginsF");
+#ifdef CXX_QT_GUI_FEATURE
+static const int register_QVector_QMatrix4x4 =
+  qRegisterMetaType<::QVector_QMatrix4x4>("QVector_QMatrix4x4");
+#endif
 static const int register_QVector_QPersistentModelIndex =
   qRegisterMetaType<::QVector_QPersistentModelIndex>(
     "QVector_QPersistentModelIndex");
@@ -97,6 +118,14 @@ static const int register_QVector_QPoint =
   qRegisterMetaType<::QVector_QPoint>("QVector_QPoint");
 static const int register_QVector_QPointF =
   qRegisterMetaType<::QVector_QPointF>("QVector_QPointF");
+#ifdef CXX_QT_GUI_FEATURE
+static const int register_QVector_QPolygon =
+  qRegisterMetaType<::QVector_QPolygon>("QVector_QPolygon");
+static const int register_QVector_QPolygonF =
+  qRegisterMetaType<::QVector_QPolygonF>("QVector_QPolygonF");
+static const int register_QVector_QQuaternion =
+  qRegisterMetaType<::QVector_QQuaternion>("QVector_QQuaternion");
+#endif
 static const int register_QVector_QRect =
   qRegisterMetaType<::QVector_QRect>("QVector_QRect");
 static const int register_QVector_QRectF =
@@ -109,6 +138,10 @@ static const int register_QVector_QString =
   qRegisterMetaType<::QVector_QString>("QVector_QString");
 static const int register_QVector_QTime =
   qRegisterMetaType<::QVector_QTime>("QVector_QTime");
+#ifdef CXX_QT_GUI_FEATURE
+static const int register_QVector_QTransform =
+  qRegisterMetaType<::QVector_QTransform>("QVector_QTransform");
+#endif
 static const int register_QVector_QUrl =
   qRegisterMetaType<::QVector_QUrl>("QVector_QUrl");
 // Ensure that QVector<QVariant> (aka QVariantList) is registered
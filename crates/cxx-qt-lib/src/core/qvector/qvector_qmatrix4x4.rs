@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmatrix4x4.h");
+        type QMatrix4x4 = crate::QMatrix4x4;
+
+        include!("cxx-qt-lib/qvector.h");
+        type QVector_QMatrix4x4 = crate::QVector<QMatrix4x4>;
+    }
+
+    unsafe extern "C++" {
+        #[rust_name = "cxx_clear"]
+        fn clear(self: &mut QVector_QMatrix4x4);
+        #[rust_name = "cxx_contains"]
+        fn contains(self: &QVector_QMatrix4x4, _: &QMatrix4x4) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qvector_clone_QMatrix4x4"]
+        fn construct(_: &QVector_QMatrix4x4) -> QVector_QMatrix4x4;
+        #[rust_name = "qvector_default_QMatrix4x4"]
+        fn construct() -> QVector_QMatrix4x4;
+        #[rust_name = "qvector_drop_QMatrix4x4"]
+        fn drop(_: &mut QVector_QMatrix4x4);
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "reserve_QMatrix4x4"]
+        fn qvectorReserve(_: &mut QVector_QMatrix4x4, size: isize);
+        #[rust_name = "append_QMatrix4x4"]
+        fn qvectorAppend(_: &mut QVector_QMatrix4x4, _: &QMatrix4x4);
+        #[rust_name = "get_unchecked_QMatrix4x4"]
+        unsafe fn qvectorGetUnchecked(set: &QVector_QMatrix4x4, pos: isize) -> &QMatrix4x4;
+        #[rust_name = "index_of_QMatrix4x4"]
+        fn qvectorIndexOf(_: &QVector_QMatrix4x4, _: &QMatrix4x4) -> isize;
+        #[rust_name = "insert_QMatrix4x4"]
+        fn qvectorInsert(_: &mut QVector_QMatrix4x4, _: isize, _: &QMatrix4x4);
+        #[rust_name = "remove_QMatrix4x4"]
+        fn qvectorRemove(_: &mut QVector_QMatrix4x4, _: isize);
+        #[rust_name = "len_QMatrix4x4"]
+        fn qvectorLen(_: &QVector_QMatrix4x4) -> isize;
+    }
+}
+
+pub(crate) fn append(v: &mut ffi::QVector_QMatrix4x4, value: &ffi::QMatrix4x4) {
+    ffi::append_QMatrix4x4(v, value);
+}
+
+pub(crate) fn clone(s: &ffi::QVector_QMatrix4x4) -> ffi::QVector_QMatrix4x4 {
+    ffi::qvector_clone_QMatrix4x4(s)
+}
+
+pub(crate) fn reserve(v: &mut ffi::QVector_QMatrix4x4, size: isize) {
+    ffi::reserve_QMatrix4x4(v, size);
+}
+
+pub(crate) fn default() -> ffi::QVector_QMatrix4x4 {
+    ffi::qvector_default_QMatrix4x4()
+}
+
+pub(crate) fn drop(s: &mut ffi::QVector_QMatrix4x4) {
+    ffi::qvector_drop_QMatrix4x4(s);
+}
+
+pub(crate) unsafe fn get_unchecked(s: &ffi::QVector_QMatrix4x4, pos: isize) -> &ffi::QMatrix4x4 {
+    ffi::get_unchecked_QMatrix4x4(s, pos)
+}
+
+pub(crate) fn index_of(v: &ffi::QVector_QMatrix4x4, value: &ffi::QMatrix4x4) -> isize {
+    ffi::index_of_QMatrix4x4(v, value)
+}
+
+pub(crate) fn insert(s: &mut ffi::QVector_QMatrix4x4, pos: isize, value: &ffi::QMatrix4x4) {
+    ffi::insert_QMatrix4x4(s, pos, value);
+}
+
+pub(crate) fn len(s: &ffi::QVector_QMatrix4x4) -> isize {
+    ffi::len_QMatrix4x4(s)
+}
+
+pub(crate) fn remove(s: &mut ffi::QVector_QMatrix4x4, pos: isize) {
+    ffi::remove_QMatrix4x4(s, pos);
+}
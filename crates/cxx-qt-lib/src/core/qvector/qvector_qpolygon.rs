@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpolygon.h");
+        type QPolygon = crate::QPolygon;
+
+        include!("cxx-qt-lib/qvector.h");
+        type QVector_QPolygon = crate::QVector<QPolygon>;
+    }
+
+    unsafe extern "C++" {
+        #[rust_name = "cxx_clear"]
+        fn clear(self: &mut QVector_QPolygon);
+        #[rust_name = "cxx_contains"]
+        fn contains(self: &QVector_QPolygon, _: &QPolygon) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qvector_clone_QPolygon"]
+        fn construct(_: &QVector_QPolygon) -> QVector_QPolygon;
+        #[rust_name = "qvector_default_QPolygon"]
+        fn construct() -> QVector_QPolygon;
+        #[rust_name = "qvector_drop_QPolygon"]
+        fn drop(_: &mut QVector_QPolygon);
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "reserve_QPolygon"]
+        fn qvectorReserve(_: &mut QVector_QPolygon, size: isize);
+        #[rust_name = "append_QPolygon"]
+        fn qvectorAppend(_: &mut QVector_QPolygon, _: &QPolygon);
+        #[rust_name = "get_unchecked_QPolygon"]
+        unsafe fn qvectorGetUnchecked(set: &QVector_QPolygon, pos: isize) -> &QPolygon;
+        #[rust_name = "index_of_QPolygon"]
+        fn qvectorIndexOf(_: &QVector_QPolygon, _: &QPolygon) -> isize;
+        #[rust_name = "insert_QPolygon"]
+        fn qvectorInsert(_: &mut QVector_QPolygon, _: isize, _: &QPolygon);
+        #[rust_name = "remove_QPolygon"]
+        fn qvectorRemove(_: &mut QVector_QPolygon, _: isize);
+        #[rust_name = "len_QPolygon"]
+        fn qvectorLen(_: &QVector_QPolygon) -> isize;
+    }
+}
+
+pub(crate) fn append(v: &mut ffi::QVector_QPolygon, value: &ffi::QPolygon) {
+    ffi::append_QPolygon(v, value);
+}
+
+pub(crate) fn clone(s: &ffi::QVector_QPolygon) -> ffi::QVector_QPolygon {
+    ffi::qvector_clone_QPolygon(s)
+}
+
+pub(crate) fn reserve(v: &mut ffi::QVector_QPolygon, size: isize) {
+    ffi::reserve_QPolygon(v, size);
+}
+
+pub(crate) fn default() -> ffi::QVector_QPolygon {
+    ffi::qvector_default_QPolygon()
+}
+
+pub(crate) fn drop(s: &mut ffi::QVector_QPolygon) {
+    ffi::qvector_drop_QPolygon(s);
+}
+
+pub(crate) unsafe fn get_unchecked(s: &ffi::QVector_QPolygon, pos: isize) -> &ffi::QPolygon {
+    ffi::get_unchecked_QPolygon(s, pos)
+}
+
+pub(crate) fn index_of(v: &ffi::QVector_QPolygon, value: &ffi::QPolygon) -> isize {
+    ffi::index_of_QPolygon(v, value)
+}
+
+pub(crate) fn insert(s: &mut ffi::QVector_QPolygon, pos: isize, value: &ffi::QPolygon) {
+    ffi::insert_QPolygon(s, pos, value);
+}
+
+pub(crate) fn len(s: &ffi::QVector_QPolygon) -> isize {
+    ffi::len_QPolygon(s)
+}
+
+pub(crate) fn remove(s: &mut ffi::QVector_QPolygon, pos: isize) {
+    ffi::remove_QPolygon(s, pos);
+}
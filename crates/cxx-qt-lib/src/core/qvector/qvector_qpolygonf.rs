@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpolygonf.h");
+        type QPolygonF = crate::QPolygonF;
+
+        include!("cxx-qt-lib/qvector.h");
+        type QVector_QPolygonF = crate::QVector<QPolygonF>;
+    }
+
+    unsafe extern "C++" {
+        #[rust_name = "cxx_clear"]
+        fn clear(self: &mut QVector_QPolygonF);
+        #[rust_name = "cxx_contains"]
+        fn contains(self: &QVector_QPolygonF, _: &QPolygonF) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qvector_clone_QPolygonF"]
+        fn construct(_: &QVector_QPolygonF) -> QVector_QPolygonF;
+        #[rust_name = "qvector_default_QPolygonF"]
+        fn construct() -> QVector_QPolygonF;
+        #[rust_name = "qvector_drop_QPolygonF"]
+        fn drop(_: &mut QVector_QPolygonF);
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "reserve_QPolygonF"]
+        fn qvectorReserve(_: &mut QVector_QPolygonF, size: isize);
+        #[rust_name = "append_QPolygonF"]
+        fn qvectorAppend(_: &mut QVector_QPolygonF, _: &QPolygonF);
+        #[rust_name = "get_unchecked_QPolygonF"]
+        unsafe fn qvectorGetUnchecked(set: &QVector_QPolygonF, pos: isize) -> &QPolygonF;
+        #[rust_name = "index_of_QPolygonF"]
+        fn qvectorIndexOf(_: &QVector_QPolygonF, _: &QPolygonF) -> isize;
+        #[rust_name = "insert_QPolygonF"]
+        fn qvectorInsert(_: &mut QVector_QPolygonF, _: isize, _: &QPolygonF);
+        #[rust_name = "remove_QPolygonF"]
+        fn qvectorRemove(_: &mut QVector_QPolygonF, _: isize);
+        #[rust_name = "len_QPolygonF"]
+        fn qvectorLen(_: &QVector_QPolygonF) -> isize;
+    }
+}
+
+pub(crate) fn append(v: &mut ffi::QVector_QPolygonF, value: &ffi::QPolygonF) {
+    ffi::append_QPolygonF(v, value);
+}
+
+pub(crate) fn clone(s: &ffi::QVector_QPolygonF) -> ffi::QVector_QPolygonF {
+    ffi::qvector_clone_QPolygonF(s)
+}
+
+pub(crate) fn reserve(v: &mut ffi::QVector_QPolygonF, size: isize) {
+    ffi::reserve_QPolygonF(v, size);
+}
+
+pub(crate) fn default() -> ffi::QVector_QPolygonF {
+    ffi::qvector_default_QPolygonF()
+}
+
+pub(crate) fn drop(s: &mut ffi::QVector_QPolygonF) {
+    ffi::qvector_drop_QPolygonF(s);
+}
+
+pub(crate) unsafe fn get_unchecked(s: &ffi::QVector_QPolygonF, pos: isize) -> &ffi::QPolygonF {
+    ffi::get_unchecked_QPolygonF(s, pos)
+}
+
+pub(crate) fn index_of(v: &ffi::QVector_QPolygonF, value: &ffi::QPolygonF) -> isize {
+    ffi::index_of_QPolygonF(v, value)
+}
+
+pub(crate) fn insert(s: &mut ffi::QVector_QPolygonF, pos: isize, value: &ffi::QPolygonF) {
+    ffi::insert_QPolygonF(s, pos, value);
+}
+
+pub(crate) fn len(s: &ffi::QVector_QPolygonF) -> isize {
+    ffi::len_QPolygonF(s)
+}
+
+pub(crate) fn remove(s: &mut ffi::QVector_QPolygonF, pos: isize) {
+    ffi::remove_QPolygonF(s, pos);
+}
@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qquaternion.h");
+        type QQuaternion = crate::QQuaternion;
+
+        include!("cxx-qt-lib/qvector.h");
+        type QVector_QQuaternion = crate::QVector<QQuaternion>;
+    }
+
+    unsafe extern "C++" {
+        #[rust_name = "cxx_clear"]
+        fn clear(self: &mut QVector_QQuaternion);
+        #[rust_name = "cxx_contains"]
+        fn contains(self: &QVector_QQuaternion, _: &QQuaternion) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qvector_clone_QQuaternion"]
+        fn construct(_: &QVector_QQuaternion) -> QVector_QQuaternion;
+        #[rust_name = "qvector_default_QQuaternion"]
+        fn construct() -> QVector_QQuaternion;
+        #[rust_name = "qvector_drop_QQuaternion"]
+        fn drop(_: &mut QVector_QQuaternion);
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "reserve_QQuaternion"]
+        fn qvectorReserve(_: &mut QVector_QQuaternion, size: isize);
+        #[rust_name = "append_QQuaternion"]
+        fn qvectorAppend(_: &mut QVector_QQuaternion, _: &QQuaternion);
+        #[rust_name = "get_unchecked_QQuaternion"]
+        unsafe fn qvectorGetUnchecked(set: &QVector_QQuaternion, pos: isize) -> &QQuaternion;
+        #[rust_name = "index_of_QQuaternion"]
+        fn qvectorIndexOf(_: &QVector_QQuaternion, _: &QQuaternion) -> isize;
+        #[rust_name = "insert_QQuaternion"]
+        fn qvectorInsert(_: &mut QVector_QQuaternion, _: isize, _: &QQuaternion);
+        #[rust_name = "remove_QQuaternion"]
+        fn qvectorRemove(_: &mut QVector_QQuaternion, _: isize);
+        #[rust_name = "len_QQuaternion"]
+        fn qvectorLen(_: &QVector_QQuaternion) -> isize;
+    }
+}
+
+pub(crate) fn append(v: &mut ffi::QVector_QQuaternion, value: &ffi::QQuaternion) {
+    ffi::append_QQuaternion(v, value);
+}
+
+pub(crate) fn clone(s: &ffi::QVector_QQuaternion) -> ffi::QVector_QQuaternion {
+    ffi::qvector_clone_QQuaternion(s)
+}
+
+pub(crate) fn reserve(v: &mut ffi::QVector_QQuaternion, size: isize) {
+    ffi::reserve_QQuaternion(v, size);
+}
+
+pub(crate) fn default() -> ffi::QVector_QQuaternion {
+    ffi::qvector_default_QQuaternion()
+}
+
+pub(crate) fn drop(s: &mut ffi::QVector_QQuaternion) {
+    ffi::qvector_drop_QQuaternion(s);
+}
+
+pub(crate) unsafe fn get_unchecked(s: &ffi::QVector_QQuaternion, pos: isize) -> &ffi::QQuaternion {
+    ffi::get_unchecked_QQuaternion(s, pos)
+}
+
+pub(crate) fn index_of(v: &ffi::QVector_QQuaternion, value: &ffi::QQuaternion) -> isize {
+    ffi::index_of_QQuaternion(v, value)
+}
+
+pub(crate) fn insert(s: &mut ffi::QVector_QQuaternion, pos: isize, value: &ffi::QQuaternion) {
+    ffi::insert_QQuaternion(s, pos, value);
+}
+
+pub(crate) fn len(s: &ffi::QVector_QQuaternion) -> isize {
+    ffi::len_QQuaternion(s)
+}
+
+pub(crate) fn remove(s: &mut ffi::QVector_QQuaternion, pos: isize) {
+    ffi::remove_QQuaternion(s, pos);
+}
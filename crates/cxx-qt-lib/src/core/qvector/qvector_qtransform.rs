@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qtransform.h");
+        type QTransform = crate::QTransform;
+
+        include!("cxx-qt-lib/qvector.h");
+        type QVector_QTransform = crate::QVector<QTransform>;
+    }
+
+    unsafe extern "C++" {
+        #[rust_name = "cxx_clear"]
+        fn clear(self: &mut QVector_QTransform);
+        #[rust_name = "cxx_contains"]
+        fn contains(self: &QVector_QTransform, _: &QTransform) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qvector_clone_QTransform"]
+        fn construct(_: &QVector_QTransform) -> QVector_QTransform;
+        #[rust_name = "qvector_default_QTransform"]
+        fn construct() -> QVector_QTransform;
+        #[rust_name = "qvector_drop_QTransform"]
+        fn drop(_: &mut QVector_QTransform);
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "reserve_QTransform"]
+        fn qvectorReserve(_: &mut QVector_QTransform, size: isize);
+        #[rust_name = "append_QTransform"]
+        fn qvectorAppend(_: &mut QVector_QTransform, _: &QTransform);
+        #[rust_name = "get_unchecked_QTransform"]
+        unsafe fn qvectorGetUnchecked(set: &QVector_QTransform, pos: isize) -> &QTransform;
+        #[rust_name = "index_of_QTransform"]
+        fn qvectorIndexOf(_: &QVector_QTransform, _: &QTransform) -> isize;
+        #[rust_name = "insert_QTransform"]
+        fn qvectorInsert(_: &mut QVector_QTransform, _: isize, _: &QTransform);
+        #[rust_name = "remove_QTransform"]
+        fn qvectorRemove(_: &mut QVector_QTransform, _: isize);
+        #[rust_name = "len_QTransform"]
+        fn qvectorLen(_: &QVector_QTransform) -> isize;
+    }
+}
+
+pub(crate) fn append(v: &mut ffi::QVector_QTransform, value: &ffi::QTransform) {
+    ffi::append_QTransform(v, value);
+}
+
+pub(crate) fn clone(s: &ffi::QVector_QTransform) -> ffi::QVector_QTransform {
+    ffi::qvector_clone_QTransform(s)
+}
+
+pub(crate) fn reserve(v: &mut ffi::QVector_QTransform, size: isize) {
+    ffi::reserve_QTransform(v, size);
+}
+
+pub(crate) fn default() -> ffi::QVector_QTransform {
+    ffi::qvector_default_QTransform()
+}
+
+pub(crate) fn drop(s: &mut ffi::QVector_QTransform) {
+    ffi::qvector_drop_QTransform(s);
+}
+
+pub(crate) unsafe fn get_unchecked(s: &ffi::QVector_QTransform, pos: isize) -> &ffi::QTransform {
+    ffi::get_unchecked_QTransform(s, pos)
+}
+
+pub(crate) fn index_of(v: &ffi::QVector_QTransform, value: &ffi::QTransform) -> isize {
+    ffi::index_of_QTransform(v, value)
+}
+
+pub(crate) fn insert(s: &mut ffi::QVector_QTransform, pos: isize, value: &ffi::QTransform) {
+    ffi::insert_QTransform(s, pos, value);
+}
+
+pub(crate) fn len(s: &ffi::QVector_QTransform) -> isize {
+    ffi::len_QTransform(s)
+}
+
+pub(crate) fn remove(s: &mut ffi::QVector_QTransform, pos: isize) {
+    ffi::remove_QTransform(s, pos);
+}
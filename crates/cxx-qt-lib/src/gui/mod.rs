@@ -3,12 +3,48 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod qbrush;
+pub use qbrush::QBrush;
+
 mod qcolor;
 pub use qcolor::QColor;
 
+mod qfont;
+pub use qfont::QFont;
+
 mod qguiapplication;
 pub use qguiapplication::QGuiApplication;
 
+mod qimage;
+pub use qimage::{QImage, QImageFormat};
+
+mod qmatrix4x4;
+pub use qmatrix4x4::QMatrix4x4;
+
+mod qpainter;
+pub use qpainter::{QPainter, QPainterRenderHint};
+
+mod qpainterpath;
+pub use qpainterpath::QPainterPath;
+
+mod qpaintevent;
+pub use qpaintevent::QPaintEvent;
+
+mod qpen;
+pub use qpen::QPen;
+
+mod qpolygon;
+pub use qpolygon::QPolygon;
+
+mod qpolygonf;
+pub use qpolygonf::QPolygonF;
+
+mod qquaternion;
+pub use qquaternion::QQuaternion;
+
+mod qtransform;
+pub use qtransform::{QTransform, QTransformTransformationType};
+
 mod qvector2d;
 pub use qvector2d::QVector2D;
 
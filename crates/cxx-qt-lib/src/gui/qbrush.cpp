@@ -0,0 +1,27 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_GUI_FEATURE
+#include "cxx-qt-lib/qbrush.h"
+
+#include "../assertion_utils.h"
+
+// QBrush has a single scoped pointer as it's member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/painting/qbrush.h?h=v5.15.6-lts-lgpl#n147
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/painting/qbrush.h?h=v6.2.4#n130
+assert_alignment_and_size(QBrush,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t));
+
+static_assert(!::std::is_trivially_copy_assignable<QBrush>::value);
+static_assert(!::std::is_trivially_copy_constructible<QBrush>::value);
+
+static_assert(!::std::is_trivially_destructible<QBrush>::value);
+
+static_assert(QTypeInfo<QBrush>::isRelocatable);
+#endif
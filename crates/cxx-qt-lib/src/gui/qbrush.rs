@@ -0,0 +1,167 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type BrushStyle = crate::BrushStyle;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcolor.h");
+        type QColor = crate::QColor;
+        include!("cxx-qt-lib/qimage.h");
+        type QImage = crate::QImage;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qbrush.h");
+        type QBrush = super::QBrush;
+
+        /// Returns the brush color.
+        fn color(self: &QBrush) -> &QColor;
+
+        /// Returns true if the brush is fully opaque otherwise false.
+        #[rust_name = "is_opaque"]
+        fn isOpaque(self: &QBrush) -> bool;
+
+        /// Sets the brush color to the given color.
+        ///
+        /// Note that calling this function will not make a difference
+        /// if the brush is a gradient brush.
+        #[rust_name = "set_color"]
+        fn setColor(self: &mut QBrush, color: &QColor);
+
+        /// Sets the brush style to style.
+        #[rust_name = "set_style"]
+        fn setStyle(self: &mut QBrush, style: BrushStyle);
+
+        /// Sets the brush image to image. The style is set to Qt::TexturePattern.
+        #[rust_name = "set_texture_image"]
+        fn setTextureImage(self: &mut QBrush, image: &QImage);
+
+        /// Returns the brush style.
+        fn style(self: &QBrush) -> BrushStyle;
+
+        /// Returns the custom brush pattern, or a null image if no custom brush pattern has been set.
+        #[rust_name = "texture_image"]
+        fn textureImage(self: &QBrush) -> QImage;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qbrush_drop"]
+        fn drop(brush: &mut QBrush);
+
+        #[doc(hidden)]
+        #[rust_name = "qbrush_init_default"]
+        fn construct() -> QBrush;
+        #[doc(hidden)]
+        #[rust_name = "qbrush_init_from_qbrush"]
+        fn construct(brush: &QBrush) -> QBrush;
+        #[doc(hidden)]
+        #[rust_name = "qbrush_init_from_qcolor"]
+        fn construct(color: &QColor, style: BrushStyle) -> QBrush;
+        #[doc(hidden)]
+        #[rust_name = "qbrush_init_from_qimage"]
+        fn construct(image: &QImage) -> QBrush;
+        #[doc(hidden)]
+        #[rust_name = "qbrush_init_from_style"]
+        fn construct(style: BrushStyle) -> QBrush;
+
+        #[doc(hidden)]
+        #[rust_name = "qbrush_eq"]
+        fn operatorEq(a: &QBrush, b: &QBrush) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qbrush_to_qstring"]
+        fn toQString(value: &QBrush) -> QString;
+    }
+}
+
+/// The QBrush class defines the fill pattern of shapes drawn by QPainter.
+#[repr(C)]
+pub struct QBrush {
+    _space: MaybeUninit<usize>,
+}
+
+impl QBrush {
+    /// Constructs a brush with the given color and style.
+    pub fn new(color: &ffi::QColor, style: ffi::BrushStyle) -> Self {
+        ffi::qbrush_init_from_qcolor(color, style)
+    }
+}
+
+impl Clone for QBrush {
+    /// Constructs a copy of other.
+    fn clone(&self) -> Self {
+        ffi::qbrush_init_from_qbrush(self)
+    }
+}
+
+impl Default for QBrush {
+    /// Constructs a default black brush with the style Qt::NoBrush (i.e. this brush will not fill shapes).
+    fn default() -> Self {
+        ffi::qbrush_init_default()
+    }
+}
+
+impl Drop for QBrush {
+    /// Destroys the brush.
+    fn drop(&mut self) {
+        ffi::qbrush_drop(self)
+    }
+}
+
+impl From<&ffi::QColor> for QBrush {
+    /// Constructs a brush with the given color and the Qt::SolidPattern style.
+    fn from(color: &ffi::QColor) -> Self {
+        ffi::qbrush_init_from_qcolor(color, ffi::BrushStyle::SolidPattern)
+    }
+}
+
+impl From<&ffi::QImage> for QBrush {
+    /// Constructs a brush with a black color and a texture set to the given image.
+    /// The style is set to Qt::TexturePattern.
+    fn from(image: &ffi::QImage) -> Self {
+        ffi::qbrush_init_from_qimage(image)
+    }
+}
+
+impl From<ffi::BrushStyle> for QBrush {
+    /// Constructs a black brush with the given style.
+    fn from(style: ffi::BrushStyle) -> Self {
+        ffi::qbrush_init_from_style(style)
+    }
+}
+
+impl std::cmp::PartialEq for QBrush {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qbrush_eq(self, other)
+    }
+}
+
+impl std::cmp::Eq for QBrush {}
+
+impl fmt::Debug for QBrush {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qbrush_to_qstring(self))
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QBrush {
+    type Id = type_id!("QBrush");
+    type Kind = cxx::kind::Trivial;
+}
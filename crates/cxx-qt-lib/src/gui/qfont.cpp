@@ -0,0 +1,27 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_GUI_FEATURE
+#include "cxx-qt-lib/qfont.h"
+
+#include "../assertion_utils.h"
+
+// QFont has a shared pointer and a uint resolve mask as it's members
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/text/qfont.h?h=v5.15.6-lts-lgpl#n351
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/text/qfont.h?h=v6.2.4#n319
+assert_alignment_and_size(QFont,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t[2]));
+
+static_assert(!::std::is_trivially_copy_assignable<QFont>::value);
+static_assert(!::std::is_trivially_copy_constructible<QFont>::value);
+
+static_assert(!::std::is_trivially_destructible<QFont>::value);
+
+static_assert(QTypeInfo<QFont>::isRelocatable);
+#endif
@@ -0,0 +1,176 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qfont.h");
+        type QFont = super::QFont;
+
+        /// Returns true if weight() is a value greater than QFont::Medium; otherwise returns false.
+        fn bold(self: &QFont) -> bool;
+
+        /// Returns the requested font family name.
+        fn family(self: &QFont) -> QString;
+
+        /// Sets this font to match the description descrip.
+        /// The description is a comma-separated list of the font attributes, as returned by toString().
+        #[rust_name = "from_string"]
+        fn fromString(self: &mut QFont, descrip: &QString) -> bool;
+
+        /// Returns true if the style() of the font is not QFont::StyleNormal
+        fn italic(self: &QFont) -> bool;
+
+        /// Returns the pixel size of the font if it was set with setPixelSize().
+        /// Returns -1 if the size was set with setPointSize() or setPointSizeF().
+        #[rust_name = "pixel_size"]
+        fn pixelSize(self: &QFont) -> i32;
+
+        /// Returns the point size of the font. Returns -1 if the font size was specified in pixels.
+        #[rust_name = "point_size"]
+        fn pointSize(self: &QFont) -> i32;
+
+        /// Returns the point size of the font. Returns -1 if the font size was specified in pixels.
+        #[rust_name = "point_size_f"]
+        fn pointSizeF(self: &QFont) -> f64;
+
+        /// If enable is true sets the font's weight to QFont::Bold; otherwise sets the weight to QFont::Normal.
+        #[rust_name = "set_bold"]
+        fn setBold(self: &mut QFont, enable: bool);
+
+        /// Sets the family name of the font. The name is case insensitive and may include a foundry name.
+        #[rust_name = "set_family"]
+        fn setFamily(self: &mut QFont, family: &QString);
+
+        /// Sets the style() of the font to QFont::StyleItalic if enable is true; otherwise the style is set to QFont::StyleNormal.
+        #[rust_name = "set_italic"]
+        fn setItalic(self: &mut QFont, enable: bool);
+
+        /// Sets the font size to pixelSize pixels.
+        #[rust_name = "set_pixel_size"]
+        fn setPixelSize(self: &mut QFont, pixel_size: i32);
+
+        /// Sets the point size to pointSize. The point size must be greater than zero.
+        #[rust_name = "set_point_size"]
+        fn setPointSize(self: &mut QFont, point_size: i32);
+
+        /// Sets the point size to pointSize. The point size must be greater than zero.
+        /// The requested precision may not be achieved on all platforms.
+        #[rust_name = "set_point_size_f"]
+        fn setPointSizeF(self: &mut QFont, point_size: f64);
+
+        /// If enable is true, sets strikeout on; otherwise sets strikeout off.
+        #[rust_name = "set_strike_out"]
+        fn setStrikeOut(self: &mut QFont, enable: bool);
+
+        /// If enable is true, sets underline on; otherwise sets underline off.
+        #[rust_name = "set_underline"]
+        fn setUnderline(self: &mut QFont, enable: bool);
+
+        /// Returns true if strikeout has been set; otherwise returns false.
+        #[rust_name = "strike_out"]
+        fn strikeOut(self: &QFont) -> bool;
+
+        /// Returns a description of the font. The description is a comma-separated list of the attributes.
+        #[rust_name = "to_qstring"]
+        fn toString(self: &QFont) -> QString;
+
+        /// Returns true if underline has been set; otherwise returns false.
+        fn underline(self: &QFont) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qfont_drop"]
+        fn drop(font: &mut QFont);
+
+        #[doc(hidden)]
+        #[rust_name = "qfont_init_default"]
+        fn construct() -> QFont;
+        #[doc(hidden)]
+        #[rust_name = "qfont_init_from_family"]
+        fn construct(family: &QString, point_size: i32) -> QFont;
+        #[doc(hidden)]
+        #[rust_name = "qfont_init_from_qfont"]
+        fn construct(font: &QFont) -> QFont;
+
+        #[doc(hidden)]
+        #[rust_name = "qfont_eq"]
+        fn operatorEq(a: &QFont, b: &QFont) -> bool;
+    }
+}
+
+/// The QFont class specifies a query for a font used for drawing text.
+#[repr(C)]
+pub struct QFont {
+    _space: MaybeUninit<[usize; 2]>,
+}
+
+impl QFont {
+    /// Constructs a font object with the specified family and point size.
+    ///
+    /// If the point size is less than or equal to 0, it is set to 12.
+    pub fn new(family: &ffi::QString, point_size: i32) -> Self {
+        ffi::qfont_init_from_family(family, point_size)
+    }
+}
+
+impl Clone for QFont {
+    /// Constructs a font that is a copy of font.
+    fn clone(&self) -> Self {
+        ffi::qfont_init_from_qfont(self)
+    }
+}
+
+impl Default for QFont {
+    /// Constructs a font object that uses the application's default font.
+    fn default() -> Self {
+        ffi::qfont_init_default()
+    }
+}
+
+impl Drop for QFont {
+    /// Destroys the font object and frees all allocated resources.
+    fn drop(&mut self) {
+        ffi::qfont_drop(self)
+    }
+}
+
+impl std::cmp::PartialEq for QFont {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qfont_eq(self, other)
+    }
+}
+
+impl std::cmp::Eq for QFont {}
+
+impl fmt::Display for QFont {
+    /// Convert the QFont description to a Rust string
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_qstring())
+    }
+}
+
+impl fmt::Debug for QFont {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QFont {
+    type Id = type_id!("QFont");
+    type Kind = cxx::kind::Trivial;
+}
@@ -4,18 +4,20 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::{QByteArray, QString, QStringList, QVector};
+use crate::core::{application_args, impl_core_application_methods};
+use crate::{QCoreApplication, QObject, Upcast};
 use core::pin::Pin;
+use std::ffi::OsStr;
 
 #[cxx::bridge]
 mod ffi {
     unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
         include!("cxx-qt-lib/qbytearray.h");
         type QByteArray = crate::QByteArray;
-        include!("cxx-qt-lib/qstring.h");
-        type QString = crate::QString;
-        include!("cxx-qt-lib/qstringlist.h");
-        type QStringList = crate::QStringList;
+        include!("cxx-qt-lib/qcoreapplication.h");
+        type QCoreApplication = crate::QCoreApplication;
         include!("cxx-qt-lib/qvector.h");
         type QVector_QByteArray = crate::QVector<QByteArray>;
 
@@ -30,48 +32,22 @@ mod ffi {
         fn qguiapplicationNew(args: &QVector_QByteArray) -> UniquePtr<QGuiApplication>;
     }
 
-    // These are all static, so we need to create bindings until CXX supports statics
     #[namespace = "rust::cxxqtlib1"]
     unsafe extern "C++" {
-        // Reuse the templated versions from QCoreApplication
-        include!("cxx-qt-lib/qcoreapplication.h");
-
-        #[doc(hidden)]
-        #[rust_name = "qguiapplication_add_library_path"]
-        fn qapplicationAddLibraryPath(app: Pin<&mut QGuiApplication>, path: &QString);
-        #[doc(hidden)]
-        #[rust_name = "qguiapplication_application_name"]
-        fn qapplicationApplicationName(app: &QGuiApplication) -> QString;
-        #[doc(hidden)]
-        #[rust_name = "qguiapplication_application_version"]
-        fn qapplicationApplicationVersion(app: &QGuiApplication) -> QString;
-        #[doc(hidden)]
-        #[rust_name = "qguiapplication_exec"]
-        fn qapplicationExec(app: Pin<&mut QGuiApplication>) -> i32;
         #[doc(hidden)]
-        #[rust_name = "qguiapplication_library_paths"]
-        fn qapplicationLibraryPaths(app: &QGuiApplication) -> QStringList;
+        #[rust_name = "qguiapplication_upcast"]
+        fn upcastQObject(object: &QGuiApplication) -> &QObject;
         #[doc(hidden)]
-        #[rust_name = "qguiapplication_organization_domain"]
-        fn qapplicationOrganizationDomain(app: &QGuiApplication) -> QString;
+        #[rust_name = "qguiapplication_upcast_mut"]
+        fn upcastQObjectMut(object: Pin<&mut QGuiApplication>) -> Pin<&mut QObject>;
         #[doc(hidden)]
-        #[rust_name = "qguiapplication_organization_name"]
-        fn qapplicationOrganizationName(app: &QGuiApplication) -> QString;
+        #[rust_name = "qguiapplication_upcast_qcoreapplication"]
+        fn upcastQCoreApplication(object: &QGuiApplication) -> &QCoreApplication;
         #[doc(hidden)]
-        #[rust_name = "qguiapplication_set_application_name"]
-        fn qapplicationSetApplicationName(app: Pin<&mut QGuiApplication>, name: &QString);
-        #[doc(hidden)]
-        #[rust_name = "qguiapplication_set_application_version"]
-        fn qapplicationSetApplicationVersion(app: Pin<&mut QGuiApplication>, version: &QString);
-        #[doc(hidden)]
-        #[rust_name = "qguiapplication_set_library_paths"]
-        fn qapplicationSetLibraryPaths(app: Pin<&mut QGuiApplication>, paths: &QStringList);
-        #[doc(hidden)]
-        #[rust_name = "qguiapplication_set_organization_domain"]
-        fn qapplicationSetOrganizationDomain(app: Pin<&mut QGuiApplication>, domain: &QString);
-        #[doc(hidden)]
-        #[rust_name = "qguiapplication_set_organization_name"]
-        fn qapplicationSetOrganizationName(app: Pin<&mut QGuiApplication>, name: &QString);
+        #[rust_name = "qguiapplication_upcast_qcoreapplication_mut"]
+        fn upcastQCoreApplicationMut(
+            object: Pin<&mut QGuiApplication>,
+        ) -> Pin<&mut QCoreApplication>;
     }
 
     // QGuiApplication is not a trivial to CXX and is not relocatable in Qt
@@ -84,92 +60,51 @@ mod ffi {
 pub use ffi::QGuiApplication;
 
 impl QGuiApplication {
-    /// Prepends path to the beginning of the library path list,
-    /// ensuring that it is searched for libraries first.
-    /// If path is empty or already in the path list, the path list is not changed.
-    pub fn add_library_path(self: Pin<&mut Self>, path: &QString) {
-        ffi::qguiapplication_add_library_path(self, path);
-    }
-
-    /// The name of this application
-    pub fn application_name(&self) -> QString {
-        ffi::qguiapplication_application_name(self)
-    }
-
-    /// The version of this application
-    pub fn application_version(&self) -> QString {
-        ffi::qguiapplication_application_version(self)
-    }
-
-    /// Enters the main event loop and waits until exit() is called,
-    /// and then returns the value that was set to exit() (which is 0 if exit() is called via quit()).
-    pub fn exec(self: Pin<&mut Self>) -> i32 {
-        ffi::qguiapplication_exec(self)
-    }
-
-    /// Returns a list of paths that the application will search when dynamically loading libraries.
-    pub fn library_paths(&self) -> QStringList {
-        ffi::qguiapplication_library_paths(self)
-    }
-
     /// Initializes the window system and constructs an application object.
     /// Standard [Qt command line arguments](https://doc.qt.io/qt-6/qguiapplication.html#supported-command-line-options) are handled automatically.
     pub fn new() -> cxx::UniquePtr<Self> {
-        let mut vector = QVector::<QByteArray>::default();
-
-        // Construct an owned QVector of the args
-        // as we need the args_os data to outlive this method
-        // so we pass a QVector to C++ which is then stored
-        for arg in std::env::args_os() {
-            // Unix OsStrings can be directly converted to bytes.
-            #[cfg(unix)]
-            use std::os::unix::ffi::OsStrExt;
-
-            // Windows OsStrings are WTF-8 encoded, so they need to be
-            // converted to UTF-8 Strings before being converted to bytes.
-            // https://simonsapin.github.io/wtf-8/
-            #[cfg(windows)]
-            let arg = arg.to_string_lossy();
-
-            vector.append(QByteArray::from(arg.as_bytes()));
-        }
-
-        ffi::qguiapplication_new(&vector)
+        Self::new_with_args(std::env::args_os())
     }
 
-    /// The Internet domain of the organization that wrote this application
-    pub fn organization_domain(&self) -> QString {
-        ffi::qguiapplication_organization_domain(self)
+    /// Constructs an application object with the given command line arguments,
+    /// the first argument is usually the program name.
+    pub fn new_with_args<I, S>(args: I) -> cxx::UniquePtr<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        ffi::qguiapplication_new(&application_args(args))
     }
+}
 
-    /// The name of the organization that wrote this application
-    pub fn organization_name(&self) -> QString {
-        ffi::qguiapplication_organization_name(self)
+impl_core_application_methods!(QGuiApplication);
+
+impl Upcast<QGuiApplication> for QGuiApplication {
+    fn upcast(&self) -> &QGuiApplication {
+        self
     }
 
-    /// Set the name of this application
-    pub fn set_application_name(self: Pin<&mut Self>, name: &QString) {
-        ffi::qguiapplication_set_application_name(self, name);
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QGuiApplication> {
+        self
     }
+}
 
-    /// Set the version of this application
-    pub fn set_application_version(self: Pin<&mut Self>, version: &QString) {
-        ffi::qguiapplication_set_application_version(self, version);
+impl Upcast<QCoreApplication> for QGuiApplication {
+    fn upcast(&self) -> &QCoreApplication {
+        ffi::qguiapplication_upcast_qcoreapplication(self)
     }
 
-    /// Sets the list of directories to search when loading plugins with QLibrary to paths.
-    /// All existing paths will be deleted and the path list will consist of the paths given in paths and the path to the application.
-    pub fn set_library_paths(self: Pin<&mut Self>, paths: &QStringList) {
-        ffi::qguiapplication_set_library_paths(self, paths);
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QCoreApplication> {
+        ffi::qguiapplication_upcast_qcoreapplication_mut(self)
     }
+}
 
-    /// Sets the Internet domain of the organization that wrote this application
-    pub fn set_organization_domain(self: Pin<&mut Self>, domain: &QString) {
-        ffi::qguiapplication_set_organization_domain(self, domain);
+impl Upcast<QObject> for QGuiApplication {
+    fn upcast(&self) -> &QObject {
+        ffi::qguiapplication_upcast(self)
     }
 
-    /// Sets the name of the organization that wrote this application
-    pub fn set_organization_name(self: Pin<&mut Self>, name: &QString) {
-        ffi::qguiapplication_set_organization_name(self, name);
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QObject> {
+        ffi::qguiapplication_upcast_mut(self)
     }
 }
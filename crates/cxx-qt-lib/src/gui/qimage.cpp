@@ -0,0 +1,99 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_GUI_FEATURE
+#include "cxx-qt-lib/qimage.h"
+
+#include <string>
+
+#include <QtCore/QBuffer>
+
+#include "../assertion_utils.h"
+
+// QImage inherits from QPaintDevice which has a vtable, a ushort and a
+// pointer, then QImage has a single pointer as it's member.
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/painting/qpaintdevice.h?h=v5.15.6-lts-lgpl#n101
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/image/qimage.h?h=v5.15.6-lts-lgpl#n363
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/painting/qpaintdevice.h?h=v6.2.4#n98
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/image/qimage.h?h=v6.2.4#n330
+assert_alignment_and_size(QImage,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t[4]));
+
+static_assert(!::std::is_trivially_copy_assignable<QImage>::value);
+static_assert(!::std::is_trivially_copy_constructible<QImage>::value);
+
+static_assert(!::std::is_trivially_destructible<QImage>::value);
+
+// QImage is only declared as movable from Qt 6 for binary compatibility
+// reasons, but as it has no pointers to itself it is relocatable in Qt 5 too.
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+static_assert(QTypeInfo<QImage>::isRelocatable);
+#endif
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::rust::Slice<const ::std::uint8_t>
+qimageAsBytes(const QImage& image)
+{
+  return ::rust::Slice<const ::std::uint8_t>(
+    image.constBits(), static_cast<::std::size_t>(image.sizeInBytes()));
+}
+
+::rust::isize
+qimageBytesPerLine(const QImage& image)
+{
+  return static_cast<::rust::isize>(image.bytesPerLine());
+}
+
+QImage
+qimageConvertToFormat(const QImage& image, QImageFormat format)
+{
+  return image.convertToFormat(format);
+}
+
+QImage
+qimageInitFromData(::rust::Slice<const ::std::uint8_t> data,
+                   ::rust::Str format)
+{
+  const ::std::string formatString(format);
+  return QImage::fromData(data.data(),
+                          static_cast<int>(data.size()),
+                          formatString.empty() ? nullptr
+                                               : formatString.c_str());
+}
+
+bool
+qimageSave(const QImage& image, const QString& fileName)
+{
+  return image.save(fileName);
+}
+
+bool
+qimageSaveToData(const QImage& image, QByteArray& data, ::rust::Str format)
+{
+  const ::std::string formatString(format);
+  QBuffer buffer(&data);
+  buffer.open(QIODevice::WriteOnly);
+  return image.save(&buffer, formatString.c_str());
+}
+
+QImage
+qimageScaled(const QImage& image,
+             ::std::int32_t width,
+             ::std::int32_t height,
+             Qt::AspectRatioMode mode)
+{
+  return image.scaled(width, height, mode, Qt::SmoothTransformation);
+}
+
+}
+}
+#endif
@@ -0,0 +1,469 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use crate::QByteArray;
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// The format of the pixels stored in a QImage.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    enum QImageFormat {
+        /// The image is invalid.
+        Format_Invalid,
+        /// The image is stored using 1-bit per pixel. Bytes are packed with the most significant bit (MSB) first.
+        Format_Mono,
+        /// The image is stored using 1-bit per pixel. Bytes are packed with the less significant bit (LSB) first.
+        Format_MonoLSB,
+        /// The image is stored using 8-bit indexes into a colormap.
+        Format_Indexed8,
+        /// The image is stored using a 32-bit RGB format (0xffRRGGBB).
+        Format_RGB32,
+        /// The image is stored using a 32-bit ARGB format (0xAARRGGBB).
+        Format_ARGB32,
+        /// The image is stored using a premultiplied 32-bit ARGB format (0xAARRGGBB).
+        Format_ARGB32_Premultiplied,
+        /// The image is stored using a 16-bit RGB format (5-6-5).
+        Format_RGB16,
+        /// The image is stored using a premultiplied 24-bit ARGB format (8-5-6-5).
+        Format_ARGB8565_Premultiplied,
+        /// The image is stored using a 24-bit RGB format (6-6-6).
+        Format_RGB666,
+        /// The image is stored using a premultiplied 24-bit ARGB format (6-6-6-6).
+        Format_ARGB6666_Premultiplied,
+        /// The image is stored using a 16-bit RGB format (5-5-5).
+        Format_RGB555,
+        /// The image is stored using a premultiplied 24-bit ARGB format (8-5-5-5).
+        Format_ARGB8555_Premultiplied,
+        /// The image is stored using a 24-bit RGB format (8-8-8).
+        Format_RGB888,
+        /// The image is stored using a 16-bit RGB format (4-4-4).
+        Format_RGB444,
+        /// The image is stored using a premultiplied 16-bit ARGB format (4-4-4-4).
+        Format_ARGB4444_Premultiplied,
+        /// The image is stored using a 32-bit byte-ordered RGB(x) format (8-8-8-8).
+        Format_RGBX8888,
+        /// The image is stored using a 32-bit byte-ordered RGBA format (8-8-8-8).
+        Format_RGBA8888,
+        /// The image is stored using a 32-bit byte-ordered premultiplied RGBA format (8-8-8-8).
+        Format_RGBA8888_Premultiplied,
+        /// The image is stored using a 32-bit BGR format (x-10-10-10).
+        Format_BGR30,
+        /// The image is stored using a 32-bit premultiplied ABGR format (2-10-10-10).
+        Format_A2BGR30_Premultiplied,
+        /// The image is stored using a 32-bit RGB format (x-10-10-10).
+        Format_RGB30,
+        /// The image is stored using a 32-bit premultiplied ARGB format (2-10-10-10).
+        Format_A2RGB30_Premultiplied,
+        /// The image is stored using an 8-bit alpha only format.
+        Format_Alpha8,
+        /// The image is stored using an 8-bit grayscale format.
+        Format_Grayscale8,
+        /// The image is stored using a 64-bit halfword-ordered RGB(x) format (16-16-16-16).
+        Format_RGBX64,
+        /// The image is stored using a 64-bit halfword-ordered RGBA format (16-16-16-16).
+        Format_RGBA64,
+        /// The image is stored using a 64-bit halfword-ordered premultiplied RGBA format (16-16-16-16).
+        Format_RGBA64_Premultiplied,
+        /// The image is stored using an 16-bit grayscale format.
+        Format_Grayscale16,
+        /// The image is stored using a 24-bit BGR format.
+        Format_BGR888,
+    }
+
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type AspectRatioMode = crate::AspectRatioMode;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qcolor.h");
+        type QColor = crate::QColor;
+        include!("cxx-qt-lib/qrect.h");
+        type QRect = crate::QRect;
+        include!("cxx-qt-lib/qsize.h");
+        type QSize = crate::QSize;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qimage.h");
+        type QImage = super::QImage;
+
+        /// Fills the entire image with the given color.
+        fn fill(self: &mut QImage, color: &QColor);
+
+        /// Returns the format of the image.
+        fn format(self: &QImage) -> QImageFormat;
+
+        /// Returns true if the image has a format that respects the alpha channel, otherwise returns false.
+        #[rust_name = "has_alpha_channel"]
+        fn hasAlphaChannel(self: &QImage) -> bool;
+
+        /// Returns the height of the image.
+        fn height(self: &QImage) -> i32;
+
+        /// For 32-bit images, this function is equivalent to allGray().
+        /// For color indexed images, this function returns true if color(i) is shades of gray for all indexes of the color table.
+        #[rust_name = "is_grayscale"]
+        fn isGrayscale(self: &QImage) -> bool;
+
+        /// Returns true if it is a null image, otherwise returns false.
+        ///
+        /// A null image has all parameters set to zero and no allocated data.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QImage) -> bool;
+
+        /// Returns the color of the pixel at coordinates (x, y) as a 32-bit ARGB value (0xAARRGGBB).
+        ///
+        /// If the position is not valid, the result is undefined.
+        fn pixel(self: &QImage, x: i32, y: i32) -> u32;
+
+        /// Returns the color of the pixel at coordinates (x, y) as a QColor.
+        ///
+        /// If the position is not valid, an invalid QColor is returned.
+        #[rust_name = "pixel_color"]
+        fn pixelColor(self: &QImage, x: i32, y: i32) -> QColor;
+
+        /// Returns the enclosing rectangle (0, 0, width(), height()) of the image.
+        fn rect(self: &QImage) -> QRect;
+
+        /// Sets the pixel index or color at (x, y) to index_or_rgb.
+        ///
+        /// If the image's format is either monochrome or paletted, the given index_or_rgb value must be an index in the image's color table,
+        /// otherwise the parameter must be a 32-bit ARGB value (0xAARRGGBB).
+        #[rust_name = "set_pixel"]
+        fn setPixel(self: &mut QImage, x: i32, y: i32, index_or_rgb: u32);
+
+        /// Sets the color at (x, y) to color.
+        ///
+        /// If the position is not valid or the image is null, this function does nothing.
+        #[rust_name = "set_pixel_color"]
+        fn setPixelColor(self: &mut QImage, x: i32, y: i32, color: &QColor);
+
+        /// Returns the size of the image, i.e. its width() and height().
+        fn size(self: &QImage) -> QSize;
+
+        /// Returns the width of the image.
+        fn width(self: &QImage) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QImageBuffer;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QImageFormat;
+
+        #[doc(hidden)]
+        #[rust_name = "qimage_as_bytes"]
+        fn qimageAsBytes(image: &QImage) -> &[u8];
+        #[doc(hidden)]
+        #[rust_name = "qimage_bytes_per_line"]
+        fn qimageBytesPerLine(image: &QImage) -> isize;
+        #[doc(hidden)]
+        #[rust_name = "qimage_convert_to_format"]
+        fn qimageConvertToFormat(image: &QImage, format: QImageFormat) -> QImage;
+        #[doc(hidden)]
+        #[rust_name = "qimage_init_from_buffer"]
+        unsafe fn qimageInitFromBuffer(
+            buffer: Box<QImageBuffer>,
+            data: *mut u8,
+            width: i32,
+            height: i32,
+            bytes_per_line: i32,
+            format: QImageFormat,
+        ) -> QImage;
+        #[doc(hidden)]
+        #[rust_name = "qimage_init_from_data"]
+        fn qimageInitFromData(data: &[u8], format: &str) -> QImage;
+        #[doc(hidden)]
+        #[rust_name = "qimage_save"]
+        fn qimageSave(image: &QImage, file_name: &QString) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qimage_save_to_data"]
+        fn qimageSaveToData(image: &QImage, data: &mut QByteArray, format: &str) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qimage_scaled"]
+        fn qimageScaled(image: &QImage, width: i32, height: i32, mode: AspectRatioMode) -> QImage;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qimage_drop"]
+        fn drop(image: &mut QImage);
+
+        #[doc(hidden)]
+        #[rust_name = "qimage_init_default"]
+        fn construct() -> QImage;
+        #[doc(hidden)]
+        #[rust_name = "qimage_init_from_file"]
+        fn construct(file_name: &QString) -> QImage;
+        #[doc(hidden)]
+        #[rust_name = "qimage_init_from_qimage"]
+        fn construct(image: &QImage) -> QImage;
+        #[doc(hidden)]
+        #[rust_name = "qimage_init_from_size"]
+        fn construct(width: i32, height: i32, format: QImageFormat) -> QImage;
+
+        #[doc(hidden)]
+        #[rust_name = "qimage_eq"]
+        fn operatorEq(a: &QImage, b: &QImage) -> bool;
+    }
+}
+
+pub use ffi::QImageFormat;
+
+/// The QImage class provides a hardware-independent image representation
+/// that allows direct access to the pixel data, and can be used as a paint device.
+#[repr(C)]
+pub struct QImage {
+    _space: MaybeUninit<[usize; 4]>,
+}
+
+/// The pixel data of a [QImage] which has been constructed from a Rust buffer.
+///
+/// This is dropped by Qt once the last copy of the image using the buffer is destroyed.
+#[doc(hidden)]
+pub struct QImageBuffer {
+    _data: Vec<u8>,
+}
+
+impl QImage {
+    /// Constructs an image with the given width, height and format.
+    ///
+    /// A null image will be returned if memory cannot be allocated.
+    pub fn new(width: i32, height: i32, format: QImageFormat) -> Self {
+        ffi::qimage_init_from_size(width, height, format)
+    }
+
+    /// Returns the pixel data of the image, each scan line is [QImage::bytes_per_line] long.
+    ///
+    /// A null image returns an empty slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        ffi::qimage_as_bytes(self)
+    }
+
+    /// Returns the number of bytes per image scanline.
+    pub fn bytes_per_line(&self) -> isize {
+        ffi::qimage_bytes_per_line(self)
+    }
+
+    /// Returns a copy of the image in the given format.
+    pub fn convert_to_format(&self, format: QImageFormat) -> Self {
+        ffi::qimage_convert_to_format(self, format)
+    }
+
+    /// Constructs an image with the given width, height and format, that uses the given buffer of pixel data.
+    ///
+    /// The buffer is not copied, instead it is moved into the image and dropped when the last copy
+    /// of the image is destroyed. Each scan line of the buffer must be bytes_per_line long.
+    ///
+    /// If the buffer is too small or the dimensions are invalid, [None] is returned.
+    pub fn from_buffer(
+        mut buffer: Vec<u8>,
+        width: i32,
+        height: i32,
+        bytes_per_line: i32,
+        format: QImageFormat,
+    ) -> Option<Self> {
+        if width <= 0 || height <= 0 || bytes_per_line <= 0 {
+            return None;
+        }
+
+        let required_len = (bytes_per_line as usize).checked_mul(height as usize)?;
+        if buffer.len() < required_len {
+            return None;
+        }
+
+        let data = buffer.as_mut_ptr();
+        // Safety: the pointer is to the heap allocation of the buffer, which does not move
+        // when the Vec is moved, and the buffer is kept alive for as long as the image uses it.
+        let image = unsafe {
+            ffi::qimage_init_from_buffer(
+                Box::new(QImageBuffer { _data: buffer }),
+                data,
+                width,
+                height,
+                bytes_per_line,
+                format,
+            )
+        };
+        if image.is_null() {
+            None
+        } else {
+            Some(image)
+        }
+    }
+
+    /// Constructs an image from the given encoded image data, such as a PNG or JPEG file.
+    ///
+    /// If format is [None] the loader attempts to detect the format from the header of the data.
+    /// If the image could not be loaded, [None] is returned.
+    pub fn from_data(data: &[u8], format: Option<&str>) -> Option<Self> {
+        let image = ffi::qimage_init_from_data(data, format.unwrap_or_default());
+        if image.is_null() {
+            None
+        } else {
+            Some(image)
+        }
+    }
+
+    /// Constructs an image by loading the image from the file with the given file name.
+    ///
+    /// If the image could not be loaded, [None] is returned.
+    pub fn from_file(file_name: &ffi::QString) -> Option<Self> {
+        let image = ffi::qimage_init_from_file(file_name);
+        if image.is_null() {
+            None
+        } else {
+            Some(image)
+        }
+    }
+
+    /// Constructs an image from a buffer of 8-bit RGBA pixels, without copying the buffer.
+    ///
+    /// This is the same as [QImage::from_buffer] using [QImageFormat::Format_RGBA8888].
+    pub fn from_rgba8(buffer: Vec<u8>, width: i32, height: i32) -> Option<Self> {
+        let bytes_per_line = width.checked_mul(4)?;
+        Self::from_buffer(
+            buffer,
+            width,
+            height,
+            bytes_per_line,
+            QImageFormat::Format_RGBA8888,
+        )
+    }
+
+    /// Saves the image to the file with the given file name, the format is deduced from the suffix.
+    ///
+    /// Returns true if the image was successfully saved; otherwise returns false.
+    pub fn save(&self, file_name: &ffi::QString) -> bool {
+        ffi::qimage_save(self, file_name)
+    }
+
+    /// Encodes the image in the given format, such as "PNG" or "JPG".
+    ///
+    /// If the image could not be encoded, [None] is returned.
+    pub fn save_to_data(&self, format: &str) -> Option<QByteArray> {
+        let mut data = QByteArray::default();
+        if ffi::qimage_save_to_data(self, &mut data, format) {
+            Some(data)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a copy of the image scaled to a rectangle defined by the given width and height
+    /// according to the given aspect ratio mode, using smooth transformation.
+    pub fn scaled(&self, width: i32, height: i32, mode: ffi::AspectRatioMode) -> Self {
+        ffi::qimage_scaled(self, width, height, mode)
+    }
+}
+
+impl Clone for QImage {
+    /// Constructs a shallow copy of the given image.
+    fn clone(&self) -> Self {
+        ffi::qimage_init_from_qimage(self)
+    }
+}
+
+impl Default for QImage {
+    /// Constructs a null image.
+    fn default() -> Self {
+        ffi::qimage_init_default()
+    }
+}
+
+impl Drop for QImage {
+    /// Destroys the image and cleans up.
+    fn drop(&mut self) {
+        ffi::qimage_drop(self)
+    }
+}
+
+impl std::cmp::PartialEq for QImage {
+    /// Returns true if this image and the given image have the same contents; otherwise returns false.
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qimage_eq(self, other)
+    }
+}
+
+#[cfg(feature = "image")]
+impl From<image::RgbaImage> for QImage {
+    /// Constructs an image which uses the pixel buffer of the given image without copying it.
+    ///
+    /// An empty image results in a null QImage.
+    fn from(value: image::RgbaImage) -> Self {
+        let (width, height) = value.dimensions();
+        Self::from_rgba8(value.into_raw(), width as i32, height as i32).unwrap_or_default()
+    }
+}
+
+#[cfg(feature = "image")]
+impl From<&image::RgbaImage> for QImage {
+    fn from(value: &image::RgbaImage) -> Self {
+        Self::from(value.clone())
+    }
+}
+
+#[cfg(feature = "image")]
+impl TryFrom<&QImage> for image::RgbaImage {
+    type Error = &'static str;
+
+    fn try_from(value: &QImage) -> Result<Self, Self::Error> {
+        if value.is_null() {
+            return Err("QImage is null");
+        }
+
+        let converted = value.convert_to_format(QImageFormat::Format_RGBA8888);
+        let width = converted.width() as usize;
+        let bytes_per_line = converted.bytes_per_line() as usize;
+        let data = converted
+            .as_bytes()
+            .chunks(bytes_per_line)
+            .flat_map(|line| &line[..width * 4])
+            .copied()
+            .collect();
+        image::RgbaImage::from_raw(converted.width() as u32, converted.height() as u32, data)
+            .ok_or("QImage could not be converted to an image::RgbaImage")
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QImage {
+    type Id = type_id!("QImage");
+    type Kind = cxx::kind::Trivial;
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "image")]
+    use super::*;
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn test_image() {
+        let rgba = image::RgbaImage::from_fn(2, 3, |x, y| image::Rgba([x as u8, y as u8, 0, 255]));
+
+        let qimage = QImage::from(&rgba);
+        assert_eq!(qimage.width(), 2);
+        assert_eq!(qimage.height(), 3);
+        assert_eq!(qimage.pixel(1, 2), 0xFF010200);
+
+        let roundtrip = image::RgbaImage::try_from(&qimage).unwrap();
+        assert_eq!(roundtrip, rgba);
+    }
+}
@@ -0,0 +1,41 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_GUI_FEATURE
+#include "cxx-qt-lib/qmatrix4x4.h"
+
+#include "../assertion_utils.h"
+
+// QMatrix4x4 has a float[4][4] member in column-major order and an int flagBits
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/math3d/qmatrix4x4.h?h=v5.15.6-lts-lgpl
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/math3d/qmatrix4x4.h?h=v6.2.4
+assert_alignment_and_size(QMatrix4x4,
+                          alignof(float),
+                          sizeof(float[4][4]) + sizeof(int));
+
+static_assert(::std::is_trivially_copyable<QMatrix4x4>::value,
+              "QMatrix4x4 should be trivially copyable");
+
+namespace rust {
+namespace cxxqtlib1 {
+
+double
+qmatrix4x4Determinant(const QMatrix4x4& matrix)
+{
+  return static_cast<double>(matrix.determinant());
+}
+
+QMatrix4x4
+qmatrix4x4Inverted(const QMatrix4x4& matrix, bool& invertible)
+{
+  return matrix.inverted(&invertible);
+}
+
+}
+}
+#endif
@@ -0,0 +1,468 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use std::fmt;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpoint.h");
+        type QPoint = crate::QPoint;
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = crate::QPointF;
+        include!("cxx-qt-lib/qquaternion.h");
+        type QQuaternion = crate::QQuaternion;
+        include!("cxx-qt-lib/qrect.h");
+        type QRect = crate::QRect;
+        include!("cxx-qt-lib/qrectf.h");
+        type QRectF = crate::QRectF;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qtransform.h");
+        type QTransform = crate::QTransform;
+        include!("cxx-qt-lib/qvector3d.h");
+        type QVector3D = crate::QVector3D;
+        include!("cxx-qt-lib/qvector4d.h");
+        type QVector4D = crate::QVector4D;
+
+        include!("cxx-qt-lib/qmatrix4x4.h");
+        type QMatrix4x4 = super::QMatrix4x4;
+
+        /// Returns the elements of column index as a 4D vector.
+        fn column(self: &QMatrix4x4, index: i32) -> QVector4D;
+
+        /// Fills all elements of this matrix with value.
+        fn fill(self: &mut QMatrix4x4, value: f32);
+
+        /// Multiplies this matrix by another that applies a perspective frustum projection for a window with lower-left corner (left, bottom),
+        /// upper-right corner (right, top), and the specified near_plane and far_plane clipping planes.
+        fn frustum(
+            self: &mut QMatrix4x4,
+            left: f32,
+            right: f32,
+            bottom: f32,
+            top: f32,
+            near_plane: f32,
+            far_plane: f32,
+        );
+
+        /// Returns true if this matrix is affine matrix; false otherwise.
+        ///
+        /// An affine matrix is a 4x4 matrix with row 3 equal to (0, 0, 0, 1), e.g. no projective coefficients.
+        #[rust_name = "is_affine"]
+        fn isAffine(self: &QMatrix4x4) -> bool;
+
+        /// Returns true if this matrix is the identity; false otherwise.
+        #[rust_name = "is_identity"]
+        fn isIdentity(self: &QMatrix4x4) -> bool;
+
+        /// Multiplies this matrix by a viewing matrix derived from an eye point.
+        ///
+        /// The center value indicates the center of the view that the eye is looking at.
+        /// The up value indicates which direction should be considered up with respect to the eye.
+        #[rust_name = "look_at"]
+        fn lookAt(self: &mut QMatrix4x4, eye: &QVector3D, center: &QVector3D, up: &QVector3D);
+
+        /// Maps point by multiplying this matrix by point.
+        /// The matrix is applied pre-point.
+        #[cxx_name = "map"]
+        fn map_point(self: &QMatrix4x4, point: &QPoint) -> QPoint;
+
+        /// Maps point by multiplying this matrix by point.
+        /// The matrix is applied pre-point.
+        #[cxx_name = "map"]
+        fn map_pointf(self: &QMatrix4x4, point: &QPointF) -> QPointF;
+
+        /// Maps rect by multiplying this matrix by the corners of rect and then forming a new rectangle from the results.
+        /// The returned rectangle will be an ordinary 2D rectangle with sides parallel to the horizontal and vertical axes.
+        #[rust_name = "map_rect"]
+        fn mapRect(self: &QMatrix4x4, rect: &QRect) -> QRect;
+
+        /// Maps rect by multiplying this matrix by the corners of rect and then forming a new rectangle from the results.
+        /// The returned rectangle will be an ordinary 2D rectangle with sides parallel to the horizontal and vertical axes.
+        #[cxx_name = "mapRect"]
+        fn map_rectf(self: &QMatrix4x4, rect: &QRectF) -> QRectF;
+
+        /// Maps point by multiplying this matrix by point extended to a 4D vector by assuming 1.0 for the w coordinate.
+        /// The matrix is applied pre-point.
+        #[cxx_name = "map"]
+        fn map_vector_3d(self: &QMatrix4x4, point: &QVector3D) -> QVector3D;
+
+        /// Maps point by multiplying this matrix by point.
+        /// The matrix is applied pre-point.
+        #[cxx_name = "map"]
+        fn map_vector_4d(self: &QMatrix4x4, point: &QVector4D) -> QVector4D;
+
+        /// Maps vector by multiplying the top 3x3 portion of this matrix by vector.
+        /// The translation and projection components of this matrix are ignored.
+        #[rust_name = "map_vector"]
+        fn mapVector(self: &QMatrix4x4, vector: &QVector3D) -> QVector3D;
+
+        /// Optimize the usage of this matrix from its current elements.
+        ///
+        /// Some operations such as translate(), scale(), and rotate() can be performed more efficiently
+        /// if the matrix being modified is already known to be the identity, a previous translate(), a previous scale(), etc.
+        fn optimize(self: &mut QMatrix4x4);
+
+        /// Multiplies this matrix by another that applies an orthographic projection for a window with lower-left corner (left, bottom),
+        /// upper-right corner (right, top), and the specified near_plane and far_plane clipping planes.
+        fn ortho(
+            self: &mut QMatrix4x4,
+            left: f32,
+            right: f32,
+            bottom: f32,
+            top: f32,
+            near_plane: f32,
+            far_plane: f32,
+        );
+
+        /// Multiplies this matrix by another that applies a perspective projection.
+        ///
+        /// The vertical field of view will be vertical_angle degrees within a window with a given aspect_ratio that determines the horizontal field of view.
+        /// The projection will have the specified near_plane and far_plane clipping planes which are the distances from the viewer to the corresponding planes.
+        fn perspective(
+            self: &mut QMatrix4x4,
+            vertical_angle: f32,
+            aspect_ratio: f32,
+            near_plane: f32,
+            far_plane: f32,
+        );
+
+        /// Multiples this matrix by another that rotates coordinates through angle degrees about vector.
+        fn rotate(self: &mut QMatrix4x4, angle: f32, vector: &QVector3D);
+
+        /// Multiples this matrix by another that rotates coordinates according to a specified quaternion.
+        /// The quaternion is assumed to have been normalized.
+        #[cxx_name = "rotate"]
+        fn rotate_quaternion(self: &mut QMatrix4x4, quaternion: &QQuaternion);
+
+        /// Returns the elements of row index as a 4D vector.
+        fn row(self: &QMatrix4x4, index: i32) -> QVector4D;
+
+        /// Multiplies this matrix by another that scales coordinates by the components of vector.
+        fn scale(self: &mut QMatrix4x4, vector: &QVector3D);
+
+        /// Multiplies this matrix by another that scales coordinates by the given factor.
+        #[cxx_name = "scale"]
+        fn scale_factor(self: &mut QMatrix4x4, factor: f32);
+
+        /// Sets the elements of column index to the components of value.
+        #[rust_name = "set_column"]
+        fn setColumn(self: &mut QMatrix4x4, index: i32, value: &QVector4D);
+
+        /// Sets the elements of row index to the components of value.
+        #[rust_name = "set_row"]
+        fn setRow(self: &mut QMatrix4x4, index: i32, value: &QVector4D);
+
+        /// Sets this matrix to the identity.
+        #[rust_name = "set_to_identity"]
+        fn setToIdentity(self: &mut QMatrix4x4);
+
+        /// Returns the conventional Qt 2D transformation matrix that corresponds to this matrix.
+        ///
+        /// The returned QTransform is formed by simply dropping the third row and third column of the QMatrix4x4.
+        /// This is suitable for implementing orthographic projections where the z coordinate should be dropped rather than projected.
+        #[rust_name = "to_transform"]
+        fn toTransform(self: &QMatrix4x4) -> QTransform;
+
+        /// Multiplies this matrix by another that translates coordinates by the components of vector.
+        fn translate(self: &mut QMatrix4x4, vector: &QVector3D);
+
+        /// Returns this matrix, transposed about its diagonal.
+        fn transposed(self: &QMatrix4x4) -> QMatrix4x4;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_determinant"]
+        fn qmatrix4x4Determinant(matrix: &QMatrix4x4) -> f64;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_inverted"]
+        fn qmatrix4x4Inverted(matrix: &QMatrix4x4, invertible: &mut bool) -> QMatrix4x4;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_init_default"]
+        fn construct() -> QMatrix4x4;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_init"]
+        #[allow(clippy::too_many_arguments)]
+        fn construct(
+            m11: f32,
+            m12: f32,
+            m13: f32,
+            m14: f32,
+            m21: f32,
+            m22: f32,
+            m23: f32,
+            m24: f32,
+            m31: f32,
+            m32: f32,
+            m33: f32,
+            m34: f32,
+            m41: f32,
+            m42: f32,
+            m43: f32,
+            m44: f32,
+        ) -> QMatrix4x4;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_init_qtransform"]
+        fn construct(transform: &QTransform) -> QMatrix4x4;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_eq"]
+        fn operatorEq(a: &QMatrix4x4, b: &QMatrix4x4) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_to_qstring"]
+        fn toQString(value: &QMatrix4x4) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_plus"]
+        fn operatorPlus(a: &QMatrix4x4, b: &QMatrix4x4) -> QMatrix4x4;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_minus"]
+        fn operatorMinus(a: &QMatrix4x4, b: &QMatrix4x4) -> QMatrix4x4;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_mul"]
+        fn operatorMul(a: QMatrix4x4, b: &QMatrix4x4) -> QMatrix4x4;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_mul_f32"]
+        fn operatorMul(a: f32, b: &QMatrix4x4) -> QMatrix4x4;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_mul_qvector4d"]
+        fn operatorMul(a: QMatrix4x4, b: &QVector4D) -> QVector4D;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_div"]
+        fn operatorDiv(a: f32, b: &QMatrix4x4) -> QMatrix4x4;
+    }
+}
+
+/// The QMatrix4x4 class represents a 4x4 transformation matrix in 3D space.
+///
+/// Note that the elements are stored in column-major order.
+#[derive(Clone)]
+#[repr(C)]
+pub struct QMatrix4x4 {
+    m: [[f32; 4]; 4],
+    flag_bits: i32,
+}
+
+impl QMatrix4x4 {
+    /// Returns the elements of this matrix in column-major order, as `[column][row]`.
+    pub fn as_columns(&self) -> &[[f32; 4]; 4] {
+        &self.m
+    }
+
+    /// Returns the determinant of this matrix.
+    pub fn determinant(&self) -> f64 {
+        ffi::qmatrix4x4_determinant(self)
+    }
+
+    /// Returns the inverse of this matrix, or None if the matrix cannot be inverted.
+    pub fn inverted(&self) -> Option<Self> {
+        let mut invertible = false;
+        let inverted = ffi::qmatrix4x4_inverted(self, &mut invertible);
+        if invertible {
+            Some(inverted)
+        } else {
+            None
+        }
+    }
+
+    /// Constructs a matrix from the 16 elements m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34, m41, m42, m43, and m44.
+    ///
+    /// The elements are specified in row-major order.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        m11: f32,
+        m12: f32,
+        m13: f32,
+        m14: f32,
+        m21: f32,
+        m22: f32,
+        m23: f32,
+        m24: f32,
+        m31: f32,
+        m32: f32,
+        m33: f32,
+        m34: f32,
+        m41: f32,
+        m42: f32,
+        m43: f32,
+        m44: f32,
+    ) -> Self {
+        ffi::qmatrix4x4_init(
+            m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34, m41, m42, m43, m44,
+        )
+    }
+
+    /// Constructs a matrix from the elements in column-major order, as `[column][row]`.
+    pub fn from_columns(columns: &[[f32; 4]; 4]) -> Self {
+        let c = columns;
+        Self::new(
+            c[0][0], c[1][0], c[2][0], c[3][0], c[0][1], c[1][1], c[2][1], c[3][1], c[0][2],
+            c[1][2], c[2][2], c[3][2], c[0][3], c[1][3], c[2][3], c[3][3],
+        )
+    }
+}
+
+impl Default for QMatrix4x4 {
+    /// Constructs an identity matrix.
+    fn default() -> Self {
+        ffi::qmatrix4x4_init_default()
+    }
+}
+
+impl std::cmp::PartialEq for QMatrix4x4 {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qmatrix4x4_eq(self, other)
+    }
+}
+
+impl fmt::Display for QMatrix4x4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qmatrix4x4_to_qstring(self))
+    }
+}
+
+impl fmt::Debug for QMatrix4x4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl From<&ffi::QTransform> for QMatrix4x4 {
+    /// Constructs a 4x4 matrix from a conventional Qt 2D transformation matrix.
+    fn from(transform: &ffi::QTransform) -> Self {
+        ffi::qmatrix4x4_init_qtransform(transform)
+    }
+}
+
+impl From<&QMatrix4x4> for ffi::QTransform {
+    /// Returns the conventional Qt 2D transformation matrix that corresponds to this matrix.
+    fn from(matrix: &QMatrix4x4) -> Self {
+        matrix.to_transform()
+    }
+}
+
+impl std::ops::Add for QMatrix4x4 {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        ffi::qmatrix4x4_plus(&self, &other)
+    }
+}
+
+impl std::ops::Sub for QMatrix4x4 {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        ffi::qmatrix4x4_minus(&self, &other)
+    }
+}
+
+impl std::ops::Mul for QMatrix4x4 {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        ffi::qmatrix4x4_mul(self, &other)
+    }
+}
+
+impl std::ops::Mul<f32> for QMatrix4x4 {
+    type Output = Self;
+    fn mul(self, rhs: f32) -> Self {
+        ffi::qmatrix4x4_mul_f32(rhs, &self)
+    }
+}
+
+impl std::ops::Mul<ffi::QVector4D> for QMatrix4x4 {
+    type Output = ffi::QVector4D;
+    fn mul(self, rhs: ffi::QVector4D) -> ffi::QVector4D {
+        ffi::qmatrix4x4_mul_qvector4d(self, &rhs)
+    }
+}
+
+impl std::ops::Div<f32> for QMatrix4x4 {
+    type Output = Self;
+    fn div(self, rhs: f32) -> Self {
+        ffi::qmatrix4x4_div(rhs, &self)
+    }
+}
+
+impl std::ops::Neg for QMatrix4x4 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self * -1.0
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<&QMatrix4x4> for glam::Mat4 {
+    fn from(value: &QMatrix4x4) -> Self {
+        Self::from_cols_array_2d(value.as_columns())
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<&glam::Mat4> for QMatrix4x4 {
+    fn from(value: &glam::Mat4) -> Self {
+        Self::from_columns(&value.to_cols_array_2d())
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<&QMatrix4x4> for nalgebra::Matrix4<f32> {
+    fn from(value: &QMatrix4x4) -> Self {
+        Self::from_fn(|row, column| value.m[column][row])
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<&nalgebra::Matrix4<f32>> for QMatrix4x4 {
+    fn from(value: &nalgebra::Matrix4<f32>) -> Self {
+        let mut columns = [[0.0; 4]; 4];
+        for (column, values) in columns.iter_mut().enumerate() {
+            for (row, element) in values.iter_mut().enumerate() {
+                *element = value[(row, column)];
+            }
+        }
+        Self::from_columns(&columns)
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl<Src, Dst> From<&QMatrix4x4> for euclid::Transform3D<f32, Src, Dst> {
+    /// Converts the column vector QMatrix4x4 into a row vector [euclid::Transform3D].
+    fn from(value: &QMatrix4x4) -> Self {
+        let m = value.as_columns();
+        Self::new(
+            m[0][0], m[0][1], m[0][2], m[0][3], m[1][0], m[1][1], m[1][2], m[1][3], m[2][0],
+            m[2][1], m[2][2], m[2][3], m[3][0], m[3][1], m[3][2], m[3][3],
+        )
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl<Src, Dst> From<&euclid::Transform3D<f32, Src, Dst>> for QMatrix4x4 {
+    /// Converts the row vector [euclid::Transform3D] into a column vector QMatrix4x4.
+    fn from(value: &euclid::Transform3D<f32, Src, Dst>) -> Self {
+        Self::from_columns(&[
+            [value.m11, value.m12, value.m13, value.m14],
+            [value.m21, value.m22, value.m23, value.m24],
+            [value.m31, value.m32, value.m33, value.m34],
+            [value.m41, value.m42, value.m43, value.m44],
+        ])
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side ensure that QMatrix4x4 is trivial.
+unsafe impl ExternType for QMatrix4x4 {
+    type Id = type_id!("QMatrix4x4");
+    type Kind = cxx::kind::Trivial;
+}
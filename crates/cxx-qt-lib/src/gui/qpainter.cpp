@@ -0,0 +1,31 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_GUI_FEATURE
+#include "cxx-qt-lib/qpainter.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qpainterDrawRoundedRect(QPainter& painter,
+                        const QRectF& rect,
+                        qreal xRadius,
+                        qreal yRadius)
+{
+  painter.drawRoundedRect(rect, xRadius, yRadius);
+}
+
+void
+qpainterSetRenderHint(QPainter& painter, QPainterRenderHint hint, bool on)
+{
+  painter.setRenderHint(hint, on);
+}
+
+}
+}
+#endif
@@ -0,0 +1,196 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    /// Renderhints are used to specify flags to QPainter that may or may not be respected by any given engine.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    enum QPainterRenderHint {
+        /// Indicates that the engine should antialias edges of primitives if possible.
+        Antialiasing = 0x01,
+        /// Indicates that the engine should antialias text if possible.
+        TextAntialiasing = 0x02,
+        /// Indicates that the engine should use a smooth pixmap transformation algorithm
+        /// (such as bilinear) rather than nearest neighbor.
+        SmoothPixmapTransform = 0x04,
+        /// Use a lossless image rendering, whenever possible.
+        LosslessImageRendering = 0x40,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbrush.h");
+        type QBrush = crate::QBrush;
+        include!("cxx-qt-lib/qcolor.h");
+        type QColor = crate::QColor;
+        include!("cxx-qt-lib/qfont.h");
+        type QFont = crate::QFont;
+        include!("cxx-qt-lib/qimage.h");
+        type QImage = crate::QImage;
+        include!("cxx-qt-lib/qpainterpath.h");
+        type QPainterPath = crate::QPainterPath;
+        include!("cxx-qt-lib/qpen.h");
+        type QPen = crate::QPen;
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = crate::QPointF;
+        include!("cxx-qt-lib/qrectf.h");
+        type QRectF = crate::QRectF;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        include!("cxx-qt-lib/qpainter.h");
+        /// The QPainter class performs low-level painting on widgets and other paint devices.
+        ///
+        /// QPainter is not trivial or relocatable, so it can only be used by reference or pointer,
+        /// such as the painter given to QQuickPaintedItem::paint.
+        type QPainter;
+
+        /// Returns the painter's current brush.
+        fn brush(self: &QPainter) -> &QBrush;
+
+        /// Draws the ellipse defined by the given rectangle.
+        #[rust_name = "draw_ellipse"]
+        fn drawEllipse(self: Pin<&mut QPainter>, rectangle: &QRectF);
+
+        /// Draws the given image into the given rectangle.
+        ///
+        /// Note: The image is scaled to fit the rectangle, if both the image and rectangle size disagree.
+        #[rust_name = "draw_image"]
+        fn drawImage(self: Pin<&mut QPainter>, rectangle: &QRectF, image: &QImage);
+
+        /// Draws the given image at the given point.
+        #[rust_name = "draw_image_at_point"]
+        fn drawImage(self: Pin<&mut QPainter>, point: &QPointF, image: &QImage);
+
+        /// Draws a line from p1 to p2.
+        #[rust_name = "draw_line"]
+        fn drawLine(self: Pin<&mut QPainter>, p1: &QPointF, p2: &QPointF);
+
+        /// Draws the given painter path using the current pen for outline and the current brush for filling.
+        #[rust_name = "draw_path"]
+        fn drawPath(self: Pin<&mut QPainter>, path: &QPainterPath);
+
+        /// Draws a single point at the given position using the current pen's color.
+        #[rust_name = "draw_point"]
+        fn drawPoint(self: Pin<&mut QPainter>, position: &QPointF);
+
+        /// Draws the current rectangle with the current pen and brush.
+        #[rust_name = "draw_rect"]
+        fn drawRect(self: Pin<&mut QPainter>, rectangle: &QRectF);
+
+        /// Draws the given text with the currently defined text direction, beginning at the given position.
+        #[rust_name = "draw_text"]
+        fn drawText(self: Pin<&mut QPainter>, position: &QPointF, text: &QString);
+
+        /// Fills the given path using the given brush. The outline is not drawn.
+        #[rust_name = "fill_path"]
+        fn fillPath(self: Pin<&mut QPainter>, path: &QPainterPath, brush: &QBrush);
+
+        /// Fills the given rectangle with the color specified.
+        #[rust_name = "fill_rect"]
+        fn fillRect(self: Pin<&mut QPainter>, rectangle: &QRectF, color: &QColor);
+
+        /// Returns the currently set font used for drawing text.
+        fn font(self: &QPainter) -> &QFont;
+
+        /// Returns true if begin() has been called and end() has not yet been called; otherwise returns false.
+        #[rust_name = "is_active"]
+        fn isActive(self: &QPainter) -> bool;
+
+        /// Returns the opacity of the painter. The default value is 1.
+        fn opacity(self: &QPainter) -> f64;
+
+        /// Returns the painter's current pen.
+        fn pen(self: &QPainter) -> &QPen;
+
+        /// Restores the current painter state (pops a saved state off the stack).
+        fn restore(self: Pin<&mut QPainter>);
+
+        /// Rotates the coordinate system clockwise. The given angle parameter is in degrees.
+        fn rotate(self: Pin<&mut QPainter>, angle: f64);
+
+        /// Saves the current painter state (pushes the state onto a stack).
+        /// A save() must be followed by a corresponding restore(); the end() function unwinds the stack.
+        fn save(self: Pin<&mut QPainter>);
+
+        /// Scales the coordinate system by (sx, sy).
+        fn scale(self: Pin<&mut QPainter>, sx: f64, sy: f64);
+
+        /// Sets the painter's brush to the given brush.
+        ///
+        /// The painter's brush defines how shapes are filled.
+        #[rust_name = "set_brush"]
+        fn setBrush(self: Pin<&mut QPainter>, brush: &QBrush);
+
+        /// Sets the painter's font to the given font.
+        #[rust_name = "set_font"]
+        fn setFont(self: Pin<&mut QPainter>, font: &QFont);
+
+        /// Sets the opacity of the painter to opacity. The value should be in the range 0.0 to 1.0,
+        /// where 0.0 is fully transparent and 1.0 is fully opaque.
+        #[rust_name = "set_opacity"]
+        fn setOpacity(self: Pin<&mut QPainter>, opacity: f64);
+
+        /// Sets the painter's pen to be the given pen.
+        ///
+        /// The pen defines how to draw lines and outlines, and it also defines the text color.
+        #[rust_name = "set_pen"]
+        fn setPen(self: Pin<&mut QPainter>, pen: &QPen);
+
+        /// Draws the outline (strokes) the path path with the pen specified by pen
+        #[rust_name = "stroke_path"]
+        fn strokePath(self: Pin<&mut QPainter>, path: &QPainterPath, pen: &QPen);
+
+        /// Translates the coordinate system by the given offset.
+        fn translate(self: Pin<&mut QPainter>, offset: &QPointF);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QPainterRenderHint;
+
+        #[doc(hidden)]
+        #[rust_name = "qpainter_draw_rounded_rect"]
+        fn qpainterDrawRoundedRect(
+            painter: Pin<&mut QPainter>,
+            rect: &QRectF,
+            x_radius: f64,
+            y_radius: f64,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qpainter_set_render_hint"]
+        fn qpainterSetRenderHint(painter: Pin<&mut QPainter>, hint: QPainterRenderHint, on: bool);
+    }
+
+    // QPainter is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QPainter>::isRelocatable);
+    impl UniquePtr<QPainter> {}
+}
+
+pub use ffi::{QPainter, QPainterRenderHint};
+
+impl QPainter {
+    /// Draws the given rectangle with rounded corners.
+    ///
+    /// The xRadius and yRadius arguments specify the radii of the ellipses
+    /// defining the corners of the rounded rectangle.
+    pub fn draw_rounded_rect(
+        self: Pin<&mut Self>,
+        rect: &ffi::QRectF,
+        x_radius: f64,
+        y_radius: f64,
+    ) {
+        ffi::qpainter_draw_rounded_rect(self, rect, x_radius, y_radius)
+    }
+
+    /// Sets the given render hint on the painter if on is true; otherwise clears the render hint.
+    pub fn set_render_hint(self: Pin<&mut Self>, hint: QPainterRenderHint, on: bool) {
+        ffi::qpainter_set_render_hint(self, hint, on)
+    }
+}
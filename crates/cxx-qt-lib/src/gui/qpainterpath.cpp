@@ -0,0 +1,46 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_GUI_FEATURE
+#include "cxx-qt-lib/qpainterpath.h"
+
+#include "../assertion_utils.h"
+
+// QPainterPath has a single scoped pointer as it's member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/painting/qpainterpath.h?h=v5.15.6-lts-lgpl#n215
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/painting/qpainterpath.h?h=v6.2.4#n189
+assert_alignment_and_size(QPainterPath,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t));
+
+static_assert(!::std::is_trivially_copy_assignable<QPainterPath>::value);
+static_assert(!::std::is_trivially_copy_constructible<QPainterPath>::value);
+
+static_assert(!::std::is_trivially_destructible<QPainterPath>::value);
+
+// QPainterPath is only declared as movable from Qt 6 for binary compatibility
+// reasons, but as it is a single pointer it is relocatable in Qt 5 too.
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+static_assert(QTypeInfo<QPainterPath>::isRelocatable);
+#endif
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qpainterpathAddRoundedRect(QPainterPath& path,
+                           const QRectF& rect,
+                           qreal xRadius,
+                           qreal yRadius)
+{
+  path.addRoundedRect(rect, xRadius, yRadius);
+}
+
+}
+}
+#endif
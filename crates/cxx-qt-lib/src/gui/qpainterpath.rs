@@ -0,0 +1,231 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type FillRule = crate::FillRule;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qfont.h");
+        type QFont = crate::QFont;
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = crate::QPointF;
+        include!("cxx-qt-lib/qrectf.h");
+        type QRectF = crate::QRectF;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qpainterpath.h");
+        type QPainterPath = super::QPainterPath;
+
+        /// Creates an ellipse within the specified boundingRectangle and adds it to the painter path as a closed subpath.
+        #[rust_name = "add_ellipse"]
+        fn addEllipse(self: &mut QPainterPath, bounding_rectangle: &QRectF);
+
+        /// Adds the given path to this path as a closed subpath.
+        #[rust_name = "add_path"]
+        fn addPath(self: &mut QPainterPath, path: &QPainterPath);
+
+        /// Adds the given rectangle to this path as a closed subpath.
+        #[rust_name = "add_rect"]
+        fn addRect(self: &mut QPainterPath, rectangle: &QRectF);
+
+        /// Adds the given text to this path as a set of closed subpaths created from the font supplied.
+        /// The subpaths are positioned so that the left end of the text's baseline lies at the specified point.
+        #[rust_name = "add_text"]
+        fn addText(self: &mut QPainterPath, point: &QPointF, font: &QFont, text: &QString);
+
+        /// Creates an arc that occupies the given rectangle, beginning at the specified startAngle
+        /// and extending sweepLength degrees counter-clockwise.
+        #[rust_name = "arc_to"]
+        fn arcTo(self: &mut QPainterPath, rectangle: &QRectF, start_angle: f64, sweep_length: f64);
+
+        /// Returns the bounding rectangle of this painter path as a rectangle with floating point precision.
+        #[rust_name = "bounding_rect"]
+        fn boundingRect(self: &QPainterPath) -> QRectF;
+
+        /// Closes the current subpath by drawing a line to the beginning of the subpath,
+        /// automatically starting a new path. The current point of the new path is (0, 0).
+        #[rust_name = "close_subpath"]
+        fn closeSubpath(self: &mut QPainterPath);
+
+        /// Returns true if the given point is inside the path, otherwise returns false.
+        fn contains(self: &QPainterPath, point: &QPointF) -> bool;
+
+        /// Adds a cubic Bezier curve between the current position and the given endPoint
+        /// using the control points specified by c1, and c2.
+        #[rust_name = "cubic_to"]
+        fn cubicTo(self: &mut QPainterPath, c1: &QPointF, c2: &QPointF, end_point: &QPointF);
+
+        /// Returns the current position of the path.
+        #[rust_name = "current_position"]
+        fn currentPosition(self: &QPainterPath) -> QPointF;
+
+        /// Returns the number of path elements in the painter path.
+        #[rust_name = "element_count"]
+        fn elementCount(self: &QPainterPath) -> i32;
+
+        /// Returns the painter path's currently set fill rule.
+        #[rust_name = "fill_rule"]
+        fn fillRule(self: &QPainterPath) -> FillRule;
+
+        /// Returns a path which is the intersection of this path's fill area and p's fill area.
+        fn intersected(self: &QPainterPath, p: &QPainterPath) -> QPainterPath;
+
+        /// Returns true if either there are no elements in this path,
+        /// or if the only element is a MoveToElement; otherwise returns false.
+        #[rust_name = "is_empty"]
+        fn isEmpty(self: &QPainterPath) -> bool;
+
+        /// Returns the length of the current path.
+        fn length(self: &QPainterPath) -> f64;
+
+        /// Adds a straight line from the current position to the given endPoint.
+        /// After the line is drawn, the current position is updated to be at the end point of the line.
+        #[rust_name = "line_to"]
+        fn lineTo(self: &mut QPainterPath, end_point: &QPointF);
+
+        /// Moves the current point to the given point, implicitly starting a new subpath and closing the previous one.
+        #[rust_name = "move_to"]
+        fn moveTo(self: &mut QPainterPath, point: &QPointF);
+
+        /// Returns the point at at the percentage t of the current path.
+        /// The argument t has to be between 0 and 1.
+        #[rust_name = "point_at_percent"]
+        fn pointAtPercent(self: &QPainterPath, t: f64) -> QPointF;
+
+        /// Adds a quadratic Bezier curve between the current position and the given endPoint
+        /// with the control point specified by c.
+        #[rust_name = "quad_to"]
+        fn quadTo(self: &mut QPainterPath, c: &QPointF, end_point: &QPointF);
+
+        /// Sets the fill rule of the painter path to the given fillRule.
+        #[rust_name = "set_fill_rule"]
+        fn setFillRule(self: &mut QPainterPath, fill_rule: FillRule);
+
+        /// Returns a simplified version of this path.
+        /// This implies merging all subpaths that intersect, and returning a path containing no intersecting edges.
+        fn simplified(self: &QPainterPath) -> QPainterPath;
+
+        /// Returns a path which is p's fill area subtracted from this path's fill area.
+        fn subtracted(self: &QPainterPath, p: &QPainterPath) -> QPainterPath;
+
+        /// Returns a copy of the path that is translated by the given offset.
+        fn translated(self: &QPainterPath, offset: &QPointF) -> QPainterPath;
+
+        /// Returns a path which is the union of this path's fill area and p's fill area.
+        fn united(self: &QPainterPath, p: &QPainterPath) -> QPainterPath;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qpainterpath_add_rounded_rect"]
+        fn qpainterpathAddRoundedRect(
+            path: &mut QPainterPath,
+            rect: &QRectF,
+            x_radius: f64,
+            y_radius: f64,
+        );
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qpainterpath_drop"]
+        fn drop(path: &mut QPainterPath);
+
+        #[doc(hidden)]
+        #[rust_name = "qpainterpath_init_default"]
+        fn construct() -> QPainterPath;
+        #[doc(hidden)]
+        #[rust_name = "qpainterpath_init_from_qpainterpath"]
+        fn construct(path: &QPainterPath) -> QPainterPath;
+        #[doc(hidden)]
+        #[rust_name = "qpainterpath_init_from_qpointf"]
+        fn construct(start_point: &QPointF) -> QPainterPath;
+
+        #[doc(hidden)]
+        #[rust_name = "qpainterpath_eq"]
+        fn operatorEq(a: &QPainterPath, b: &QPainterPath) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qpainterpath_to_qstring"]
+        fn toQString(value: &QPainterPath) -> QString;
+    }
+}
+
+/// The QPainterPath class provides a container for painting operations,
+/// enabling graphical shapes to be constructed and reused.
+#[repr(C)]
+pub struct QPainterPath {
+    _space: MaybeUninit<usize>,
+}
+
+impl QPainterPath {
+    /// Adds the given rectangle with rounded corners to the path.
+    ///
+    /// The xRadius and yRadius arguments specify the radii of the ellipses
+    /// defining the corners of the rounded rectangle.
+    pub fn add_rounded_rect(&mut self, rect: &ffi::QRectF, x_radius: f64, y_radius: f64) {
+        ffi::qpainterpath_add_rounded_rect(self, rect, x_radius, y_radius)
+    }
+}
+
+impl Clone for QPainterPath {
+    /// Creates a QPainterPath object that is a copy of the given path.
+    fn clone(&self) -> Self {
+        ffi::qpainterpath_init_from_qpainterpath(self)
+    }
+}
+
+impl Default for QPainterPath {
+    /// Constructs an empty QPainterPath object.
+    fn default() -> Self {
+        ffi::qpainterpath_init_default()
+    }
+}
+
+impl Drop for QPainterPath {
+    /// Destroys this QPainterPath object.
+    fn drop(&mut self) {
+        ffi::qpainterpath_drop(self)
+    }
+}
+
+impl From<&ffi::QPointF> for QPainterPath {
+    /// Creates a QPainterPath object with the given startPoint as its current position.
+    fn from(start_point: &ffi::QPointF) -> Self {
+        ffi::qpainterpath_init_from_qpointf(start_point)
+    }
+}
+
+impl std::cmp::PartialEq for QPainterPath {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qpainterpath_eq(self, other)
+    }
+}
+
+impl fmt::Debug for QPainterPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qpainterpath_to_qstring(self))
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QPainterPath {
+    type Id = type_id!("QPainterPath");
+    type Kind = cxx::kind::Trivial;
+}
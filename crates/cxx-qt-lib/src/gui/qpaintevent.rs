@@ -0,0 +1,29 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qrect.h");
+        type QRect = crate::QRect;
+
+        include!("cxx-qt-lib/qpaintevent.h");
+        /// The QPaintEvent class contains event parameters for paint events.
+        ///
+        /// Paint events are sent to widgets that need to update themselves,
+        /// such as the event given to QWidget::paintEvent.
+        type QPaintEvent;
+
+        /// Returns the rectangle that needs to be updated.
+        fn rect(self: &QPaintEvent) -> &QRect;
+    }
+
+    // QPaintEvent is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QPaintEvent>::isRelocatable);
+}
+
+pub use ffi::QPaintEvent;
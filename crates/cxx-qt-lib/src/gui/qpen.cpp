@@ -0,0 +1,25 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_GUI_FEATURE
+#include "cxx-qt-lib/qpen.h"
+
+#include "../assertion_utils.h"
+
+// QPen has a single pointer as it's member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/painting/qpen.h?h=v5.15.6-lts-lgpl#n132
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/painting/qpen.h?h=v6.2.4#n117
+assert_alignment_and_size(QPen, alignof(::std::size_t), sizeof(::std::size_t));
+
+static_assert(!::std::is_trivially_copy_assignable<QPen>::value);
+static_assert(!::std::is_trivially_copy_constructible<QPen>::value);
+
+static_assert(!::std::is_trivially_destructible<QPen>::value);
+
+static_assert(QTypeInfo<QPen>::isRelocatable);
+#endif
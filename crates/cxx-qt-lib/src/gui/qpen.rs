@@ -0,0 +1,224 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type PenCapStyle = crate::PenCapStyle;
+        type PenJoinStyle = crate::PenJoinStyle;
+        type PenStyle = crate::PenStyle;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbrush.h");
+        type QBrush = crate::QBrush;
+        include!("cxx-qt-lib/qcolor.h");
+        type QColor = crate::QColor;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qpen.h");
+        type QPen = super::QPen;
+
+        /// Returns the brush used to fill strokes generated with this pen.
+        fn brush(self: &QPen) -> QBrush;
+
+        /// Returns the pen's cap style.
+        #[rust_name = "cap_style"]
+        fn capStyle(self: &QPen) -> PenCapStyle;
+
+        /// Returns the color of this pen's brush.
+        fn color(self: &QPen) -> QColor;
+
+        /// Returns true if the pen is cosmetic; otherwise returns false.
+        ///
+        /// Cosmetic pens are used to draw strokes that have a constant width regardless
+        /// of any transformations applied to the QPainter they are used with.
+        #[rust_name = "is_cosmetic"]
+        fn isCosmetic(self: &QPen) -> bool;
+
+        /// Returns true if the pen has a solid fill, otherwise false.
+        #[rust_name = "is_solid"]
+        fn isSolid(self: &QPen) -> bool;
+
+        /// Returns the pen's join style.
+        #[rust_name = "join_style"]
+        fn joinStyle(self: &QPen) -> PenJoinStyle;
+
+        /// Returns the miter limit of the pen.
+        /// The miter limit is only relevant when the join style is set to Qt::MiterJoin.
+        #[rust_name = "miter_limit"]
+        fn miterLimit(self: &QPen) -> f64;
+
+        /// Sets the brush used to fill strokes generated with this pen to the given brush.
+        #[rust_name = "set_brush"]
+        fn setBrush(self: &mut QPen, brush: &QBrush);
+
+        /// Sets the pen's cap style to the given style. The default value is Qt::SquareCap.
+        #[rust_name = "set_cap_style"]
+        fn setCapStyle(self: &mut QPen, style: PenCapStyle);
+
+        /// Sets the color of this pen's brush to the given color.
+        #[rust_name = "set_color"]
+        fn setColor(self: &mut QPen, color: &QColor);
+
+        /// Sets this pen to cosmetic or non-cosmetic, depending on the value of cosmetic.
+        #[rust_name = "set_cosmetic"]
+        fn setCosmetic(self: &mut QPen, cosmetic: bool);
+
+        /// Sets the pen's join style to the given style. The default value is Qt::BevelJoin.
+        #[rust_name = "set_join_style"]
+        fn setJoinStyle(self: &mut QPen, style: PenJoinStyle);
+
+        /// Sets the miter limit of this pen to the given limit.
+        #[rust_name = "set_miter_limit"]
+        fn setMiterLimit(self: &mut QPen, limit: f64);
+
+        /// Sets the pen style to the given style.
+        #[rust_name = "set_style"]
+        fn setStyle(self: &mut QPen, style: PenStyle);
+
+        /// Sets the pen width to the given width in pixels with integer precision.
+        ///
+        /// A line width of zero indicates a cosmetic pen.
+        #[rust_name = "set_width"]
+        fn setWidth(self: &mut QPen, width: i32);
+
+        /// Sets the pen width to the given width in pixels with floating point precision.
+        ///
+        /// A line width of zero indicates a cosmetic pen.
+        #[rust_name = "set_width_f"]
+        fn setWidthF(self: &mut QPen, width: f64);
+
+        /// Returns the pen style.
+        fn style(self: &QPen) -> PenStyle;
+
+        /// Returns the pen width with integer precision.
+        fn width(self: &QPen) -> i32;
+
+        /// Returns the pen width with floating point precision.
+        #[rust_name = "width_f"]
+        fn widthF(self: &QPen) -> f64;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qpen_drop"]
+        fn drop(pen: &mut QPen);
+
+        #[doc(hidden)]
+        #[rust_name = "qpen_init_default"]
+        fn construct() -> QPen;
+        #[doc(hidden)]
+        #[rust_name = "qpen_init_from_brush"]
+        fn construct(
+            brush: &QBrush,
+            width: f64,
+            style: PenStyle,
+            cap: PenCapStyle,
+            join: PenJoinStyle,
+        ) -> QPen;
+        #[doc(hidden)]
+        #[rust_name = "qpen_init_from_qcolor"]
+        fn construct(color: &QColor) -> QPen;
+        #[doc(hidden)]
+        #[rust_name = "qpen_init_from_qpen"]
+        fn construct(pen: &QPen) -> QPen;
+        #[doc(hidden)]
+        #[rust_name = "qpen_init_from_style"]
+        fn construct(style: PenStyle) -> QPen;
+
+        #[doc(hidden)]
+        #[rust_name = "qpen_eq"]
+        fn operatorEq(a: &QPen, b: &QPen) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qpen_to_qstring"]
+        fn toQString(value: &QPen) -> QString;
+    }
+}
+
+/// The QPen class defines how a QPainter should draw lines and outlines of shapes.
+#[repr(C)]
+pub struct QPen {
+    _space: MaybeUninit<usize>,
+}
+
+impl QPen {
+    /// Constructs a pen with the specified brush, width, pen style, cap style and join style.
+    pub fn new(
+        brush: &ffi::QBrush,
+        width: f64,
+        style: ffi::PenStyle,
+        cap: ffi::PenCapStyle,
+        join: ffi::PenJoinStyle,
+    ) -> Self {
+        ffi::qpen_init_from_brush(brush, width, style, cap, join)
+    }
+}
+
+impl Clone for QPen {
+    /// Constructs a pen that is a copy of the given pen.
+    fn clone(&self) -> Self {
+        ffi::qpen_init_from_qpen(self)
+    }
+}
+
+impl Default for QPen {
+    /// Constructs a default black solid line pen with 1 width.
+    fn default() -> Self {
+        ffi::qpen_init_default()
+    }
+}
+
+impl Drop for QPen {
+    /// Destroys the pen.
+    fn drop(&mut self) {
+        ffi::qpen_drop(self)
+    }
+}
+
+impl From<&ffi::QColor> for QPen {
+    /// Constructs a solid line pen with 1 width and the given color.
+    fn from(color: &ffi::QColor) -> Self {
+        ffi::qpen_init_from_qcolor(color)
+    }
+}
+
+impl From<ffi::PenStyle> for QPen {
+    /// Constructs a black pen with 1 width and the given style.
+    fn from(style: ffi::PenStyle) -> Self {
+        ffi::qpen_init_from_style(style)
+    }
+}
+
+impl std::cmp::PartialEq for QPen {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qpen_eq(self, other)
+    }
+}
+
+impl std::cmp::Eq for QPen {}
+
+impl fmt::Debug for QPen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qpen_to_qstring(self))
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QPen {
+    type Id = type_id!("QPen");
+    type Kind = cxx::kind::Trivial;
+}
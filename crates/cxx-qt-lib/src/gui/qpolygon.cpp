@@ -0,0 +1,50 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_GUI_FEATURE
+#include "cxx-qt-lib/qpolygon.h"
+
+#include "../assertion_utils.h"
+
+// The layout has changed between Qt 5 and Qt 6
+//
+// Qt5 QPolygon is a QVector<QPoint> which has one pointer as a member
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/painting/qpolygon.h?h=v5.15.6-lts-lgpl
+//
+// Qt6 QPolygon is a QList<QPoint> which has one member, which contains two pointers
+// and a size_t
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/painting/qpolygon.h?h=v6.2.4
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+assert_alignment_and_size(QPolygon,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t[3]));
+#else
+assert_alignment_and_size(QPolygon,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t));
+#endif
+
+static_assert(!::std::is_trivially_copy_assignable<QPolygon>::value);
+static_assert(!::std::is_trivially_copy_constructible<QPolygon>::value);
+static_assert(!::std::is_trivially_destructible<QPolygon>::value);
+
+static_assert(QTypeInfo<QPolygon>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QVector<QPoint>
+qpolygonAsQVectorQPoint(const QPolygon& polygon)
+{
+  // Cast to a QVector then copy it
+  const auto vector_cast = static_cast<QVector<QPoint>>(polygon);
+  return QVector<QPoint>(vector_cast);
+}
+
+}
+}
+#endif
@@ -0,0 +1,207 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QPoint, QRect, QVector};
+use core::mem::MaybeUninit;
+use cxx::{type_id, ExternType};
+use std::fmt;
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type FillRule = crate::FillRule;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpoint.h");
+        type QPoint = crate::QPoint;
+        include!("cxx-qt-lib/qrect.h");
+        type QRect = crate::QRect;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qvector.h");
+        type QVector_QPoint = crate::QVector<QPoint>;
+
+        include!("cxx-qt-lib/qpolygon.h");
+        type QPolygon = super::QPolygon;
+
+        /// Returns the bounding rectangle of the polygon, or QRect(0, 0, 0, 0) if the polygon is empty.
+        #[rust_name = "bounding_rect"]
+        fn boundingRect(self: &QPolygon) -> QRect;
+
+        /// Returns true if the given point is inside the polygon according to the specified fill_rule; otherwise returns false.
+        #[rust_name = "contains_point"]
+        fn containsPoint(self: &QPolygon, point: &QPoint, fill_rule: FillRule) -> bool;
+
+        /// Returns a polygon which is the intersection of this polygon and r.
+        ///
+        /// Set operations on polygons will treat the polygons as areas. Non-closed polygons will be treated as implicitly closed.
+        fn intersected(self: &QPolygon, r: &QPolygon) -> QPolygon;
+
+        /// Returns true if the current polygon intersects at any point the given polygon p.
+        /// Also returns true if the current polygon contains or is contained by any part of p.
+        fn intersects(self: &QPolygon, p: &QPolygon) -> bool;
+
+        /// Returns a polygon which is r subtracted from this polygon.
+        ///
+        /// Set operations on polygons will treat the polygons as areas. Non-closed polygons will be treated as implicitly closed.
+        fn subtracted(self: &QPolygon, r: &QPolygon) -> QPolygon;
+
+        /// Translates all points in the polygon by the given offset.
+        fn translate(self: &mut QPolygon, offset: &QPoint);
+
+        /// Returns a copy of the polygon that is translated by the given offset.
+        fn translated(self: &QPolygon, offset: &QPoint) -> QPolygon;
+
+        /// Returns a polygon which is the union of this polygon and r.
+        ///
+        /// Set operations on polygons, will treat the polygons as areas, and implicitly close the polygon.
+        fn united(self: &QPolygon, r: &QPolygon) -> QPolygon;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qpolygon_clone"]
+        fn construct(polygon: &QPolygon) -> QPolygon;
+        #[doc(hidden)]
+        #[rust_name = "qpolygon_default"]
+        fn construct() -> QPolygon;
+        #[doc(hidden)]
+        #[rust_name = "qpolygon_from_qrect"]
+        fn construct(rectangle: &QRect) -> QPolygon;
+        #[doc(hidden)]
+        #[rust_name = "qpolygon_from_qvector_qpoint"]
+        fn construct(points: &QVector_QPoint) -> QPolygon;
+        #[doc(hidden)]
+        #[rust_name = "qpolygon_drop"]
+        fn drop(polygon: &mut QPolygon);
+        #[doc(hidden)]
+        #[rust_name = "qpolygon_eq"]
+        fn operatorEq(a: &QPolygon, b: &QPolygon) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qpolygon_to_qstring"]
+        fn toQString(value: &QPolygon) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qpolygon_as_qvector_qpoint"]
+        fn qpolygonAsQVectorQPoint(polygon: &QPolygon) -> QVector_QPoint;
+    }
+}
+
+/// The QPolygon class provides a list of points using integer precision.
+///
+/// To access the points as a [`QVector<QPoint>`] use the [`From`] implementations.
+#[repr(C)]
+pub struct QPolygon {
+    #[cfg(qt_version_major = "5")]
+    _space: MaybeUninit<usize>,
+    #[cfg(qt_version_major = "6")]
+    _space: MaybeUninit<[usize; 3]>,
+}
+
+impl QPolygon {
+    /// Returns the points of the polygon as a [`QVector<QPoint>`].
+    pub fn points(&self) -> QVector<QPoint> {
+        ffi::qpolygon_as_qvector_qpoint(self)
+    }
+}
+
+impl Clone for QPolygon {
+    /// Constructs a copy of the given polygon.
+    fn clone(&self) -> Self {
+        ffi::qpolygon_clone(self)
+    }
+}
+
+impl Default for QPolygon {
+    /// Constructs a polygon with no points.
+    fn default() -> Self {
+        ffi::qpolygon_default()
+    }
+}
+
+impl std::cmp::PartialEq for QPolygon {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qpolygon_eq(self, other)
+    }
+}
+
+impl std::cmp::Eq for QPolygon {}
+
+impl fmt::Display for QPolygon {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qpolygon_to_qstring(self))
+    }
+}
+
+impl fmt::Debug for QPolygon {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl Drop for QPolygon {
+    /// Destroys the polygon.
+    fn drop(&mut self) {
+        ffi::qpolygon_drop(self);
+    }
+}
+
+impl From<&QRect> for QPolygon {
+    /// Constructs a polygon from the given rectangle.
+    ///
+    /// The polygon contains the four vertices of the rectangle in clockwise order starting and ending with the top-left vertex.
+    fn from(rectangle: &QRect) -> Self {
+        ffi::qpolygon_from_qrect(rectangle)
+    }
+}
+
+impl From<&QVector<QPoint>> for QPolygon {
+    /// Constructs a polygon containing the specified points.
+    fn from(points: &QVector<QPoint>) -> Self {
+        ffi::qpolygon_from_qvector_qpoint(points)
+    }
+}
+
+impl From<&QPolygon> for QVector<QPoint> {
+    /// Copies the points of the polygon into a [`QVector<QPoint>`].
+    fn from(polygon: &QPolygon) -> Self {
+        polygon.points()
+    }
+}
+
+impl std::ops::Add<QPoint> for QPolygon {
+    type Output = Self;
+
+    /// Returns a copy of the polygon that is translated by the given offset.
+    fn add(self, offset: QPoint) -> Self {
+        self.translated(&offset)
+    }
+}
+
+impl std::ops::Sub<QPoint> for QPolygon {
+    type Output = Self;
+
+    /// Returns a copy of the polygon that is translated by the negative of the given offset.
+    fn sub(self, offset: QPoint) -> Self {
+        self.translated(&QPoint::new(-offset.x(), -offset.y()))
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QPolygon {
+    type Id = type_id!("QPolygon");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,50 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_GUI_FEATURE
+#include "cxx-qt-lib/qpolygonf.h"
+
+#include "../assertion_utils.h"
+
+// The layout has changed between Qt 5 and Qt 6
+//
+// Qt5 QPolygonF is a QVector<QPointF> which has one pointer as a member
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/painting/qpolygon.h?h=v5.15.6-lts-lgpl
+//
+// Qt6 QPolygonF is a QList<QPointF> which has one member, which contains two pointers
+// and a size_t
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/painting/qpolygon.h?h=v6.2.4
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+assert_alignment_and_size(QPolygonF,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t[3]));
+#else
+assert_alignment_and_size(QPolygonF,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t));
+#endif
+
+static_assert(!::std::is_trivially_copy_assignable<QPolygonF>::value);
+static_assert(!::std::is_trivially_copy_constructible<QPolygonF>::value);
+static_assert(!::std::is_trivially_destructible<QPolygonF>::value);
+
+static_assert(QTypeInfo<QPolygonF>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QVector<QPointF>
+qpolygonfAsQVectorQPointF(const QPolygonF& polygon)
+{
+  // Cast to a QVector then copy it
+  const auto vector_cast = static_cast<QVector<QPointF>>(polygon);
+  return QVector<QPointF>(vector_cast);
+}
+
+}
+}
+#endif
@@ -0,0 +1,234 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QPointF, QPolygon, QRectF, QVector};
+use core::mem::MaybeUninit;
+use cxx::{type_id, ExternType};
+use std::fmt;
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type FillRule = crate::FillRule;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = crate::QPointF;
+        include!("cxx-qt-lib/qrectf.h");
+        type QRectF = crate::QRectF;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qvector.h");
+        type QVector_QPointF = crate::QVector<QPointF>;
+
+        include!("cxx-qt-lib/qpolygon.h");
+        type QPolygon = crate::QPolygon;
+        include!("cxx-qt-lib/qpolygonf.h");
+        type QPolygonF = super::QPolygonF;
+
+        /// Returns the bounding rectangle of the polygon, or QRectF(0,0,0,0) if the polygon is empty.
+        #[rust_name = "bounding_rect"]
+        fn boundingRect(self: &QPolygonF) -> QRectF;
+
+        /// Returns true if the given point is inside the polygon according to the specified fill_rule; otherwise returns false.
+        #[rust_name = "contains_point"]
+        fn containsPoint(self: &QPolygonF, point: &QPointF, fill_rule: FillRule) -> bool;
+
+        /// Returns a polygon which is the intersection of this polygon and r.
+        ///
+        /// Set operations on polygons will treat the polygons as areas. Non-closed polygons will be treated as implicitly closed.
+        fn intersected(self: &QPolygonF, r: &QPolygonF) -> QPolygonF;
+
+        /// Returns true if the current polygon intersects at any point the given polygon p.
+        /// Also returns true if the current polygon contains or is contained by any part of p.
+        fn intersects(self: &QPolygonF, p: &QPolygonF) -> bool;
+
+        /// Returns true if the polygon is closed; otherwise returns false.
+        ///
+        /// A polygon is said to be closed if its start point and end point are equal.
+        #[rust_name = "is_closed"]
+        fn isClosed(self: &QPolygonF) -> bool;
+
+        /// Returns a polygon which is r subtracted from this polygon.
+        ///
+        /// Set operations on polygons will treat the polygons as areas. Non-closed polygons will be treated as implicitly closed.
+        fn subtracted(self: &QPolygonF, r: &QPolygonF) -> QPolygonF;
+
+        /// Creates and returns a QPolygon by converting each QPointF to a QPoint.
+        #[rust_name = "to_polygon"]
+        fn toPolygon(self: &QPolygonF) -> QPolygon;
+
+        /// Translate all points in the polygon by the given offset.
+        fn translate(self: &mut QPolygonF, offset: &QPointF);
+
+        /// Returns a copy of the polygon that is translated by the given offset.
+        fn translated(self: &QPolygonF, offset: &QPointF) -> QPolygonF;
+
+        /// Returns a polygon which is the union of this polygon and r.
+        ///
+        /// Set operations on polygons will treat the polygons as areas. Non-closed polygons will be treated as implicitly closed.
+        fn united(self: &QPolygonF, r: &QPolygonF) -> QPolygonF;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qpolygonf_clone"]
+        fn construct(polygon: &QPolygonF) -> QPolygonF;
+        #[doc(hidden)]
+        #[rust_name = "qpolygonf_default"]
+        fn construct() -> QPolygonF;
+        #[doc(hidden)]
+        #[rust_name = "qpolygonf_from_qpolygon"]
+        fn construct(polygon: &QPolygon) -> QPolygonF;
+        #[doc(hidden)]
+        #[rust_name = "qpolygonf_from_qrectf"]
+        fn construct(rectangle: &QRectF) -> QPolygonF;
+        #[doc(hidden)]
+        #[rust_name = "qpolygonf_from_qvector_qpointf"]
+        fn construct(points: &QVector_QPointF) -> QPolygonF;
+        #[doc(hidden)]
+        #[rust_name = "qpolygonf_drop"]
+        fn drop(polygon: &mut QPolygonF);
+        #[doc(hidden)]
+        #[rust_name = "qpolygonf_eq"]
+        fn operatorEq(a: &QPolygonF, b: &QPolygonF) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qpolygonf_to_qstring"]
+        fn toQString(value: &QPolygonF) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qpolygonf_as_qvector_qpointf"]
+        fn qpolygonfAsQVectorQPointF(polygon: &QPolygonF) -> QVector_QPointF;
+    }
+}
+
+/// The QPolygonF class provides a list of points using floating point precision.
+///
+/// To access the points as a [`QVector<QPointF>`] use the [`From`] implementations.
+#[repr(C)]
+pub struct QPolygonF {
+    #[cfg(qt_version_major = "5")]
+    _space: MaybeUninit<usize>,
+    #[cfg(qt_version_major = "6")]
+    _space: MaybeUninit<[usize; 3]>,
+}
+
+impl QPolygonF {
+    /// Returns the points of the polygon as a [`QVector<QPointF>`].
+    pub fn points(&self) -> QVector<QPointF> {
+        ffi::qpolygonf_as_qvector_qpointf(self)
+    }
+}
+
+impl Clone for QPolygonF {
+    /// Constructs a copy of the given polygon.
+    fn clone(&self) -> Self {
+        ffi::qpolygonf_clone(self)
+    }
+}
+
+impl Default for QPolygonF {
+    /// Constructs a polygon with no points.
+    fn default() -> Self {
+        ffi::qpolygonf_default()
+    }
+}
+
+impl std::cmp::PartialEq for QPolygonF {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qpolygonf_eq(self, other)
+    }
+}
+
+impl fmt::Display for QPolygonF {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qpolygonf_to_qstring(self))
+    }
+}
+
+impl fmt::Debug for QPolygonF {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl Drop for QPolygonF {
+    /// Destroys the polygon.
+    fn drop(&mut self) {
+        ffi::qpolygonf_drop(self);
+    }
+}
+
+impl From<&QPolygon> for QPolygonF {
+    /// Constructs a polygon from the given integer based polygon.
+    fn from(polygon: &QPolygon) -> Self {
+        ffi::qpolygonf_from_qpolygon(polygon)
+    }
+}
+
+impl From<&QPolygonF> for QPolygon {
+    /// Creates and returns a QPolygon by converting each QPointF to a QPoint.
+    fn from(polygon: &QPolygonF) -> Self {
+        polygon.to_polygon()
+    }
+}
+
+impl From<&QRectF> for QPolygonF {
+    /// Constructs a closed polygon from the specified rectangle.
+    ///
+    /// The polygon contains the four vertices of the rectangle in clockwise order starting and ending with the top-left vertex.
+    fn from(rectangle: &QRectF) -> Self {
+        ffi::qpolygonf_from_qrectf(rectangle)
+    }
+}
+
+impl From<&QVector<QPointF>> for QPolygonF {
+    /// Constructs a polygon containing the specified points.
+    fn from(points: &QVector<QPointF>) -> Self {
+        ffi::qpolygonf_from_qvector_qpointf(points)
+    }
+}
+
+impl From<&QPolygonF> for QVector<QPointF> {
+    /// Copies the points of the polygon into a [`QVector<QPointF>`].
+    fn from(polygon: &QPolygonF) -> Self {
+        polygon.points()
+    }
+}
+
+impl std::ops::Add<QPointF> for QPolygonF {
+    type Output = Self;
+
+    /// Returns a copy of the polygon that is translated by the given offset.
+    fn add(self, offset: QPointF) -> Self {
+        self.translated(&offset)
+    }
+}
+
+impl std::ops::Sub<QPointF> for QPolygonF {
+    type Output = Self;
+
+    /// Returns a copy of the polygon that is translated by the negative of the given offset.
+    fn sub(self, offset: QPointF) -> Self {
+        self.translated(&QPointF::new(-offset.x(), -offset.y()))
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QPolygonF {
+    type Id = type_id!("QPolygonF");
+    type Kind = cxx::kind::Trivial;
+}
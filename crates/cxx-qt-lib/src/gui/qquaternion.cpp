@@ -0,0 +1,77 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_GUI_FEATURE
+#include "cxx-qt-lib/qquaternion.h"
+
+#include "../assertion_utils.h"
+
+// QQuaternion has four float members - wp, xp, yp and zp
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/math3d/qquaternion.h?h=v5.15.6-lts-lgpl
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/math3d/qquaternion.h?h=v6.2.4
+assert_alignment_and_size(QQuaternion, alignof(float), sizeof(float[4]));
+
+static_assert(::std::is_trivially_copyable<QQuaternion>::value,
+              "QQuaternion should be trivially copyable");
+
+namespace rust {
+namespace cxxqtlib1 {
+
+float
+qquaternionDotProduct(const QQuaternion& q1, const QQuaternion& q2)
+{
+  return QQuaternion::dotProduct(q1, q2);
+}
+
+QQuaternion
+qquaternionFromAxisAndAngle(const QVector3D& axis, float angle)
+{
+  return QQuaternion::fromAxisAndAngle(axis, angle);
+}
+
+QQuaternion
+qquaternionFromDirection(const QVector3D& direction, const QVector3D& up)
+{
+  return QQuaternion::fromDirection(direction, up);
+}
+
+QQuaternion
+qquaternionFromEulerAngles(float pitch, float yaw, float roll)
+{
+  return QQuaternion::fromEulerAngles(pitch, yaw, roll);
+}
+
+void
+qquaternionGetAxisAndAngle(const QQuaternion& quaternion,
+                           QVector3D& axis,
+                           float& angle)
+{
+  quaternion.getAxisAndAngle(&axis, &angle);
+}
+
+QQuaternion
+qquaternionNlerp(const QQuaternion& q1, const QQuaternion& q2, float t)
+{
+  return QQuaternion::nlerp(q1, q2, t);
+}
+
+QQuaternion
+qquaternionRotationTo(const QVector3D& from, const QVector3D& to)
+{
+  return QQuaternion::rotationTo(from, to);
+}
+
+QQuaternion
+qquaternionSlerp(const QQuaternion& q1, const QQuaternion& q2, float t)
+{
+  return QQuaternion::slerp(q1, q2, t);
+}
+
+}
+}
+#endif
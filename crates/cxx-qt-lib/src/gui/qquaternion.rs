@@ -0,0 +1,371 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use std::fmt;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qvector3d.h");
+        type QVector3D = crate::QVector3D;
+        include!("cxx-qt-lib/qvector4d.h");
+        type QVector4D = crate::QVector4D;
+
+        include!("cxx-qt-lib/qquaternion.h");
+        type QQuaternion = super::QQuaternion;
+
+        /// Returns the conjugate of this quaternion, which is (-x, -y, -z, scalar).
+        fn conjugated(self: &QQuaternion) -> QQuaternion;
+
+        /// Returns the inverse of this quaternion. If this quaternion is null, then a null quaternion is returned.
+        fn inverted(self: &QQuaternion) -> QQuaternion;
+
+        /// Returns true if the x, y, and z components of this quaternion are set to 0.0, and the scalar component is set to 1.0;
+        /// otherwise returns false.
+        #[rust_name = "is_identity"]
+        fn isIdentity(self: &QQuaternion) -> bool;
+
+        /// Returns true if the x, y, z, and scalar components of this quaternion are set to 0.0; otherwise returns false.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QQuaternion) -> bool;
+
+        /// Returns the length of the quaternion. This is also called the "norm".
+        fn length(self: &QQuaternion) -> f32;
+
+        /// Returns the squared length of the quaternion.
+        #[rust_name = "length_squared"]
+        fn lengthSquared(self: &QQuaternion) -> f32;
+
+        /// Normalizes the current quaternion in place. Nothing happens if this is a null quaternion
+        /// or the length of the quaternion is very close to 1.
+        fn normalize(self: &mut QQuaternion);
+
+        /// Returns the normalized unit form of this quaternion.
+        ///
+        /// If this quaternion is null, then a null quaternion is returned.
+        /// If the length of the quaternion is very close to 1, then the quaternion will be returned as-is.
+        /// Otherwise the normalized form of the quaternion of length 1 will be returned.
+        fn normalized(self: &QQuaternion) -> QQuaternion;
+
+        /// Rotates vector with this quaternion to produce a new vector in 3D space.
+        #[rust_name = "rotated_vector"]
+        fn rotatedVector(self: &QQuaternion, vector: &QVector3D) -> QVector3D;
+
+        /// Returns the scalar component of this quaternion.
+        fn scalar(self: &QQuaternion) -> f32;
+
+        /// Sets the scalar component of this quaternion to scalar.
+        #[rust_name = "set_scalar"]
+        fn setScalar(self: &mut QQuaternion, scalar: f32);
+
+        /// Sets the vector component of this quaternion to vector.
+        #[rust_name = "set_vector"]
+        fn setVector(self: &mut QQuaternion, vector: &QVector3D);
+
+        /// Sets the x coordinate of this quaternion's vector to the given x coordinate.
+        #[rust_name = "set_x"]
+        fn setX(self: &mut QQuaternion, x: f32);
+
+        /// Sets the y coordinate of this quaternion's vector to the given y coordinate.
+        #[rust_name = "set_y"]
+        fn setY(self: &mut QQuaternion, y: f32);
+
+        /// Sets the z coordinate of this quaternion's vector to the given z coordinate.
+        #[rust_name = "set_z"]
+        fn setZ(self: &mut QQuaternion, z: f32);
+
+        /// Calculates roll, pitch, and yaw Euler angles (in degrees) that corresponds to this quaternion.
+        ///
+        /// The returned vector contains the pitch in x, the yaw in y and the roll in z.
+        #[rust_name = "to_euler_angles"]
+        fn toEulerAngles(self: &QQuaternion) -> QVector3D;
+
+        /// Converts this quaternion to a QVector4D. The returned vector will contain the x, y, z and scalar components.
+        #[rust_name = "to_vector_4d"]
+        fn toVector4D(self: &QQuaternion) -> QVector4D;
+
+        /// Returns the vector component of this quaternion.
+        fn vector(self: &QQuaternion) -> QVector3D;
+
+        /// Returns the x coordinate of this quaternion's vector.
+        fn x(self: &QQuaternion) -> f32;
+
+        /// Returns the y coordinate of this quaternion's vector.
+        fn y(self: &QQuaternion) -> f32;
+
+        /// Returns the z coordinate of this quaternion's vector.
+        fn z(self: &QQuaternion) -> f32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_dot_product"]
+        fn qquaternionDotProduct(q1: &QQuaternion, q2: &QQuaternion) -> f32;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_from_axis_and_angle"]
+        fn qquaternionFromAxisAndAngle(axis: &QVector3D, angle: f32) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_from_direction"]
+        fn qquaternionFromDirection(direction: &QVector3D, up: &QVector3D) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_from_euler_angles"]
+        fn qquaternionFromEulerAngles(pitch: f32, yaw: f32, roll: f32) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_get_axis_and_angle"]
+        fn qquaternionGetAxisAndAngle(
+            quaternion: &QQuaternion,
+            axis: &mut QVector3D,
+            angle: &mut f32,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_nlerp"]
+        fn qquaternionNlerp(q1: &QQuaternion, q2: &QQuaternion, t: f32) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_rotation_to"]
+        fn qquaternionRotationTo(from: &QVector3D, to: &QVector3D) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_slerp"]
+        fn qquaternionSlerp(q1: &QQuaternion, q2: &QQuaternion, t: f32) -> QQuaternion;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_init_default"]
+        fn construct() -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_init"]
+        fn construct(scalar: f32, xpos: f32, ypos: f32, zpos: f32) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_init_qvector4d"]
+        fn construct(vector: &QVector4D) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_to_qstring"]
+        fn toQString(value: &QQuaternion) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_plus"]
+        fn operatorPlus(a: &QQuaternion, b: &QQuaternion) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_minus"]
+        fn operatorMinus(a: &QQuaternion, b: &QQuaternion) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_mul"]
+        fn operatorMul(a: QQuaternion, b: &QQuaternion) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_mul_f32"]
+        fn operatorMul(a: f32, b: &QQuaternion) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_div"]
+        fn operatorDiv(a: f32, b: &QQuaternion) -> QQuaternion;
+    }
+}
+
+/// The QQuaternion class represents a quaternion consisting of a vector and scalar.
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct QQuaternion {
+    wp: f32,
+    xp: f32,
+    yp: f32,
+    zp: f32,
+}
+
+impl QQuaternion {
+    /// Returns the dot product of q1 and q2.
+    pub fn dot_product(q1: &QQuaternion, q2: &QQuaternion) -> f32 {
+        ffi::qquaternion_dot_product(q1, q2)
+    }
+
+    /// Creates a normalized quaternion that corresponds to rotating through angle degrees about the specified 3D axis.
+    pub fn from_axis_and_angle(axis: &ffi::QVector3D, angle: f32) -> Self {
+        ffi::qquaternion_from_axis_and_angle(axis, angle)
+    }
+
+    /// Returns the shortest arc quaternion to rotate from the direction described by the vector direction,
+    /// to the direction described by the vector up.
+    pub fn from_direction(direction: &ffi::QVector3D, up: &ffi::QVector3D) -> Self {
+        ffi::qquaternion_from_direction(direction, up)
+    }
+
+    /// Creates a quaternion that corresponds to a rotation of roll degrees around the z axis,
+    /// pitch degrees around the x axis, and yaw degrees around the y axis (in that order).
+    pub fn from_euler_angles(pitch: f32, yaw: f32, roll: f32) -> Self {
+        ffi::qquaternion_from_euler_angles(pitch, yaw, roll)
+    }
+
+    /// Extracts a 3D axis and a rotating angle (in degrees) that corresponds to this quaternion.
+    pub fn get_axis_and_angle(&self) -> (ffi::QVector3D, f32) {
+        let mut axis = ffi::QVector3D::default();
+        let mut angle = 0.0;
+        ffi::qquaternion_get_axis_and_angle(self, &mut axis, &mut angle);
+        (axis, angle)
+    }
+
+    /// Constructs a quaternion with the vector (xpos, ypos, zpos) and scalar.
+    pub fn new(scalar: f32, xpos: f32, ypos: f32, zpos: f32) -> Self {
+        ffi::qquaternion_init(scalar, xpos, ypos, zpos)
+    }
+
+    /// Interpolates along the shortest linear path between the rotational positions q1 and q2.
+    /// The value t should be between 0 and 1, indicating the distance to travel between q1 and q2.
+    /// The result will be normalized().
+    pub fn nlerp(q1: &QQuaternion, q2: &QQuaternion, t: f32) -> Self {
+        ffi::qquaternion_nlerp(q1, q2, t)
+    }
+
+    /// Returns the shortest arc quaternion to rotate from the direction described by the vector from
+    /// to the direction described by the vector to.
+    pub fn rotation_to(from: &ffi::QVector3D, to: &ffi::QVector3D) -> Self {
+        ffi::qquaternion_rotation_to(from, to)
+    }
+
+    /// Interpolates along the shortest spherical path between the rotational positions q1 and q2.
+    /// The value t should be between 0 and 1, indicating the spherical distance to travel between q1 and q2.
+    pub fn slerp(q1: &QQuaternion, q2: &QQuaternion, t: f32) -> Self {
+        ffi::qquaternion_slerp(q1, q2, t)
+    }
+}
+
+impl Default for QQuaternion {
+    /// Constructs an identity quaternion (1, 0, 0, 0), i.e. with the vector (0, 0, 0) and scalar 1.
+    fn default() -> Self {
+        ffi::qquaternion_init_default()
+    }
+}
+
+impl fmt::Display for QQuaternion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qquaternion_to_qstring(self))
+    }
+}
+
+impl From<&ffi::QVector4D> for QQuaternion {
+    /// Constructs a quaternion from the components of vector.
+    fn from(vector: &ffi::QVector4D) -> Self {
+        ffi::qquaternion_init_qvector4d(vector)
+    }
+}
+
+impl From<&QQuaternion> for ffi::QVector4D {
+    /// Converts the quaternion to a QVector4D containing the x, y, z and scalar components.
+    fn from(quaternion: &QQuaternion) -> Self {
+        quaternion.to_vector_4d()
+    }
+}
+
+impl std::ops::Add for QQuaternion {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        ffi::qquaternion_plus(&self, &other)
+    }
+}
+
+impl std::ops::Sub for QQuaternion {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        ffi::qquaternion_minus(&self, &other)
+    }
+}
+
+impl std::ops::Mul for QQuaternion {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        ffi::qquaternion_mul(self, &other)
+    }
+}
+
+impl std::ops::Mul<f32> for QQuaternion {
+    type Output = Self;
+    fn mul(self, rhs: f32) -> Self {
+        ffi::qquaternion_mul_f32(rhs, &self)
+    }
+}
+
+impl std::ops::Mul<ffi::QVector3D> for QQuaternion {
+    type Output = ffi::QVector3D;
+
+    /// Rotates a vector with the quaternion, this is the same as [QQuaternion::rotated_vector].
+    fn mul(self, rhs: ffi::QVector3D) -> ffi::QVector3D {
+        self.rotated_vector(&rhs)
+    }
+}
+
+impl std::ops::Div<f32> for QQuaternion {
+    type Output = Self;
+    fn div(self, rhs: f32) -> Self {
+        ffi::qquaternion_div(rhs, &self)
+    }
+}
+
+impl std::ops::Neg for QQuaternion {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self * -1.0
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<&QQuaternion> for glam::Quat {
+    fn from(value: &QQuaternion) -> Self {
+        Self::from_xyzw(value.xp, value.yp, value.zp, value.wp)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<&glam::Quat> for QQuaternion {
+    fn from(value: &glam::Quat) -> Self {
+        let [x, y, z, w] = value.to_array();
+        Self::new(w, x, y, z)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<&QQuaternion> for nalgebra::Quaternion<f32> {
+    fn from(value: &QQuaternion) -> Self {
+        Self::new(value.wp, value.xp, value.yp, value.zp)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<&nalgebra::Quaternion<f32>> for QQuaternion {
+    fn from(value: &nalgebra::Quaternion<f32>) -> Self {
+        Self::new(value.w, value.i, value.j, value.k)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<&nalgebra::UnitQuaternion<f32>> for QQuaternion {
+    fn from(value: &nalgebra::UnitQuaternion<f32>) -> Self {
+        Self::from(value.quaternion())
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl<Src, Dst> From<&QQuaternion> for euclid::Rotation3D<f32, Src, Dst> {
+    fn from(value: &QQuaternion) -> Self {
+        Self::quaternion(value.xp, value.yp, value.zp, value.wp)
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl<Src, Dst> From<&euclid::Rotation3D<f32, Src, Dst>> for QQuaternion {
+    fn from(value: &euclid::Rotation3D<f32, Src, Dst>) -> Self {
+        Self::new(value.r, value.i, value.j, value.k)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side ensure that QQuaternion is trivial.
+unsafe impl ExternType for QQuaternion {
+    type Id = type_id!("QQuaternion");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,75 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_GUI_FEATURE
+#include "cxx-qt-lib/qtransform.h"
+
+#include "../assertion_utils.h"
+
+// QTransform has nine "qreal" members, a uint containing the type and dirty
+// bitfields, and a private pointer which is unused.
+//
+// Qt5 stores the first six members as a QMatrix
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/painting/qtransform.h?h=v5.15.6-lts-lgpl
+//
+// Qt6 stores the members as a qreal[3][3]
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/painting/qtransform.h?h=v6.2.4
+//
+// The uint is padded to the size of the pointer
+assert_alignment_and_size(QTransform,
+                          alignof(double),
+                          sizeof(double[9]) + sizeof(::std::size_t[2]));
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+static_assert(::std::is_trivially_copyable<QTransform>::value,
+              "QTransform should be trivially copyable");
+#else
+static_assert(!::std::is_trivially_copy_assignable<QTransform>::value);
+static_assert(!::std::is_trivially_copy_constructible<QTransform>::value);
+#endif
+
+static_assert(::std::is_trivially_destructible<QTransform>::value);
+
+static_assert(QTypeInfo<QTransform>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QTransform
+qtransformFromScale(double dx, double dy)
+{
+  return QTransform::fromScale(dx, dy);
+}
+
+QTransform
+qtransformFromTranslate(double dx, double dy)
+{
+  return QTransform::fromTranslate(dx, dy);
+}
+
+QTransform
+qtransformInverted(const QTransform& transform, bool& invertible)
+{
+  return transform.inverted(&invertible);
+}
+
+// Qt 6.5 adds a distanceToPlane parameter so we cannot bind rotate directly
+void
+qtransformRotate(QTransform& transform, double a)
+{
+  transform.rotate(a);
+}
+
+void
+qtransformRotateRadians(QTransform& transform, double a)
+{
+  transform.rotateRadians(a);
+}
+
+}
+}
+#endif
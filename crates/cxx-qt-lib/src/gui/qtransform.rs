@@ -0,0 +1,499 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the type of a transformation, from the simplest to the most complex.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    enum QTransformTransformationType {
+        /// The transformation is the identity.
+        TxNone = 0x00,
+        /// The transformation only translates.
+        TxTranslate = 0x01,
+        /// The transformation scales, and may translate.
+        TxScale = 0x02,
+        /// The transformation rotates, and may scale and translate.
+        TxRotate = 0x04,
+        /// The transformation shears, and may rotate, scale and translate.
+        TxShear = 0x08,
+        /// The transformation is a projection.
+        TxProject = 0x10,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qline.h");
+        type QLine = crate::QLine;
+        include!("cxx-qt-lib/qlinef.h");
+        type QLineF = crate::QLineF;
+        include!("cxx-qt-lib/qpainterpath.h");
+        type QPainterPath = crate::QPainterPath;
+        include!("cxx-qt-lib/qpoint.h");
+        type QPoint = crate::QPoint;
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = crate::QPointF;
+        include!("cxx-qt-lib/qpolygon.h");
+        type QPolygon = crate::QPolygon;
+        include!("cxx-qt-lib/qpolygonf.h");
+        type QPolygonF = crate::QPolygonF;
+        include!("cxx-qt-lib/qrect.h");
+        type QRect = crate::QRect;
+        include!("cxx-qt-lib/qrectf.h");
+        type QRectF = crate::QRectF;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qtransform.h");
+        type QTransform = super::QTransform;
+
+        /// Returns the adjoint of this matrix.
+        fn adjoint(self: &QTransform) -> QTransform;
+
+        /// Returns the matrix's determinant.
+        fn determinant(self: &QTransform) -> f64;
+
+        /// Returns the horizontal translation factor.
+        fn dx(self: &QTransform) -> f64;
+
+        /// Returns the vertical translation factor.
+        fn dy(self: &QTransform) -> f64;
+
+        /// Returns true if the matrix represent an affine transformation, otherwise returns false.
+        #[rust_name = "is_affine"]
+        fn isAffine(self: &QTransform) -> bool;
+
+        /// Returns true if the matrix is the identity matrix, otherwise returns false.
+        #[rust_name = "is_identity"]
+        fn isIdentity(self: &QTransform) -> bool;
+
+        /// Returns true if the matrix is invertible, otherwise returns false.
+        #[rust_name = "is_invertible"]
+        fn isInvertible(self: &QTransform) -> bool;
+
+        /// Returns true if the matrix represents some kind of a rotating transformation, otherwise returns false.
+        #[rust_name = "is_rotating"]
+        fn isRotating(self: &QTransform) -> bool;
+
+        /// Returns true if the matrix represents a scaling transformation, otherwise returns false.
+        #[rust_name = "is_scaling"]
+        fn isScaling(self: &QTransform) -> bool;
+
+        /// Returns true if the matrix represents a translating transformation, otherwise returns false.
+        #[rust_name = "is_translating"]
+        fn isTranslating(self: &QTransform) -> bool;
+
+        /// Returns the horizontal scaling factor.
+        fn m11(self: &QTransform) -> f64;
+
+        /// Returns the vertical shearing factor.
+        fn m12(self: &QTransform) -> f64;
+
+        /// Returns the horizontal projection factor.
+        fn m13(self: &QTransform) -> f64;
+
+        /// Returns the horizontal shearing factor.
+        fn m21(self: &QTransform) -> f64;
+
+        /// Returns the vertical scaling factor.
+        fn m22(self: &QTransform) -> f64;
+
+        /// Returns the vertical projection factor.
+        fn m23(self: &QTransform) -> f64;
+
+        /// Returns the horizontal translation factor.
+        fn m31(self: &QTransform) -> f64;
+
+        /// Returns the vertical translation factor.
+        fn m32(self: &QTransform) -> f64;
+
+        /// Returns the division factor.
+        fn m33(self: &QTransform) -> f64;
+
+        /// Creates and returns a QLine object that is a copy of the given line, mapped into the coordinate system defined by this matrix.
+        #[cxx_name = "map"]
+        fn map_line(self: &QTransform, l: &QLine) -> QLine;
+
+        /// Creates and returns a QLineF object that is a copy of the given line, mapped into the coordinate system defined by this matrix.
+        #[cxx_name = "map"]
+        fn map_linef(self: &QTransform, l: &QLineF) -> QLineF;
+
+        /// Creates and returns a QPainterPath object that is a copy of the given path, mapped into the coordinate system defined by this matrix.
+        #[cxx_name = "map"]
+        fn map_path(self: &QTransform, path: &QPainterPath) -> QPainterPath;
+
+        /// Creates and returns a QPoint object that is a copy of the given point, mapped into the coordinate system defined by this matrix.
+        /// Note that the transformed coordinates are rounded to the nearest integer.
+        #[cxx_name = "map"]
+        fn map_point(self: &QTransform, p: &QPoint) -> QPoint;
+
+        /// Creates and returns a QPointF object that is a copy of the given point, mapped into the coordinate system defined by this matrix.
+        #[cxx_name = "map"]
+        fn map_pointf(self: &QTransform, p: &QPointF) -> QPointF;
+
+        /// Creates and returns a QPolygon object that is a copy of the given polygon, mapped into the coordinate system defined by this matrix.
+        /// Note that the transformed coordinates are rounded to the nearest integer.
+        #[cxx_name = "map"]
+        fn map_polygon(self: &QTransform, polygon: &QPolygon) -> QPolygon;
+
+        /// Creates and returns a QPolygonF object that is a copy of the given polygon, mapped into the coordinate system defined by this matrix.
+        #[cxx_name = "map"]
+        fn map_polygonf(self: &QTransform, polygon: &QPolygonF) -> QPolygonF;
+
+        /// Creates and returns a QRect object that is a copy of the given rectangle, mapped into the coordinate system defined by this matrix.
+        ///
+        /// The bounding rectangle of the four transformed corners is returned.
+        #[rust_name = "map_rect"]
+        fn mapRect(self: &QTransform, rectangle: &QRect) -> QRect;
+
+        /// Creates and returns a QRectF object that is a copy of the given rectangle, mapped into the coordinate system defined by this matrix.
+        ///
+        /// The bounding rectangle of the four transformed corners is returned.
+        #[cxx_name = "mapRect"]
+        fn map_rectf(self: &QTransform, rectangle: &QRectF) -> QRectF;
+
+        /// Creates and returns a QPolygon representation of the given rectangle, mapped into the coordinate system defined by this matrix.
+        #[rust_name = "map_to_polygon"]
+        fn mapToPolygon(self: &QTransform, rectangle: &QRect) -> QPolygon;
+
+        /// Resets the matrix to an identity matrix, i.e. all elements are set to zero, except m11 and m22 (specifying the scale) and m33 which are set to 1.
+        fn reset(self: &mut QTransform);
+
+        /// Scales the coordinate system by sx horizontally and sy vertically, and returns a reference to the matrix.
+        fn scale(self: &mut QTransform, sx: f64, sy: f64) -> &mut QTransform;
+
+        /// Sets the matrix elements to the specified values, m11, m12, m13 m21, m22, m23 m31, m32 and m33.
+        #[rust_name = "set_matrix"]
+        #[allow(clippy::too_many_arguments)]
+        fn setMatrix(
+            self: &mut QTransform,
+            m11: f64,
+            m12: f64,
+            m13: f64,
+            m21: f64,
+            m22: f64,
+            m23: f64,
+            m31: f64,
+            m32: f64,
+            m33: f64,
+        );
+
+        /// Shears the coordinate system by sh horizontally and sv vertically, and returns a reference to the matrix.
+        fn shear(self: &mut QTransform, sh: f64, sv: f64) -> &mut QTransform;
+
+        /// Moves the coordinate system dx along the x axis and dy along the y axis, and returns a reference to the matrix.
+        fn translate(self: &mut QTransform, dx: f64, dy: f64) -> &mut QTransform;
+
+        /// Returns the transpose of this matrix.
+        fn transposed(self: &QTransform) -> QTransform;
+
+        /// Returns the transformation type of this matrix.
+        ///
+        /// The transformation type is the highest enumeration value capturing all of the matrix's transformations.
+        #[cxx_name = "type"]
+        fn transformation_type(self: &QTransform) -> QTransformTransformationType;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qtransform_from_scale"]
+        fn qtransformFromScale(dx: f64, dy: f64) -> QTransform;
+        #[doc(hidden)]
+        #[rust_name = "qtransform_from_translate"]
+        fn qtransformFromTranslate(dx: f64, dy: f64) -> QTransform;
+        #[doc(hidden)]
+        #[rust_name = "qtransform_inverted"]
+        fn qtransformInverted(transform: &QTransform, invertible: &mut bool) -> QTransform;
+        #[doc(hidden)]
+        #[rust_name = "qtransform_rotate"]
+        fn qtransformRotate(transform: &mut QTransform, a: f64);
+        #[doc(hidden)]
+        #[rust_name = "qtransform_rotate_radians"]
+        fn qtransformRotateRadians(transform: &mut QTransform, a: f64);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qtransform_init_default"]
+        fn construct() -> QTransform;
+        #[doc(hidden)]
+        #[rust_name = "qtransform_init"]
+        #[allow(clippy::too_many_arguments)]
+        fn construct(
+            m11: f64,
+            m12: f64,
+            m13: f64,
+            m21: f64,
+            m22: f64,
+            m23: f64,
+            m31: f64,
+            m32: f64,
+            m33: f64,
+        ) -> QTransform;
+        #[doc(hidden)]
+        #[rust_name = "qtransform_init_affine"]
+        fn construct(m11: f64, m12: f64, m21: f64, m22: f64, dx: f64, dy: f64) -> QTransform;
+        #[doc(hidden)]
+        #[rust_name = "qtransform_clone"]
+        fn construct(other: &QTransform) -> QTransform;
+        #[doc(hidden)]
+        #[rust_name = "qtransform_eq"]
+        fn operatorEq(a: &QTransform, b: &QTransform) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qtransform_mul"]
+        fn operatorMul(a: QTransform, b: &QTransform) -> QTransform;
+        #[doc(hidden)]
+        #[rust_name = "qtransform_to_qstring"]
+        fn toQString(value: &QTransform) -> QString;
+    }
+}
+
+pub use ffi::QTransformTransformationType;
+
+/// The QTransform class specifies 2D transformations of a coordinate system.
+///
+/// Note that QTransform uses the row vector convention, so the translation factors are in m31 and m32.
+#[repr(C)]
+pub struct QTransform {
+    _matrix: MaybeUninit<[f64; 9]>,
+    _private: MaybeUninit<[usize; 2]>,
+}
+
+impl QTransform {
+    /// Creates a matrix which corresponds to a scaling of sx horizontally and sy vertically.
+    pub fn from_scale(sx: f64, sy: f64) -> Self {
+        ffi::qtransform_from_scale(sx, sy)
+    }
+
+    /// Creates a matrix which corresponds to a translation of dx along the x axis and dy along the y axis.
+    pub fn from_translate(dx: f64, dy: f64) -> Self {
+        ffi::qtransform_from_translate(dx, dy)
+    }
+
+    /// Returns an inverted copy of this matrix, or None if the matrix is not invertible.
+    pub fn inverted(&self) -> Option<Self> {
+        let mut invertible = false;
+        let inverted = ffi::qtransform_inverted(self, &mut invertible);
+        if invertible {
+            Some(inverted)
+        } else {
+            None
+        }
+    }
+
+    /// Constructs a matrix with the elements, m11, m12, m13, m21, m22, m23, m31, m32, m33.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        m11: f64,
+        m12: f64,
+        m13: f64,
+        m21: f64,
+        m22: f64,
+        m23: f64,
+        m31: f64,
+        m32: f64,
+        m33: f64,
+    ) -> Self {
+        ffi::qtransform_init(m11, m12, m13, m21, m22, m23, m31, m32, m33)
+    }
+
+    /// Constructs a matrix with the elements, m11, m12, m21, m22, dx and dy.
+    pub fn new_affine(m11: f64, m12: f64, m21: f64, m22: f64, dx: f64, dy: f64) -> Self {
+        ffi::qtransform_init_affine(m11, m12, m21, m22, dx, dy)
+    }
+
+    /// Rotates the coordinate system counterclockwise by the given angle in degrees, and returns a reference to the matrix.
+    pub fn rotate(&mut self, angle: f64) -> &mut Self {
+        ffi::qtransform_rotate(self, angle);
+        self
+    }
+
+    /// Rotates the coordinate system counterclockwise by the given angle in radians, and returns a reference to the matrix.
+    pub fn rotate_radians(&mut self, angle: f64) -> &mut Self {
+        ffi::qtransform_rotate_radians(self, angle);
+        self
+    }
+}
+
+impl Clone for QTransform {
+    /// Constructs a copy of the given matrix.
+    fn clone(&self) -> Self {
+        ffi::qtransform_clone(self)
+    }
+}
+
+impl Default for QTransform {
+    /// Constructs an identity matrix.
+    fn default() -> Self {
+        ffi::qtransform_init_default()
+    }
+}
+
+impl std::cmp::PartialEq for QTransform {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qtransform_eq(self, other)
+    }
+}
+
+impl fmt::Display for QTransform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qtransform_to_qstring(self))
+    }
+}
+
+impl fmt::Debug for QTransform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl std::ops::Mul for QTransform {
+    type Output = Self;
+
+    /// Returns the result of multiplying this matrix by the given matrix.
+    ///
+    /// Note that matrix multiplication is not commutative, i.e. a*b != b*a.
+    fn mul(self, other: Self) -> Self {
+        ffi::qtransform_mul(self, &other)
+    }
+}
+
+impl std::ops::Mul<&QTransform> for ffi::QPointF {
+    type Output = Self;
+
+    /// Maps the point by the given matrix, this is the same as [QTransform::map_pointf].
+    fn mul(self, transform: &QTransform) -> Self {
+        transform.map_pointf(&self)
+    }
+}
+
+impl std::ops::Mul<&QTransform> for ffi::QPoint {
+    type Output = Self;
+
+    /// Maps the point by the given matrix, this is the same as [QTransform::map_point].
+    fn mul(self, transform: &QTransform) -> Self {
+        transform.map_point(&self)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<&QTransform> for glam::DMat3 {
+    /// Converts the row vector QTransform into a column vector [glam::DMat3].
+    fn from(value: &QTransform) -> Self {
+        Self::from_cols(
+            glam::DVec3::new(value.m11(), value.m12(), value.m13()),
+            glam::DVec3::new(value.m21(), value.m22(), value.m23()),
+            glam::DVec3::new(value.m31(), value.m32(), value.m33()),
+        )
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<&glam::DMat3> for QTransform {
+    /// Converts the column vector [glam::DMat3] into a row vector QTransform.
+    fn from(value: &glam::DMat3) -> Self {
+        Self::new(
+            value.x_axis.x,
+            value.x_axis.y,
+            value.x_axis.z,
+            value.y_axis.x,
+            value.y_axis.y,
+            value.y_axis.z,
+            value.z_axis.x,
+            value.z_axis.y,
+            value.z_axis.z,
+        )
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<&glam::DAffine2> for QTransform {
+    /// Converts the [glam::DAffine2] into an affine QTransform.
+    fn from(value: &glam::DAffine2) -> Self {
+        Self::new_affine(
+            value.matrix2.x_axis.x,
+            value.matrix2.x_axis.y,
+            value.matrix2.y_axis.x,
+            value.matrix2.y_axis.y,
+            value.translation.x,
+            value.translation.y,
+        )
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<&QTransform> for nalgebra::Matrix3<f64> {
+    /// Converts the row vector QTransform into a column vector [nalgebra::Matrix3].
+    fn from(value: &QTransform) -> Self {
+        Self::new(
+            value.m11(),
+            value.m21(),
+            value.m31(),
+            value.m12(),
+            value.m22(),
+            value.m32(),
+            value.m13(),
+            value.m23(),
+            value.m33(),
+        )
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<&nalgebra::Matrix3<f64>> for QTransform {
+    /// Converts the column vector [nalgebra::Matrix3] into a row vector QTransform.
+    fn from(value: &nalgebra::Matrix3<f64>) -> Self {
+        Self::new(
+            value[(0, 0)],
+            value[(1, 0)],
+            value[(2, 0)],
+            value[(0, 1)],
+            value[(1, 1)],
+            value[(2, 1)],
+            value[(0, 2)],
+            value[(1, 2)],
+            value[(2, 2)],
+        )
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl<Src, Dst> From<&QTransform> for euclid::Transform2D<f64, Src, Dst> {
+    /// Converts the QTransform into an [euclid::Transform2D], note that any projection factors are dropped.
+    fn from(value: &QTransform) -> Self {
+        Self::new(
+            value.m11(),
+            value.m12(),
+            value.m21(),
+            value.m22(),
+            value.dx(),
+            value.dy(),
+        )
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl<Src, Dst> From<&euclid::Transform2D<f64, Src, Dst>> for QTransform {
+    /// Converts the [euclid::Transform2D] into an affine QTransform.
+    fn from(value: &euclid::Transform2D<f64, Src, Dst>) -> Self {
+        Self::new_affine(
+            value.m11, value.m12, value.m21, value.m22, value.m31, value.m32,
+        )
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QTransform {
+    type Id = type_id!("QTransform");
+    type Kind = cxx::kind::Trivial;
+}
@@ -237,6 +237,52 @@ impl From<QVector2D> for crate::QPoint {
     }
 }
 
+#[cfg(feature = "glam")]
+impl From<&QVector2D> for glam::Vec2 {
+    fn from(value: &QVector2D) -> Self {
+        Self::from_array(value.v)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<&glam::Vec2> for QVector2D {
+    fn from(value: &glam::Vec2) -> Self {
+        Self {
+            v: value.to_array(),
+        }
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<&QVector2D> for nalgebra::Vector2<f32> {
+    fn from(value: &QVector2D) -> Self {
+        Self::from(value.v)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<&nalgebra::Vector2<f32>> for QVector2D {
+    fn from(value: &nalgebra::Vector2<f32>) -> Self {
+        Self { v: (*value).into() }
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl<U> From<&QVector2D> for euclid::Vector2D<f32, U> {
+    fn from(value: &QVector2D) -> Self {
+        Self::from(value.v)
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl<U> From<&euclid::Vector2D<f32, U>> for QVector2D {
+    fn from(value: &euclid::Vector2D<f32, U>) -> Self {
+        Self {
+            v: value.to_array(),
+        }
+    }
+}
+
 // Safety:
 //
 // Static checks on the C++ side ensure that QVector2D is trivial.
@@ -251,6 +251,52 @@ impl From<QVector3D> for crate::QPoint {
     }
 }
 
+#[cfg(feature = "glam")]
+impl From<&QVector3D> for glam::Vec3 {
+    fn from(value: &QVector3D) -> Self {
+        Self::from_array(value.v)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<&glam::Vec3> for QVector3D {
+    fn from(value: &glam::Vec3) -> Self {
+        Self {
+            v: value.to_array(),
+        }
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<&QVector3D> for nalgebra::Vector3<f32> {
+    fn from(value: &QVector3D) -> Self {
+        Self::from(value.v)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<&nalgebra::Vector3<f32>> for QVector3D {
+    fn from(value: &nalgebra::Vector3<f32>) -> Self {
+        Self { v: (*value).into() }
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl<U> From<&QVector3D> for euclid::Vector3D<f32, U> {
+    fn from(value: &QVector3D) -> Self {
+        Self::from(value.v)
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl<U> From<&euclid::Vector3D<f32, U>> for QVector3D {
+    fn from(value: &euclid::Vector3D<f32, U>) -> Self {
+        Self {
+            v: value.to_array(),
+        }
+    }
+}
+
 // Safety:
 //
 // Static checks on the C++ side ensure that QVector3D is trivial.
@@ -232,6 +232,36 @@ impl From<QVector4D> for crate::QPoint {
     }
 }
 
+#[cfg(feature = "glam")]
+impl From<&QVector4D> for glam::Vec4 {
+    fn from(value: &QVector4D) -> Self {
+        Self::from_array(value.v)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<&glam::Vec4> for QVector4D {
+    fn from(value: &glam::Vec4) -> Self {
+        Self {
+            v: value.to_array(),
+        }
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<&QVector4D> for nalgebra::Vector4<f32> {
+    fn from(value: &QVector4D) -> Self {
+        Self::from(value.v)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<&nalgebra::Vector4<f32>> for QVector4D {
+    fn from(value: &nalgebra::Vector4<f32>) -> Self {
+        Self { v: (*value).into() }
+    }
+}
+
 // Safety:
 //
 // Static checks on the C++ side ensure that QVector4D is trivial.
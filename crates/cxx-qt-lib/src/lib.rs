@@ -20,3 +20,13 @@ pub use crate::gui::*;
 mod qml;
 #[cfg(feature = "qt_qml")]
 pub use crate::qml::*;
+
+#[cfg(feature = "qt_quick")]
+mod quick;
+#[cfg(feature = "qt_quick")]
+pub use crate::quick::*;
+
+#[cfg(feature = "qt_widgets")]
+mod widgets;
+#[cfg(feature = "qt_widgets")]
+pub use crate::widgets::*;
@@ -3,8 +3,20 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod qjsengine;
+pub use qjsengine::QJSEngine;
+
+mod qjsvalue;
+pub use qjsvalue::QJSValue;
+
 mod qqmlapplicationengine;
 pub use qqmlapplicationengine::QQmlApplicationEngine;
 
+mod qqmlcomponent;
+pub use qqmlcomponent::{QQmlComponent, QQmlComponentStatus};
+
+mod qqmlcontext;
+pub use qqmlcontext::QQmlContext;
+
 mod qqmlengine;
 pub use qqmlengine::QQmlEngine;
@@ -0,0 +1,34 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_QML_FEATURE
+#include "cxx-qt-lib/qjsengine.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QVariant
+qjsengineFromScriptValue(QJSEngine& engine, const QJSValue& value)
+{
+  return engine.fromScriptValue<QVariant>(value);
+}
+
+::std::unique_ptr<QJSEngine>
+qjsengineNew()
+{
+  return ::std::make_unique<QJSEngine>();
+}
+
+QJSValue
+qjsengineToScriptValue(QJSEngine& engine, const QVariant& value)
+{
+  return engine.toScriptValue(value);
+}
+
+}
+}
+#endif
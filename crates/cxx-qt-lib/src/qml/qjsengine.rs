@@ -0,0 +1,121 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QJSValue, QObject, QVariant, Upcast};
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qjsvalue.h");
+        type QJSValue = crate::QJSValue;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        include!("cxx-qt-lib/qjsengine.h");
+        type QJSEngine;
+
+        /// Runs the garbage collector.
+        #[rust_name = "collect_garbage"]
+        fn collectGarbage(self: Pin<&mut QJSEngine>);
+
+        /// Evaluates program, using line_number as the base line number, and returns the result of the evaluation.
+        ///
+        /// The script code will be evaluated in the context of the global object.
+        /// The file_name is used for error reporting, it can be empty.
+        fn evaluate(
+            self: Pin<&mut QJSEngine>,
+            program: &QString,
+            file_name: &QString,
+            line_number: i32,
+        ) -> QJSValue;
+
+        /// Returns this engine's Global Object.
+        #[rust_name = "global_object"]
+        fn globalObject(self: &QJSEngine) -> QJSValue;
+
+        /// Creates a JavaScript object of class Array with the given length.
+        #[rust_name = "new_array"]
+        fn newArray(self: Pin<&mut QJSEngine>, length: u32) -> QJSValue;
+
+        /// Creates a JavaScript object of class Object.
+        ///
+        /// The prototype of the created object will be the Object prototype object.
+        #[rust_name = "new_object"]
+        fn newObject(self: Pin<&mut QJSEngine>) -> QJSValue;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qjsengine_from_script_value"]
+        fn qjsengineFromScriptValue(engine: Pin<&mut QJSEngine>, value: &QJSValue) -> QVariant;
+        #[doc(hidden)]
+        #[rust_name = "qjsengine_new"]
+        fn qjsengineNew() -> UniquePtr<QJSEngine>;
+        #[doc(hidden)]
+        #[rust_name = "qjsengine_to_script_value"]
+        fn qjsengineToScriptValue(engine: Pin<&mut QJSEngine>, value: &QVariant) -> QJSValue;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qjsengine_upcast"]
+        fn upcastQObject(object: &QJSEngine) -> &QObject;
+        #[doc(hidden)]
+        #[rust_name = "qjsengine_upcast_mut"]
+        fn upcastQObjectMut(object: Pin<&mut QJSEngine>) -> Pin<&mut QObject>;
+    }
+
+    // QJSEngine is a QObject so it is not trivial or relocatable,
+    // therefore we need to use references or pointers.
+    impl UniquePtr<QJSEngine> {}
+}
+
+pub use ffi::QJSEngine;
+
+impl QJSEngine {
+    /// Constructs an empty QJSEngine.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qjsengine_new()
+    }
+
+    /// Converts the given QJSValue into a QVariant.
+    pub fn from_script_value(self: Pin<&mut Self>, value: &QJSValue) -> QVariant {
+        ffi::qjsengine_from_script_value(self, value)
+    }
+
+    /// Creates a QJSValue with the given QVariant value.
+    ///
+    /// Lists and maps of variants are converted to JavaScript arrays and objects.
+    pub fn to_script_value(self: Pin<&mut Self>, value: &QVariant) -> QJSValue {
+        ffi::qjsengine_to_script_value(self, value)
+    }
+}
+
+impl Upcast<QJSEngine> for QJSEngine {
+    fn upcast(&self) -> &QJSEngine {
+        self
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QJSEngine> {
+        self
+    }
+}
+
+impl Upcast<QObject> for QJSEngine {
+    fn upcast(&self) -> &QObject {
+        ffi::qjsengine_upcast(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QObject> {
+        ffi::qjsengine_upcast_mut(self)
+    }
+}
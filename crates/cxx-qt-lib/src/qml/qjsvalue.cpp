@@ -0,0 +1,82 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_QML_FEATURE
+#include "cxx-qt-lib/qjsvalue.h"
+
+#include <cstdint>
+
+#include "../assertion_utils.h"
+
+// QJSValue has a single member, which is a pointer in Qt 5 and a quint64 in
+// Qt 6
+//
+// https://code.qt.io/cgit/qt/qtdeclarative.git/tree/src/qml/jsapi/qjsvalue.h?h=v5.15.6-lts-lgpl#n152
+// https://code.qt.io/cgit/qt/qtdeclarative.git/tree/src/qml/jsapi/qjsvalue.h?h=v6.2.4#n146
+#if (QT_VERSION < QT_VERSION_CHECK(6, 0, 0))
+assert_alignment_and_size(QJSValue,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t));
+#else
+assert_alignment_and_size(QJSValue,
+                          alignof(::std::uint64_t),
+                          sizeof(::std::uint64_t));
+#endif
+
+static_assert(!::std::is_trivially_copy_assignable<QJSValue>::value);
+static_assert(!::std::is_trivially_copy_constructible<QJSValue>::value);
+
+static_assert(!::std::is_trivially_destructible<QJSValue>::value);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// Note that call is not const in Qt 5, but a copy of a QJSValue refers
+// to the same JavaScript value, so we call on a copy.
+
+QJSValue
+qjsvalueCall(const QJSValue& value, ::rust::Slice<const QJSValue> args)
+{
+  return QJSValue(value).call(qjsvalueListFromSlice(args));
+}
+
+QJSValue
+qjsvalueCallAsConstructor(const QJSValue& value,
+                          ::rust::Slice<const QJSValue> args)
+{
+  return QJSValue(value).callAsConstructor(qjsvalueListFromSlice(args));
+}
+
+QJSValue
+qjsvalueCallWithInstance(const QJSValue& value,
+                         const QJSValue& instance,
+                         ::rust::Slice<const QJSValue> args)
+{
+  return QJSValue(value).callWithInstance(instance,
+                                          qjsvalueListFromSlice(args));
+}
+
+QJSValue
+qjsvalueInitNull()
+{
+  return QJSValue(QJSValue::NullValue);
+}
+
+QJSValueList
+qjsvalueListFromSlice(::rust::Slice<const QJSValue> args)
+{
+  QJSValueList list;
+  list.reserve(static_cast<int>(args.size()));
+  for (const auto& arg : args) {
+    list.append(arg);
+  }
+  return list;
+}
+
+}
+}
+#endif
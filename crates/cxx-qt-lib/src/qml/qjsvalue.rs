@@ -0,0 +1,353 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+use crate::QString;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        include!("cxx-qt-lib/qjsvalue.h");
+        type QJSValue = super::QJSValue;
+
+        /// Deletes the property with the given name from this object.
+        ///
+        /// Returns true if the property was deleted, otherwise returns false.
+        #[rust_name = "delete_property"]
+        fn deleteProperty(self: &mut QJSValue, name: &QString) -> bool;
+
+        /// Returns true if this QJSValue is equal to other, otherwise returns false.
+        ///
+        /// The comparison follows the behavior described in ECMA-262 section 11.9.3, "The Abstract Equality Comparison Algorithm".
+        fn equals(self: &QJSValue, other: &QJSValue) -> bool;
+
+        /// Returns true if this object has an own (not prototype-inherited) property of the given name, otherwise returns false.
+        #[rust_name = "has_own_property"]
+        fn hasOwnProperty(self: &QJSValue, name: &QString) -> bool;
+
+        /// Returns true if this object has a property of the given name, otherwise returns false.
+        #[rust_name = "has_property"]
+        fn hasProperty(self: &QJSValue, name: &QString) -> bool;
+
+        /// Returns true if this QJSValue is an object of the Array class; otherwise returns false.
+        #[rust_name = "is_array"]
+        fn isArray(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is of the primitive type Boolean; otherwise returns false.
+        #[rust_name = "is_bool"]
+        fn isBool(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is a function; otherwise returns false.
+        #[rust_name = "is_callable"]
+        fn isCallable(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is an object of the Date class; otherwise returns false.
+        #[rust_name = "is_date"]
+        fn isDate(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is an object of the Error class; otherwise returns false.
+        #[rust_name = "is_error"]
+        fn isError(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is of the primitive type Null; otherwise returns false.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is of the primitive type Number; otherwise returns false.
+        #[rust_name = "is_number"]
+        fn isNumber(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is of the Object type; otherwise returns false.
+        ///
+        /// Note that function values, variant values, and QObject values are objects,
+        /// so this function returns true for such values.
+        #[rust_name = "is_object"]
+        fn isObject(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is a QObject; otherwise returns false.
+        #[rust_name = "is_qobject"]
+        fn isQObject(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is an object of the RegExp class; otherwise returns false.
+        #[rust_name = "is_reg_exp"]
+        fn isRegExp(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is of the primitive type String; otherwise returns false.
+        #[rust_name = "is_string"]
+        fn isString(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is of the primitive type Undefined; otherwise returns false.
+        #[rust_name = "is_undefined"]
+        fn isUndefined(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is a variant value; otherwise returns false.
+        #[rust_name = "is_variant"]
+        fn isVariant(self: &QJSValue) -> bool;
+
+        /// Returns the value of this QJSValue's property with the given name.
+        ///
+        /// If no such property exists, an undefined QJSValue is returned.
+        fn property(self: &QJSValue, name: &QString) -> QJSValue;
+
+        /// Returns the property at the given array index.
+        #[rust_name = "property_at"]
+        fn property(self: &QJSValue, array_index: u32) -> QJSValue;
+
+        /// Sets the value of this QJSValue's property with the given name to the given value.
+        #[rust_name = "set_property"]
+        fn setProperty(self: &mut QJSValue, name: &QString, value: &QJSValue);
+
+        /// Sets the property at the given array index to the given value.
+        #[rust_name = "set_property_at"]
+        fn setProperty(self: &mut QJSValue, array_index: u32, value: &QJSValue);
+
+        /// Returns true if this QJSValue is equal to other using strict comparison (no conversion), otherwise returns false.
+        ///
+        /// The comparison follows the behavior described in ECMA-262 section 11.9.6, "The Strict Equality Comparison Algorithm".
+        #[rust_name = "strictly_equals"]
+        fn strictlyEquals(self: &QJSValue, other: &QJSValue) -> bool;
+
+        /// Returns the boolean value of this QJSValue, using the conversion rules described in ECMA-262 section 9.2, "ToBoolean".
+        #[rust_name = "to_bool"]
+        fn toBool(self: &QJSValue) -> bool;
+
+        /// Returns the signed 32-bit integer value of this QJSValue, using the conversion rules described in ECMA-262 section 9.5, "ToInt32".
+        #[rust_name = "to_int"]
+        fn toInt(self: &QJSValue) -> i32;
+
+        /// Returns the number value of this QJSValue, as defined in ECMA-262 section 9.3, "ToNumber".
+        #[rust_name = "to_number"]
+        fn toNumber(self: &QJSValue) -> f64;
+
+        /// If this QJSValue is a QObject, returns the QObject pointer that the QJSValue represents;
+        /// otherwise, returns a null pointer.
+        #[rust_name = "to_qobject"]
+        fn toQObject(self: &QJSValue) -> *mut QObject;
+
+        /// Returns the string value of this QJSValue, as defined in ECMA-262 section 9.8, "ToString".
+        #[rust_name = "to_qstring"]
+        fn toString(self: &QJSValue) -> QString;
+
+        /// Returns the unsigned 32-bit integer value of this QJSValue, using the conversion rules described in ECMA-262 section 9.6, "ToUint32".
+        #[rust_name = "to_uint"]
+        fn toUInt(self: &QJSValue) -> u32;
+
+        /// Returns the QVariant value of this QJSValue, if it can be converted to a QVariant;
+        /// otherwise returns an invalid QVariant.
+        ///
+        /// Arrays are converted to a list of variants and objects to a map of variants.
+        #[rust_name = "to_variant"]
+        fn toVariant(self: &QJSValue) -> QVariant;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_call"]
+        fn qjsvalueCall(value: &QJSValue, args: &[QJSValue]) -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_call_as_constructor"]
+        fn qjsvalueCallAsConstructor(value: &QJSValue, args: &[QJSValue]) -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_call_with_instance"]
+        fn qjsvalueCallWithInstance(
+            value: &QJSValue,
+            instance: &QJSValue,
+            args: &[QJSValue],
+        ) -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_init_null"]
+        fn qjsvalueInitNull() -> QJSValue;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_drop"]
+        fn drop(value: &mut QJSValue);
+
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_init_default"]
+        fn construct() -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_init_from_bool"]
+        fn construct(value: bool) -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_init_from_f64"]
+        fn construct(value: f64) -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_init_from_i32"]
+        fn construct(value: i32) -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_init_from_qjsvalue"]
+        fn construct(value: &QJSValue) -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_init_from_qstring"]
+        fn construct(value: &QString) -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_init_from_u32"]
+        fn construct(value: u32) -> QJSValue;
+    }
+}
+
+/// The QJSValue class acts as a container for Qt/JavaScript data types.
+///
+/// It is used to hold values such as callbacks and objects from QML,
+/// which can be passed into invokables as `&QJSValue`.
+#[repr(C)]
+pub struct QJSValue {
+    /// QJSValue has a single member, which is a pointer in Qt 5 and a quint64 in Qt 6
+    #[cfg(qt_version_major = "5")]
+    _space: MaybeUninit<usize>,
+    #[cfg(qt_version_major = "6")]
+    _space: MaybeUninit<u64>,
+}
+
+impl QJSValue {
+    /// Constructs a new QJSValue with the null value.
+    pub fn null() -> Self {
+        ffi::qjsvalue_init_null()
+    }
+
+    /// Creates an iterator over the elements of this QJSValue when it is an array.
+    ///
+    /// The length property of the value is used to find the number of elements,
+    /// so if this is not an array the iterator is empty.
+    pub fn array_iter(&self) -> impl Iterator<Item = QJSValue> + '_ {
+        let len = if self.is_array() {
+            self.property(&QString::from("length")).to_uint()
+        } else {
+            0
+        };
+        (0..len).map(|index| self.property_at(index))
+    }
+
+    /// Calls this QJSValue as a function, passing args as arguments to the function,
+    /// and using the globalObject() as the "this"-object. Returns the value returned from the function.
+    ///
+    /// If this QJSValue is not callable, call() does nothing and returns an undefined QJSValue.
+    pub fn call(&self, args: &[QJSValue]) -> QJSValue {
+        ffi::qjsvalue_call(self, args)
+    }
+
+    /// Creates a new Object and calls this QJSValue as a constructor,
+    /// using the created object as the `this' object and passing args as arguments.
+    ///
+    /// If the return value from the constructor call is an object, then that object is returned;
+    /// otherwise the default constructed object is returned.
+    pub fn call_as_constructor(&self, args: &[QJSValue]) -> QJSValue {
+        ffi::qjsvalue_call_as_constructor(self, args)
+    }
+
+    /// Calls this QJSValue as a function, using instance as the "this"-object in the function call,
+    /// and passing args as arguments to the function. Returns the value returned from the function.
+    pub fn call_with_instance(&self, instance: &QJSValue, args: &[QJSValue]) -> QJSValue {
+        ffi::qjsvalue_call_with_instance(self, instance, args)
+    }
+}
+
+impl Clone for QJSValue {
+    /// Constructs a new QJSValue that is a copy of other.
+    ///
+    /// Note that if other is an object (i.e., is_object() would return true),
+    /// then only a reference to the underlying object is copied into the new script value.
+    fn clone(&self) -> Self {
+        ffi::qjsvalue_init_from_qjsvalue(self)
+    }
+}
+
+impl Default for QJSValue {
+    /// Constructs a new QJSValue with the undefined value.
+    fn default() -> Self {
+        ffi::qjsvalue_init_default()
+    }
+}
+
+impl Drop for QJSValue {
+    /// Destroys this QJSValue.
+    fn drop(&mut self) {
+        ffi::qjsvalue_drop(self);
+    }
+}
+
+impl fmt::Display for QJSValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_qstring())
+    }
+}
+
+impl fmt::Debug for QJSValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl From<bool> for QJSValue {
+    /// Constructs a new QJSValue with a boolean value.
+    fn from(value: bool) -> Self {
+        ffi::qjsvalue_init_from_bool(value)
+    }
+}
+
+impl From<f64> for QJSValue {
+    /// Constructs a new QJSValue with a number value.
+    fn from(value: f64) -> Self {
+        ffi::qjsvalue_init_from_f64(value)
+    }
+}
+
+impl From<i32> for QJSValue {
+    /// Constructs a new QJSValue with a number value.
+    fn from(value: i32) -> Self {
+        ffi::qjsvalue_init_from_i32(value)
+    }
+}
+
+impl From<&QString> for QJSValue {
+    /// Constructs a new QJSValue with a string value.
+    fn from(value: &QString) -> Self {
+        ffi::qjsvalue_init_from_qstring(value)
+    }
+}
+
+impl From<&str> for QJSValue {
+    /// Constructs a new QJSValue with a string value.
+    fn from(value: &str) -> Self {
+        Self::from(&QString::from(value))
+    }
+}
+
+impl From<u32> for QJSValue {
+    /// Constructs a new QJSValue with a number value.
+    fn from(value: u32) -> Self {
+        ffi::qjsvalue_init_from_u32(value)
+    }
+}
+
+impl std::cmp::PartialEq for QJSValue {
+    /// Compares the values using strict comparison (no conversion)
+    fn eq(&self, other: &Self) -> bool {
+        self.strictly_equals(other)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QJSValue {
+    type Id = type_id!("QJSValue");
+    type Kind = cxx::kind::Trivial;
+}
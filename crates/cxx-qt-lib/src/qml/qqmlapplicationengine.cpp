@@ -17,6 +17,34 @@ qqmlapplicationengineNew()
   return ::std::make_unique<QQmlApplicationEngine>();
 }
 
+QObject*
+qqmlapplicationengineRootObjectAt(const QQmlApplicationEngine& engine,
+                                  ::rust::isize index)
+{
+  const auto rootObjects = engine.rootObjects();
+  Q_ASSERT(index >= 0);
+  Q_ASSERT(index < static_cast<::rust::isize>(rootObjects.size()));
+  return rootObjects.at(static_cast<int>(index));
+}
+
+::rust::isize
+qqmlapplicationengineRootObjectsLen(const QQmlApplicationEngine& engine)
+{
+  return static_cast<::rust::isize>(engine.rootObjects().size());
+}
+
+const QQmlEngine&
+qqmlapplicationengineUpcastQQmlEngine(const QQmlApplicationEngine& engine)
+{
+  return engine;
+}
+
+QQmlEngine&
+qqmlapplicationengineUpcastQQmlEngineMut(QQmlApplicationEngine& engine)
+{
+  return engine;
+}
+
 }
 }
 #endif
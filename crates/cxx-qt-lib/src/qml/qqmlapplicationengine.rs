@@ -3,9 +3,20 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use crate::{QJSEngine, QMetaObjectConnection, QObject, QQmlEngine, QUrl, Upcast};
+use core::pin::Pin;
+
 #[cxx::bridge]
 mod ffi {
     unsafe extern "C++" {
+        include!("cxx-qt-lib/qjsengine.h");
+        type QJSEngine = crate::QJSEngine;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qqmlcontext.h");
+        type QQmlContext = crate::QQmlContext;
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = crate::QQmlEngine;
         include!("cxx-qt-lib/qstring.h");
         type QString = crate::QString;
         include!("cxx-qt-lib/qstringlist.h");
@@ -40,6 +51,14 @@ mod ffi {
         #[rust_name = "plugin_path_list"]
         fn pluginPathList(self: &QQmlApplicationEngine) -> QStringList;
 
+        /// Returns the engine's root context.
+        ///
+        /// The root context is automatically created by the QQmlEngine.
+        /// Data that should be available to all QML component instances instantiated by the engine
+        /// should be put in the root context.
+        #[rust_name = "root_context"]
+        fn rootContext(self: &QQmlApplicationEngine) -> *mut QQmlContext;
+
         /// Set the base URL for this engine to url.
         #[rust_name = "set_base_url"]
         fn setBaseUrl(self: Pin<&mut QQmlApplicationEngine>, url: &QUrl);
@@ -53,11 +72,65 @@ mod ffi {
         fn setPluginPathList(self: Pin<&mut QQmlApplicationEngine>, paths: &QStringList);
     }
 
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QQmlApplicationEngineObjectCreatedClosure;
+    }
+
     #[namespace = "rust::cxxqtlib1"]
     unsafe extern "C++" {
         #[doc(hidden)]
         #[rust_name = "qqmlapplicationengine_new"]
         fn qqmlapplicationengineNew() -> UniquePtr<QQmlApplicationEngine>;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlapplicationengine_on_object_created"]
+        fn qqmlapplicationengineOnObjectCreated(
+            engine: Pin<&mut QQmlApplicationEngine>,
+            func: unsafe fn(&mut QQmlApplicationEngineObjectCreatedClosure, *mut QObject, &QUrl),
+            closure: Box<QQmlApplicationEngineObjectCreatedClosure>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qqmlapplicationengine_root_object_at"]
+        fn qqmlapplicationengineRootObjectAt(
+            engine: &QQmlApplicationEngine,
+            index: isize,
+        ) -> *mut QObject;
+        #[doc(hidden)]
+        #[rust_name = "qqmlapplicationengine_root_objects_len"]
+        fn qqmlapplicationengineRootObjectsLen(engine: &QQmlApplicationEngine) -> isize;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlapplicationengine_upcast_qqmlengine"]
+        fn qqmlapplicationengineUpcastQQmlEngine(object: &QQmlApplicationEngine) -> &QQmlEngine;
+        #[doc(hidden)]
+        #[rust_name = "qqmlapplicationengine_upcast_qqmlengine_mut"]
+        fn qqmlapplicationengineUpcastQQmlEngineMut(
+            object: Pin<&mut QQmlApplicationEngine>,
+        ) -> Pin<&mut QQmlEngine>;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qqmlapplicationengine_upcast"]
+        fn upcastQObject(object: &QQmlApplicationEngine) -> &QObject;
+        #[doc(hidden)]
+        #[rust_name = "qqmlapplicationengine_upcast_mut"]
+        fn upcastQObjectMut(object: Pin<&mut QQmlApplicationEngine>) -> Pin<&mut QObject>;
+        #[doc(hidden)]
+        #[rust_name = "qqmlapplicationengine_upcast_qjsengine"]
+        fn upcastQJSEngine(object: &QQmlApplicationEngine) -> &QJSEngine;
+        #[doc(hidden)]
+        #[rust_name = "qqmlapplicationengine_upcast_qjsengine_mut"]
+        fn upcastQJSEngineMut(object: Pin<&mut QQmlApplicationEngine>) -> Pin<&mut QJSEngine>;
     }
 
     // QQmlApplicationEngine is not a trivial to CXX and is not relocatable in Qt
@@ -69,9 +142,84 @@ mod ffi {
 
 pub use ffi::QQmlApplicationEngine;
 
+type ObjectCreatedFn = dyn FnMut(*mut QObject, &QUrl);
+
+/// A Rust closure which is called from C++ when the engine has created an object.
+#[doc(hidden)]
+pub struct QQmlApplicationEngineObjectCreatedClosure {
+    closure: Box<ObjectCreatedFn>,
+}
+
+impl QQmlApplicationEngineObjectCreatedClosure {
+    fn new(closure: impl FnMut(*mut QObject, &QUrl) + 'static) -> Box<Self> {
+        Box::new(Self {
+            closure: Box::new(closure),
+        })
+    }
+
+    fn call(&mut self, object: *mut QObject, url: &QUrl) {
+        (self.closure)(object, url);
+    }
+}
+
 impl QQmlApplicationEngine {
     /// Create a new QQmlApplicationEngine
     pub fn new() -> cxx::UniquePtr<Self> {
         ffi::qqmlapplicationengine_new()
     }
+
+    /// Connect the given closure to the objectCreated signal of the engine.
+    ///
+    /// The closure is called with the created object and the url of the component
+    /// whenever the engine has finished loading a root component.
+    /// If there are errors the object is a null pointer.
+    pub fn on_object_created<F>(self: Pin<&mut Self>, closure: F) -> QMetaObjectConnection
+    where
+        F: FnMut(*mut QObject, &QUrl) + 'static,
+    {
+        ffi::qqmlapplicationengine_on_object_created(
+            self,
+            QQmlApplicationEngineObjectCreatedClosure::call,
+            QQmlApplicationEngineObjectCreatedClosure::new(closure),
+        )
+    }
+
+    /// Returns a list of all the root objects instantiated by the QQmlApplicationEngine.
+    ///
+    /// This will only contain objects loaded via load() or a convenience constructor.
+    pub fn root_objects(&self) -> Vec<*mut QObject> {
+        (0..ffi::qqmlapplicationengine_root_objects_len(self))
+            .map(|index| ffi::qqmlapplicationengine_root_object_at(self, index))
+            .collect()
+    }
+}
+
+impl Upcast<QQmlEngine> for QQmlApplicationEngine {
+    fn upcast(&self) -> &QQmlEngine {
+        ffi::qqmlapplicationengine_upcast_qqmlengine(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QQmlEngine> {
+        ffi::qqmlapplicationengine_upcast_qqmlengine_mut(self)
+    }
+}
+
+impl Upcast<QJSEngine> for QQmlApplicationEngine {
+    fn upcast(&self) -> &QJSEngine {
+        ffi::qqmlapplicationengine_upcast_qjsengine(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QJSEngine> {
+        ffi::qqmlapplicationengine_upcast_qjsengine_mut(self)
+    }
+}
+
+impl Upcast<QObject> for QQmlApplicationEngine {
+    fn upcast(&self) -> &QObject {
+        ffi::qqmlapplicationengine_upcast(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QObject> {
+        ffi::qqmlapplicationengine_upcast_mut(self)
+    }
 }
@@ -0,0 +1,49 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_QML_FEATURE
+#include "cxx-qt-lib/qqmlcomponent.h"
+
+#include "cxx-qt-lib/qqmlengine.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QObject*
+qqmlcomponentCreate(QQmlComponent& component)
+{
+  return component.create();
+}
+
+QObject*
+qqmlcomponentCreateWithInitialProperties(QQmlComponent& component,
+                                         const QVariantMap& properties)
+{
+  return component.createWithInitialProperties(properties);
+}
+
+QStringList
+qqmlcomponentErrors(const QQmlComponent& component)
+{
+  return qqmlerrorsToQStringList(component.errors());
+}
+
+::std::unique_ptr<QQmlComponent>
+qqmlcomponentNew(QQmlEngine& engine)
+{
+  return ::std::make_unique<QQmlComponent>(&engine);
+}
+
+::std::unique_ptr<QQmlComponent>
+qqmlcomponentNewFromUrl(QQmlEngine& engine, const QUrl& url)
+{
+  return ::std::make_unique<QQmlComponent>(&engine, url);
+}
+
+}
+}
+#endif
@@ -0,0 +1,186 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QMap, QMapPair_QString_QVariant, QObject, QQmlEngine, QStringList, QUrl, Upcast};
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    /// Specifies the loading status of the QQmlComponent.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    enum QQmlComponentStatus {
+        /// This QQmlComponent has no data. Call load_url() or set_data() to add QML content.
+        Null,
+        /// This QQmlComponent is ready and create() may be called.
+        Ready,
+        /// This QQmlComponent is loading network data.
+        Loading,
+        /// An error has occurred. Call errors() to retrieve a list of errors.
+        Error,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qmap.h");
+        type QMap_QString_QVariant = crate::QMap<crate::QMapPair_QString_QVariant>;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = crate::QQmlEngine;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = crate::QUrl;
+
+        include!("cxx-qt-lib/qqmlcomponent.h");
+        type QQmlComponent;
+
+        /// Returns a human readable description of any error.
+        ///
+        /// The string includes the file, location, and description of each error.
+        /// If multiple errors are present, they are separated by a newline character.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QQmlComponent) -> QString;
+
+        /// Returns true if status() == QQmlComponentStatus::Error.
+        #[rust_name = "is_error"]
+        fn isError(self: &QQmlComponent) -> bool;
+
+        /// Returns true if status() == QQmlComponentStatus::Loading.
+        #[rust_name = "is_loading"]
+        fn isLoading(self: &QQmlComponent) -> bool;
+
+        /// Returns true if status() == QQmlComponentStatus::Null.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QQmlComponent) -> bool;
+
+        /// Returns true if status() == QQmlComponentStatus::Ready.
+        #[rust_name = "is_ready"]
+        fn isReady(self: &QQmlComponent) -> bool;
+
+        /// Load the QQmlComponent from the provided url.
+        #[rust_name = "load_url"]
+        fn loadUrl(self: Pin<&mut QQmlComponent>, url: &QUrl);
+
+        /// The progress of loading the component, from 0.0 (nothing loaded) to 1.0 (finished).
+        fn progress(self: &QQmlComponent) -> f64;
+
+        /// Sets the QQmlComponent to use the given QML data.
+        ///
+        /// If url is provided, it is used to set the component name and to provide a base path for items resolved by this component.
+        #[rust_name = "set_data"]
+        fn setData(self: Pin<&mut QQmlComponent>, data: &QByteArray, url: &QUrl);
+
+        /// The component's current status.
+        fn status(self: &QQmlComponent) -> QQmlComponentStatus;
+
+        /// The component URL.
+        ///
+        /// This is the URL passed to either the constructor, or the load_url(), or set_data() methods.
+        fn url(self: &QQmlComponent) -> QUrl;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QQmlComponentStatus;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlcomponent_create"]
+        fn qqmlcomponentCreate(component: Pin<&mut QQmlComponent>) -> *mut QObject;
+        #[doc(hidden)]
+        #[rust_name = "qqmlcomponent_create_with_initial_properties"]
+        fn qqmlcomponentCreateWithInitialProperties(
+            component: Pin<&mut QQmlComponent>,
+            properties: &QMap_QString_QVariant,
+        ) -> *mut QObject;
+        #[doc(hidden)]
+        #[rust_name = "qqmlcomponent_errors"]
+        fn qqmlcomponentErrors(component: &QQmlComponent) -> QStringList;
+        #[doc(hidden)]
+        #[rust_name = "qqmlcomponent_new"]
+        fn qqmlcomponentNew(engine: Pin<&mut QQmlEngine>) -> UniquePtr<QQmlComponent>;
+        #[doc(hidden)]
+        #[rust_name = "qqmlcomponent_new_from_url"]
+        fn qqmlcomponentNewFromUrl(
+            engine: Pin<&mut QQmlEngine>,
+            url: &QUrl,
+        ) -> UniquePtr<QQmlComponent>;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qqmlcomponent_upcast"]
+        fn upcastQObject(object: &QQmlComponent) -> &QObject;
+        #[doc(hidden)]
+        #[rust_name = "qqmlcomponent_upcast_mut"]
+        fn upcastQObjectMut(object: Pin<&mut QQmlComponent>) -> Pin<&mut QObject>;
+    }
+
+    // QQmlComponent is a QObject so it is not trivial or relocatable,
+    // therefore we need to use references or pointers.
+    impl UniquePtr<QQmlComponent> {}
+}
+
+pub use ffi::{QQmlComponent, QQmlComponentStatus};
+
+impl QQmlComponent {
+    /// Create a QQmlComponent with no data for the given engine.
+    ///
+    /// Set the data with load_url() or set_data().
+    pub fn new<T>(engine: Pin<&mut T>) -> cxx::UniquePtr<Self>
+    where
+        T: Upcast<QQmlEngine>,
+    {
+        ffi::qqmlcomponent_new(engine.upcast_pin())
+    }
+
+    /// Create a QQmlComponent from the given url for the given engine.
+    pub fn from_url<T>(engine: Pin<&mut T>, url: &QUrl) -> cxx::UniquePtr<Self>
+    where
+        T: Upcast<QQmlEngine>,
+    {
+        ffi::qqmlcomponent_new_from_url(engine.upcast_pin(), url)
+    }
+
+    /// Create an object instance from this component.
+    ///
+    /// Returns a null pointer if creation failed, the caller takes ownership of the returned object.
+    pub fn create(self: Pin<&mut Self>) -> *mut QObject {
+        ffi::qqmlcomponent_create(self)
+    }
+
+    /// Create an object instance from this component, setting the given initial properties
+    /// before the component is completed.
+    ///
+    /// Returns a null pointer if creation failed, the caller takes ownership of the returned object.
+    pub fn create_with_initial_properties(
+        self: Pin<&mut Self>,
+        properties: &QMap<QMapPair_QString_QVariant>,
+    ) -> *mut QObject {
+        ffi::qqmlcomponent_create_with_initial_properties(self, properties)
+    }
+
+    /// Return the list of errors that occurred during the last compile or create operation.
+    ///
+    /// An empty list is returned if is_error() is not set.
+    pub fn errors(&self) -> QStringList {
+        ffi::qqmlcomponent_errors(self)
+    }
+}
+
+impl Upcast<QObject> for QQmlComponent {
+    fn upcast(&self) -> &QObject {
+        ffi::qqmlcomponent_upcast(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QObject> {
+        ffi::qqmlcomponent_upcast_mut(self)
+    }
+}
@@ -0,0 +1,114 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QObject, QString, Upcast};
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = crate::QQmlEngine;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = crate::QUrl;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        include!("cxx-qt-lib/qqmlcontext.h");
+        type QQmlContext;
+
+        /// Returns the context's base url.
+        ///
+        /// This is the url of the component that created the context,
+        /// if no base url has been set with set_base_url().
+        #[rust_name = "base_url"]
+        fn baseUrl(self: &QQmlContext) -> QUrl;
+
+        /// Returns the value of the name property for this context as a QVariant.
+        #[rust_name = "context_property"]
+        fn contextProperty(self: &QQmlContext, name: &QString) -> QVariant;
+
+        /// Return the context's QQmlEngine, or a null pointer if the context has no QQmlEngine or the QQmlEngine was destroyed.
+        fn engine(self: &QQmlContext) -> *mut QQmlEngine;
+
+        /// Returns whether the context is valid.
+        ///
+        /// To be valid, a context must have a engine, and it's contextObject(), if any, must not have been deleted.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QQmlContext) -> bool;
+
+        /// Return the context's parent QQmlContext, or a null pointer if this context has no parent or if the parent has been destroyed.
+        #[rust_name = "parent_context"]
+        fn parentContext(self: &QQmlContext) -> *mut QQmlContext;
+
+        /// Resolves the URL src relative to the URL of the containing component.
+        #[rust_name = "resolved_url"]
+        fn resolvedUrl(self: &QQmlContext, src: &QUrl) -> QUrl;
+
+        /// Explicitly sets the url returned by base_url() to base_url.
+        #[rust_name = "set_base_url"]
+        fn setBaseUrl(self: Pin<&mut QQmlContext>, base_url: &QUrl);
+
+        /// Set the value of the name property on this context.
+        #[rust_name = "set_context_property"]
+        fn setContextProperty(self: Pin<&mut QQmlContext>, name: &QString, value: &QVariant);
+
+        #[doc(hidden)]
+        #[rust_name = "set_context_property_qobject_raw"]
+        unsafe fn setContextProperty(
+            self: Pin<&mut QQmlContext>,
+            name: &QString,
+            value: *mut QObject,
+        );
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qqmlcontext_upcast"]
+        fn upcastQObject(object: &QQmlContext) -> &QObject;
+        #[doc(hidden)]
+        #[rust_name = "qqmlcontext_upcast_mut"]
+        fn upcastQObjectMut(object: Pin<&mut QQmlContext>) -> Pin<&mut QObject>;
+    }
+}
+
+pub use ffi::QQmlContext;
+
+impl QQmlContext {
+    /// Set the value of the name property on this context to the given QObject.
+    ///
+    /// This can be any QObject from cxx-qt-lib or a `#[qobject]` generated by CXX-Qt,
+    /// which is then available to QML under the given name.
+    ///
+    /// # Safety
+    ///
+    /// The context does not take ownership of the object,
+    /// so the object must outlive any QML which uses the context property.
+    pub unsafe fn set_context_property_qobject<T>(
+        self: Pin<&mut Self>,
+        name: &QString,
+        object: Pin<&mut T>,
+    ) where
+        T: Upcast<QObject>,
+    {
+        let object = object.upcast_pin().get_unchecked_mut() as *mut QObject;
+        self.set_context_property_qobject_raw(name, object);
+    }
+}
+
+impl Upcast<QObject> for QQmlContext {
+    fn upcast(&self) -> &QObject {
+        ffi::qqmlcontext_upcast(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QObject> {
+        ffi::qqmlcontext_upcast_mut(self)
+    }
+}
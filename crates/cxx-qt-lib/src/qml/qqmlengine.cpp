@@ -17,6 +17,16 @@ qqmlengineNew()
   return ::std::make_unique<QQmlEngine>();
 }
 
+QStringList
+qqmlerrorsToQStringList(const QList<QQmlError>& errors)
+{
+  QStringList list;
+  for (const auto& error : errors) {
+    list.append(error.toString());
+  }
+  return list;
+}
+
 }
 }
 #endif
@@ -3,9 +3,18 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use crate::{QJSEngine, QMetaObjectConnection, QObject, QStringList, Upcast};
+use core::pin::Pin;
+
 #[cxx::bridge]
 mod ffi {
     unsafe extern "C++" {
+        include!("cxx-qt-lib/qjsengine.h");
+        type QJSEngine = crate::QJSEngine;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qqmlcontext.h");
+        type QQmlContext = crate::QQmlContext;
         include!("cxx-qt-lib/qstring.h");
         type QString = crate::QString;
         include!("cxx-qt-lib/qstringlist.h");
@@ -37,6 +46,18 @@ mod ffi {
         #[rust_name = "plugin_path_list"]
         fn pluginPathList(self: &QQmlEngine) -> QStringList;
 
+        /// Removes the image provider for provider_id.
+        #[rust_name = "remove_image_provider"]
+        fn removeImageProvider(self: Pin<&mut QQmlEngine>, provider_id: &QString);
+
+        /// Returns the engine's root context.
+        ///
+        /// The root context is automatically created by the QQmlEngine.
+        /// Data that should be available to all QML component instances instantiated by the engine
+        /// should be put in the root context.
+        #[rust_name = "root_context"]
+        fn rootContext(self: &QQmlEngine) -> *mut QQmlContext;
+
         /// Set the base URL for this engine to url.
         #[rust_name = "set_base_url"]
         fn setBaseUrl(self: Pin<&mut QQmlEngine>, url: &QUrl);
@@ -50,11 +71,47 @@ mod ffi {
         fn setPluginPathList(self: Pin<&mut QQmlEngine>, paths: &QStringList);
     }
 
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QQmlEngineWarningsClosure;
+    }
+
     #[namespace = "rust::cxxqtlib1"]
     unsafe extern "C++" {
         #[doc(hidden)]
         #[rust_name = "qqmlengine_new"]
         fn qqmlengineNew() -> UniquePtr<QQmlEngine>;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlengine_on_warnings"]
+        fn qqmlengineOnWarnings(
+            engine: Pin<&mut QQmlEngine>,
+            func: fn(&mut QQmlEngineWarningsClosure, &QStringList),
+            closure: Box<QQmlEngineWarningsClosure>,
+        ) -> QMetaObjectConnection;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qqmlengine_upcast"]
+        fn upcastQObject(object: &QQmlEngine) -> &QObject;
+        #[doc(hidden)]
+        #[rust_name = "qqmlengine_upcast_mut"]
+        fn upcastQObjectMut(object: Pin<&mut QQmlEngine>) -> Pin<&mut QObject>;
+        #[doc(hidden)]
+        #[rust_name = "qqmlengine_upcast_qjsengine"]
+        fn upcastQJSEngine(object: &QQmlEngine) -> &QJSEngine;
+        #[doc(hidden)]
+        #[rust_name = "qqmlengine_upcast_qjsengine_mut"]
+        fn upcastQJSEngineMut(object: Pin<&mut QQmlEngine>) -> Pin<&mut QJSEngine>;
     }
 
     // QQmlEngine is not a trivial to CXX and is not relocatable in Qt
@@ -66,9 +123,72 @@ mod ffi {
 
 pub use ffi::QQmlEngine;
 
+/// A Rust closure which is called from C++ when the engine emits warnings.
+#[doc(hidden)]
+pub struct QQmlEngineWarningsClosure {
+    closure: Box<dyn FnMut(&QStringList)>,
+}
+
+impl QQmlEngineWarningsClosure {
+    fn new(closure: impl FnMut(&QStringList) + 'static) -> Box<Self> {
+        Box::new(Self {
+            closure: Box::new(closure),
+        })
+    }
+
+    fn call(&mut self, warnings: &QStringList) {
+        (self.closure)(warnings);
+    }
+}
+
 impl QQmlEngine {
     /// Create a new QQmlEngine
     pub fn new() -> cxx::UniquePtr<Self> {
         ffi::qqmlengine_new()
     }
+
+    /// Connect the given closure to the warnings signal of the engine.
+    ///
+    /// The closure is called with the warnings as strings when the engine
+    /// encounters errors in QML, until the returned [QMetaObjectConnection] is dropped.
+    pub fn on_warnings<F>(self: Pin<&mut Self>, closure: F) -> QMetaObjectConnection
+    where
+        F: FnMut(&QStringList) + 'static,
+    {
+        ffi::qqmlengine_on_warnings(
+            self,
+            QQmlEngineWarningsClosure::call,
+            QQmlEngineWarningsClosure::new(closure),
+        )
+    }
+}
+
+impl Upcast<QQmlEngine> for QQmlEngine {
+    fn upcast(&self) -> &QQmlEngine {
+        self
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QQmlEngine> {
+        self
+    }
+}
+
+impl Upcast<QJSEngine> for QQmlEngine {
+    fn upcast(&self) -> &QJSEngine {
+        ffi::qqmlengine_upcast_qjsengine(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QJSEngine> {
+        ffi::qqmlengine_upcast_qjsengine_mut(self)
+    }
+}
+
+impl Upcast<QObject> for QQmlEngine {
+    fn upcast(&self) -> &QObject {
+        ffi::qqmlengine_upcast(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QObject> {
+        ffi::qqmlengine_upcast_mut(self)
+    }
 }
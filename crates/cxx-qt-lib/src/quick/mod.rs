@@ -0,0 +1,13 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qquickimageprovider;
+pub use qquickimageprovider::QQuickImageProvider;
+
+mod qquickitem;
+pub use qquickitem::{QQuickItem, QQuickItemFlag};
+
+mod qquickpainteditem;
+pub use qquickpainteditem::{QQuickPaintedItem, QQuickPaintedItemRenderTarget};
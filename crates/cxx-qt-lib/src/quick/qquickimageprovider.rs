@@ -0,0 +1,120 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QImage, QQmlEngine, QSize, QString};
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qimage.h");
+        type QImage = crate::QImage;
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = crate::QQmlEngine;
+        include!("cxx-qt-lib/qsize.h");
+        type QSize = crate::QSize;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QQuickImageProviderBox;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qquickimageprovider.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlengine_add_async_image_provider"]
+        fn qqmlengineAddAsyncImageProvider(
+            engine: Pin<&mut QQmlEngine>,
+            id: &QString,
+            func: fn(&QQuickImageProviderBox, &QString, &QSize) -> QImage,
+            provider: Box<QQuickImageProviderBox>,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qqmlengine_add_image_provider"]
+        fn qqmlengineAddImageProvider(
+            engine: Pin<&mut QQmlEngine>,
+            id: &QString,
+            func: fn(&QQuickImageProviderBox, &QString, &QSize) -> QImage,
+            provider: Box<QQuickImageProviderBox>,
+        );
+    }
+}
+
+/// An image provider which is implemented in Rust and provides images to QML,
+/// see [QQmlEngine::add_image_provider] and [QQmlEngine::add_async_image_provider].
+///
+/// The provider can be called from threads other than the one that the engine lives in,
+/// such as when an `Image` has `asynchronous: true`, so it must be [Send] and [Sync].
+pub trait QQuickImageProvider: Send + Sync {
+    /// Implement this method to return the image with the given id.
+    ///
+    /// The id is the part of the "image://" URL after the provider id.
+    /// If requested_size is valid it is the width and height that was requested by the `Image` item,
+    /// and the image should be scaled to fit this size.
+    ///
+    /// Return a null [QImage] if the image could not be provided.
+    fn request_image(&self, id: &QString, requested_size: &QSize) -> QImage;
+}
+
+/// A Rust image provider which is called from C++ when QML requests an image.
+#[doc(hidden)]
+pub struct QQuickImageProviderBox {
+    provider: Box<dyn QQuickImageProvider>,
+}
+
+impl QQuickImageProviderBox {
+    fn new(provider: impl QQuickImageProvider + 'static) -> Box<Self> {
+        Box::new(Self {
+            provider: Box::new(provider),
+        })
+    }
+
+    fn request_image(&self, id: &QString, requested_size: &QSize) -> QImage {
+        self.provider.request_image(id, requested_size)
+    }
+}
+
+impl QQmlEngine {
+    /// Sets the provider to use for images requested via the "image:" url scheme, with host provider_id.
+    ///
+    /// The engine takes ownership of the provider, any existing provider with the same id is replaced.
+    ///
+    /// Images are requested synchronously unless the `Image` item has `asynchronous: true`.
+    pub fn add_image_provider<T>(self: Pin<&mut Self>, provider_id: &QString, provider: T)
+    where
+        T: QQuickImageProvider + 'static,
+    {
+        ffi::qqmlengine_add_image_provider(
+            self,
+            provider_id,
+            QQuickImageProviderBox::request_image,
+            QQuickImageProviderBox::new(provider),
+        );
+    }
+
+    /// Sets the provider to use for images requested via the "image:" url scheme, with host provider_id.
+    ///
+    /// The engine takes ownership of the provider, any existing provider with the same id is replaced.
+    ///
+    /// Images are always requested asynchronously on a thread pool which is owned by the provider,
+    /// so that slow image decoding does not block the GUI thread.
+    pub fn add_async_image_provider<T>(self: Pin<&mut Self>, provider_id: &QString, provider: T)
+    where
+        T: QQuickImageProvider + 'static,
+    {
+        ffi::qqmlengine_add_async_image_provider(
+            self,
+            provider_id,
+            QQuickImageProviderBox::request_image,
+            QQuickImageProviderBox::new(provider),
+        );
+    }
+}
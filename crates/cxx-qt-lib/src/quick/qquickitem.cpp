@@ -0,0 +1,28 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_QUICK_FEATURE
+#include "cxx-qt-lib/qquickitem.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+qquickitemHasFlag(const QQuickItem& item, QQuickItemFlag flag)
+{
+  return item.flags().testFlag(flag);
+}
+
+::std::unique_ptr<QQuickItem>
+qquickitemNew()
+{
+  return ::std::make_unique<QQuickItem>();
+}
+
+}
+}
+#endif
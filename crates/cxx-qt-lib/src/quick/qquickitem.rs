@@ -0,0 +1,252 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QObject, Upcast};
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    /// Flags which control the behaviour of a QQuickItem.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    enum QQuickItemFlag {
+        /// Indicates this item should visually clip its children
+        /// so that they are rendered only within the boundaries of this item.
+        ItemClipsChildrenToShape = 0x01,
+        /// Indicates the item supports text input methods.
+        ItemAcceptsInputMethod = 0x02,
+        /// Indicates the item is a focus scope.
+        ItemIsFocusScope = 0x04,
+        /// Indicates the item has visual content and should be rendered by the scene graph.
+        ItemHasContents = 0x08,
+        /// Indicates the item accepts drag and drop events.
+        ItemAcceptsDrops = 0x10,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qrectf.h");
+        type QRectF = crate::QRectF;
+        include!("cxx-qt-lib/qsizef.h");
+        type QSizeF = crate::QSizeF;
+
+        include!("cxx-qt-lib/qquickitem.h");
+        /// The QQuickItem class provides the most basic of all visual items in Qt Quick.
+        type QQuickItem;
+
+        /// Returns true if the item is antialiased.
+        fn antialiasing(self: &QQuickItem) -> bool;
+
+        /// Returns the extents of the item in its own coordinate system:
+        /// a rectangle from 0, 0 to width() and height().
+        #[rust_name = "bounding_rect"]
+        fn boundingRect(self: &QQuickItem) -> QRectF;
+
+        /// Forces active focus on the item.
+        ///
+        /// This method sets focus on the item and ensures that all ancestor FocusScope objects
+        /// in the object hierarchy are also given focus.
+        #[rust_name = "force_active_focus"]
+        fn forceActiveFocus(self: Pin<&mut QQuickItem>);
+
+        /// Returns whether the item has active focus.
+        #[rust_name = "has_active_focus"]
+        fn hasActiveFocus(self: &QQuickItem) -> bool;
+
+        /// Returns the height of the item.
+        fn height(self: &QQuickItem) -> f64;
+
+        /// Returns the height that is implied by the content of the item.
+        #[rust_name = "implicit_height"]
+        fn implicitHeight(self: &QQuickItem) -> f64;
+
+        /// Returns the width that is implied by the content of the item.
+        #[rust_name = "implicit_width"]
+        fn implicitWidth(self: &QQuickItem) -> f64;
+
+        /// Returns whether the item receives mouse and keyboard events.
+        #[rust_name = "is_enabled"]
+        fn isEnabled(self: &QQuickItem) -> bool;
+
+        /// Returns whether the item is visible.
+        #[rust_name = "is_visible"]
+        fn isVisible(self: &QQuickItem) -> bool;
+
+        /// Returns the opacity of the item.
+        fn opacity(self: &QQuickItem) -> f64;
+
+        /// Returns the visual parent of the item.
+        #[rust_name = "parent_item"]
+        fn parentItem(self: &QQuickItem) -> *mut QQuickItem;
+
+        /// Schedules a polish event for this item.
+        ///
+        /// When the scene graph processes the request, it will call updatePolish() on this item.
+        fn polish(self: Pin<&mut QQuickItem>);
+
+        /// Returns the rotation of the item in degrees clockwise around its transformOrigin.
+        fn rotation(self: &QQuickItem) -> f64;
+
+        /// Returns the scale factor of the item.
+        fn scale(self: &QQuickItem) -> f64;
+
+        /// Sets whether the item is antialiased.
+        #[rust_name = "set_antialiasing"]
+        fn setAntialiasing(self: Pin<&mut QQuickItem>, antialiasing: bool);
+
+        /// Sets whether the item receives mouse and keyboard events.
+        #[rust_name = "set_enabled"]
+        fn setEnabled(self: Pin<&mut QQuickItem>, enabled: bool);
+
+        /// Enables the specified flag for this item if enabled is true; if enabled is false, the flag is disabled.
+        ///
+        /// For example QQuickItemFlag::ItemHasContents needs to be set on items which render content.
+        #[rust_name = "set_flag"]
+        fn setFlag(self: Pin<&mut QQuickItem>, flag: QQuickItemFlag, enabled: bool);
+
+        /// Sets the height of the item.
+        #[rust_name = "set_height"]
+        fn setHeight(self: Pin<&mut QQuickItem>, height: f64);
+
+        /// Sets the height that is implied by the content of the item.
+        #[rust_name = "set_implicit_height"]
+        fn setImplicitHeight(self: Pin<&mut QQuickItem>, height: f64);
+
+        /// Sets the width that is implied by the content of the item.
+        #[rust_name = "set_implicit_width"]
+        fn setImplicitWidth(self: Pin<&mut QQuickItem>, width: f64);
+
+        /// Sets the opacity of the item.
+        #[rust_name = "set_opacity"]
+        fn setOpacity(self: Pin<&mut QQuickItem>, opacity: f64);
+
+        #[doc(hidden)]
+        #[rust_name = "set_parent_item_raw"]
+        unsafe fn setParentItem(self: Pin<&mut QQuickItem>, parent: *mut QQuickItem);
+
+        /// Sets the rotation of the item in degrees clockwise around its transformOrigin.
+        #[rust_name = "set_rotation"]
+        fn setRotation(self: Pin<&mut QQuickItem>, rotation: f64);
+
+        /// Sets the scale factor of the item.
+        #[rust_name = "set_scale"]
+        fn setScale(self: Pin<&mut QQuickItem>, scale: f64);
+
+        /// Sets the size of the item to size.
+        #[rust_name = "set_size"]
+        fn setSize(self: Pin<&mut QQuickItem>, size: &QSizeF);
+
+        /// Sets whether the item is visible.
+        #[rust_name = "set_visible"]
+        fn setVisible(self: Pin<&mut QQuickItem>, visible: bool);
+
+        /// Sets the width of the item.
+        #[rust_name = "set_width"]
+        fn setWidth(self: Pin<&mut QQuickItem>, width: f64);
+
+        /// Sets the x position of the item relative to its parent.
+        #[rust_name = "set_x"]
+        fn setX(self: Pin<&mut QQuickItem>, x: f64);
+
+        /// Sets the y position of the item relative to its parent.
+        #[rust_name = "set_y"]
+        fn setY(self: Pin<&mut QQuickItem>, y: f64);
+
+        /// Sets the stacking order of sibling items.
+        #[rust_name = "set_z"]
+        fn setZ(self: Pin<&mut QQuickItem>, z: f64);
+
+        /// Returns the size of the item.
+        fn size(self: &QQuickItem) -> QSizeF;
+
+        /// Schedules a call to updatePaintNode() for this item,
+        /// for a QQuickPaintedItem this schedules a repaint.
+        fn update(self: Pin<&mut QQuickItem>);
+
+        /// Returns the width of the item.
+        fn width(self: &QQuickItem) -> f64;
+
+        /// Returns the x position of the item relative to its parent.
+        fn x(self: &QQuickItem) -> f64;
+
+        /// Returns the y position of the item relative to its parent.
+        fn y(self: &QQuickItem) -> f64;
+
+        /// Returns the stacking order of sibling items.
+        fn z(self: &QQuickItem) -> f64;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QQuickItemFlag;
+
+        #[doc(hidden)]
+        #[rust_name = "qquickitem_has_flag"]
+        fn qquickitemHasFlag(item: &QQuickItem, flag: QQuickItemFlag) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qquickitem_new"]
+        fn qquickitemNew() -> UniquePtr<QQuickItem>;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qquickitem_upcast"]
+        fn upcastQObject(object: &QQuickItem) -> &QObject;
+        #[doc(hidden)]
+        #[rust_name = "qquickitem_upcast_mut"]
+        fn upcastQObjectMut(object: Pin<&mut QQuickItem>) -> Pin<&mut QObject>;
+    }
+
+    // QQuickItem is a QObject so it is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QQuickItem>::isRelocatable);
+    impl UniquePtr<QQuickItem> {}
+}
+
+pub use ffi::{QQuickItem, QQuickItemFlag};
+
+impl QQuickItem {
+    /// Create a new QQuickItem without a parent item
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qquickitem_new()
+    }
+
+    /// Returns true if the given flag is enabled for this item.
+    pub fn has_flag(&self, flag: QQuickItemFlag) -> bool {
+        ffi::qquickitem_has_flag(self, flag)
+    }
+
+    /// Sets the visual parent of the item, a null pointer removes the visual parent.
+    ///
+    /// # Safety
+    ///
+    /// The parent must be a null pointer or point to a valid QQuickItem.
+    pub unsafe fn set_parent_item(self: Pin<&mut Self>, parent: *mut QQuickItem) {
+        self.set_parent_item_raw(parent)
+    }
+}
+
+impl Upcast<QQuickItem> for QQuickItem {
+    fn upcast(&self) -> &QQuickItem {
+        self
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QQuickItem> {
+        self
+    }
+}
+
+impl Upcast<QObject> for QQuickItem {
+    fn upcast(&self) -> &QObject {
+        ffi::qquickitem_upcast(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QObject> {
+        ffi::qquickitem_upcast_mut(self)
+    }
+}
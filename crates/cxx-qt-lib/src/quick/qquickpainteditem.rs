@@ -0,0 +1,160 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QObject, QQuickItem, QRect, Upcast};
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes QQuickPaintedItem's render targets.
+    /// The render target is the surface QPainter paints onto before the item is rendered on screen.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    enum QQuickPaintedItemRenderTarget {
+        /// The default; QPainter paints into a QImage using the raster paint engine.
+        Image,
+        /// QPainter paints into a QOpenGLFramebufferObject using the GL paint engine.
+        FramebufferObject,
+        /// Exactly as for FramebufferObject above, except once the painting is done,
+        /// prior to rendering the painted image is flipped about the x-axis.
+        InvertedYFramebufferObject,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcolor.h");
+        type QColor = crate::QColor;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qquickitem.h");
+        type QQuickItem = crate::QQuickItem;
+        include!("cxx-qt-lib/qrect.h");
+        type QRect = crate::QRect;
+        include!("cxx-qt-lib/qsize.h");
+        type QSize = crate::QSize;
+
+        include!("cxx-qt-lib/qquickpainteditem.h");
+        /// The QQuickPaintedItem class provides a way to use the QPainter API in the QML Scene Graph.
+        ///
+        /// To implement a painted item in Rust use `#[base = "QQuickPaintedItem"]` on a `#[qobject]`
+        /// and override `paint` using `#[cxx_override]`.
+        type QQuickPaintedItem;
+
+        /// Returns the item's background fill color.
+        #[rust_name = "fill_color"]
+        fn fillColor(self: &QQuickPaintedItem) -> QColor;
+
+        /// Returns true if mipmaps are enabled; otherwise, false is returned.
+        fn mipmap(self: &QQuickPaintedItem) -> bool;
+
+        /// Returns true if this item is opaque; otherwise, false is returned.
+        #[rust_name = "opaque_painting"]
+        fn opaquePainting(self: &QQuickPaintedItem) -> bool;
+
+        /// Returns the item's render target.
+        #[rust_name = "render_target"]
+        fn renderTarget(self: &QQuickPaintedItem) -> QQuickPaintedItemRenderTarget;
+
+        /// Sets the item's background fill color.
+        #[rust_name = "set_fill_color"]
+        fn setFillColor(self: Pin<&mut QQuickPaintedItem>, color: &QColor);
+
+        /// If enable is true, mipmapping is enabled on the associated texture.
+        #[rust_name = "set_mipmap"]
+        fn setMipmap(self: Pin<&mut QQuickPaintedItem>, enable: bool);
+
+        /// If opaque is true, the item is opaque; otherwise, it is considered as translucent.
+        ///
+        /// Opaque items do not render items underneath them, which can be used as an optimization.
+        #[rust_name = "set_opaque_painting"]
+        fn setOpaquePainting(self: Pin<&mut QQuickPaintedItem>, opaque: bool);
+
+        /// Sets the item's render target.
+        ///
+        /// Only the Image render target is supported when using the software scene graph backend.
+        #[rust_name = "set_render_target"]
+        fn setRenderTarget(
+            self: Pin<&mut QQuickPaintedItem>,
+            target: QQuickPaintedItemRenderTarget,
+        );
+
+        /// Sets the size of the texture the painter paints onto,
+        /// an invalid size means that the size follows the size of the item.
+        #[rust_name = "set_texture_size"]
+        fn setTextureSize(self: Pin<&mut QQuickPaintedItem>, size: &QSize);
+
+        /// Returns the size of the texture the painter paints onto.
+        #[rust_name = "texture_size"]
+        fn textureSize(self: &QQuickPaintedItem) -> QSize;
+
+        /// Schedules a redraw of the area covered by rect in this item.
+        /// You can call this function whenever your item needs to be redrawn, such as if it changes appearance or size.
+        #[rust_name = "update_rect"]
+        fn update(self: Pin<&mut QQuickPaintedItem>, rect: &QRect);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QQuickPaintedItemRenderTarget;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qquickpainteditem_upcast"]
+        fn upcastQObject(object: &QQuickPaintedItem) -> &QObject;
+        #[doc(hidden)]
+        #[rust_name = "qquickpainteditem_upcast_mut"]
+        fn upcastQObjectMut(object: Pin<&mut QQuickPaintedItem>) -> Pin<&mut QObject>;
+        #[doc(hidden)]
+        #[rust_name = "qquickpainteditem_upcast_qquickitem"]
+        fn upcastQQuickItem(object: &QQuickPaintedItem) -> &QQuickItem;
+        #[doc(hidden)]
+        #[rust_name = "qquickpainteditem_upcast_qquickitem_mut"]
+        fn upcastQQuickItemMut(object: Pin<&mut QQuickPaintedItem>) -> Pin<&mut QQuickItem>;
+    }
+
+    // QQuickPaintedItem is an abstract QObject so it cannot be constructed from Rust,
+    // it is not trivial to CXX and is not relocatable in Qt.
+    // So we cannot mark it as a trivial type and need to use references or pointers.
+}
+
+pub use ffi::{QQuickPaintedItem, QQuickPaintedItemRenderTarget};
+
+impl QQuickPaintedItem {
+    /// Schedules a redraw of the whole item.
+    pub fn update(self: Pin<&mut Self>) {
+        self.update_rect(&QRect::default())
+    }
+}
+
+impl Upcast<QQuickPaintedItem> for QQuickPaintedItem {
+    fn upcast(&self) -> &QQuickPaintedItem {
+        self
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QQuickPaintedItem> {
+        self
+    }
+}
+
+impl Upcast<QQuickItem> for QQuickPaintedItem {
+    fn upcast(&self) -> &QQuickItem {
+        ffi::qquickpainteditem_upcast_qquickitem(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QQuickItem> {
+        ffi::qquickpainteditem_upcast_qquickitem_mut(self)
+    }
+}
+
+impl Upcast<QObject> for QQuickPaintedItem {
+    fn upcast(&self) -> &QObject {
+        ffi::qquickpainteditem_upcast(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QObject> {
+        ffi::qquickpainteditem_upcast_mut(self)
+    }
+}
@@ -0,0 +1,16 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qapplication;
+pub use qapplication::QApplication;
+
+mod qboxlayout;
+pub use qboxlayout::{QBoxLayout, QBoxLayoutDirection};
+
+mod qdialog;
+pub use qdialog::QDialog;
+
+mod qwidget;
+pub use qwidget::QWidget;
@@ -0,0 +1,109 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::core::{application_args, impl_core_application_methods};
+use crate::{QCoreApplication, QObject, Upcast};
+use core::pin::Pin;
+use std::ffi::OsStr;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qcoreapplication.h");
+        type QCoreApplication = crate::QCoreApplication;
+        include!("cxx-qt-lib/qvector.h");
+        type QVector_QByteArray = crate::QVector<QByteArray>;
+
+        include!("cxx-qt-lib/qapplication.h");
+        /// The QApplication class manages the GUI application's control flow and main settings
+        /// for applications which use widgets.
+        type QApplication;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qapplication_new"]
+        fn qapplicationNew(args: &QVector_QByteArray) -> UniquePtr<QApplication>;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qapplication_upcast"]
+        fn upcastQObject(object: &QApplication) -> &QObject;
+        #[doc(hidden)]
+        #[rust_name = "qapplication_upcast_mut"]
+        fn upcastQObjectMut(object: Pin<&mut QApplication>) -> Pin<&mut QObject>;
+        #[doc(hidden)]
+        #[rust_name = "qapplication_upcast_qcoreapplication"]
+        fn upcastQCoreApplication(object: &QApplication) -> &QCoreApplication;
+        #[doc(hidden)]
+        #[rust_name = "qapplication_upcast_qcoreapplication_mut"]
+        fn upcastQCoreApplicationMut(object: Pin<&mut QApplication>) -> Pin<&mut QCoreApplication>;
+    }
+
+    // QApplication is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QApplication>::isRelocatable);
+    impl UniquePtr<QApplication> {}
+}
+
+pub use ffi::QApplication;
+
+impl QApplication {
+    /// Initializes the window system and constructs an application object for widgets based applications.
+    /// Standard [Qt command line arguments](https://doc.qt.io/qt-6/qapplication.html#QApplication) are handled automatically.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        Self::new_with_args(std::env::args_os())
+    }
+
+    /// Constructs an application object with the given command line arguments,
+    /// the first argument is usually the program name.
+    pub fn new_with_args<I, S>(args: I) -> cxx::UniquePtr<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        ffi::qapplication_new(&application_args(args))
+    }
+}
+
+impl_core_application_methods!(QApplication);
+
+impl Upcast<QApplication> for QApplication {
+    fn upcast(&self) -> &QApplication {
+        self
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QApplication> {
+        self
+    }
+}
+
+impl Upcast<QCoreApplication> for QApplication {
+    fn upcast(&self) -> &QCoreApplication {
+        ffi::qapplication_upcast_qcoreapplication(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QCoreApplication> {
+        ffi::qapplication_upcast_qcoreapplication_mut(self)
+    }
+}
+
+impl Upcast<QObject> for QApplication {
+    fn upcast(&self) -> &QObject {
+        ffi::qapplication_upcast(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QObject> {
+        ffi::qapplication_upcast_mut(self)
+    }
+}
@@ -0,0 +1,40 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_WIDGETS_FEATURE
+#include "cxx-qt-lib/qboxlayout.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qboxlayoutAddLayout(QBoxLayout& layout,
+                    ::std::unique_ptr<QBoxLayout> child,
+                    ::std::int32_t stretch)
+{
+  if (child) {
+    layout.addLayout(child.release(), static_cast<int>(stretch));
+  }
+}
+
+void
+qboxlayoutAddWidget(QBoxLayout& layout,
+                    QWidget& widget,
+                    ::std::int32_t stretch)
+{
+  layout.addWidget(&widget, static_cast<int>(stretch));
+}
+
+::std::unique_ptr<QBoxLayout>
+qboxlayoutNew(QBoxLayoutDirection direction)
+{
+  return ::std::make_unique<QBoxLayout>(direction);
+}
+
+}
+}
+#endif
@@ -0,0 +1,155 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QObject, QWidget, Upcast};
+use core::pin::Pin;
+use cxx::{memory::UniquePtrTarget, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    /// This type is used to determine the direction of a box layout.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    enum QBoxLayoutDirection {
+        /// Horizontal from left to right.
+        LeftToRight,
+        /// Horizontal from right to left.
+        RightToLeft,
+        /// Vertical from top to bottom.
+        TopToBottom,
+        /// Vertical from bottom to top.
+        BottomToTop,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qwidget.h");
+        type QWidget = crate::QWidget;
+
+        include!("cxx-qt-lib/qboxlayout.h");
+        /// The QBoxLayout class lines up child widgets horizontally or vertically.
+        type QBoxLayout;
+
+        /// Adds a non-stretchable space with the given size to the end of this box layout.
+        #[rust_name = "add_spacing"]
+        fn addSpacing(self: Pin<&mut QBoxLayout>, size: i32);
+
+        /// Adds a stretchable space with zero minimum size and the given stretch factor
+        /// to the end of this box layout.
+        #[rust_name = "add_stretch"]
+        fn addStretch(self: Pin<&mut QBoxLayout>, stretch: i32);
+
+        /// Returns the number of items in the layout.
+        fn count(self: &QBoxLayout) -> i32;
+
+        /// Returns the direction of the box.
+        fn direction(self: &QBoxLayout) -> QBoxLayoutDirection;
+
+        /// Sets the left, top, right, and bottom margins to use around the layout.
+        #[rust_name = "set_contents_margins"]
+        fn setContentsMargins(
+            self: Pin<&mut QBoxLayout>,
+            left: i32,
+            top: i32,
+            right: i32,
+            bottom: i32,
+        );
+
+        /// Sets the direction of this layout to direction.
+        #[rust_name = "set_direction"]
+        fn setDirection(self: Pin<&mut QBoxLayout>, direction: QBoxLayoutDirection);
+
+        /// Sets the spacing between widgets inside the layout.
+        #[rust_name = "set_spacing"]
+        fn setSpacing(self: Pin<&mut QBoxLayout>, spacing: i32);
+
+        /// Returns the spacing between widgets inside the layout.
+        fn spacing(self: &QBoxLayout) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QBoxLayoutDirection;
+
+        #[doc(hidden)]
+        #[rust_name = "qboxlayout_add_layout"]
+        fn qboxlayoutAddLayout(
+            layout: Pin<&mut QBoxLayout>,
+            child: UniquePtr<QBoxLayout>,
+            stretch: i32,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qboxlayout_add_widget"]
+        fn qboxlayoutAddWidget(
+            layout: Pin<&mut QBoxLayout>,
+            widget: Pin<&mut QWidget>,
+            stretch: i32,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qboxlayout_new"]
+        fn qboxlayoutNew(direction: QBoxLayoutDirection) -> UniquePtr<QBoxLayout>;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qboxlayout_upcast"]
+        fn upcastQObject(object: &QBoxLayout) -> &QObject;
+        #[doc(hidden)]
+        #[rust_name = "qboxlayout_upcast_mut"]
+        fn upcastQObjectMut(object: Pin<&mut QBoxLayout>) -> Pin<&mut QObject>;
+    }
+
+    // QBoxLayout is a QObject so it is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QBoxLayout>::isRelocatable);
+    impl UniquePtr<QBoxLayout> {}
+}
+
+pub use ffi::{QBoxLayout, QBoxLayoutDirection};
+
+impl QBoxLayout {
+    /// Create a new QBoxLayout with the given direction, which can then be set on a [QWidget].
+    pub fn new(direction: QBoxLayoutDirection) -> UniquePtr<Self> {
+        ffi::qboxlayout_new(direction)
+    }
+
+    /// Adds the given layout to the end of the box, with serial stretch factor stretch.
+    ///
+    /// This layout takes ownership of the child layout.
+    pub fn add_layout(self: Pin<&mut Self>, child: UniquePtr<QBoxLayout>, stretch: i32) {
+        ffi::qboxlayout_add_layout(self, child, stretch);
+    }
+
+    /// Adds the given widget to the end of this box layout, with a stretch factor of stretch.
+    ///
+    /// The widget is reparented to the widget that this layout is installed on,
+    /// which then takes ownership of it.
+    pub fn add_widget<T>(self: Pin<&mut Self>, widget: UniquePtr<T>, stretch: i32)
+    where
+        T: Upcast<QWidget> + UniquePtrTarget,
+    {
+        if widget.is_null() {
+            return;
+        }
+
+        // Safety: the pointer is valid as the UniquePtr is not null,
+        // ownership of the widget is passed to Qt
+        let widget = unsafe { Pin::new_unchecked(&mut *widget.into_raw()) };
+        ffi::qboxlayout_add_widget(self, widget.upcast_pin(), stretch);
+    }
+}
+
+impl Upcast<QObject> for QBoxLayout {
+    fn upcast(&self) -> &QObject {
+        ffi::qboxlayout_upcast(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QObject> {
+        ffi::qboxlayout_upcast_mut(self)
+    }
+}
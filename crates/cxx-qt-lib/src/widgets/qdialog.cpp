@@ -0,0 +1,22 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_WIDGETS_FEATURE
+#include "cxx-qt-lib/qdialog.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QDialog>
+qdialogNew()
+{
+  return ::std::make_unique<QDialog>();
+}
+
+}
+}
+#endif
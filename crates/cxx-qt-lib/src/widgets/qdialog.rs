@@ -0,0 +1,123 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QObject, QWidget, Upcast};
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qwidget.h");
+        type QWidget = crate::QWidget;
+
+        include!("cxx-qt-lib/qdialog.h");
+        /// The QDialog class is the base class of dialog windows.
+        ///
+        /// To implement a dialog in Rust use `#[base = "QDialog"]` on a `#[qobject]`.
+        type QDialog;
+
+        /// Hides the modal dialog and sets the result code to Accepted.
+        fn accept(self: Pin<&mut QDialog>);
+
+        /// Closes the dialog and sets its result code to r.
+        fn done(self: Pin<&mut QDialog>, r: i32);
+
+        /// Shows the dialog as a modal dialog, blocking until the user closes it.
+        ///
+        /// The function returns a DialogCode result, where Accepted is 1 and Rejected is 0.
+        fn exec(self: Pin<&mut QDialog>) -> i32;
+
+        /// Returns true if the dialog is modal; otherwise returns false.
+        #[rust_name = "is_modal"]
+        fn isModal(self: &QDialog) -> bool;
+
+        /// Shows the dialog as a window modal dialog, returning immediately.
+        fn open(self: Pin<&mut QDialog>);
+
+        /// Hides the modal dialog and sets the result code to Rejected.
+        fn reject(self: Pin<&mut QDialog>);
+
+        /// Returns the modal dialog's result code, Accepted or Rejected.
+        fn result(self: &QDialog) -> i32;
+
+        /// Sets whether the dialog is modal.
+        #[rust_name = "set_modal"]
+        fn setModal(self: Pin<&mut QDialog>, modal: bool);
+
+        /// Sets the modal dialog's result code to i.
+        #[rust_name = "set_result"]
+        fn setResult(self: Pin<&mut QDialog>, i: i32);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qdialog_new"]
+        fn qdialogNew() -> UniquePtr<QDialog>;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qdialog_upcast"]
+        fn upcastQObject(object: &QDialog) -> &QObject;
+        #[doc(hidden)]
+        #[rust_name = "qdialog_upcast_mut"]
+        fn upcastQObjectMut(object: Pin<&mut QDialog>) -> Pin<&mut QObject>;
+        #[doc(hidden)]
+        #[rust_name = "qdialog_upcast_qwidget"]
+        fn upcastQWidget(object: &QDialog) -> &QWidget;
+        #[doc(hidden)]
+        #[rust_name = "qdialog_upcast_qwidget_mut"]
+        fn upcastQWidgetMut(object: Pin<&mut QDialog>) -> Pin<&mut QWidget>;
+    }
+
+    // QDialog is a QObject so it is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QDialog>::isRelocatable);
+    impl UniquePtr<QDialog> {}
+}
+
+pub use ffi::QDialog;
+
+impl QDialog {
+    /// Create a new QDialog without a parent
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qdialog_new()
+    }
+}
+
+impl Upcast<QDialog> for QDialog {
+    fn upcast(&self) -> &QDialog {
+        self
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QDialog> {
+        self
+    }
+}
+
+impl Upcast<QWidget> for QDialog {
+    fn upcast(&self) -> &QWidget {
+        ffi::qdialog_upcast_qwidget(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QWidget> {
+        ffi::qdialog_upcast_qwidget_mut(self)
+    }
+}
+
+impl Upcast<QObject> for QDialog {
+    fn upcast(&self) -> &QObject {
+        ffi::qdialog_upcast(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QObject> {
+        ffi::qdialog_upcast_mut(self)
+    }
+}
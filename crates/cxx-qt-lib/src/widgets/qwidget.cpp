@@ -0,0 +1,43 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_WIDGETS_FEATURE
+#include "cxx-qt-lib/qwidget.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QWidget>
+qwidgetNew()
+{
+  return ::std::make_unique<QWidget>();
+}
+
+::std::unique_ptr<QPainter>
+qwidgetPainterNew(QWidget& widget)
+{
+  return ::std::make_unique<QPainter>(&widget);
+}
+
+void
+qwidgetSetLayout(QWidget& widget, ::std::unique_ptr<QBoxLayout> layout)
+{
+  if (!layout) {
+    return;
+  }
+
+  // If the widget already has a layout then Qt does not take ownership,
+  // so the unique_ptr destroys the new layout
+  widget.setLayout(layout.get());
+  if (layout->parent() == &widget) {
+    layout.release();
+  }
+}
+
+}
+}
+#endif
@@ -0,0 +1,233 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QBoxLayout, QObject, QPainter, Upcast};
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qboxlayout.h");
+        type QBoxLayout = crate::QBoxLayout;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qpainter.h");
+        type QPainter = crate::QPainter;
+        include!("cxx-qt-lib/qpoint.h");
+        type QPoint = crate::QPoint;
+        include!("cxx-qt-lib/qrect.h");
+        type QRect = crate::QRect;
+        include!("cxx-qt-lib/qsize.h");
+        type QSize = crate::QSize;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        include!("cxx-qt-lib/qwidget.h");
+        /// The QWidget class is the base class of all user interface objects.
+        ///
+        /// To implement a widget in Rust use `#[base = "QWidget"]` on a `#[qobject]`
+        /// and override virtual methods such as `paintEvent` using `#[cxx_override]`.
+        type QWidget;
+
+        /// Adjusts the size of the widget to fit its contents.
+        #[rust_name = "adjust_size"]
+        fn adjustSize(self: Pin<&mut QWidget>);
+
+        /// Closes this widget.
+        ///
+        /// Returns true if the widget was closed; otherwise returns false.
+        fn close(self: Pin<&mut QWidget>) -> bool;
+
+        /// Returns the geometry of the widget relative to its parent and excluding the window frame.
+        fn geometry(self: &QWidget) -> &QRect;
+
+        /// Returns the height of the widget excluding any window frame.
+        fn height(self: &QWidget) -> i32;
+
+        /// Hides the widget.
+        fn hide(self: Pin<&mut QWidget>);
+
+        /// Returns true if the widget is enabled; otherwise returns false.
+        #[rust_name = "is_enabled"]
+        fn isEnabled(self: &QWidget) -> bool;
+
+        /// Returns true if the widget is visible; otherwise returns false.
+        #[rust_name = "is_visible"]
+        fn isVisible(self: &QWidget) -> bool;
+
+        /// Returns true if the widget is an independent window, otherwise returns false.
+        #[rust_name = "is_window"]
+        fn isWindow(self: &QWidget) -> bool;
+
+        /// Returns the widget's minimum size.
+        #[rust_name = "minimum_size"]
+        fn minimumSize(self: &QWidget) -> QSize;
+
+        /// Moves the widget to the given position relative to its parent, including any window frame.
+        #[cxx_name = "move"]
+        fn move_to(self: Pin<&mut QWidget>, position: &QPoint);
+
+        /// Returns the parent of this widget, or a null pointer if it does not have any parent widget.
+        #[rust_name = "parent_widget"]
+        fn parentWidget(self: &QWidget) -> *mut QWidget;
+
+        /// Returns the position of the widget within its parent widget.
+        fn pos(self: &QWidget) -> QPoint;
+
+        /// Returns the internal geometry of the widget excluding any window frame.
+        fn rect(self: &QWidget) -> QRect;
+
+        /// Repaints the widget directly by calling paintEvent() immediately.
+        fn repaint(self: Pin<&mut QWidget>);
+
+        /// Resizes the widget to the given size, excluding any window frame.
+        fn resize(self: Pin<&mut QWidget>, size: &QSize);
+
+        /// Sets whether the widget is enabled.
+        #[rust_name = "set_enabled"]
+        fn setEnabled(self: Pin<&mut QWidget>, enabled: bool);
+
+        /// Sets both the minimum and maximum sizes of the widget to size,
+        /// thereby preventing it from ever growing or shrinking.
+        #[rust_name = "set_fixed_size"]
+        fn setFixedSize(self: Pin<&mut QWidget>, size: &QSize);
+
+        /// Sets the geometry of the widget relative to its parent and excluding the window frame.
+        #[rust_name = "set_geometry"]
+        fn setGeometry(self: Pin<&mut QWidget>, rect: &QRect);
+
+        /// Sets the widget's minimum size.
+        #[rust_name = "set_minimum_size"]
+        fn setMinimumSize(self: Pin<&mut QWidget>, size: &QSize);
+
+        #[doc(hidden)]
+        #[rust_name = "set_parent_raw"]
+        unsafe fn setParent(self: Pin<&mut QWidget>, parent: *mut QWidget);
+
+        /// Sets the widget's tooltip.
+        #[rust_name = "set_tool_tip"]
+        fn setToolTip(self: Pin<&mut QWidget>, tool_tip: &QString);
+
+        /// Sets whether the widget is visible.
+        #[rust_name = "set_visible"]
+        fn setVisible(self: Pin<&mut QWidget>, visible: bool);
+
+        /// Sets the window title of the widget.
+        #[rust_name = "set_window_title"]
+        fn setWindowTitle(self: Pin<&mut QWidget>, title: &QString);
+
+        /// Shows the widget and its child widgets.
+        fn show(self: Pin<&mut QWidget>);
+
+        /// Returns the size of the widget excluding any window frame.
+        fn size(self: &QWidget) -> QSize;
+
+        /// Returns the widget's tooltip.
+        #[rust_name = "tool_tip"]
+        fn toolTip(self: &QWidget) -> QString;
+
+        /// Updates the widget unless updates are disabled or the widget is hidden.
+        ///
+        /// This does not cause an immediate repaint, instead it schedules a paint event
+        /// for processing when Qt returns to the main event loop.
+        fn update(self: Pin<&mut QWidget>);
+
+        /// Returns the width of the widget excluding any window frame.
+        fn width(self: &QWidget) -> i32;
+
+        /// Returns the window title of the widget.
+        #[rust_name = "window_title"]
+        fn windowTitle(self: &QWidget) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qwidget_new"]
+        fn qwidgetNew() -> UniquePtr<QWidget>;
+        #[doc(hidden)]
+        #[rust_name = "qwidget_painter_new"]
+        fn qwidgetPainterNew(widget: Pin<&mut QWidget>) -> UniquePtr<QPainter>;
+        #[doc(hidden)]
+        #[rust_name = "qwidget_set_layout"]
+        fn qwidgetSetLayout(widget: Pin<&mut QWidget>, layout: UniquePtr<QBoxLayout>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qwidget_upcast"]
+        fn upcastQObject(object: &QWidget) -> &QObject;
+        #[doc(hidden)]
+        #[rust_name = "qwidget_upcast_mut"]
+        fn upcastQObjectMut(object: Pin<&mut QWidget>) -> Pin<&mut QObject>;
+    }
+
+    // QWidget is a QObject so it is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QWidget>::isRelocatable);
+    impl UniquePtr<QWidget> {}
+}
+
+pub use ffi::QWidget;
+
+impl QWidget {
+    /// Create a new QWidget without a parent, which is a window when it is shown
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qwidget_new()
+    }
+
+    /// Sets the layout manager for this widget, which manages the geometry of its child widgets.
+    ///
+    /// The widget takes ownership of the layout. If there already is a layout manager
+    /// installed on this widget, the new layout is not installed and is destroyed.
+    pub fn set_layout(self: Pin<&mut Self>, layout: cxx::UniquePtr<QBoxLayout>) {
+        ffi::qwidget_set_layout(self, layout);
+    }
+
+    /// Sets the parent of the widget, a null pointer makes the widget a window.
+    ///
+    /// Note that the widget becomes invisible as part of changing its parent,
+    /// even if it was previously visible. You must call show() to make the widget visible again.
+    ///
+    /// # Safety
+    ///
+    /// The parent must be a null pointer or point to a valid QWidget.
+    /// When a parent is set the parent takes ownership of the widget.
+    pub unsafe fn set_parent(self: Pin<&mut Self>, parent: *mut QWidget) {
+        self.set_parent_raw(parent)
+    }
+}
+
+impl QPainter {
+    /// Constructs a painter that begins painting the given widget immediately.
+    ///
+    /// This is intended to be used in the paintEvent of the widget,
+    /// the painter must be dropped before the end of the paintEvent.
+    pub fn from_widget(widget: Pin<&mut QWidget>) -> cxx::UniquePtr<Self> {
+        ffi::qwidget_painter_new(widget)
+    }
+}
+
+impl Upcast<QWidget> for QWidget {
+    fn upcast(&self) -> &QWidget {
+        self
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QWidget> {
+        self
+    }
+}
+
+impl Upcast<QObject> for QWidget {
+    fn upcast(&self) -> &QObject {
+        ffi::qwidget_upcast(self)
+    }
+
+    fn upcast_pin(self: Pin<&mut Self>) -> Pin<&mut QObject> {
+        ffi::qwidget_upcast_mut(self)
+    }
+}
@@ -235,3 +235,94 @@ where
         Self::initialize(self);
     }
 }
+
+/// This trait can be implemented on a `#[qml_singleton]` [CxxQtType] to create the instance of
+/// the singleton in Rust, rather than QML default-constructing it.
+///
+/// The `Engine` must be the QQmlEngine type declared in the bridge, e.g. `cxx_qt_lib::QQmlEngine`.
+///
+/// If this trait is implemented for a given [CxxQtType], it must also be declared inside the
+/// [cxx_qt::bridge](bridge) macro.
+/// CXX-Qt then generates the static `create(QQmlEngine*, QJSEngine*)` function in C++, which QML
+/// uses to create the singleton, and a `singleton_instance` function on the QObject to retrieve the
+/// instance that was created for a given engine.
+///
+/// The QObject is then only constructed by the factory, so the Rust struct does not need to
+/// implement [Default], unless other constructors are declared with [Constructor].
+///
+/// # Example
+/// ```ignore
+/// #[cxx_qt::bridge]
+/// mod qobject {
+///     unsafe extern "C++" {
+///         include!("cxx-qt-lib/qqmlengine.h");
+///         type QQmlEngine = cxx_qt_lib::QQmlEngine;
+///     }
+///
+///     extern "RustQt" {
+///         #[qobject]
+///         #[qml_element]
+///         #[qml_singleton]
+///         type MySingleton = super::MySingletonRust;
+///     }
+///
+///     // Declare that the singleton is created by Rust
+///     impl cxx_qt::QmlSingletonFactory<QQmlEngine> for MySingleton {}
+/// }
+///
+/// pub struct MySingletonRust {
+///     database: Database,
+/// }
+///
+/// impl cxx_qt::QmlSingletonFactory<cxx_qt_lib::QQmlEngine> for qobject::MySingleton {
+///     fn create(_engine: core::pin::Pin<&mut cxx_qt_lib::QQmlEngine>) -> MySingletonRust {
+///         MySingletonRust {
+///             database: Database::open(),
+///         }
+///     }
+/// }
+/// ```
+pub trait QmlSingletonFactory<Engine>: CxxQtType {
+    /// This function is called when QML first uses the singleton in the given engine,
+    /// to construct the inner Rust struct of the singleton.
+    fn create(engine: core::pin::Pin<&mut Engine>) -> <Self as CxxQtType>::Rust;
+}
+
+/// This trait must be implemented on a [CxxQtType] that has a `#[qml_attached(Attached)]`
+/// attribute, to create the QML attached object for a given object.
+///
+/// The `Object` is `cxx_qt_lib::QObject` and `Attached` is the type given in the attribute.
+///
+/// CXX-Qt generates `QML_ATTACHED` and the static `qmlAttachedProperties(QObject*)` function in
+/// C++, which QML calls once for each object that uses the attached properties.
+/// The returned object should have the given object as its parent, as QML does not take ownership
+/// of the attached object.
+///
+/// # Example
+/// ```ignore
+/// #[cxx_qt::bridge]
+/// mod qobject {
+///     extern "RustQt" {
+///         #[qobject]
+///         #[qproperty(bool, valid)]
+///         type MyAttached = super::MyAttachedRust;
+///
+///         #[qobject]
+///         #[qml_element]
+///         #[qml_attached(MyAttached)]
+///         type MyObject = super::MyObjectRust;
+///     }
+/// }
+///
+/// impl cxx_qt::QmlAttached<cxx_qt_lib::QObject, qobject::MyAttached> for qobject::MyObject {
+///     fn qml_attached_properties(
+///         object: core::pin::Pin<&mut cxx_qt_lib::QObject>,
+///     ) -> *mut qobject::MyAttached {
+///         qobject::MyAttached::new_with_parent(object)
+///     }
+/// }
+/// ```
+pub trait QmlAttached<Object, Attached>: CxxQtType {
+    /// This function is called by QML to create the attached object for the given object.
+    fn qml_attached_properties(object: core::pin::Pin<&mut Object>) -> *mut Attached;
+}
@@ -12,10 +12,13 @@ fn main() {
         .qml_module(QmlModule {
             uri: "com.kdab.cxx_qt.demo",
             rust_files: &[
+                "src/attached.rs",
                 "src/containers.rs",
                 "src/custom_base_class.rs",
                 "src/custom_parent_class.rs",
+                "src/foreign.rs",
                 "src/invokables.rs",
+                "src/list_properties.rs",
                 "src/multiple_qobjects.rs",
                 "src/nested_qobjects.rs",
                 "src/serialisation.rs",
@@ -50,9 +53,6 @@ fn main() {
             cc.file("../cpp/custom_object.cpp");
         })
         .qobject_header("../cpp/custom_object.h")
-        // Ensure that Quick module is linked, so that cargo test can work.
-        // In a CMake project this isn't required as the linking happens in CMake.
-        .qt_module("Quick")
         .build();
 }
 // ANCHOR_END: book_build_rs
@@ -0,0 +1,72 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! This example shows how QML attached properties can be used
+
+/// A CXX-Qt bridge which shows how QML attached properties can be used
+// ANCHOR: book_macro_code
+#[cxx_qt::bridge(cxx_file_stem = "rust_attached")]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        /// QString from cxx_qt_lib
+        type QString = cxx_qt_lib::QString;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qproperty(bool, required)]
+        #[qproperty(i32, minimum_length)]
+        type RustValidatorAttached = super::RustValidatorAttachedRust;
+
+        /// Validate the given text against the attached properties
+        #[qinvokable]
+        fn validate(self: &RustValidatorAttached, text: &QString) -> bool;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qml_uncreatable]
+        #[qml_attached(RustValidatorAttached)]
+        type RustValidator = super::RustValidatorRust;
+    }
+}
+
+use core::pin::Pin;
+use cxx_qt_lib::{QObject, QString};
+
+/// The attached object which stores the validation properties of an object
+#[derive(Default)]
+pub struct RustValidatorAttachedRust {
+    /// Whether the text is required to be non-empty
+    required: bool,
+    /// The minimum length of the text when it is non-empty
+    minimum_length: i32,
+}
+
+impl qobject::RustValidatorAttached {
+    /// Validate the given text against the attached properties
+    pub fn validate(&self, text: &QString) -> bool {
+        let length = String::from(text).chars().count();
+        if length == 0 {
+            !*self.required()
+        } else {
+            length >= usize::try_from(*self.minimum_length()).unwrap_or_default()
+        }
+    }
+}
+
+/// A QObject which provides the validation attached properties
+#[derive(Default)]
+pub struct RustValidatorRust;
+
+impl cxx_qt::QmlAttached<QObject, qobject::RustValidatorAttached> for qobject::RustValidator {
+    fn qml_attached_properties(object: Pin<&mut QObject>) -> *mut qobject::RustValidatorAttached {
+        // The attached object is owned by the object it is attached to
+        qobject::RustValidatorAttached::new_with_parent(object)
+    }
+}
+// ANCHOR_END: book_macro_code
@@ -20,26 +20,18 @@ pub mod qobject {
         /// QSizeF from cxx_qt_lib
         type QSizeF = cxx_qt_lib::QSizeF;
         include!("cxx-qt-lib/qsizef.h");
-    }
 
-    // Define the API from QPainter that we need
-    unsafe extern "C++" {
-        /// QPainter from Qt
-        type QPainter;
-        include!(<QtGui/QPainter>);
+        /// QPainter from cxx_qt_lib
+        type QPainter = cxx_qt_lib::QPainter;
+        include!("cxx-qt-lib/qpainter.h");
 
-        /// QPainter::fillRect from Qt
-        #[rust_name = "fill_rect"]
-        fn fillRect(self: Pin<&mut QPainter>, rectangle: &QRectF, color: &QColor);
-    }
-
-    // Define the API from QtQuick that we need
-    unsafe extern "C++" {
-        /// Define QQuickItem as a type
-        type QQuickItem;
-        include!(<QtQuick/QQuickItem>);
+        /// QQuickItem from cxx_qt_lib
+        type QQuickItem = cxx_qt_lib::QQuickItem;
+        include!("cxx-qt-lib/qquickitem.h");
 
-        include!(<QtQuick/QQuickPaintedItem>);
+        /// QQuickPaintedItem from cxx_qt_lib
+        type QQuickPaintedItem = cxx_qt_lib::QQuickPaintedItem;
+        include!("cxx-qt-lib/qquickpainteditem.h");
     }
 
     unsafe extern "RustQt" {
@@ -66,7 +58,7 @@ pub mod qobject {
 }
 
 use core::pin::Pin;
-use cxx_qt_lib::{QColor, QRectF};
+use cxx_qt_lib::{QColor, QPainter, QRectF};
 
 /// A struct which inherits from QQuickPaintedItem
 ///
@@ -82,7 +74,7 @@ impl qobject::CustomParentClass {
     /// # Safety
     ///
     /// As we deref a pointer in a public method this needs to be marked as unsafe
-    pub unsafe fn paint(self: Pin<&mut Self>, painter: *mut qobject::QPainter) {
+    pub unsafe fn paint(self: Pin<&mut Self>, painter: *mut QPainter) {
         // We need to convert the *mut QPainter to a Pin<&mut QPainter> so that we can reach the methods
         if let Some(painter) = painter.as_mut() {
             let mut pinned_painter = Pin::new_unchecked(painter);
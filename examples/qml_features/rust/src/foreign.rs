@@ -0,0 +1,41 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! This example shows how an existing C++ type can be registered with QML
+//! and extended with properties implemented in Rust
+
+/// A CXX-Qt bridge which shows how QML_FOREIGN and QML_EXTENDED can be used
+// ANCHOR: book_macro_code
+#[cxx_qt::bridge(cxx_file_stem = "rust_foreign")]
+pub mod ffi {
+    unsafe extern "C++Qt" {
+        include!(<QtCore/QTimer>);
+
+        /// QTimer registered as ForeignTimer in QML and extended by TimerExtension
+        #[qml_element = "ForeignTimer"]
+        #[qml_extended(TimerExtension)]
+        type QTimer;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        /// QString from cxx_qt_lib
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qproperty(QString, label)]
+        type TimerExtension = super::TimerExtensionRust;
+    }
+}
+
+/// A QObject which adds a label property to the ForeignTimer in QML
+#[derive(Default)]
+pub struct TimerExtensionRust {
+    /// A label Q_PROPERTY
+    label: cxx_qt_lib::QString,
+}
+// ANCHOR_END: book_macro_code
@@ -13,6 +13,9 @@ pub mod qobject {
         include!("cxx-qt-lib/qcolor.h");
         /// QColor from cxx_qt_lib
         type QColor = cxx_qt_lib::QColor;
+        include!("cxx-qt-lib/qstring.h");
+        /// QString from cxx_qt_lib
+        type QString = cxx_qt_lib::QString;
     }
 
     unsafe extern "RustQt" {
@@ -35,6 +38,11 @@ pub mod qobject {
         #[qinvokable]
         fn reset(self: Pin<&mut RustInvokables>);
 
+        /// Immutable invokable method that parses a number, an error is thrown into QML as a RangeError
+        #[qinvokable]
+        #[qjs_error(RangeError, code = "E_PARSE")]
+        fn parse_number(self: &RustInvokables, text: &QString) -> Result<i32>;
+
         /// C++ only method which returns the red value
         fn red_value(self: &RustInvokables) -> f32;
     }
@@ -43,7 +51,7 @@ pub mod qobject {
 
 use core::pin::Pin;
 use cxx_qt::CxxQtType;
-use cxx_qt_lib::QColor;
+use cxx_qt_lib::{QColor, QString};
 
 /// A QObject which has Q_INVOKABLEs
 pub struct RustInvokablesRust {
@@ -79,6 +87,14 @@ impl qobject::RustInvokables {
         self.store_helper(0.0, 0.4667, 0.7843);
     }
 
+    /// Immutable invokable method that parses a number, an error is thrown into QML as a RangeError
+    pub fn parse_number(&self, text: &QString) -> Result<i32, String> {
+        let text = String::from(text);
+        text.trim()
+            .parse::<i32>()
+            .map_err(|err| format!("Could not parse '{text}' as a number: {err}"))
+    }
+
     /// C++ only method which returns the red value
     pub fn red_value(&self) -> f32 {
         self.red
@@ -10,10 +10,13 @@
 //! This example provides demonstrations of most of the features of CXX-Qt
 //! split into separate modules
 
+pub mod attached;
 pub mod containers;
 pub mod custom_base_class;
 pub mod custom_parent_class;
+pub mod foreign;
 pub mod invokables;
+pub mod list_properties;
 pub mod multiple_qobjects;
 pub mod nested_qobjects;
 pub mod properties;
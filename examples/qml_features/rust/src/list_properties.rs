@@ -0,0 +1,67 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! This example shows how a QQmlListProperty of QObjects can be used
+
+/// A CXX-Qt bridge which shows how a QQmlListProperty of QObjects can be used
+// ANCHOR: book_macro_code
+#[cxx_qt::bridge(cxx_file_stem = "rust_list_properties")]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        /// QString from cxx_qt_lib
+        type QString = cxx_qt_lib::QString;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(QString, name)]
+        type RustListItem = super::RustListItemRust;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(QQmlListProperty<RustListItem>, items)]
+        #[qml_default_property(items)]
+        type RustListContainer = super::RustListContainerRust;
+
+        /// Join the names of the items with a comma
+        #[qinvokable]
+        fn joined_names(self: &RustListContainer) -> QString;
+    }
+}
+
+use cxx_qt_lib::QString;
+
+/// A QObject which is an item in the list
+#[derive(Default)]
+pub struct RustListItemRust {
+    /// The name Q_PROPERTY
+    name: QString,
+}
+
+/// A QObject which has a list of items as its default property
+#[derive(Default)]
+pub struct RustListContainerRust {
+    /// The items of the QQmlListProperty, these are owned by QML
+    items: Vec<*mut qobject::RustListItem>,
+}
+
+impl qobject::RustListContainer {
+    /// Join the names of the items with a comma
+    pub fn joined_names(&self) -> QString {
+        let names: Vec<String> = self
+            .items()
+            .iter()
+            // SAFETY: the items are children of this object in QML so are valid while it is
+            .filter_map(|item| unsafe { item.as_ref() })
+            .map(|item| item.name().to_string())
+            .collect();
+        QString::from(&names.join(", "))
+    }
+}
+// ANCHOR_END: book_macro_code
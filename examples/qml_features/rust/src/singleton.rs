@@ -20,9 +20,32 @@ pub mod qobject {
         #[qinvokable]
         fn increment(self: Pin<&mut RustSingleton>);
     }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlengine.h");
+        /// QQmlEngine from cxx_qt_lib
+        type QQmlEngine = cxx_qt_lib::QQmlEngine;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qml_singleton]
+        #[qproperty(bool, engine_base_url_valid)]
+        #[qproperty(i32, persistent_value)]
+        type RustFactorySingleton = super::RustFactorySingletonRust;
+
+        /// Increment the persistent value Q_PROPERTY of the QML_SINGLETON
+        #[qinvokable]
+        fn increment(self: Pin<&mut RustFactorySingleton>);
+    }
+
+    // Create the QML_SINGLETON from Rust with the engine that is using it
+    impl cxx_qt::QmlSingletonFactory<QQmlEngine> for RustFactorySingleton {}
 }
 
 use core::pin::Pin;
+use cxx_qt_lib::QQmlEngine;
 
 /// A QObject which is a QML_SINGLETON
 #[derive(Default)]
@@ -38,3 +61,33 @@ impl qobject::RustSingleton {
         self.set_persistent_value(new_value);
     }
 }
+
+/// A QObject which is a QML_SINGLETON created by a Rust factory
+///
+/// This does not need to implement Default as it is only created by the factory
+pub struct RustFactorySingletonRust {
+    /// A Q_PROPERTY which is seeded from the engine when the singleton is created
+    engine_base_url_valid: bool,
+    /// A Q_PROPERTY with a persistent value
+    persistent_value: i32,
+}
+
+// ANCHOR: book_singleton_factory
+impl cxx_qt::QmlSingletonFactory<QQmlEngine> for qobject::RustFactorySingleton {
+    fn create(engine: Pin<&mut QQmlEngine>) -> RustFactorySingletonRust {
+        // Application state, such as a database handle or config, could be used here
+        RustFactorySingletonRust {
+            engine_base_url_valid: engine.base_url().is_valid(),
+            persistent_value: 10,
+        }
+    }
+}
+// ANCHOR_END: book_singleton_factory
+
+impl qobject::RustFactorySingleton {
+    /// Increment the persistent value Q_PROPERTY of the QML_SINGLETON
+    pub fn increment(self: Pin<&mut Self>) {
+        let new_value = self.persistent_value() + 1;
+        self.set_persistent_value(new_value);
+    }
+}
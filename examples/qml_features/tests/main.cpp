@@ -6,6 +6,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #include <QtCore/QtGlobal>
 #include <QtQml/QQmlEngine>
+#include <QtQuick/QQuickWindow>
 #include <QtQuickTest/quicktest.h>
 
 #include "custom_object.h"
@@ -17,6 +18,9 @@ class Setup : public QObject
 public:
   Setup()
   {
+    // Render with the software backend so that items painted from Rust
+    // can be grabbed and compared without a GPU
+    QQuickWindow::setSceneGraphBackend(QStringLiteral("software"));
 
     qRegisterMetaType<CustomStruct>("CustomStruct");
     // Note the _cpp at the end of the URI. If qmlRegisterMetatype is used here
@@ -0,0 +1,36 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+import QtQuick 2.12
+import QtTest 1.12
+
+import com.kdab.cxx_qt.demo 1.0
+
+TestCase {
+    name: "AttachedTests"
+
+    Item {
+        id: requiredItem
+        RustValidator.required: true
+        RustValidator.minimumLength: 3
+    }
+
+    Item {
+        id: optionalItem
+    }
+
+    function test_attached_properties() {
+        compare(requiredItem.RustValidator.required, true);
+        compare(requiredItem.RustValidator.minimumLength, 3);
+        compare(optionalItem.RustValidator.required, false);
+        compare(optionalItem.RustValidator.minimumLength, 0);
+    }
+
+    function test_attached_validate() {
+        verify(!requiredItem.RustValidator.validate(""));
+        verify(!requiredItem.RustValidator.validate("ab"));
+        verify(requiredItem.RustValidator.validate("abc"));
+        verify(optionalItem.RustValidator.validate(""));
+    }
+}
@@ -0,0 +1,38 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+import QtQuick 2.12
+import QtTest 1.12
+
+import com.kdab.cxx_qt.demo 1.0
+
+TestCase {
+    name: "CustomParentClassTests"
+    when: windowShown
+
+    Component {
+        id: componentCustomParentClass
+
+        CustomParentClass {
+            color: "red"
+            height: 200
+            width: 200
+        }
+    }
+
+    function test_paint() {
+        const item = createTemporaryObject(componentCustomParentClass, this, {});
+        verify(waitForRendering(item));
+
+        const image = grabImage(item);
+        compare(image.red(50, 100), 255);
+        compare(image.green(50, 100), 0);
+        compare(image.blue(50, 100), 0);
+
+        // The right half is painted with a darker colour
+        verify(image.red(150, 100) < 255);
+        compare(image.green(150, 100), 0);
+        compare(image.blue(150, 100), 0);
+    }
+}
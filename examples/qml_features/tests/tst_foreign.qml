@@ -0,0 +1,35 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+import QtQuick 2.12
+import QtTest 1.12
+
+import com.kdab.cxx_qt.demo 1.0
+
+TestCase {
+    name: "ForeignTests"
+
+    Component {
+        id: componentForeignTimer
+
+        ForeignTimer {
+            interval: 50
+            label: "extended"
+        }
+    }
+
+    function test_foreign_properties() {
+        const timer = createTemporaryObject(componentForeignTimer, null, {});
+        compare(timer.interval, 50);
+        compare(timer.singleShot, false);
+    }
+
+    function test_extended_properties() {
+        const timer = createTemporaryObject(componentForeignTimer, null, {});
+        compare(timer.label, "extended");
+
+        timer.label = "changed";
+        compare(timer.label, "changed");
+    }
+}
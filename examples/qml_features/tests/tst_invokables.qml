@@ -31,4 +31,22 @@ TestCase {
         obj.reset();
         compare(obj.loadColor(), kdabColor);
     }
+
+    function test_parse_number() {
+        const obj = createTemporaryObject(componentInvokables, null, {});
+        compare(obj.parseNumber("42"), 42);
+
+        let caught = null;
+        try {
+            obj.parseNumber("not a number");
+        } catch (error) {
+            caught = error;
+        }
+        verify(caught instanceof RangeError);
+        // The code of the error object requires Qt 6.1 or later
+        if (caught.code !== undefined) {
+            compare(caught.code, "E_PARSE");
+        }
+        verify(caught.message.startsWith("Could not parse 'not a number' as a number"));
+    }
 }
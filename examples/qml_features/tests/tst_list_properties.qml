@@ -0,0 +1,54 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+import QtQuick 2.12
+import QtTest 1.12
+
+import com.kdab.cxx_qt.demo 1.0
+
+TestCase {
+    name: "ListPropertiesTests"
+
+    RustListContainer {
+        id: defaultContainer
+
+        RustListItem {
+            name: "a"
+        }
+
+        RustListItem {
+            name: "b"
+        }
+    }
+
+    RustListContainer {
+        id: explicitContainer
+        items: [
+            RustListItem {
+                name: "c"
+            }
+        ]
+    }
+
+    RustListContainer {
+        id: emptyContainer
+    }
+
+    function test_default_property() {
+        compare(defaultContainer.items.length, 2);
+        compare(defaultContainer.items[0].name, "a");
+        compare(defaultContainer.items[1].name, "b");
+        compare(defaultContainer.joinedNames(), "a, b");
+    }
+
+    function test_explicit_property() {
+        compare(explicitContainer.items.length, 1);
+        compare(explicitContainer.joinedNames(), "c");
+    }
+
+    function test_empty_property() {
+        compare(emptyContainer.items.length, 0);
+        compare(emptyContainer.joinedNames(), "");
+    }
+}
@@ -15,4 +15,11 @@ TestCase {
         RustSingleton.increment();
         compare(RustSingleton.persistentValue, 1);
     }
+
+    function test_factory() {
+        verify(RustFactorySingleton.engineBaseUrlValid);
+        compare(RustFactorySingleton.persistentValue, 10);
+        RustFactorySingleton.increment();
+        compare(RustFactorySingleton.persistentValue, 11);
+    }
 }
@@ -7,6 +7,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #include <QtCore/QThread>
 #include <QtCore/QTimer>
+#include <QtQml/QJSEngine>
 #include <QtTest/QSignalSpy>
 #include <QtTest/QTest>
 
@@ -15,6 +16,7 @@
 #include "cxx-qt-gen/my_data.cxxqt.h"
 #include "cxx-qt-gen/my_object.cxxqt.h"
 #include "cxx-qt-gen/my_types.cxxqt.h"
+#include "cxx-qt-gen/my_widget.cxxqt.h"
 
 class LockingWorkerThread : public QThread
 {
@@ -275,6 +277,69 @@ private Q_SLOTS:
 
     QCOMPARE(thrown, true);
   }
+
+  void testUpcast()
+  {
+    cxx_qt::my_object::MyObject obj;
+    obj.setObjectName(QStringLiteral("upcast"));
+    QCOMPARE(obj.upcastObjectName(), QStringLiteral("upcast"));
+
+    // Q_PROPERTY is set via the QObject base
+    QCOMPARE(obj.upcastSetProperty(QStringLiteral("number"), 4), true);
+    QCOMPARE(obj.getNumber(), 4);
+
+    // Unknown properties are added as dynamic properties
+    QCOMPARE(obj.upcastSetProperty(QStringLiteral("dynamic"), 2), false);
+    QCOMPARE(obj.property("dynamic").toInt(), 2);
+  }
+
+  void testCreateFromRust()
+  {
+    cxx_qt::my_object::MyObject obj;
+
+    // An object owned by a UniquePtr in Rust
+    QCOMPARE(obj.createOwnedDoubleNumber(4), 8);
+
+    // An object owned by the given parent
+    obj.createChild(2);
+    const auto children = obj.findChildren<cxx_qt::my_object::MyObject*>(
+      QString(), Qt::FindDirectChildrenOnly);
+    QCOMPARE(children.size(), 1);
+    QCOMPARE(children.first()->getNumber(), 2);
+  }
+
+  void testQJSValueInvokable()
+  {
+    cxx_qt::my_object::MyObject obj;
+    obj.setNumber(4);
+
+    QJSEngine engine;
+    const auto callback = engine.evaluate(
+      QStringLiteral("(function(value) { return value * 2; })"));
+    QVERIFY(callback.isCallable());
+    QCOMPARE(obj.callJsCallback(callback), 8);
+    QCOMPARE(obj.callJsCallback(QJSValue(1)), -1);
+
+    // Ensure that QJSValue is usable via the meta object as QML would
+    int result = 0;
+    QVERIFY(QMetaObject::invokeMethod(&obj,
+                                      "callJsCallback",
+                                      Q_RETURN_ARG(int, result),
+                                      Q_ARG(QJSValue, callback)));
+    QCOMPARE(result, 8);
+  }
+
+  // A QWidget subclass can override paintEvent in Rust
+  void testWidgetPaintEvent()
+  {
+    cxx_qt::my_widget::MyWidget widget;
+    widget.setColor(QColor(Qt::red));
+    widget.resize(20, 20);
+
+    const auto image = widget.grab().toImage();
+    QCOMPARE(image.size(), QSize(20, 20));
+    QCOMPARE(image.pixelColor(10, 10), QColor(Qt::red));
+  }
 };
 
 QTEST_MAIN(CxxQtTest)
@@ -12,5 +12,6 @@ fn main() {
         .file("src/lib.rs")
         .file("src/locking.rs")
         .file("src/types.rs")
+        .file("src/widget.rs")
         .build();
 }
@@ -8,11 +8,14 @@ mod data;
 mod empty;
 mod locking;
 mod types;
+mod widget;
 
 #[cxx_qt::bridge(cxx_file_stem = "my_object", namespace = "cxx_qt::my_object")]
 mod qobject {
     #[namespace = ""]
     unsafe extern "C++" {
+        include!("cxx-qt-lib/qjsvalue.h");
+        type QJSValue = cxx_qt_lib::QJSValue;
         include!("cxx-qt-lib/qstring.h");
         type QString = cxx_qt_lib::QString;
     }
@@ -42,12 +45,24 @@ mod qobject {
         fn fetch_update_call_count(self: &MyObject) -> i32;
 
         fn throw_exception(self: &MyObject) -> Result<i32>;
+
+        fn upcast_object_name(self: &MyObject) -> QString;
+
+        fn upcast_set_property(self: Pin<&mut MyObject>, name: &QString, value: i32) -> bool;
+
+        fn create_owned_double_number(self: &MyObject, number: i32) -> i32;
+
+        fn create_child(self: Pin<&mut MyObject>, number: i32);
+
+        // A QJSValue can be passed from QML, so test that this works as an invokable
+        #[qinvokable]
+        fn call_js_callback(self: &MyObject, callback: &QJSValue) -> i32;
     }
 }
 
 use core::pin::Pin;
 use cxx_qt::{CxxQtType, Threading};
-use cxx_qt_lib::QString;
+use cxx_qt_lib::{QJSValue, QString, QVariant, Upcast};
 
 pub struct MyObjectRust {
     number: i32,
@@ -125,4 +140,34 @@ impl qobject::MyObject {
     fn throw_exception(&self) -> Result<i32, String> {
         Err("RustException".to_string())
     }
+
+    fn upcast_object_name(&self) -> QString {
+        self.upcast().object_name()
+    }
+
+    fn upcast_set_property(self: Pin<&mut Self>, name: &QString, value: i32) -> bool {
+        self.upcast_pin()
+            .set_property(name, &QVariant::from(&value))
+    }
+
+    fn create_owned_double_number(&self, number: i32) -> i32 {
+        let mut object = qobject::MyObject::new();
+        object.pin_mut().set_number(number);
+        object.pin_mut().double_number_self();
+        *object.number()
+    }
+
+    fn create_child(self: Pin<&mut Self>, number: i32) {
+        let child = qobject::MyObject::new_with_parent(self.upcast_pin());
+        // SAFETY: the child was just created and is owned by self which is still alive
+        unsafe { Pin::new_unchecked(&mut *child) }.set_number(number);
+    }
+
+    fn call_js_callback(&self, callback: &QJSValue) -> i32 {
+        if !callback.is_callable() {
+            return -1;
+        }
+
+        callback.call(&[QJSValue::from(self.number())]).to_int()
+    }
 }
@@ -0,0 +1,63 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge(cxx_file_stem = "my_widget", namespace = "cxx_qt::my_widget")]
+mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcolor.h");
+        type QColor = cxx_qt_lib::QColor;
+        include!("cxx-qt-lib/qpaintevent.h");
+        type QPaintEvent = cxx_qt_lib::QPaintEvent;
+        include!("cxx-qt-lib/qrect.h");
+        type QRect = cxx_qt_lib::QRect;
+        include!("cxx-qt-lib/qwidget.h");
+        type QWidget = cxx_qt_lib::QWidget;
+
+        include!(<QtWidgets/QWidget>);
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[base = "QWidget"]
+        #[qproperty(QColor, color)]
+        type MyWidget = super::MyWidgetRust;
+
+        #[cxx_override]
+        unsafe fn paint_event(self: Pin<&mut MyWidget>, event: *mut QPaintEvent);
+
+        #[inherit]
+        fn rect(self: &MyWidget) -> QRect;
+    }
+
+    // Upcast to a QWidget so that a QPainter can be constructed on the widget
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[cxx_name = "upcastQWidgetMut"]
+        fn upcast_qwidget(object: Pin<&mut MyWidget>) -> Pin<&mut QWidget>;
+    }
+
+    impl cxx_qt::Constructor<()> for MyWidget {}
+}
+
+use core::pin::Pin;
+use cxx_qt_lib::{QColor, QPaintEvent, QPainter, QRectF};
+
+#[derive(Default)]
+pub struct MyWidgetRust {
+    color: QColor,
+}
+
+impl ffi::MyWidget {
+    unsafe fn paint_event(self: Pin<&mut Self>, _event: *mut QPaintEvent) {
+        let rect = QRectF::from(&self.rect());
+        let color = self.color().clone();
+
+        let mut painter = QPainter::from_widget(ffi::upcast_qwidget(self));
+        if let Some(painter) = painter.as_mut() {
+            painter.fill_rect(&rect, &color);
+        }
+    }
+}
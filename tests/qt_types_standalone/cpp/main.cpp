@@ -7,39 +7,68 @@
 #include <QtCore/QScopedPointer>
 #include <QtTest/QTest>
 
+#include "qapplication.h"
+#include "qbrush.h"
 #include "qbytearray.h"
 #include "qcolor.h"
 #include "qcoreapplication.h"
 #include "qdate.h"
 #include "qdatetime.h"
+#include "qdir.h"
+#include "qfile.h"
+#include "qfont.h"
 #include "qguiapplication.h"
 #include "qhash.h"
+#include "qimage.h"
+#include "qjsengine.h"
+#include "qjsvalue.h"
+#include "qline.h"
 #include "qlist.h"
+#include "qlocale.h"
+#include "qloggingcategory.h"
 #include "qmap.h"
 #include "qmargins.h"
 #include "qmarginsf.h"
+#include "qmatrix4x4.h"
 #include "qmetaobjectconnection.h"
 #include "qmodelindex.h"
+#include "qobject.h"
+#include "qpainter.h"
+#include "qpainterpath.h"
+#include "qpen.h"
 #include "qpersistentmodelindex.h"
 #include "qpoint.h"
+#include "qpointer.h"
 #include "qpointf.h"
+#include "qpolygon.h"
 #include "qqmlapplicationengine.h"
+#include "qqmlcomponent.h"
+#include "qqmlcontext.h"
 #include "qqmlengine.h"
+#include "qquaternion.h"
+#include "qquickimageprovider.h"
+#include "qquickitem.h"
+#include "qquickpainteditem.h"
 #include "qrect.h"
 #include "qrectf.h"
+#include "qregularexpression.h"
 #include "qset.h"
+#include "qsettings.h"
 #include "qsize.h"
 #include "qsizef.h"
 #include "qstring.h"
 #include "qstringlist.h"
 #include "qtime.h"
+#include "qtimer.h"
 #include "qtimezone.h"
+#include "qtransform.h"
 #include "qurl.h"
 #include "qvariant.h"
 #include "qvector.h"
 #include "qvector2d.h"
 #include "qvector3d.h"
 #include "qvector4d.h"
+#include "qwidget.h"
 
 int
 main(int argc, char* argv[])
@@ -53,39 +82,68 @@ main(int argc, char* argv[])
     }
   };
 
+  runTest(QScopedPointer<QObject>(new QApplicationTest));
+  runTest(QScopedPointer<QObject>(new QBrushTest));
   runTest(QScopedPointer<QObject>(new QByteArrayTest));
   runTest(QScopedPointer<QObject>(new QColorTest));
   runTest(QScopedPointer<QObject>(new QCoreApplicationTest));
   runTest(QScopedPointer<QObject>(new QDateTest));
   runTest(QScopedPointer<QObject>(new QDateTimeTest));
+  runTest(QScopedPointer<QObject>(new QDirTest));
+  runTest(QScopedPointer<QObject>(new QFileTest));
+  runTest(QScopedPointer<QObject>(new QFontTest));
   runTest(QScopedPointer<QObject>(new QGuiApplicationTest));
   runTest(QScopedPointer<QObject>(new QHashTest));
+  runTest(QScopedPointer<QObject>(new QImageTest));
+  runTest(QScopedPointer<QObject>(new QJSEngineTest));
+  runTest(QScopedPointer<QObject>(new QJSValueTest));
+  runTest(QScopedPointer<QObject>(new QLineTest));
   runTest(QScopedPointer<QObject>(new QListTest));
+  runTest(QScopedPointer<QObject>(new QLocaleTest));
+  runTest(QScopedPointer<QObject>(new QLoggingCategoryTest));
   runTest(QScopedPointer<QObject>(new QMapTest));
   runTest(QScopedPointer<QObject>(new QMarginsTest));
   runTest(QScopedPointer<QObject>(new QMarginsFTest));
+  runTest(QScopedPointer<QObject>(new QMatrix4x4Test));
   runTest(QScopedPointer<QObject>(new QMetaObjectConnectionTest));
   runTest(QScopedPointer<QObject>(new QModelIndexTest));
+  runTest(QScopedPointer<QObject>(new QObjectTest));
+  runTest(QScopedPointer<QObject>(new QPainterPathTest));
+  runTest(QScopedPointer<QObject>(new QPainterTest));
+  runTest(QScopedPointer<QObject>(new QPenTest));
   runTest(QScopedPointer<QObject>(new QPersistentModelIndexTest));
   runTest(QScopedPointer<QObject>(new QPointTest));
+  runTest(QScopedPointer<QObject>(new QPointerTest));
   runTest(QScopedPointer<QObject>(new QPointFTest));
+  runTest(QScopedPointer<QObject>(new QPolygonTest));
   runTest(QScopedPointer<QObject>(new QQmlApplicationEngineTest));
+  runTest(QScopedPointer<QObject>(new QQmlComponentTest));
+  runTest(QScopedPointer<QObject>(new QQmlContextTest));
   runTest(QScopedPointer<QObject>(new QQmlEngineTest));
+  runTest(QScopedPointer<QObject>(new QQuaternionTest));
+  runTest(QScopedPointer<QObject>(new QQuickImageProviderTest));
+  runTest(QScopedPointer<QObject>(new QQuickItemTest));
+  runTest(QScopedPointer<QObject>(new QQuickPaintedItemTest));
   runTest(QScopedPointer<QObject>(new QRectTest));
   runTest(QScopedPointer<QObject>(new QRectFTest));
+  runTest(QScopedPointer<QObject>(new QRegularExpressionTest));
   runTest(QScopedPointer<QObject>(new QSetTest));
+  runTest(QScopedPointer<QObject>(new QSettingsTest));
   runTest(QScopedPointer<QObject>(new QSizeTest));
   runTest(QScopedPointer<QObject>(new QSizeFTest));
   runTest(QScopedPointer<QObject>(new QStringTest));
   runTest(QScopedPointer<QObject>(new QStringListTest));
   runTest(QScopedPointer<QObject>(new QTimeTest));
+  runTest(QScopedPointer<QObject>(new QTimerTest));
   runTest(QScopedPointer<QObject>(new QTimeZoneTest));
+  runTest(QScopedPointer<QObject>(new QTransformTest));
   runTest(QScopedPointer<QObject>(new QUrlTest));
   runTest(QScopedPointer<QObject>(new QVariantTest));
   runTest(QScopedPointer<QObject>(new QVectorTest));
   runTest(QScopedPointer<QObject>(new QVector2DTest));
   runTest(QScopedPointer<QObject>(new QVector3DTest));
   runTest(QScopedPointer<QObject>(new QVector4DTest));
+  runTest(QScopedPointer<QObject>(new QWidgetTest));
 
   return status;
 }
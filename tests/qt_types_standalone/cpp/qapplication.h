@@ -0,0 +1,37 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtWidgets/QApplication>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qapplication_cxx.cxx.h"
+
+class QApplicationTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto app = construct_qapplication();
+    QVERIFY(app != nullptr);
+    QCOMPARE(app->applicationName(), QStringLiteral("kdab"));
+  }
+
+  void read()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+
+    QApplication app(argc, args.data());
+    app.setApplicationName(QStringLiteral("kdab"));
+    QVERIFY(read_qapplication(app));
+  }
+};
@@ -0,0 +1,38 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QBrush>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qbrush_cxx.cxx.h"
+
+class QBrushTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto b = construct_qbrush();
+    QCOMPARE(b.color(), QColor(Qt::green));
+    QCOMPARE(b.style(), Qt::CrossPattern);
+  }
+
+  void read()
+  {
+    const auto b = QBrush(Qt::blue);
+    QVERIFY(read_qbrush(b));
+  }
+
+  void clone()
+  {
+    const auto b = QBrush(Qt::red, Qt::Dense3Pattern);
+    const auto c = clone_qbrush(b);
+    QCOMPARE(c, b);
+  }
+};
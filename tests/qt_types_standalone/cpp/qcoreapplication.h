@@ -7,10 +7,23 @@
 #pragma once
 
 #include <QtCore/QCoreApplication>
+#include <QtCore/QEvent>
 #include <QtTest/QTest>
 
 #include "cxx-qt-gen/qcoreapplication_cxx.cxx.h"
 
+class CustomEventReceiver : public QObject
+{
+public:
+  int lastEventType = 0;
+
+protected:
+  void customEvent(QEvent* event) override
+  {
+    lastEventType = static_cast<int>(event->type());
+  }
+};
+
 class QCoreApplicationTest : public QObject
 {
   Q_OBJECT
@@ -23,6 +36,36 @@ private Q_SLOTS:
     QCOMPARE(app->applicationName(), QStringLiteral("kdab"));
   }
 
+  void construct_with_args()
+  {
+    const auto app = construct_qcoreapplication_with_args();
+    QVERIFY(app != nullptr);
+    QCOMPARE(app->arguments(),
+             QStringList({ QStringLiteral("/path"), QStringLiteral("--kdab") }));
+    QVERIFY(read_qcoreapplication_arguments(*app));
+  }
+
+  void exec()
+  {
+    const auto app = construct_qcoreapplication_with_args();
+    QVERIFY(app != nullptr);
+    QCOMPARE(exec_qcoreapplication(*app), 42);
+  }
+
+  void post_event()
+  {
+    const auto app = construct_qcoreapplication_with_args();
+    QVERIFY(app != nullptr);
+
+    CustomEventReceiver receiver;
+    const auto eventType = post_qcoreapplication_event(&receiver);
+    QVERIFY(eventType >= QEvent::User);
+    QCOMPARE(receiver.lastEventType, 0);
+
+    QCoreApplication::sendPostedEvents();
+    QCOMPARE(receiver.lastEventType, eventType);
+  }
+
   void read()
   {
     std::vector<char*> args;
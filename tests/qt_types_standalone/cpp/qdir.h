@@ -0,0 +1,81 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QDir>
+#include <QtCore/QFile>
+#include <QtCore/QTemporaryDir>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qdir_cxx.cxx.h"
+
+class QDirTest : public QObject
+{
+  Q_OBJECT
+
+private:
+  void writeFile(const QDir& dir, const QString& name)
+  {
+    QFile file(dir.filePath(name));
+    QVERIFY(file.open(QIODevice::WriteOnly));
+    QCOMPARE(file.write("test"), 4);
+  }
+
+private Q_SLOTS:
+  void construct()
+  {
+    QTemporaryDir temporaryDir;
+    QVERIFY(temporaryDir.isValid());
+
+    const auto d = construct_qdir(temporaryDir.path());
+    QVERIFY(d.exists());
+    QCOMPARE(d, QDir(temporaryDir.path()));
+  }
+
+  void clone()
+  {
+    const auto d = QDir(QStringLiteral("/tmp"));
+    const auto c = clone_qdir(d);
+    QCOMPARE(c, d);
+  }
+
+  void entryList()
+  {
+    QTemporaryDir temporaryDir;
+    QVERIFY(temporaryDir.isValid());
+
+    const QDir d(temporaryDir.path());
+    writeFile(d, QStringLiteral("a.txt"));
+    writeFile(d, QStringLiteral("b.txt"));
+    writeFile(d, QStringLiteral("c.png"));
+    QVERIFY(d.mkdir(QStringLiteral("d.txt")));
+
+    QCOMPARE(qdir_entry_list(d),
+             QStringList({ QStringLiteral("b.txt"), QStringLiteral("a.txt") }));
+  }
+
+  void mkpath()
+  {
+    QTemporaryDir temporaryDir;
+    QVERIFY(temporaryDir.isValid());
+
+    const QDir d(temporaryDir.path());
+    QVERIFY(qdir_make_subdir(d, QStringLiteral("a/b")));
+    QVERIFY(QFileInfo(d.filePath(QStringLiteral("a/b"))).isDir());
+  }
+
+  void pathBuf()
+  {
+    QTemporaryDir temporaryDir;
+    QVERIFY(temporaryDir.isValid());
+
+    const QDir d(temporaryDir.path());
+    writeFile(d, QStringLiteral("a.txt"));
+    QVERIFY(qfileinfo_path_buf(d, QStringLiteral("a.txt")));
+    QVERIFY(!qfileinfo_path_buf(d, QStringLiteral("missing.txt")));
+  }
+};
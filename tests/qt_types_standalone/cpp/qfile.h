@@ -0,0 +1,43 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QStandardPaths>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qfile_cxx.cxx.h"
+
+class QFileTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void readResource()
+  {
+    QCOMPARE(read_qfile(QStringLiteral(":/test/hello.txt")),
+             QByteArrayLiteral("Hello from a resource\n"));
+    QVERIFY(read_qfile(QStringLiteral(":/test/missing.txt")).isEmpty());
+  }
+
+  void qrcUrl()
+  {
+    QCOMPARE(qfileinfo_from_qrc_url(QStringLiteral("qrc:/test/hello.txt")),
+             QStringLiteral(":/test/hello.txt"));
+    QVERIFY(qfileinfo_from_qrc_url(QStringLiteral("qrc:/test/missing.txt"))
+              .isEmpty());
+    QVERIFY(qfileinfo_from_qrc_url(QStringLiteral("https://kdab.com/hello.txt"))
+              .isEmpty());
+  }
+
+  void standardPaths()
+  {
+    QCOMPARE(qstandardpaths_temp_location(),
+             QStandardPaths::writableLocation(QStandardPaths::TempLocation));
+  }
+
+  void temporaryDir() { QVERIFY(qtemporarydir_write_file()); }
+};
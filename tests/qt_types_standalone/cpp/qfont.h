@@ -0,0 +1,44 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QFont>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qfont_cxx.cxx.h"
+
+class QFontTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto f = construct_qfont();
+    QCOMPARE(f.family(), QStringLiteral("Monospace"));
+    QCOMPARE(f.pointSize(), 14);
+    QVERIFY(f.bold());
+    QVERIFY(f.underline());
+    QVERIFY(!f.italic());
+  }
+
+  void read()
+  {
+    auto f = QFont(QStringLiteral("Serif"));
+    f.setPixelSize(20);
+    f.setItalic(true);
+    QVERIFY(read_qfont(f));
+  }
+
+  void clone()
+  {
+    auto f = QFont(QStringLiteral("Sans"), 10);
+    f.setStrikeOut(true);
+    const auto c = clone_qfont(f);
+    QCOMPARE(c, f);
+  }
+};
@@ -0,0 +1,97 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QBuffer>
+#include <QtGui/QImage>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qimage_cxx.cxx.h"
+
+class QImageTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto i = construct_qimage();
+    QCOMPARE(i.size(), QSize(4, 2));
+    QCOMPARE(i.format(), QImage::Format_ARGB32);
+    QCOMPARE(i.pixelColor(0, 0), QColor(Qt::red));
+    QCOMPARE(i.pixelColor(1, 1), QColor(Qt::blue));
+  }
+
+  void construct_from_rgba8()
+  {
+    const auto i = construct_qimage_from_rgba8();
+    QCOMPARE(i.size(), QSize(2, 1));
+    QCOMPARE(i.format(), QImage::Format_RGBA8888);
+    QCOMPARE(i.pixelColor(0, 0), QColor(255, 0, 0, 255));
+    QCOMPARE(i.pixelColor(1, 0), QColor(0, 0, 255, 128));
+
+    // Ensure that the buffer stays alive in copies of the image
+    auto c = i;
+    c.setPixelColor(0, 0, QColor(Qt::green));
+    QCOMPARE(c.pixelColor(0, 0), QColor(Qt::green));
+    QCOMPARE(i.pixelColor(0, 0), QColor(255, 0, 0, 255));
+  }
+
+  void construct_from_data()
+  {
+    auto i = QImage(3, 2, QImage::Format_ARGB32);
+    i.fill(Qt::cyan);
+
+    QByteArray data;
+    QBuffer buffer(&data);
+    buffer.open(QIODevice::WriteOnly);
+    QVERIFY(i.save(&buffer, "PNG"));
+
+    const auto c = construct_qimage_from_data(data);
+    QCOMPARE(c.size(), QSize(3, 2));
+    QCOMPARE(c.pixelColor(1, 1), QColor(Qt::cyan));
+  }
+
+  void read()
+  {
+    auto i = QImage(2, 2, QImage::Format_RGB32);
+    i.fill(Qt::green);
+    QVERIFY(read_qimage(i));
+  }
+
+  void read_bytes()
+  {
+    // A 2x2 image in RGBA8888 has 8 bytes per line
+    auto i = QImage(2, 2, QImage::Format_RGBA8888);
+    i.setPixelColor(0, 0, QColor(Qt::green));
+    i.setPixelColor(1, 0, QColor(Qt::blue));
+    i.setPixelColor(0, 1, QColor(0, 0, 0, 0));
+    i.setPixelColor(1, 1, QColor(Qt::white));
+    QVERIFY(read_qimage_bytes(i));
+  }
+
+  void save_to_data()
+  {
+    auto i = QImage(2, 3, QImage::Format_ARGB32);
+    i.fill(Qt::magenta);
+
+    const auto data = save_qimage_to_data(i);
+    QVERIFY(data.startsWith("\x89PNG"));
+
+    const auto c = QImage::fromData(data, "PNG");
+    QCOMPARE(c.size(), QSize(2, 3));
+    QCOMPARE(c.pixelColor(1, 2), QColor(Qt::magenta));
+  }
+
+  void clone()
+  {
+    auto i = QImage(3, 3, QImage::Format_ARGB32);
+    i.fill(Qt::yellow);
+    const auto c = clone_qimage(i);
+    QCOMPARE(c, i);
+  }
+};
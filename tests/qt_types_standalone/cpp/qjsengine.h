@@ -0,0 +1,37 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtQml/QJSEngine>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qjsengine_cxx.cxx.h"
+
+class QJSEngineTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto engine = construct_qjsengine();
+    QVERIFY(engine != nullptr);
+    QCOMPARE(engine->evaluate(QStringLiteral("kdab.value")).toInt(), 42);
+  }
+
+  void evaluate()
+  {
+    QJSEngine engine;
+    QVERIFY(evaluate_qjsengine(engine));
+  }
+
+  void convert()
+  {
+    QJSEngine engine;
+    QVERIFY(convert_qjsengine_values(engine));
+  }
+};
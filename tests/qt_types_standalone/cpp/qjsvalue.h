@@ -0,0 +1,75 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtQml/QJSEngine>
+#include <QtQml/QJSValue>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qjsvalue_cxx.cxx.h"
+
+class QJSValueTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto value = construct_qjsvalue();
+    QVERIFY(value.isString());
+    QCOMPARE(value.toString(), QStringLiteral("kdab"));
+
+    QVERIFY(construct_qjsvalue_null().isNull());
+  }
+
+  void read()
+  {
+    QJSEngine engine;
+    auto object = engine.newObject();
+    object.setProperty(QStringLiteral("name"), QStringLiteral("kdab"));
+    QVERIFY(read_qjsvalue_object(object));
+    QVERIFY(!read_qjsvalue_object(QJSValue(1)));
+  }
+
+  void array()
+  {
+    QJSEngine engine;
+    const auto array = engine.evaluate(QStringLiteral("[1, 2, 3, 4]"));
+    QCOMPARE(sum_qjsvalue_array(array), 10);
+    QCOMPARE(sum_qjsvalue_array(QJSValue(1)), 0);
+  }
+
+  void call()
+  {
+    QJSEngine engine;
+    const auto func =
+      engine.evaluate(QStringLiteral("(function(a, b) { return a + b; })"));
+    QCOMPARE(call_qjsvalue(func).toNumber(), 3.5);
+    QVERIFY(call_qjsvalue(QJSValue(1)).isUndefined());
+  }
+
+  void setProperty()
+  {
+    QJSEngine engine;
+    auto object = engine.newObject();
+    set_qjsvalue_property(object);
+    QCOMPARE(object.property(QStringLiteral("number")).toInt(), 42);
+    QCOMPARE(object.property(QStringLiteral("flag")).toBool(), true);
+  }
+
+  void clone()
+  {
+    QJSEngine engine;
+    auto object = engine.newObject();
+    const auto cloned = clone_qjsvalue(object);
+    QVERIFY(cloned.strictlyEquals(object));
+
+    // The clone refers to the same JavaScript object
+    object.setProperty(QStringLiteral("number"), 1);
+    QCOMPARE(cloned.property(QStringLiteral("number")).toInt(), 1);
+  }
+};
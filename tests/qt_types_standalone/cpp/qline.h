@@ -0,0 +1,61 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QLine>
+#include <QtCore/QLineF>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qline_cxx.cxx.h"
+
+class QLineTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto l = construct_qline();
+    QCOMPARE(l.p1(), QPoint(1, 2));
+    QCOMPARE(l.p2(), QPoint(3, 4));
+  }
+
+  void constructF()
+  {
+    const auto l = construct_qlinef();
+    QCOMPARE(l.length(), 2.0);
+    QCOMPARE(l.angle(), 90.0);
+  }
+
+  void read()
+  {
+    const auto l = QLine(1, 2, 3, 4);
+    QVERIFY(read_qline(l));
+  }
+
+  void readF()
+  {
+    const auto l = QLineF(1.0, 0.0, 1.0, 2.0);
+    QVERIFY(read_qlinef(l));
+  }
+
+  void clone()
+  {
+    const auto l = QLine(1, 2, 3, 4);
+    const auto c = clone_qline(l);
+    QCOMPARE(c.p1(), QPoint(1, 2));
+    QCOMPARE(c.p2(), QPoint(3, 4));
+  }
+
+  void translateF()
+  {
+    const auto l = QLineF(0.0, 0.0, 1.0, 1.0);
+    const auto t = translate_qlinef(l);
+    QCOMPARE(t.p1(), QPointF(1.0, 1.0));
+    QCOMPARE(t.p2(), QPointF(2.0, 2.0));
+  }
+};
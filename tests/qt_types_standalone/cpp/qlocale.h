@@ -0,0 +1,50 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QLocale>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qlocale_cxx.cxx.h"
+
+class QLocaleTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto l = construct_qlocale();
+    QCOMPARE(l.name(), QStringLiteral("de_DE"));
+    QCOMPARE(l.language(), QLocale::German);
+  }
+
+  void read()
+  {
+    const auto l = QLocale(QStringLiteral("de_DE"));
+    QVERIFY(read_qlocale(l));
+  }
+
+  void clone()
+  {
+    const auto l = QLocale(QStringLiteral("de_DE"));
+    const auto c = clone_qlocale(l);
+    QCOMPARE(c, l);
+  }
+
+  void format()
+  {
+    const auto l = QLocale(QStringLiteral("de_DE"));
+    QVERIFY(format_qlocale(l));
+  }
+
+  void parse()
+  {
+    const auto l = QLocale(QStringLiteral("de_DE"));
+    QVERIFY(parse_qlocale(l));
+  }
+};
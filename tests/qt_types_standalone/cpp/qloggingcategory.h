@@ -0,0 +1,76 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QLoggingCategory>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qloggingcategory_cxx.cxx.h"
+
+namespace {
+
+struct LoggedMessage
+{
+  QtMsgType type = QtDebugMsg;
+  QString category;
+  QString file;
+  int line = 0;
+  QString message;
+};
+
+LoggedMessage lastLoggedMessage;
+
+void
+loggedMessageHandler(QtMsgType type,
+                     const QMessageLogContext& context,
+                     const QString& message)
+{
+  lastLoggedMessage.type = type;
+  lastLoggedMessage.category = QString::fromUtf8(context.category);
+  lastLoggedMessage.file = QString::fromUtf8(context.file);
+  lastLoggedMessage.line = context.line;
+  lastLoggedMessage.message = message;
+}
+
+}
+
+class QLoggingCategoryTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void forward_messages()
+  {
+    install_qt_message_handler();
+    QMessageLogger(nullptr, 0, nullptr, "kdab.forward").warning("kdab");
+    restore_qt_message_handler();
+
+    QCOMPARE(last_qt_message(), QStringLiteral("kdab.forward:1:kdab"));
+  }
+
+  void log()
+  {
+    qInstallMessageHandler(loggedMessageHandler);
+    log_qloggingcategory();
+    qInstallMessageHandler(nullptr);
+
+    QCOMPARE(lastLoggedMessage.type, QtWarningMsg);
+    QCOMPARE(lastLoggedMessage.category, QStringLiteral("kdab.test"));
+    QVERIFY(lastLoggedMessage.file.endsWith(
+      QStringLiteral("qloggingcategory.rs")));
+    QVERIFY(lastLoggedMessage.line > 0);
+    QCOMPARE(lastLoggedMessage.message, QStringLiteral("kdab"));
+  }
+
+  void filter_rules()
+  {
+    QVERIFY(read_qloggingcategory_filter_rules());
+    QLoggingCategory category("kdab.filtered");
+    QVERIFY(!category.isDebugEnabled());
+    QLoggingCategory::setFilterRules(QString());
+  }
+};
@@ -0,0 +1,53 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QMatrix4x4>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qmatrix_4_x_4_cxx.cxx.h"
+
+class QMatrix4x4Test : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto m = construct_qmatrix4x4();
+    QCOMPARE(m.column(3), QVector4D(1.0f, 2.0f, 3.0f, 1.0f));
+  }
+
+  void read()
+  {
+    // clang-format off
+    const auto m = QMatrix4x4(1.0f, 2.0f, 3.0f, 4.0f,
+                              5.0f, 6.0f, 7.0f, 8.0f,
+                              9.0f, 10.0f, 11.0f, 12.0f,
+                              13.0f, 14.0f, 15.0f, 16.0f);
+    // clang-format on
+    QVERIFY(read_qmatrix4x4(m));
+  }
+
+  void clone()
+  {
+    auto m = QMatrix4x4();
+    m.scale(2.0f);
+    const auto c = clone_qmatrix4x4(m);
+    QCOMPARE(c, m);
+  }
+
+  void multiply()
+  {
+    auto a = QMatrix4x4();
+    a.scale(2.0f);
+    auto b = QMatrix4x4();
+    b.translate(1.0f, 2.0f, 3.0f);
+    const auto m = multiply_qmatrix4x4(a, b);
+    QCOMPARE(m, a * b);
+  }
+};
@@ -0,0 +1,53 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QObject>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qobject_cxx.cxx.h"
+
+class QObjectTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void read()
+  {
+    QObject parent;
+    parent.setObjectName(QStringLiteral("parent"));
+    QVERIFY(read_qobject(parent));
+  }
+
+  void children()
+  {
+    QObject parent;
+    auto first = new QObject(&parent);
+    first->setObjectName(QStringLiteral("first"));
+    auto second = new QObject(&parent);
+    auto grandchild = new QObject(second);
+    grandchild->setObjectName(QStringLiteral("grandchild"));
+    QVERIFY(read_qobject_children(parent));
+  }
+
+  void property()
+  {
+    QObject obj;
+    write_qobject_property(obj);
+    QCOMPARE(obj.property("dynamic").toInt(), 42);
+    QVERIFY(read_qobject_property(obj));
+  }
+
+  void reparent()
+  {
+    QObject parent;
+    auto child = new QObject();
+    reparent_qobject(*child, &parent);
+    QCOMPARE(child->parent(), &parent);
+    QCOMPARE(parent.children().size(), 1);
+  }
+};
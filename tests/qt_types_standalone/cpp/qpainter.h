@@ -0,0 +1,47 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QGuiApplication>
+#include <QtGui/QImage>
+#include <QtGui/QPainter>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qpainter_cxx.cxx.h"
+
+class QPainterTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void paint()
+  {
+    // Fonts require a QGuiApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QGuiApplication app(argc, args.data());
+
+    auto image = QImage(20, 20, QImage::Format_ARGB32);
+    image.fill(Qt::transparent);
+
+    {
+      QPainter painter(&image);
+      paint_qpainter(painter);
+
+      QCOMPARE(painter.pen().color(), QColor(Qt::white));
+      QCOMPARE(painter.brush().style(), Qt::NoBrush);
+      QCOMPARE(painter.font().family(), QStringLiteral("Monospace"));
+      QVERIFY(!painter.testRenderHint(QPainter::Antialiasing));
+    }
+
+    QCOMPARE(image.pixelColor(5, 5), QColor(Qt::red));
+    QCOMPARE(image.pixelColor(15, 5), QColor(Qt::green));
+    QCOMPARE(image.pixelColor(5, 15), QColor(Qt::blue));
+  }
+};
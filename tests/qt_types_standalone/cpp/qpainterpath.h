@@ -0,0 +1,42 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QPainterPath>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qpainterpath_cxx.cxx.h"
+
+class QPainterPathTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto p = construct_qpainterpath();
+    QCOMPARE(p.elementCount(), 4);
+    QCOMPARE(p.boundingRect(), QRectF(0.0, 0.0, 10.0, 10.0));
+    QVERIFY(p.contains(QPointF(2.0, 2.0)));
+    QVERIFY(!p.contains(QPointF(8.0, 8.0)));
+  }
+
+  void read()
+  {
+    QPainterPath p;
+    p.addRect(QRectF(0.0, 0.0, 20.0, 10.0));
+    QVERIFY(read_qpainterpath(p));
+  }
+
+  void clone()
+  {
+    QPainterPath p;
+    p.addEllipse(QRectF(0.0, 0.0, 5.0, 5.0));
+    const auto c = clone_qpainterpath(p);
+    QCOMPARE(c, p);
+  }
+};
@@ -0,0 +1,43 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QPen>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qpen_cxx.cxx.h"
+
+class QPenTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto p = construct_qpen();
+    QCOMPARE(p.color(), QColor(Qt::red));
+    QCOMPARE(p.widthF(), 2.5);
+    QCOMPARE(p.style(), Qt::DashLine);
+    QCOMPARE(p.capStyle(), Qt::RoundCap);
+    QCOMPARE(p.joinStyle(), Qt::RoundJoin);
+    QVERIFY(p.isCosmetic());
+  }
+
+  void read()
+  {
+    const auto p =
+      QPen(QBrush(Qt::blue), 3.0, Qt::DotLine, Qt::FlatCap, Qt::MiterJoin);
+    QVERIFY(read_qpen(p));
+  }
+
+  void clone()
+  {
+    const auto p = QPen(Qt::green);
+    const auto c = clone_qpen(p);
+    QCOMPARE(c, p);
+  }
+};
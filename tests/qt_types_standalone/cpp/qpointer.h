@@ -0,0 +1,30 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QTimer>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qpointer_cxx.cxx.h"
+
+class QPointerTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void upgrade()
+  {
+    QTimer timer;
+    timer.setInterval(10);
+    QVERIFY(qpointer_upgrade_set_interval(timer, 20));
+    QCOMPARE(timer.interval(), 20);
+  }
+
+  void destroyed() { QVERIFY(qpointer_is_null_after_destroyed()); }
+
+  void defaultIsNull() { QVERIFY(qpointer_default_is_null()); }
+};
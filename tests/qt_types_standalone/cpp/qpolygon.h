@@ -0,0 +1,55 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QPolygon>
+#include <QtGui/QPolygonF>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qpolygon_cxx.cxx.h"
+
+class QPolygonTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto p = construct_qpolygon();
+    QCOMPARE(p.size(), 3);
+    QCOMPARE(p.at(1), QPoint(10, 0));
+  }
+
+  void constructF()
+  {
+    const QPolygon polygon = QPolygon() << QPoint(0, 0) << QPoint(1, 1);
+    const auto p = construct_qpolygonf(polygon);
+    QCOMPARE(p.size(), 2);
+    QCOMPARE(p.at(1), QPointF(1.5, 1.5));
+  }
+
+  void read()
+  {
+    const QPolygon p = QPolygon()
+                       << QPoint(0, 0) << QPoint(10, 0) << QPoint(10, 10);
+    QVERIFY(read_qpolygon(p));
+  }
+
+  void readF()
+  {
+    const auto p = QPolygonF(QRectF(0.0, 0.0, 5.0, 5.0));
+    QVERIFY(read_qpolygonf(p));
+  }
+
+  void clone()
+  {
+    const QPolygon p = QPolygon()
+                       << QPoint(0, 0) << QPoint(10, 0) << QPoint(10, 10);
+    const auto c = clone_qpolygon(p);
+    QCOMPARE(c, p);
+  }
+};
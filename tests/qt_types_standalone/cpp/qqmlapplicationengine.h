@@ -44,4 +44,24 @@ private Q_SLOTS:
     engine.setBaseUrl(QUrl(QStringLiteral("qrc:/kdab.qml")));
     QVERIFY(read_qqmlapplicationengine(engine));
   }
+
+  void objectCreated()
+  {
+    // QQmlEngine requires a QApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QQmlApplicationEngine engine;
+    connect_qqmlapplicationengine_object_created(engine);
+    QCOMPARE(read_qqmlapplicationengine_object_created_failed_count(), 0u);
+
+    // Loading a missing file emits objectCreated with a nullptr
+    engine.load(QUrl(QStringLiteral("qrc:/missing.qml")));
+    QCOMPARE(read_qqmlapplicationengine_object_created_failed_count(), 1u);
+    QCOMPARE(read_qqmlapplicationengine_root_objects_len(engine),
+             static_cast<::std::size_t>(0));
+  }
 };
@@ -0,0 +1,51 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QCoreApplication>
+#include <QtQml/QQmlEngine>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qqmlcomponent_cxx.cxx.h"
+
+class QQmlComponentTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void createFromData()
+  {
+    // QQmlEngine requires a QApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QQmlEngine engine;
+    const auto object =
+      std::unique_ptr<QObject>(create_qqmlcomponent_from_data(engine));
+    QVERIFY(object != nullptr);
+    QCOMPARE(object->property("value").toInt(), 42);
+    QCOMPARE(object->property("name").toString(), QStringLiteral("Rust"));
+  }
+
+  void errors()
+  {
+    // QQmlEngine requires a QApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QQmlEngine engine;
+    QVERIFY(read_qqmlcomponent_errors(engine));
+  }
+};
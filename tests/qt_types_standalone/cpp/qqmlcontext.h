@@ -0,0 +1,56 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtQml/QQmlContext>
+#include <QtQml/QQmlEngine>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qqmlcontext_cxx.cxx.h"
+
+class QQmlContextTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void setProperties()
+  {
+    // QQmlEngine requires a QApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QObject object;
+    QQmlEngine engine;
+    set_qqmlcontext_properties(*engine.rootContext(), object);
+
+    QCOMPARE(
+      engine.rootContext()->contextProperty(QStringLiteral("kdabValue")).toInt(),
+      42);
+    QCOMPARE(engine.rootContext()
+               ->contextProperty(QStringLiteral("kdabObject"))
+               .value<QObject*>(),
+             &object);
+  }
+
+  void read()
+  {
+    // QQmlEngine requires a QApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QQmlEngine engine;
+    engine.rootContext()->setContextProperty(QStringLiteral("kdabValue"), 42);
+    QVERIFY(read_qqmlcontext(*engine.rootContext()));
+  }
+};
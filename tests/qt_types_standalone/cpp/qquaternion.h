@@ -0,0 +1,48 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QQuaternion>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qquaternion_cxx.cxx.h"
+
+class QQuaternionTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto q = construct_qquaternion();
+    QCOMPARE(q, QQuaternion::fromAxisAndAngle(0.0f, 0.0f, 1.0f, 90.0f));
+  }
+
+  void read()
+  {
+    const auto q = QQuaternion::fromAxisAndAngle(0.0f, 0.0f, 1.0f, 90.0f);
+    QVERIFY(read_qquaternion(q));
+  }
+
+  void clone()
+  {
+    const auto q = QQuaternion(1.0f, 2.0f, 3.0f, 4.0f);
+    const auto c = clone_qquaternion(q);
+    QCOMPARE(c.scalar(), 1.0f);
+    QCOMPARE(c.x(), 2.0f);
+    QCOMPARE(c.y(), 3.0f);
+    QCOMPARE(c.z(), 4.0f);
+  }
+
+  void multiply()
+  {
+    const auto a = QQuaternion::fromAxisAndAngle(0.0f, 0.0f, 1.0f, 45.0f);
+    const auto b = QQuaternion::fromAxisAndAngle(0.0f, 0.0f, 1.0f, 45.0f);
+    const auto m = multiply_qquaternion(a, b);
+    QCOMPARE(m, QQuaternion::fromAxisAndAngle(0.0f, 0.0f, 1.0f, 90.0f));
+  }
+};
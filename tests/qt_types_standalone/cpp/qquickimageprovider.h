@@ -0,0 +1,77 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtGui/QGuiApplication>
+#include <QtQml/QQmlEngine>
+#include <QtQuick/QQuickImageProvider>
+#include <QtTest/QSignalSpy>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qquickimageprovider_cxx.cxx.h"
+
+class QQuickImageProviderTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void request_image()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QGuiApplication app(argc, args.data());
+
+    QQmlEngine engine;
+    add_qquickimageproviders(engine);
+
+    auto provider =
+      dynamic_cast<QQuickImageProvider*>(engine.imageProvider("colors"));
+    QVERIFY(provider != nullptr);
+    QCOMPARE(provider->imageType(), QQmlImageProviderBase::Image);
+
+    QSize size;
+    const auto image = provider->requestImage("red", &size, QSize(4, 2));
+    QCOMPARE(size, QSize(4, 2));
+    QCOMPARE(image.size(), QSize(4, 2));
+    QCOMPARE(image.pixelColor(3, 1), QColor(Qt::red));
+
+    // An invalid id results in a null image
+    QVERIFY(provider->requestImage("notacolor", &size, QSize()).isNull());
+  }
+
+  void request_image_async()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QGuiApplication app(argc, args.data());
+
+    QQmlEngine engine;
+    add_qquickimageproviders(engine);
+
+    auto provider = dynamic_cast<QQuickAsyncImageProvider*>(
+      engine.imageProvider("colorsasync"));
+    QVERIFY(provider != nullptr);
+    QCOMPARE(provider->imageType(), QQmlImageProviderBase::ImageResponse);
+
+    std::unique_ptr<QQuickImageResponse> response(
+      provider->requestImageResponse("blue", QSize()));
+    QSignalSpy spy(response.get(), &QQuickImageResponse::finished);
+    QVERIFY(spy.wait());
+    QVERIFY(response->errorString().isEmpty());
+
+    std::unique_ptr<QQuickTextureFactory> factory(response->textureFactory());
+    const auto image = factory->image();
+    QCOMPARE(image.size(), QSize(8, 8));
+    QCOMPARE(image.pixelColor(0, 0), QColor(Qt::blue));
+  }
+};
@@ -0,0 +1,75 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtGui/QGuiApplication>
+#include <QtQuick/QQuickItem>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qquickitem_cxx.cxx.h"
+
+class QQuickItemTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    // QQuickItem requires a QGuiApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QGuiApplication app(argc, args.data());
+
+    const auto item = construct_qquickitem();
+    QVERIFY(item != nullptr);
+    QCOMPARE(item->size(), QSizeF(100.0, 50.0));
+    QCOMPARE(item->x(), 10.0);
+    QCOMPARE(item->opacity(), 0.5);
+    QVERIFY(item->flags().testFlag(QQuickItem::ItemHasContents));
+  }
+
+  void read()
+  {
+    // QQuickItem requires a QGuiApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QGuiApplication app(argc, args.data());
+
+    QQuickItem item;
+    item.setWidth(20.0);
+    item.setHeight(30.0);
+    item.setY(5.0);
+    item.setVisible(false);
+    item.setFlag(QQuickItem::ItemClipsChildrenToShape);
+    QVERIFY(read_qquickitem(item));
+  }
+
+  void parent()
+  {
+    // QQuickItem requires a QGuiApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QGuiApplication app(argc, args.data());
+
+    QQuickItem parent;
+    QQuickItem child;
+    set_qquickitem_parent(child, &parent);
+    QCOMPARE(child.parentItem(), &parent);
+    QCOMPARE(parent.childItems().size(), 1);
+
+    set_qquickitem_parent(child, nullptr);
+    QCOMPARE(child.parentItem(), nullptr);
+  }
+};
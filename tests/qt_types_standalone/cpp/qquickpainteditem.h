@@ -0,0 +1,61 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QGuiApplication>
+#include <QtGui/QPainter>
+#include <QtQuick/QQuickPaintedItem>
+#include <QtQuick/QQuickWindow>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qquickpainteditem_cxx.cxx.h"
+
+class RustPaintedItem : public QQuickPaintedItem
+{
+  Q_OBJECT
+
+public:
+  void paint(QPainter* painter) override
+  {
+    paint_qquickpainteditem(*this, *painter);
+  }
+};
+
+class QQuickPaintedItemTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void paint()
+  {
+    // Use the software backend so that this can be tested headless
+    QQuickWindow::setSceneGraphBackend(QStringLiteral("software"));
+
+    // QQuickWindow requires a QGuiApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QGuiApplication app(argc, args.data());
+
+    QQuickWindow window;
+    window.resize(20, 20);
+
+    RustPaintedItem item;
+    item.setParentItem(window.contentItem());
+    setup_qquickpainteditem(item);
+    QCOMPARE(item.fillColor(), QColor(Qt::blue));
+    QCOMPARE(item.size(), QSizeF(20.0, 20.0));
+
+    window.show();
+    QVERIFY(QTest::qWaitForWindowExposed(&window));
+
+    const auto image = window.grabWindow();
+    QCOMPARE(image.pixelColor(5, 10), QColor(Qt::red));
+    QCOMPARE(image.pixelColor(15, 10), QColor(Qt::blue));
+  }
+};
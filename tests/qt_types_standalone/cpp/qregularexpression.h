@@ -0,0 +1,57 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QRegularExpression>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qregularexpression_cxx.cxx.h"
+
+class QRegularExpressionTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto re = construct_qregularexpression();
+    QVERIFY(re.isValid());
+    QCOMPARE(re.pattern(),
+             QStringLiteral("^(?<key>[a-z]+)=(?<value>\\d+)$"));
+    QCOMPARE(re.patternOptions(),
+             QRegularExpression::CaseInsensitiveOption |
+               QRegularExpression::MultilineOption);
+    QCOMPARE(re.namedCaptureGroups(),
+             QStringList() << QString() << QStringLiteral("key")
+                           << QStringLiteral("value"));
+  }
+
+  void read()
+  {
+    const auto re =
+      QRegularExpression(QStringLiteral("^(?<key>[a-z]+)=(?<value>\\d+)$"));
+    QVERIFY(read_qregularexpression(re));
+  }
+
+  void clone()
+  {
+    const auto re = QRegularExpression(QStringLiteral("[a-z]+"));
+    const auto c = clone_qregularexpression(re);
+    QCOMPARE(c, re);
+  }
+
+  void globalMatch()
+  {
+    const auto re = QRegularExpression(
+      QStringLiteral("^([a-z])=(\\d)$"), QRegularExpression::MultilineOption);
+    QCOMPARE(global_match_qregularexpression(re), 3);
+  }
+
+  void invalid() { QVERIFY(invalid_qregularexpression()); }
+
+  void qstring() { QVERIFY(qstring_with_qregularexpression()); }
+};
@@ -0,0 +1,74 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QSettings>
+#include <QtCore/QTemporaryDir>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qsettings_cxx.cxx.h"
+
+class QSettingsTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void read()
+  {
+    QTemporaryDir dir;
+    QVERIFY(dir.isValid());
+    const auto fileName = dir.filePath(QStringLiteral("settings.ini"));
+
+    {
+      QSettings settings(fileName, QSettings::IniFormat);
+      settings.setValue(QStringLiteral("count"), 42);
+      settings.setValue(QStringLiteral("window/width"), 100);
+      settings.beginWriteArray(QStringLiteral("recent"));
+      settings.setArrayIndex(0);
+      settings.setValue(QStringLiteral("file"), QStringLiteral("a.txt"));
+      settings.setArrayIndex(1);
+      settings.setValue(QStringLiteral("file"), QStringLiteral("b.txt"));
+      settings.endArray();
+    }
+
+    QVERIFY(read_qsettings(fileName));
+  }
+
+  void serde()
+  {
+    QTemporaryDir dir;
+    QVERIFY(dir.isValid());
+    const auto fileName = dir.filePath(QStringLiteral("settings.ini"));
+
+    QVERIFY(roundtrip_qsettings_serde(fileName));
+
+    QSettings settings(fileName, QSettings::IniFormat);
+    QCOMPARE(settings.value(QStringLiteral("preferences/name")).toString(),
+             QStringLiteral("kdab"));
+    QCOMPARE(settings.value(QStringLiteral("preferences/window/width")).toInt(),
+             100);
+    QVERIFY(!settings.contains(QStringLiteral("preferences/theme")));
+  }
+
+  void write()
+  {
+    QTemporaryDir dir;
+    QVERIFY(dir.isValid());
+    const auto fileName = dir.filePath(QStringLiteral("settings.ini"));
+
+    write_qsettings(fileName);
+
+    QSettings settings(fileName, QSettings::IniFormat);
+    QCOMPARE(settings.value(QStringLiteral("count")).toInt(), 42);
+    QCOMPARE(settings.value(QStringLiteral("window/width")).toInt(), 100);
+    QCOMPARE(settings.beginReadArray(QStringLiteral("recent")), 2);
+    settings.setArrayIndex(1);
+    QCOMPARE(settings.value(QStringLiteral("file")).toString(),
+             QStringLiteral("b.txt"));
+    settings.endArray();
+  }
+};
@@ -0,0 +1,66 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QTimer>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qtimer_cxx.cxx.h"
+
+class QTimerTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto timer = construct_qtimer();
+    QVERIFY(timer != nullptr);
+    QCOMPARE(timer->interval(), 10);
+    QVERIFY(timer->isSingleShot());
+    QCOMPARE(timer->timerType(), Qt::PreciseTimer);
+  }
+
+  void read()
+  {
+    QTimer timer;
+    timer.setInterval(10);
+    timer.setSingleShot(true);
+    timer.setTimerType(Qt::PreciseTimer);
+    QVERIFY(read_qtimer(timer));
+  }
+
+  void timeout()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QTimer timer;
+    timer.setInterval(0);
+    connect_qtimer_timeout(timer);
+    QCOMPARE(read_qtimer_timeout_count(), 0u);
+
+    timer.start();
+    QTRY_VERIFY(read_qtimer_timeout_count() > 0);
+  }
+
+  void singleShot()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    queue_qtimer_single_shot();
+    QTRY_COMPARE(read_qtimer_single_shot_count(), 1u);
+  }
+};
@@ -0,0 +1,47 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QTransform>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qtransform_cxx.cxx.h"
+
+class QTransformTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto t = construct_qtransform();
+    QCOMPARE(t, QTransform(2.0, 0.0, 0.0, 2.0, 10.0, 20.0));
+  }
+
+  void read()
+  {
+    const auto t = QTransform(2.0, 0.0, 0.0, 2.0, 10.0, 20.0);
+    QVERIFY(read_qtransform(t));
+  }
+
+  void clone()
+  {
+    const auto t = QTransform::fromTranslate(1.0, 2.0);
+    const auto c = clone_qtransform(t);
+    QCOMPARE(c.dx(), 1.0);
+    QCOMPARE(c.dy(), 2.0);
+  }
+
+  void multiply()
+  {
+    const auto a = QTransform::fromScale(2.0, 2.0);
+    const auto b = QTransform::fromTranslate(1.0, 2.0);
+    const auto m = multiply_qtransform(a, b);
+    QCOMPARE(m, a * b);
+    QCOMPARE(m.map(QPointF(1.0, 1.0)), QPointF(3.0, 4.0));
+  }
+};
@@ -0,0 +1,75 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtWidgets/QApplication>
+#include <QtWidgets/QLayout>
+#include <QtWidgets/QWidget>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qwidget_cxx.cxx.h"
+
+class QWidgetTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    // QWidget requires a QApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QApplication app(argc, args.data());
+
+    const auto widget = construct_qwidget();
+    QVERIFY(widget != nullptr);
+    QCOMPARE(widget->windowTitle(), QStringLiteral("kdab"));
+    QCOMPARE(widget->geometry(), QRect(10, 20, 100, 50));
+    QVERIFY(!widget->isEnabled());
+    QVERIFY(widget->isVisible());
+  }
+
+  void construct_with_layout()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QApplication app(argc, args.data());
+
+    const auto widget = construct_qwidget_with_layout();
+    QVERIFY(widget != nullptr);
+    QVERIFY(widget->layout() != nullptr);
+    QCOMPARE(widget->layout()->count(), 2);
+
+    // The child widgets are now owned by the widget
+    const auto children = widget->findChildren<QWidget*>();
+    QCOMPARE(children.size(), 2);
+
+    // The layout places the fixed size child at the top
+    // and the stretched child below it
+    widget->layout()->activate();
+    QCOMPARE(children.at(0)->geometry(), QRect(0, 0, 20, 10));
+    QCOMPARE(children.at(1)->geometry(), QRect(0, 10, 20, 20));
+  }
+
+  void read()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QApplication app(argc, args.data());
+
+    QWidget widget;
+    widget.setWindowTitle(QStringLiteral("test"));
+    widget.setGeometry(QRect(1, 2, 30, 40));
+    QVERIFY(read_qwidget(widget));
+  }
+};
@@ -7,38 +7,67 @@ use cxx_qt_build::CxxQtBuilder;
 
 fn main() {
     CxxQtBuilder::new()
+        .file("src/qapplication.rs")
+        .file("src/qbrush.rs")
         .file("src/qbytearray.rs")
         .file("src/qcolor.rs")
         .file("src/qcoreapplication.rs")
         .file("src/qdate.rs")
         .file("src/qdatetime.rs")
+        .file("src/qdir.rs")
+        .file("src/qfile.rs")
+        .file("src/qfont.rs")
         .file("src/qguiapplication.rs")
         .file("src/qhash.rs")
+        .file("src/qimage.rs")
+        .file("src/qjsengine.rs")
+        .file("src/qjsvalue.rs")
+        .file("src/qline.rs")
         .file("src/qlist.rs")
+        .file("src/qlocale.rs")
+        .file("src/qloggingcategory.rs")
         .file("src/qmap.rs")
         .file("src/qmargins.rs")
         .file("src/qmarginsf.rs")
+        .file("src/qmatrix4x4.rs")
         .file("src/qmetaobjectconnection.rs")
         .file("src/qmodelindex.rs")
+        .file("src/qobject.rs")
+        .file("src/qpainter.rs")
+        .file("src/qpainterpath.rs")
+        .file("src/qpen.rs")
         .file("src/qpersistentmodelindex.rs")
         .file("src/qpoint.rs")
+        .file("src/qpointer.rs")
         .file("src/qpointf.rs")
+        .file("src/qpolygon.rs")
         .file("src/qqmlapplicationengine.rs")
+        .file("src/qqmlcomponent.rs")
+        .file("src/qqmlcontext.rs")
         .file("src/qqmlengine.rs")
+        .file("src/qquaternion.rs")
+        .file("src/qquickimageprovider.rs")
+        .file("src/qquickitem.rs")
+        .file("src/qquickpainteditem.rs")
         .file("src/qrect.rs")
         .file("src/qrectf.rs")
+        .file("src/qregularexpression.rs")
         .file("src/qset.rs")
+        .file("src/qsettings.rs")
         .file("src/qsize.rs")
         .file("src/qsizef.rs")
         .file("src/qstring.rs")
         .file("src/qstringlist.rs")
         .file("src/qtime.rs")
+        .file("src/qtimer.rs")
         .file("src/qtimezone.rs")
+        .file("src/qtransform.rs")
         .file("src/qurl.rs")
         .file("src/qvariant.rs")
         .file("src/qvector.rs")
         .file("src/qvector2d.rs")
         .file("src/qvector3d.rs")
         .file("src/qvector4d.rs")
+        .file("src/qwidget.rs")
         .build();
 }
@@ -4,36 +4,65 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod qapplication;
+mod qbrush;
 mod qbytearray;
 mod qcolor;
 mod qcoreapplication;
 mod qdate;
 mod qdatetime;
+mod qdir;
+mod qfile;
+mod qfont;
 mod qguiapplication;
 mod qhash;
+mod qimage;
+mod qjsengine;
+mod qjsvalue;
+mod qline;
 mod qlist;
+mod qlocale;
+mod qloggingcategory;
 mod qmap;
 mod qmargins;
 mod qmarginsf;
+mod qmatrix4x4;
 mod qmetaobjectconnection;
 mod qmodelindex;
+mod qobject;
+mod qpainter;
+mod qpainterpath;
+mod qpen;
 mod qpersistentmodelindex;
 mod qpoint;
+mod qpointer;
 mod qpointf;
+mod qpolygon;
 mod qqmlapplicationengine;
+mod qqmlcomponent;
+mod qqmlcontext;
 mod qqmlengine;
+mod qquaternion;
+mod qquickimageprovider;
+mod qquickitem;
+mod qquickpainteditem;
 mod qrect;
 mod qrectf;
+mod qregularexpression;
 mod qset;
+mod qsettings;
 mod qsize;
 mod qsizef;
 mod qstring;
 mod qstringlist;
 mod qtime;
+mod qtimer;
 mod qtimezone;
+mod qtransform;
 mod qurl;
 mod qvariant;
 mod qvector;
 mod qvector2d;
 mod qvector3d;
 mod qvector4d;
+mod qwidget;
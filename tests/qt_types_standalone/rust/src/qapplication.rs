@@ -0,0 +1,31 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QApplication, QString};
+
+#[cxx::bridge]
+mod qapplication_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qapplication.h");
+        type QApplication = cxx_qt_lib::QApplication;
+    }
+
+    extern "Rust" {
+        fn construct_qapplication() -> UniquePtr<QApplication>;
+        fn read_qapplication(c: &QApplication) -> bool;
+    }
+}
+
+fn construct_qapplication() -> cxx::UniquePtr<QApplication> {
+    let mut app = QApplication::new();
+    if let Some(app) = app.as_mut() {
+        app.set_application_name(&QString::from("kdab"));
+    }
+    app
+}
+
+fn read_qapplication(app: &QApplication) -> bool {
+    app.application_name().to_string() == "kdab"
+}
@@ -0,0 +1,34 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{BrushStyle, QBrush, QColor};
+
+#[cxx::bridge]
+mod qbrush_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbrush.h");
+        type QBrush = cxx_qt_lib::QBrush;
+    }
+
+    extern "Rust" {
+        fn construct_qbrush() -> QBrush;
+        fn read_qbrush(b: &QBrush) -> bool;
+        fn clone_qbrush(b: &QBrush) -> QBrush;
+    }
+}
+
+fn construct_qbrush() -> QBrush {
+    QBrush::new(&QColor::from_rgb(0, 255, 0), BrushStyle::CrossPattern)
+}
+
+fn read_qbrush(b: &QBrush) -> bool {
+    *b.color() == QColor::from_rgb(0, 0, 255)
+        && b.style() == BrushStyle::SolidPattern
+        && b.is_opaque()
+}
+
+fn clone_qbrush(b: &QBrush) -> QBrush {
+    b.clone()
+}
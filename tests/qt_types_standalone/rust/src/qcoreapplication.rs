@@ -3,18 +3,26 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use cxx_qt_lib::{QCoreApplication, QString};
+use core::pin::Pin;
+use cxx_qt_lib::{CaseSensitivity, QCoreApplication, QEvent, QObject, QString};
+use std::{cell::Cell, rc::Rc};
 
 #[cxx::bridge]
 mod qcoreapplication_cxx {
     unsafe extern "C++" {
         include!("cxx-qt-lib/qcoreapplication.h");
         type QCoreApplication = cxx_qt_lib::QCoreApplication;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt_lib::QObject;
     }
 
     extern "Rust" {
         fn construct_qcoreapplication() -> UniquePtr<QCoreApplication>;
+        fn construct_qcoreapplication_with_args() -> UniquePtr<QCoreApplication>;
+        fn exec_qcoreapplication(app: Pin<&mut QCoreApplication>) -> i32;
+        unsafe fn post_qcoreapplication_event(receiver: *mut QObject) -> i32;
         fn read_qcoreapplication(c: &QCoreApplication) -> bool;
+        fn read_qcoreapplication_arguments(c: &QCoreApplication) -> bool;
     }
 }
 
@@ -26,6 +34,40 @@ fn construct_qcoreapplication() -> cxx::UniquePtr<QCoreApplication> {
     app
 }
 
+fn construct_qcoreapplication_with_args() -> cxx::UniquePtr<QCoreApplication> {
+    QCoreApplication::new_with_args(["/path", "--kdab"])
+}
+
+fn exec_qcoreapplication(mut app: Pin<&mut QCoreApplication>) -> i32 {
+    let about_to_quit = Rc::new(Cell::new(false));
+    let _connection = app.as_mut().on_about_to_quit({
+        let about_to_quit = about_to_quit.clone();
+        move || about_to_quit.set(true)
+    });
+
+    // The exit is queued, so it is processed once the event loop is running
+    QCoreApplication::exit_queued(42);
+    let code = app.exec();
+
+    if about_to_quit.get() {
+        code
+    } else {
+        -1
+    }
+}
+
+unsafe fn post_qcoreapplication_event(receiver: *mut QObject) -> i32 {
+    let event_type = QEvent::register_event_type(-1);
+    QCoreApplication::post_event(receiver, QEvent::new(event_type), 0);
+    event_type
+}
+
 fn read_qcoreapplication(app: &QCoreApplication) -> bool {
     app.application_name().to_string() == "kdab"
 }
+
+fn read_qcoreapplication_arguments(app: &QCoreApplication) -> bool {
+    app.arguments()
+        .contains(&QString::from("--kdab"), CaseSensitivity::CaseSensitive)
+        && !QCoreApplication::instance().is_null()
+}
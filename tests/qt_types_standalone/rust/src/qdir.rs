@@ -0,0 +1,60 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QDir, QDirFilter, QDirSortFlag, QFileInfo, QString, QStringList};
+use std::path::PathBuf;
+
+#[cxx::bridge]
+mod qdir_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qdir.h");
+        type QDir = cxx_qt_lib::QDir;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = cxx_qt_lib::QStringList;
+    }
+
+    extern "Rust" {
+        fn clone_qdir(d: &QDir) -> QDir;
+        fn construct_qdir(path: &QString) -> QDir;
+        fn qdir_entry_list(d: &QDir) -> QStringList;
+        fn qdir_make_subdir(d: &QDir, name: &QString) -> bool;
+        fn qfileinfo_path_buf(d: &QDir, name: &QString) -> bool;
+    }
+}
+
+fn clone_qdir(d: &QDir) -> QDir {
+    d.clone()
+}
+
+fn construct_qdir(path: &QString) -> QDir {
+    QDir::from(path)
+}
+
+fn qdir_entry_list(d: &QDir) -> QStringList {
+    d.entry_list_with_name_filters(
+        &QStringList::from(&QString::from("*.txt")),
+        QDirFilter::Files | QDirFilter::NoDotAndDotDot,
+        QDirSortFlag::Name | QDirSortFlag::Reversed,
+    )
+}
+
+fn qdir_make_subdir(d: &QDir, name: &QString) -> bool {
+    d.mkpath(name) && d.exists_entry(name)
+}
+
+fn qfileinfo_path_buf(d: &QDir, name: &QString) -> bool {
+    let info = QFileInfo::from(&d.file_path(name));
+    if !info.exists() || !info.is_file() {
+        return false;
+    }
+
+    // Converting to a PathBuf and back should point at the same file
+    let path = PathBuf::from(&info);
+    path == PathBuf::from(&d.path()).join(name.to_string())
+        && QFileInfo::from(path.as_path()) == info
+        && std::fs::metadata(&path).is_ok_and(|metadata| metadata.len() as i64 == info.size())
+}
@@ -0,0 +1,63 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{
+    QByteArray, QFile, QFileInfo, QIODeviceOpenModeFlag, QStandardPaths,
+    QStandardPathsStandardLocation, QString, QTemporaryDir, QUrl,
+};
+use std::path::PathBuf;
+
+#[cxx::bridge]
+mod qfile_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn read_qfile(file_name: &QString) -> QByteArray;
+        fn qfileinfo_from_qrc_url(url: &QString) -> QString;
+        fn qstandardpaths_temp_location() -> QString;
+        fn qtemporarydir_write_file() -> bool;
+    }
+}
+
+fn read_qfile(file_name: &QString) -> QByteArray {
+    let mut file = QFile::new(file_name);
+    if !file.pin_mut().open(QIODeviceOpenModeFlag::ReadOnly) {
+        return QByteArray::default();
+    }
+    file.pin_mut().read_all()
+}
+
+fn qfileinfo_from_qrc_url(url: &QString) -> QString {
+    QFileInfo::from_url(&QUrl::from(url))
+        .filter(|info| info.exists())
+        .map(|info| info.file_path())
+        .unwrap_or_default()
+}
+
+fn qstandardpaths_temp_location() -> QString {
+    QStandardPaths::writable_location(QStandardPathsStandardLocation::TempLocation)
+        .unwrap_or_default()
+}
+
+fn qtemporarydir_write_file() -> bool {
+    let dir = QTemporaryDir::new();
+    let path = match dir.path_buf() {
+        Some(path) => path,
+        None => return false,
+    };
+
+    let file_path = path.join("data.bin");
+    if std::fs::write(&file_path, b"cxx-qt").is_err() {
+        return false;
+    }
+
+    let file = QFile::new(&dir.file_path(&QString::from("data.bin")));
+    file.exists() && file.size() == 6 && PathBuf::from(&file.file_name()) == file_path
+}
@@ -0,0 +1,35 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QFont, QString};
+
+#[cxx::bridge]
+mod qfont_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qfont.h");
+        type QFont = cxx_qt_lib::QFont;
+    }
+
+    extern "Rust" {
+        fn construct_qfont() -> QFont;
+        fn read_qfont(f: &QFont) -> bool;
+        fn clone_qfont(f: &QFont) -> QFont;
+    }
+}
+
+fn construct_qfont() -> QFont {
+    let mut font = QFont::new(&QString::from("Monospace"), 14);
+    font.set_bold(true);
+    font.set_underline(true);
+    font
+}
+
+fn read_qfont(f: &QFont) -> bool {
+    f.family().to_string() == "Serif" && f.pixel_size() == 20 && f.italic() && !f.bold()
+}
+
+fn clone_qfont(f: &QFont) -> QFont {
+    f.clone()
+}
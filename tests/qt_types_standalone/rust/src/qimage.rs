@@ -0,0 +1,68 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QByteArray, QColor, QImage, QImageFormat};
+
+#[cxx::bridge]
+mod qimage_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qimage.h");
+        type QImage = cxx_qt_lib::QImage;
+    }
+
+    extern "Rust" {
+        fn construct_qimage() -> QImage;
+        fn construct_qimage_from_rgba8() -> QImage;
+        fn construct_qimage_from_data(data: &QByteArray) -> QImage;
+        fn read_qimage(i: &QImage) -> bool;
+        fn read_qimage_bytes(i: &QImage) -> bool;
+        fn save_qimage_to_data(i: &QImage) -> QByteArray;
+        fn clone_qimage(i: &QImage) -> QImage;
+    }
+}
+
+fn construct_qimage() -> QImage {
+    let mut image = QImage::new(4, 2, QImageFormat::Format_ARGB32);
+    image.fill(&QColor::from_rgb(255, 0, 0));
+    image.set_pixel_color(1, 1, &QColor::from_rgb(0, 0, 255));
+    image
+}
+
+fn construct_qimage_from_rgba8() -> QImage {
+    // A 2x1 image with a red and a translucent blue pixel
+    let buffer = vec![255, 0, 0, 255, 0, 0, 255, 128];
+    QImage::from_rgba8(buffer, 2, 1).unwrap()
+}
+
+fn construct_qimage_from_data(data: &QByteArray) -> QImage {
+    QImage::from_data(data.as_slice(), None).unwrap()
+}
+
+fn read_qimage(i: &QImage) -> bool {
+    !i.is_null()
+        && i.width() == 2
+        && i.height() == 2
+        && i.format() == QImageFormat::Format_RGB32
+        && i.pixel_color(0, 0) == QColor::from_rgb(0, 255, 0)
+}
+
+fn read_qimage_bytes(i: &QImage) -> bool {
+    i.bytes_per_line() == 8
+        && i.as_bytes()
+            == [
+                0, 255, 0, 255, 0, 0, 255, 255, 0, 0, 0, 0, 255, 255, 255, 255,
+            ]
+        && i.pixel(0, 0) == 0xFF00FF00
+}
+
+fn save_qimage_to_data(i: &QImage) -> QByteArray {
+    i.save_to_data("PNG").unwrap()
+}
+
+fn clone_qimage(i: &QImage) -> QImage {
+    i.clone()
+}
@@ -0,0 +1,58 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QJSEngine, QJSValue, QString, QVariant};
+
+#[cxx::bridge]
+mod qjsengine_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qjsengine.h");
+        type QJSEngine = cxx_qt_lib::QJSEngine;
+    }
+
+    extern "Rust" {
+        fn construct_qjsengine() -> UniquePtr<QJSEngine>;
+        fn evaluate_qjsengine(engine: Pin<&mut QJSEngine>) -> bool;
+        fn convert_qjsengine_values(engine: Pin<&mut QJSEngine>) -> bool;
+    }
+}
+
+fn construct_qjsengine() -> cxx::UniquePtr<QJSEngine> {
+    let mut engine = QJSEngine::new();
+    if let Some(mut engine) = engine.as_mut() {
+        let mut object = engine.as_mut().new_object();
+        object.set_property(&QString::from("value"), &QJSValue::from(42));
+        let mut global = engine.global_object();
+        global.set_property(&QString::from("kdab"), &object);
+    }
+    engine
+}
+
+fn evaluate_qjsengine(mut engine: Pin<&mut QJSEngine>) -> bool {
+    let result = engine.as_mut().evaluate(
+        &QString::from("[1, 2, 3].map(function(value) { return value * 2; })"),
+        &QString::from("test.js"),
+        1,
+    );
+    let values: Vec<i32> = result.array_iter().map(|value| value.to_int()).collect();
+    if values != vec![2, 4, 6] {
+        return false;
+    }
+
+    let error = engine
+        .as_mut()
+        .evaluate(&QString::from("missing()"), &QString::default(), 1);
+    error.is_error() && engine.new_array(2).is_array()
+}
+
+fn convert_qjsengine_values(mut engine: Pin<&mut QJSEngine>) -> bool {
+    let value = engine.as_mut().to_script_value(&QVariant::from(&42));
+    if !value.is_number() || value.to_int() != 42 {
+        return false;
+    }
+
+    engine.from_script_value(&QJSValue::from(true)) == QVariant::from(&true)
+}
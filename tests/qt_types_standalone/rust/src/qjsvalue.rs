@@ -0,0 +1,56 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QJSValue, QString};
+
+#[cxx::bridge]
+mod qjsvalue_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qjsvalue.h");
+        type QJSValue = cxx_qt_lib::QJSValue;
+    }
+
+    extern "Rust" {
+        fn construct_qjsvalue() -> QJSValue;
+        fn construct_qjsvalue_null() -> QJSValue;
+        fn read_qjsvalue_object(v: &QJSValue) -> bool;
+        fn sum_qjsvalue_array(v: &QJSValue) -> i32;
+        fn call_qjsvalue(v: &QJSValue) -> QJSValue;
+        fn set_qjsvalue_property(v: &mut QJSValue);
+        fn clone_qjsvalue(v: &QJSValue) -> QJSValue;
+    }
+}
+
+fn construct_qjsvalue() -> QJSValue {
+    QJSValue::from("kdab")
+}
+
+fn construct_qjsvalue_null() -> QJSValue {
+    QJSValue::null()
+}
+
+fn read_qjsvalue_object(v: &QJSValue) -> bool {
+    v.is_object()
+        && v.has_property(&QString::from("name"))
+        && v.property(&QString::from("name")).to_qstring().to_string() == "kdab"
+        && v.property(&QString::from("missing")).is_undefined()
+}
+
+fn sum_qjsvalue_array(v: &QJSValue) -> i32 {
+    v.array_iter().map(|value| value.to_int()).sum()
+}
+
+fn call_qjsvalue(v: &QJSValue) -> QJSValue {
+    v.call(&[QJSValue::from(1), QJSValue::from(2.5)])
+}
+
+fn set_qjsvalue_property(v: &mut QJSValue) {
+    v.set_property(&QString::from("number"), &QJSValue::from(42));
+    v.set_property(&QString::from("flag"), &QJSValue::from(true));
+}
+
+fn clone_qjsvalue(v: &QJSValue) -> QJSValue {
+    v.clone()
+}
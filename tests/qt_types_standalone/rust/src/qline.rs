@@ -0,0 +1,50 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QLine, QLineF, QPoint, QPointF};
+
+#[cxx::bridge]
+mod qline_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qline.h");
+        type QLine = cxx_qt_lib::QLine;
+        include!("cxx-qt-lib/qlinef.h");
+        type QLineF = cxx_qt_lib::QLineF;
+    }
+
+    extern "Rust" {
+        fn construct_qline() -> QLine;
+        fn construct_qlinef() -> QLineF;
+        fn read_qline(l: &QLine) -> bool;
+        fn read_qlinef(l: &QLineF) -> bool;
+        fn clone_qline(l: &QLine) -> QLine;
+        fn translate_qlinef(l: &QLineF) -> QLineF;
+    }
+}
+
+fn construct_qline() -> QLine {
+    QLine::new(&QPoint::new(1, 2), &QPoint::new(3, 4))
+}
+
+fn construct_qlinef() -> QLineF {
+    QLineF::from_polar(2.0, 90.0)
+}
+
+fn read_qline(l: &QLine) -> bool {
+    l.p1() == QPoint::new(1, 2) && l.dx() == 2 && l.dy() == 2
+}
+
+fn read_qlinef(l: &QLineF) -> bool {
+    let (_, point) = l.intersects(&QLineF::from_coordinates(0.0, 1.0, 2.0, 1.0));
+    ((l.length() - 2.0).abs() < f64::EPSILON) && point == QPointF::new(1.0, 1.0)
+}
+
+fn clone_qline(l: &QLine) -> QLine {
+    l.clone()
+}
+
+fn translate_qlinef(l: &QLineF) -> QLineF {
+    l.clone() + QPointF::new(1.0, 1.0)
+}
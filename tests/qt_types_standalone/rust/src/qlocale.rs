@@ -0,0 +1,58 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{DayOfWeek, QDate, QLocale, QLocaleFormatType, QLocaleMeasurementSystem, QString};
+
+#[cxx::bridge]
+mod qlocale_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qlocale.h");
+        type QLocale = cxx_qt_lib::QLocale;
+    }
+
+    extern "Rust" {
+        fn construct_qlocale() -> QLocale;
+        fn read_qlocale(l: &QLocale) -> bool;
+        fn clone_qlocale(l: &QLocale) -> QLocale;
+        fn format_qlocale(l: &QLocale) -> bool;
+        fn parse_qlocale(l: &QLocale) -> bool;
+    }
+}
+
+fn construct_qlocale() -> QLocale {
+    QLocale::from("de_DE")
+}
+
+fn read_qlocale(l: &QLocale) -> bool {
+    l.name().to_string() == "de_DE"
+        && l.decimal_point().to_string() == ","
+        && l.group_separator().to_string() == "."
+        && l.measurement_system() == QLocaleMeasurementSystem::MetricSystem
+        && l.first_day_of_week() == DayOfWeek::Monday
+}
+
+fn clone_qlocale(l: &QLocale) -> QLocale {
+    l.clone()
+}
+
+fn format_qlocale(l: &QLocale) -> bool {
+    l.to_string_i64(1234567).to_string() == "1.234.567"
+        && l.to_string_f64(1234.5, b'f', 2).to_string() == "1.234,50"
+        && l.to_currency_string_f64(1.5, &QString::from("€"))
+            .to_string()
+            == "1,50\u{a0}€"
+        && l.to_string_date_format(&QDate::new(2023, 6, 1), &QString::from("dd.MM.yyyy"))
+            .to_string()
+            == "01.06.2023"
+        && !l
+            .to_string_date(&QDate::new(2023, 6, 1), QLocaleFormatType::LongFormat)
+            .is_empty()
+}
+
+fn parse_qlocale(l: &QLocale) -> bool {
+    l.to_double(&QString::from("1.234,5")) == Some(1234.5)
+        && l.to_int(&QString::from("42")) == Some(42)
+        && l.to_long_long(&QString::from("not a number")).is_none()
+}
@@ -0,0 +1,56 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{
+    q_install_message_handler, QLoggingCategory, QMessageLogContext, QString, QtMsgType,
+};
+use std::sync::Mutex;
+
+#[cxx::bridge]
+mod qloggingcategory_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn install_qt_message_handler();
+        fn last_qt_message() -> QString;
+        fn log_qloggingcategory();
+        fn read_qloggingcategory_filter_rules() -> bool;
+        fn restore_qt_message_handler();
+    }
+}
+
+static LAST_MESSAGE: Mutex<String> = Mutex::new(String::new());
+
+fn message_handler(msg_type: QtMsgType, context: &QMessageLogContext, message: &QString) {
+    *LAST_MESSAGE.lock().unwrap() = format!("{}:{}:{message}", context.category, msg_type.repr);
+}
+
+fn install_qt_message_handler() {
+    q_install_message_handler(Some(message_handler));
+}
+
+fn last_qt_message() -> QString {
+    QString::from(LAST_MESSAGE.lock().unwrap().as_str())
+}
+
+fn log_qloggingcategory() {
+    let category = QLoggingCategory::new("kdab.test");
+    category.warning(&QString::from("kdab"));
+}
+
+fn read_qloggingcategory_filter_rules() -> bool {
+    QLoggingCategory::set_filter_rules(&QString::from("kdab.filtered.debug=false"));
+    let category = QLoggingCategory::new("kdab.filtered");
+    category.category_name().to_string() == "kdab.filtered"
+        && category.is_warning_enabled()
+        && !category.is_debug_enabled()
+}
+
+fn restore_qt_message_handler() {
+    q_install_message_handler(None);
+}
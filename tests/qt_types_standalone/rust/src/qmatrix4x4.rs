@@ -0,0 +1,42 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QMatrix4x4, QVector3D, QVector4D};
+
+#[cxx::bridge]
+mod qmatrix4x4_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmatrix4x4.h");
+        type QMatrix4x4 = cxx_qt_lib::QMatrix4x4;
+    }
+
+    extern "Rust" {
+        fn construct_qmatrix4x4() -> QMatrix4x4;
+        fn read_qmatrix4x4(m: &QMatrix4x4) -> bool;
+        fn clone_qmatrix4x4(m: &QMatrix4x4) -> QMatrix4x4;
+        fn multiply_qmatrix4x4(a: &QMatrix4x4, b: &QMatrix4x4) -> QMatrix4x4;
+    }
+}
+
+fn construct_qmatrix4x4() -> QMatrix4x4 {
+    let mut matrix = QMatrix4x4::default();
+    matrix.translate(&QVector3D::new(1.0, 2.0, 3.0));
+    matrix
+}
+
+fn read_qmatrix4x4(m: &QMatrix4x4) -> bool {
+    m.row(0) == QVector4D::new(1.0, 2.0, 3.0, 4.0)
+        && m.column(3) == QVector4D::new(4.0, 8.0, 12.0, 16.0)
+        && m.as_columns()[3] == [4.0, 8.0, 12.0, 16.0]
+        && m.clone() * QVector4D::new(0.0, 0.0, 0.0, 1.0) == QVector4D::new(4.0, 8.0, 12.0, 16.0)
+}
+
+fn clone_qmatrix4x4(m: &QMatrix4x4) -> QMatrix4x4 {
+    m.clone()
+}
+
+fn multiply_qmatrix4x4(a: &QMatrix4x4, b: &QMatrix4x4) -> QMatrix4x4 {
+    a.clone() * b.clone()
+}
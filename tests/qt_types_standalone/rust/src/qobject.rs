@@ -0,0 +1,58 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QList, QObject, QString, QThread, QVariant};
+
+#[cxx::bridge]
+mod qobject_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt_lib::QObject;
+    }
+
+    extern "Rust" {
+        fn read_qobject(o: &QObject) -> bool;
+        fn read_qobject_children(o: &QObject) -> bool;
+        fn read_qobject_property(o: &QObject) -> bool;
+        fn write_qobject_property(o: Pin<&mut QObject>);
+        unsafe fn reparent_qobject(o: Pin<&mut QObject>, parent: *mut QObject);
+    }
+}
+
+fn read_qobject(o: &QObject) -> bool {
+    o.object_name().to_string() == "parent"
+        && o.parent().is_null()
+        && o.thread() == QThread::current_thread()
+}
+
+fn read_qobject_children(o: &QObject) -> bool {
+    let children = o.children();
+    if children.len() != 2 {
+        return false;
+    }
+
+    let first = unsafe { &*children[0] };
+    if first.object_name().to_string() != "first" {
+        return false;
+    }
+
+    // find_child searches recursively
+    let grandchild = o.find_child(&QString::from("grandchild"));
+    !grandchild.is_null() && o.find_child(&QString::from("missing")).is_null()
+}
+
+fn read_qobject_property(o: &QObject) -> bool {
+    o.property(&QString::from("dynamic")).value::<i32>() == Some(42)
+        && QList::<QString>::from(&o.dynamic_property_names()).len() == 1
+}
+
+fn write_qobject_property(o: Pin<&mut QObject>) {
+    o.set_property(&QString::from("dynamic"), &QVariant::from(&42));
+}
+
+unsafe fn reparent_qobject(o: Pin<&mut QObject>, parent: *mut QObject) {
+    o.set_parent(parent);
+}
@@ -0,0 +1,58 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{
+    BrushStyle, QBrush, QColor, QFont, QImage, QImageFormat, QPainter, QPainterPath,
+    QPainterRenderHint, QPen, QPointF, QRectF, QString,
+};
+
+#[cxx::bridge]
+mod qpainter_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpainter.h");
+        type QPainter = cxx_qt_lib::QPainter;
+    }
+
+    extern "Rust" {
+        fn paint_qpainter(p: Pin<&mut QPainter>);
+    }
+}
+
+/// Paints four 10x10 squares in a 20x20 image
+///
+/// - top left is red
+/// - top right is green from a path
+/// - bottom left is blue from an image
+/// - bottom right is left transparent with white text
+fn paint_qpainter(mut p: Pin<&mut QPainter>) {
+    p.as_mut()
+        .set_render_hint(QPainterRenderHint::Antialiasing, false);
+
+    p.as_mut().fill_rect(
+        &QRectF::new(0.0, 0.0, 10.0, 10.0),
+        &QColor::from_rgb(255, 0, 0),
+    );
+
+    let mut path = QPainterPath::default();
+    path.add_rect(&QRectF::new(10.0, 0.0, 10.0, 10.0));
+    p.as_mut().fill_path(
+        &path,
+        &QBrush::new(&QColor::from_rgb(0, 255, 0), BrushStyle::SolidPattern),
+    );
+
+    let mut image = QImage::new(10, 10, QImageFormat::Format_ARGB32);
+    image.fill(&QColor::from_rgb(0, 0, 255));
+    p.as_mut()
+        .draw_image_at_point(&QPointF::new(0.0, 10.0), &image);
+
+    p.as_mut()
+        .set_pen(&QPen::from(&QColor::from_rgb(255, 255, 255)));
+    p.as_mut().set_brush(&QBrush::from(BrushStyle::NoBrush));
+    p.as_mut()
+        .set_font(&QFont::new(&QString::from("Monospace"), 4));
+    p.as_mut()
+        .draw_text(&QPointF::new(10.0, 20.0), &QString::from("kdab"));
+}
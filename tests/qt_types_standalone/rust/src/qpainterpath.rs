@@ -0,0 +1,39 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QPainterPath, QPointF, QRectF};
+
+#[cxx::bridge]
+mod qpainterpath_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpainterpath.h");
+        type QPainterPath = cxx_qt_lib::QPainterPath;
+    }
+
+    extern "Rust" {
+        fn construct_qpainterpath() -> QPainterPath;
+        fn read_qpainterpath(p: &QPainterPath) -> bool;
+        fn clone_qpainterpath(p: &QPainterPath) -> QPainterPath;
+    }
+}
+
+fn construct_qpainterpath() -> QPainterPath {
+    // Draw a triangle
+    let mut path = QPainterPath::from(&QPointF::new(0.0, 0.0));
+    path.line_to(&QPointF::new(10.0, 0.0));
+    path.line_to(&QPointF::new(0.0, 10.0));
+    path.close_subpath();
+    path
+}
+
+fn read_qpainterpath(p: &QPainterPath) -> bool {
+    p.bounding_rect() == QRectF::new(0.0, 0.0, 20.0, 10.0)
+        && p.contains(&QPointF::new(5.0, 5.0))
+        && !p.contains(&QPointF::new(25.0, 5.0))
+}
+
+fn clone_qpainterpath(p: &QPainterPath) -> QPainterPath {
+    p.clone()
+}
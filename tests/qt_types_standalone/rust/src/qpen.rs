@@ -0,0 +1,44 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{PenCapStyle, PenJoinStyle, PenStyle, QBrush, QColor, QPen};
+
+#[cxx::bridge]
+mod qpen_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpen.h");
+        type QPen = cxx_qt_lib::QPen;
+    }
+
+    extern "Rust" {
+        fn construct_qpen() -> QPen;
+        fn read_qpen(p: &QPen) -> bool;
+        fn clone_qpen(p: &QPen) -> QPen;
+    }
+}
+
+fn construct_qpen() -> QPen {
+    let mut pen = QPen::new(
+        &QBrush::from(&QColor::from_rgb(255, 0, 0)),
+        2.5,
+        PenStyle::DashLine,
+        PenCapStyle::RoundCap,
+        PenJoinStyle::RoundJoin,
+    );
+    pen.set_cosmetic(true);
+    pen
+}
+
+fn read_qpen(p: &QPen) -> bool {
+    p.color() == QColor::from_rgb(0, 0, 255)
+        && p.width_f() == 3.0
+        && p.style() == PenStyle::DotLine
+        && p.cap_style() == PenCapStyle::FlatCap
+        && p.join_style() == PenJoinStyle::MiterJoin
+}
+
+fn clone_qpen(p: &QPen) -> QPen {
+    p.clone()
+}
@@ -0,0 +1,65 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QPointer, QTimer};
+
+#[cxx::bridge]
+mod qpointer_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qtimer.h");
+        type QTimer = cxx_qt_lib::QTimer;
+    }
+
+    extern "Rust" {
+        fn qpointer_upgrade_set_interval(t: Pin<&mut QTimer>, msec: i32) -> bool;
+        fn qpointer_is_null_after_destroyed() -> bool;
+        fn qpointer_default_is_null() -> bool;
+    }
+}
+
+fn qpointer_upgrade_set_interval(t: Pin<&mut QTimer>, msec: i32) -> bool {
+    let mut pointer = QPointer::new(t);
+    let mut cloned = pointer.clone();
+    if pointer.is_null() || cloned.is_null() {
+        return false;
+    }
+
+    // Safety: the timer is only referenced by the upgraded pointer
+    // until the end of the statement and it is not destroyed meanwhile
+    if let Some(timer) = unsafe { pointer.upgrade() } {
+        timer.set_interval(msec);
+    } else {
+        return false;
+    }
+
+    // Safety: the reference from the other pointer is no longer live
+    unsafe { cloned.upgrade() }
+        .map(|timer| timer.interval() == msec)
+        .unwrap_or(false)
+}
+
+fn qpointer_is_null_after_destroyed() -> bool {
+    let mut timer = QTimer::new();
+    let mut pointer = if let Some(timer) = timer.as_mut() {
+        QPointer::new(timer)
+    } else {
+        return false;
+    };
+    // Safety: the upgraded reference is not used while the UniquePtr is
+    if unsafe { pointer.upgrade() }.is_none() {
+        return false;
+    }
+
+    drop(timer);
+    // Safety: the object has been destroyed so no reference is returned
+    pointer.is_null() && unsafe { pointer.upgrade() }.is_none() && pointer.as_ptr().is_null()
+}
+
+fn qpointer_default_is_null() -> bool {
+    let mut pointer = QPointer::<QTimer>::default();
+    // Safety: a null pointer never returns a reference
+    pointer.is_null() && unsafe { pointer.upgrade() }.is_none()
+}
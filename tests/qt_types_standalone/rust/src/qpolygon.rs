@@ -0,0 +1,50 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{FillRule, QPoint, QPointF, QPolygon, QPolygonF, QRect, QVector};
+
+#[cxx::bridge]
+mod qpolygon_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpolygon.h");
+        type QPolygon = cxx_qt_lib::QPolygon;
+        include!("cxx-qt-lib/qpolygonf.h");
+        type QPolygonF = cxx_qt_lib::QPolygonF;
+    }
+
+    extern "Rust" {
+        fn construct_qpolygon() -> QPolygon;
+        fn construct_qpolygonf(p: &QPolygon) -> QPolygonF;
+        fn read_qpolygon(p: &QPolygon) -> bool;
+        fn read_qpolygonf(p: &QPolygonF) -> bool;
+        fn clone_qpolygon(p: &QPolygon) -> QPolygon;
+    }
+}
+
+fn construct_qpolygon() -> QPolygon {
+    let mut points = QVector::<QPoint>::default();
+    points.append(QPoint::new(0, 0));
+    points.append(QPoint::new(10, 0));
+    points.append(QPoint::new(10, 10));
+    QPolygon::from(&points)
+}
+
+fn construct_qpolygonf(p: &QPolygon) -> QPolygonF {
+    QPolygonF::from(p) + QPointF::new(0.5, 0.5)
+}
+
+fn read_qpolygon(p: &QPolygon) -> bool {
+    p.bounding_rect() == QRect::new(0, 0, 11, 11)
+        && p.contains_point(&QPoint::new(8, 2), FillRule::OddEvenFill)
+        && p.points().len() == 3
+}
+
+fn read_qpolygonf(p: &QPolygonF) -> bool {
+    p.is_closed() && p.to_polygon().points().len() == 5
+}
+
+fn clone_qpolygon(p: &QPolygon) -> QPolygon {
+    p.clone()
+}
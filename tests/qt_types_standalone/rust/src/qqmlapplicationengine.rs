@@ -3,7 +3,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use core::pin::Pin;
 use cxx_qt_lib::{QQmlApplicationEngine, QUrl};
+use std::sync::atomic::{AtomicU32, Ordering};
 
 #[cxx::bridge]
 mod qqmlapplicationengine_cxx {
@@ -15,9 +17,14 @@ mod qqmlapplicationengine_cxx {
     extern "Rust" {
         fn construct_qqmlapplicationengine() -> UniquePtr<QQmlApplicationEngine>;
         fn read_qqmlapplicationengine(c: &QQmlApplicationEngine) -> bool;
+        fn connect_qqmlapplicationengine_object_created(c: Pin<&mut QQmlApplicationEngine>);
+        fn read_qqmlapplicationengine_object_created_failed_count() -> u32;
+        fn read_qqmlapplicationengine_root_objects_len(c: &QQmlApplicationEngine) -> usize;
     }
 }
 
+static OBJECT_CREATED_FAILED_COUNT: AtomicU32 = AtomicU32::new(0);
+
 fn construct_qqmlapplicationengine() -> cxx::UniquePtr<QQmlApplicationEngine> {
     let mut engine = QQmlApplicationEngine::new();
     if let Some(engine) = engine.as_mut() {
@@ -29,3 +36,21 @@ fn construct_qqmlapplicationengine() -> cxx::UniquePtr<QQmlApplicationEngine> {
 fn read_qqmlapplicationengine(engine: &QQmlApplicationEngine) -> bool {
     engine.base_url().to_string() == "qrc:/kdab.qml"
 }
+
+fn connect_qqmlapplicationengine_object_created(engine: Pin<&mut QQmlApplicationEngine>) {
+    engine
+        .on_object_created(|object, _url| {
+            if object.is_null() {
+                OBJECT_CREATED_FAILED_COUNT.fetch_add(1, Ordering::SeqCst);
+            }
+        })
+        .release();
+}
+
+fn read_qqmlapplicationengine_object_created_failed_count() -> u32 {
+    OBJECT_CREATED_FAILED_COUNT.load(Ordering::SeqCst)
+}
+
+fn read_qqmlapplicationengine_root_objects_len(engine: &QQmlApplicationEngine) -> usize {
+    engine.root_objects().len()
+}
@@ -0,0 +1,71 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{
+    QByteArray, QList, QMap, QMapPair_QString_QVariant, QObject, QQmlComponent,
+    QQmlComponentStatus, QQmlEngine, QString, QUrl, QVariant,
+};
+
+#[cxx::bridge]
+mod qqmlcomponent_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt_lib::QObject;
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = cxx_qt_lib::QQmlEngine;
+    }
+
+    extern "Rust" {
+        fn create_qqmlcomponent_from_data(engine: Pin<&mut QQmlEngine>) -> *mut QObject;
+        fn read_qqmlcomponent_errors(engine: Pin<&mut QQmlEngine>) -> bool;
+    }
+}
+
+fn create_qqmlcomponent_from_data(mut engine: Pin<&mut QQmlEngine>) -> *mut QObject {
+    // Safety: the root context is owned by the engine, which outlives this function
+    if let Some(context) = unsafe { engine.root_context().as_mut() } {
+        unsafe { Pin::new_unchecked(context) }
+            .set_context_property(&QString::from("kdabValue"), &QVariant::from(&42));
+    }
+
+    let mut component = QQmlComponent::new(engine.as_mut());
+    if let Some(mut component) = component.as_mut() {
+        component.as_mut().set_data(
+            &QByteArray::from(
+                "import QtQml 2.15\nQtObject {\n property int value: kdabValue\n property string name\n}",
+            ),
+            &QUrl::default(),
+        );
+        if !component.is_ready() {
+            return std::ptr::null_mut();
+        }
+
+        let mut properties = QMap::<QMapPair_QString_QVariant>::default();
+        properties.insert(
+            QString::from("name"),
+            QVariant::from(&QString::from("Rust")),
+        );
+        component.create_with_initial_properties(&properties)
+    } else {
+        std::ptr::null_mut()
+    }
+}
+
+fn read_qqmlcomponent_errors(engine: Pin<&mut QQmlEngine>) -> bool {
+    let mut component = QQmlComponent::new(engine);
+    if let Some(mut component) = component.as_mut() {
+        component.as_mut().set_data(
+            &QByteArray::from("import QtQml 2.15\nQtObject {"),
+            &QUrl::default(),
+        );
+        component.is_error()
+            && component.status() == QQmlComponentStatus::Error
+            && !QList::<QString>::from(&component.errors()).is_empty()
+            && component.as_mut().create().is_null()
+    } else {
+        false
+    }
+}
@@ -0,0 +1,38 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QObject, QQmlContext, QString, QVariant};
+
+#[cxx::bridge]
+mod qqmlcontext_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt_lib::QObject;
+        include!("cxx-qt-lib/qqmlcontext.h");
+        type QQmlContext = cxx_qt_lib::QQmlContext;
+    }
+
+    extern "Rust" {
+        fn set_qqmlcontext_properties(context: Pin<&mut QQmlContext>, object: Pin<&mut QObject>);
+        fn read_qqmlcontext(context: &QQmlContext) -> bool;
+    }
+}
+
+fn set_qqmlcontext_properties(mut context: Pin<&mut QQmlContext>, object: Pin<&mut QObject>) {
+    context
+        .as_mut()
+        .set_context_property(&QString::from("kdabValue"), &QVariant::from(&42));
+    // Safety: the object outlives the context in the test
+    unsafe {
+        context.set_context_property_qobject(&QString::from("kdabObject"), object);
+    }
+}
+
+fn read_qqmlcontext(context: &QQmlContext) -> bool {
+    context.is_valid()
+        && !context.engine().is_null()
+        && context.context_property(&QString::from("kdabValue")) == QVariant::from(&42)
+}
@@ -0,0 +1,41 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QQuaternion, QVector3D};
+
+#[cxx::bridge]
+mod qquaternion_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qquaternion.h");
+        type QQuaternion = cxx_qt_lib::QQuaternion;
+    }
+
+    extern "Rust" {
+        fn construct_qquaternion() -> QQuaternion;
+        fn read_qquaternion(q: &QQuaternion) -> bool;
+        fn clone_qquaternion(q: &QQuaternion) -> QQuaternion;
+        fn multiply_qquaternion(a: &QQuaternion, b: &QQuaternion) -> QQuaternion;
+    }
+}
+
+fn construct_qquaternion() -> QQuaternion {
+    QQuaternion::from_axis_and_angle(&QVector3D::new(0.0, 0.0, 1.0), 90.0)
+}
+
+fn read_qquaternion(q: &QQuaternion) -> bool {
+    let rotated = q.clone() * QVector3D::new(1.0, 0.0, 0.0);
+    let (axis, angle) = q.get_axis_and_angle();
+    (rotated - QVector3D::new(0.0, 1.0, 0.0)).length() < 0.0001
+        && (axis - QVector3D::new(0.0, 0.0, 1.0)).length() < 0.0001
+        && (angle - 90.0).abs() < 0.001
+}
+
+fn clone_qquaternion(q: &QQuaternion) -> QQuaternion {
+    q.clone()
+}
+
+fn multiply_qquaternion(a: &QQuaternion, b: &QQuaternion) -> QQuaternion {
+    a.clone() * b.clone()
+}
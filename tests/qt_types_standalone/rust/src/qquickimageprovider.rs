@@ -0,0 +1,48 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QColor, QImage, QImageFormat, QQmlEngine, QQuickImageProvider, QSize, QString};
+
+#[cxx::bridge]
+mod qquickimageprovider_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = cxx_qt_lib::QQmlEngine;
+    }
+
+    extern "Rust" {
+        fn add_qquickimageproviders(engine: Pin<&mut QQmlEngine>);
+    }
+}
+
+/// Provides images filled with the color named by the id
+struct ColorImageProvider;
+
+impl QQuickImageProvider for ColorImageProvider {
+    fn request_image(&self, id: &QString, requested_size: &QSize) -> QImage {
+        let Ok(color) = QColor::try_from(id) else {
+            return QImage::default();
+        };
+
+        let size = if requested_size.is_valid() {
+            requested_size.clone()
+        } else {
+            QSize::new(8, 8)
+        };
+        let mut image = QImage::new(size.width(), size.height(), QImageFormat::Format_ARGB32);
+        image.fill(&color);
+        image
+    }
+}
+
+fn add_qquickimageproviders(mut engine: Pin<&mut QQmlEngine>) {
+    engine
+        .as_mut()
+        .add_image_provider(&QString::from("colors"), ColorImageProvider);
+    engine
+        .as_mut()
+        .add_async_image_provider(&QString::from("colorsasync"), ColorImageProvider);
+}
@@ -0,0 +1,49 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QQuickItem, QQuickItemFlag, QSizeF};
+
+#[cxx::bridge]
+mod qquickitem_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qquickitem.h");
+        type QQuickItem = cxx_qt_lib::QQuickItem;
+    }
+
+    extern "Rust" {
+        fn construct_qquickitem() -> UniquePtr<QQuickItem>;
+        fn read_qquickitem(item: &QQuickItem) -> bool;
+        unsafe fn set_qquickitem_parent(item: Pin<&mut QQuickItem>, parent: *mut QQuickItem);
+    }
+}
+
+fn construct_qquickitem() -> cxx::UniquePtr<QQuickItem> {
+    let mut item = QQuickItem::new();
+    if let Some(mut item) = item.as_mut() {
+        item.as_mut().set_size(&QSizeF::new(100.0, 50.0));
+        item.as_mut().set_x(10.0);
+        item.as_mut().set_opacity(0.5);
+        item.as_mut()
+            .set_flag(QQuickItemFlag::ItemHasContents, true);
+    }
+    item
+}
+
+fn read_qquickitem(item: &QQuickItem) -> bool {
+    item.width() == 20.0
+        && item.height() == 30.0
+        && item.y() == 5.0
+        && !item.is_visible()
+        && item.has_flag(QQuickItemFlag::ItemClipsChildrenToShape)
+        && !item.has_flag(QQuickItemFlag::ItemHasContents)
+}
+
+/// # Safety
+///
+/// The parent must be a null pointer or a valid QQuickItem
+unsafe fn set_qquickitem_parent(item: Pin<&mut QQuickItem>, parent: *mut QQuickItem) {
+    item.set_parent_item(parent);
+}
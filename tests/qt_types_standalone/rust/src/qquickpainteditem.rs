@@ -0,0 +1,40 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QColor, QPainter, QQuickItem, QQuickPaintedItem, QRectF, Upcast};
+
+#[cxx::bridge]
+mod qquickpainteditem_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpainter.h");
+        type QPainter = cxx_qt_lib::QPainter;
+        include!("cxx-qt-lib/qquickpainteditem.h");
+        type QQuickPaintedItem = cxx_qt_lib::QQuickPaintedItem;
+    }
+
+    extern "Rust" {
+        fn setup_qquickpainteditem(item: Pin<&mut QQuickPaintedItem>);
+        fn paint_qquickpainteditem(item: &QQuickPaintedItem, painter: Pin<&mut QPainter>);
+    }
+}
+
+fn setup_qquickpainteditem(mut item: Pin<&mut QQuickPaintedItem>) {
+    item.as_mut().set_fill_color(&QColor::from_rgb(0, 0, 255));
+    let mut quick_item: Pin<&mut QQuickItem> = item.as_mut().upcast_pin();
+    quick_item.as_mut().set_width(20.0);
+    quick_item.as_mut().set_height(20.0);
+    item.update();
+}
+
+/// Paints the left half of the item red, the right half is the blue fill color
+fn paint_qquickpainteditem(item: &QQuickPaintedItem, painter: Pin<&mut QPainter>) {
+    let quick_item: &QQuickItem = item.upcast();
+    let size = quick_item.size();
+    painter.fill_rect(
+        &QRectF::new(0.0, 0.0, size.width() / 2.0, size.height()),
+        &QColor::from_rgb(255, 0, 0),
+    );
+}
@@ -0,0 +1,80 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{
+    CaseSensitivity, QRegularExpression, QRegularExpressionMatchType,
+    QRegularExpressionPatternOption, QString, SplitBehaviorFlags,
+};
+
+#[cxx::bridge]
+mod qregularexpression_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qregularexpression.h");
+        type QRegularExpression = cxx_qt_lib::QRegularExpression;
+    }
+
+    extern "Rust" {
+        fn construct_qregularexpression() -> QRegularExpression;
+        fn read_qregularexpression(re: &QRegularExpression) -> bool;
+        fn clone_qregularexpression(re: &QRegularExpression) -> QRegularExpression;
+        fn global_match_qregularexpression(re: &QRegularExpression) -> i32;
+        fn invalid_qregularexpression() -> bool;
+        fn qstring_with_qregularexpression() -> bool;
+    }
+}
+
+fn construct_qregularexpression() -> QRegularExpression {
+    QRegularExpression::new(
+        &QString::from("^(?<key>[a-z]+)=(?<value>\\d+)$"),
+        QRegularExpressionPatternOption::CaseInsensitiveOption
+            | QRegularExpressionPatternOption::MultilineOption,
+    )
+}
+
+fn read_qregularexpression(re: &QRegularExpression) -> bool {
+    let m = re.match_at(
+        &QString::from("width=42"),
+        0,
+        QRegularExpressionMatchType::NormalMatch,
+    );
+    m.has_match()
+        && m.captured(0).to_string() == "width=42"
+        && m.captured_name(&QString::from("key"))
+            .map(|s| s.to_string())
+            == Some("width".to_owned())
+        && m.captured_name(&QString::from("value"))
+            .map(|s| s.to_string())
+            == Some("42".to_owned())
+        && m.captured_start_name(&QString::from("value")) == 6
+}
+
+fn clone_qregularexpression(re: &QRegularExpression) -> QRegularExpression {
+    re.clone()
+}
+
+fn global_match_qregularexpression(re: &QRegularExpression) -> i32 {
+    re.global_match(
+        &QString::from("a=1\nb=2\nc=3"),
+        0,
+        QRegularExpressionMatchType::NormalMatch,
+    )
+    .filter(|m| m.has_match())
+    .count() as i32
+}
+
+fn invalid_qregularexpression() -> bool {
+    let re = QRegularExpression::from("(unclosed");
+    re.pattern_error_offset().is_some() && re.validate().is_err()
+}
+
+fn qstring_with_qregularexpression() -> bool {
+    let re = QRegularExpression::from("\\s*,\\s*");
+    let mut s = QString::from("a , b,c");
+    let parts = s.split_regex(&re, SplitBehaviorFlags::SkipEmptyParts);
+    s.replace_regex(&re, &QString::from(";"));
+    !s.contains_regex(&re)
+        && s.to_string() == "a;b;c"
+        && parts.contains(&QString::from("b"), CaseSensitivity::CaseSensitive)
+}
@@ -0,0 +1,130 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QSettings, QSettingsFormat, QString, QVariant};
+use serde::{Deserialize, Serialize};
+
+#[cxx::bridge]
+mod qsettings_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn read_qsettings(file_name: &QString) -> bool;
+        fn roundtrip_qsettings_serde(file_name: &QString) -> bool;
+        fn write_qsettings(file_name: &QString);
+    }
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+struct Window {
+    width: i32,
+    height: i32,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Preferences {
+    name: String,
+    volume: f64,
+    muted: bool,
+    window: Window,
+    recent: Vec<String>,
+    theme: Option<String>,
+}
+
+fn read_qsettings(file_name: &QString) -> bool {
+    let mut settings = QSettings::from_file(file_name, QSettingsFormat::IniFormat);
+    let mut settings = settings.pin_mut();
+
+    // The INI format does not store types, so values need to be converted
+    if settings.value(&QString::from("count")).value::<i32>() != Some(42) {
+        return false;
+    }
+
+    settings.as_mut().begin_group(&QString::from("window"));
+    let width = settings.value(&QString::from("width")).value::<i32>();
+    settings.as_mut().end_group();
+    if width != Some(100) {
+        return false;
+    }
+
+    let size = settings.as_mut().begin_read_array(&QString::from("recent"));
+    let mut recent = vec![];
+    for i in 0..size {
+        settings.as_mut().set_array_index(i);
+        recent.push(
+            settings
+                .value(&QString::from("file"))
+                .value::<QString>()
+                .unwrap()
+                .to_string(),
+        );
+    }
+    settings.as_mut().end_array();
+
+    recent == ["a.txt", "b.txt"]
+        && settings
+            .value_or(&QString::from("missing"), &QVariant::from(&7))
+            .value::<i32>()
+            == Some(7)
+}
+
+fn roundtrip_qsettings_serde(file_name: &QString) -> bool {
+    let preferences = Preferences {
+        name: "kdab".to_owned(),
+        volume: 0.5,
+        muted: true,
+        window: Window {
+            width: 100,
+            height: 50,
+        },
+        recent: vec!["a.txt".to_owned(), "b.txt".to_owned()],
+        theme: None,
+    };
+    let group = QString::from("preferences");
+
+    let mut settings = QSettings::from_file(file_name, QSettingsFormat::IniFormat);
+    if settings
+        .pin_mut()
+        .serialize_group(&group, &preferences)
+        .is_err()
+    {
+        return false;
+    }
+    settings.pin_mut().sync();
+
+    // Read back with a new QSettings so that the values come from the file
+    let mut settings = QSettings::from_file(file_name, QSettingsFormat::IniFormat);
+    settings.pin_mut().deserialize_group::<Preferences>(&group) == Ok(preferences)
+}
+
+fn write_qsettings(file_name: &QString) {
+    let mut settings = QSettings::from_file(file_name, QSettingsFormat::IniFormat);
+    let mut settings = settings.pin_mut();
+    settings
+        .as_mut()
+        .set_value(&QString::from("count"), &QVariant::from(&42));
+
+    settings.as_mut().begin_group(&QString::from("window"));
+    settings
+        .as_mut()
+        .set_value(&QString::from("width"), &QVariant::from(&100));
+    settings.as_mut().end_group();
+
+    settings
+        .as_mut()
+        .begin_write_array(&QString::from("recent"), 2);
+    for (i, file) in ["a.txt", "b.txt"].into_iter().enumerate() {
+        settings.as_mut().set_array_index(i as i32);
+        settings.as_mut().set_value(
+            &QString::from("file"),
+            &QVariant::from(&QString::from(file)),
+        );
+    }
+    settings.as_mut().end_array();
+    settings.sync();
+}
@@ -0,0 +1,66 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QTimer, TimerType};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[cxx::bridge]
+mod qtimer_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qtimer.h");
+        type QTimer = cxx_qt_lib::QTimer;
+    }
+
+    extern "Rust" {
+        fn construct_qtimer() -> UniquePtr<QTimer>;
+        fn read_qtimer(t: &QTimer) -> bool;
+        fn connect_qtimer_timeout(t: Pin<&mut QTimer>);
+        fn queue_qtimer_single_shot();
+        fn read_qtimer_timeout_count() -> u32;
+        fn read_qtimer_single_shot_count() -> u32;
+    }
+}
+
+static TIMEOUT_COUNT: AtomicU32 = AtomicU32::new(0);
+static SINGLE_SHOT_COUNT: AtomicU32 = AtomicU32::new(0);
+
+fn construct_qtimer() -> cxx::UniquePtr<QTimer> {
+    let mut timer = QTimer::new();
+    if let Some(mut timer) = timer.as_mut() {
+        timer.as_mut().set_interval(10);
+        timer.as_mut().set_single_shot(true);
+        timer.as_mut().set_timer_type(TimerType::PreciseTimer);
+    }
+    timer
+}
+
+fn read_qtimer(t: &QTimer) -> bool {
+    t.interval() == 10
+        && t.is_single_shot()
+        && t.timer_type() == TimerType::PreciseTimer
+        && !t.is_active()
+}
+
+fn connect_qtimer_timeout(t: Pin<&mut QTimer>) {
+    t.on_timeout(|| {
+        TIMEOUT_COUNT.fetch_add(1, Ordering::SeqCst);
+    })
+    .release();
+}
+
+fn queue_qtimer_single_shot() {
+    QTimer::single_shot(0, || {
+        SINGLE_SHOT_COUNT.fetch_add(1, Ordering::SeqCst);
+    });
+}
+
+fn read_qtimer_timeout_count() -> u32 {
+    TIMEOUT_COUNT.load(Ordering::SeqCst)
+}
+
+fn read_qtimer_single_shot_count() -> u32 {
+    SINGLE_SHOT_COUNT.load(Ordering::SeqCst)
+}
@@ -0,0 +1,42 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QPointF, QTransform, QTransformTransformationType};
+
+#[cxx::bridge]
+mod qtransform_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qtransform.h");
+        type QTransform = cxx_qt_lib::QTransform;
+    }
+
+    extern "Rust" {
+        fn construct_qtransform() -> QTransform;
+        fn read_qtransform(t: &QTransform) -> bool;
+        fn clone_qtransform(t: &QTransform) -> QTransform;
+        fn multiply_qtransform(a: &QTransform, b: &QTransform) -> QTransform;
+    }
+}
+
+fn construct_qtransform() -> QTransform {
+    let mut transform = QTransform::default();
+    transform.translate(10.0, 20.0).scale(2.0, 2.0);
+    transform
+}
+
+fn read_qtransform(t: &QTransform) -> bool {
+    t.transformation_type() == QTransformTransformationType::TxScale
+        && QPointF::new(1.0, 1.0) * t == QPointF::new(12.0, 22.0)
+        && t.inverted().is_some()
+        && QTransform::from_scale(0.0, 0.0).inverted().is_none()
+}
+
+fn clone_qtransform(t: &QTransform) -> QTransform {
+    t.clone()
+}
+
+fn multiply_qtransform(a: &QTransform, b: &QTransform) -> QTransform {
+    a.clone() * b.clone()
+}
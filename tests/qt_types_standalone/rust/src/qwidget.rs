@@ -0,0 +1,61 @@
+// SPDX-FileCopyrightText: 2023 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QBoxLayout, QBoxLayoutDirection, QRect, QSize, QString, QWidget};
+
+#[cxx::bridge]
+mod qwidget_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qwidget.h");
+        type QWidget = cxx_qt_lib::QWidget;
+    }
+
+    extern "Rust" {
+        fn construct_qwidget() -> UniquePtr<QWidget>;
+        fn construct_qwidget_with_layout() -> UniquePtr<QWidget>;
+        fn read_qwidget(widget: &QWidget) -> bool;
+    }
+}
+
+fn construct_qwidget() -> cxx::UniquePtr<QWidget> {
+    let mut widget = QWidget::new();
+    if let Some(mut widget) = widget.as_mut() {
+        widget.as_mut().set_window_title(&QString::from("kdab"));
+        widget.as_mut().set_geometry(&QRect::new(10, 20, 100, 50));
+        widget.as_mut().set_enabled(false);
+        widget.as_mut().show();
+    }
+    widget
+}
+
+fn construct_qwidget_with_layout() -> cxx::UniquePtr<QWidget> {
+    let mut widget = QWidget::new();
+    if let Some(mut widget) = widget.as_mut() {
+        let mut layout = QBoxLayout::new(QBoxLayoutDirection::TopToBottom);
+        if let Some(mut layout) = layout.as_mut() {
+            layout.as_mut().set_contents_margins(0, 0, 0, 0);
+            layout.as_mut().set_spacing(0);
+
+            let mut child = QWidget::new();
+            if let Some(child) = child.as_mut() {
+                child.set_fixed_size(&QSize::new(20, 10));
+            }
+            layout.as_mut().add_widget(child, 0);
+            layout.as_mut().add_widget(QWidget::new(), 1);
+        }
+        widget.as_mut().set_layout(layout);
+        widget.as_mut().resize(&QSize::new(20, 30));
+    }
+    widget
+}
+
+fn read_qwidget(widget: &QWidget) -> bool {
+    widget.window_title().to_string() == "test"
+        && widget.geometry() == &QRect::new(1, 2, 30, 40)
+        && widget.size() == QSize::new(30, 40)
+        && widget.is_window()
+        && !widget.is_visible()
+        && widget.parent_widget().is_null()
+}